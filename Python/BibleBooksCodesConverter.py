@@ -28,6 +28,8 @@ Module handling BibleBooksCodes.xml and to export to JSON, TSV, C, and Python da
 
 CHANGELOG:
     2025-10-02 Added TSV export (as part of getting prepared to convert to Rust)
+    2026-10-19 Added optional chapterStructure element and TSV column
+    2026-10-19 Added optional fourCharacterAbbreviation element (only used by the Rust crate for now)
 """
 from gettext import gettext as _
 import logging
@@ -46,10 +48,10 @@ from BibleOrgSys import BibleOrgSysGlobals
 from BibleOrgSys.BibleOrgSysGlobals import fnPrint, vPrint, dPrint
 
 
-LAST_MODIFIED_DATE = '2025-10-21' # by RJH
+LAST_MODIFIED_DATE = '2026-10-19' # by RJH
 SHORT_PROGRAM_NAME = "BibleBooksCodesConverter"
 PROGRAM_NAME = "Bible Books Codes converter"
-PROGRAM_VERSION = '0.96'
+PROGRAM_VERSION = '0.97'
 PROGRAM_NAME_VERSION = f'{SHORT_PROGRAM_NAME} v{PROGRAM_VERSION}'
 
 DEBUGGING_THIS_MODULE = False
@@ -86,7 +88,7 @@ class BibleBooksCodesConverter:
         self._compulsoryElements = ( 'originalLanguageCode', 'bookName', 'bookNameEnglishGuide', 'referenceAbbreviation',
                                     'referenceNumber', 'sequenceNumber',
                                     'typicalSection' )
-        self._optionalElements = ( 'expectedChapters', 'shortAbbreviation', 'SBLAbbreviation', 'OSISAbbreviation', 'SwordAbbreviation',
+        self._optionalElements = ( 'expectedChapters', 'chapterStructure', 'shortAbbreviation', 'fourCharacterAbbreviation', 'SBLAbbreviation', 'OSISAbbreviation', 'SwordAbbreviation',
                                     'CCELNumber', 'USFMAbbreviation', 'USFMNumber', 'USXNumber', 'UnboundCode',
                                     'BibleditNumber', 'LogosNumber', 'LogosAbbreviation', 'NETBibleAbbreviation', 'DrupalBibleAbbreviation',
                                     'BibleWorksAbbreviation', 'ByzantineAbbreviation',
@@ -874,7 +876,7 @@ class BibleBooksCodesConverter:
             filepath = os.path.join( folderpath, f'{self._filenameBase}_Tables.tsv' )
         vPrint( 'Quiet', DEBUGGING_THIS_MODULE, f"Exporting to {filepath}…" )
 
-        header = "originalLanguageCode\tbookName\tbookNameEnglishGuide\tBOSReferenceAbbreviation\tBOSReferenceNumber\tBOSSequenceNumber\texpectedChapters\tshortAbbreviation\tSBLAbbreviation\tOSISAbbreviation\tSwordAbbreviation\tCCELNumber\tUSFMAbbreviation\tUSFMNumber\tUSXNumber\tUnboundCode\tBibleditNumber\tLogosNumber\tLogosAbbreviation\tNETBibleAbbreviation\tDrupalBibleAbbreviation\tBibleWorksAbbreviation\tByzantineAbbreviation\tpossibleAlternativeAbbreviations\tpossibleAlternativeBooksCodes\tconsistsOfBooks\ttypicalSection\ttypicalSubsection\tallEnglishDerivedAbbreviations"
+        header = "originalLanguageCode\tbookName\tbookNameEnglishGuide\tBOSReferenceAbbreviation\tBOSReferenceNumber\tBOSSequenceNumber\texpectedChapters\tchapterStructure\tshortAbbreviation\tSBLAbbreviation\tOSISAbbreviation\tSwordAbbreviation\tCCELNumber\tUSFMAbbreviation\tUSFMNumber\tUSXNumber\tUnboundCode\tBibleditNumber\tLogosNumber\tLogosAbbreviation\tNETBibleAbbreviation\tDrupalBibleAbbreviation\tBibleWorksAbbreviation\tByzantineAbbreviation\tpossibleAlternativeAbbreviations\tpossibleAlternativeBooksCodes\tconsistsOfBooks\ttypicalSection\ttypicalSubsection\tallEnglishDerivedAbbreviations"
         numColumns = header.count( '\t' ) + 1
         vPrint( 'Quiet', DEBUGGING_THIS_MODULE, f"  Writing {numColumns} columns…" )
         with open( filepath, 'wt', encoding='utf-8' ) as myFile:
@@ -896,6 +898,7 @@ class BibleBooksCodesConverter:
                 intSequenceNumber = int( sequenceNumber )
                 # The optional elements are set to None if they don't exist
                 expectedChapters = '' if element.find('expectedChapters') is None else element.find('expectedChapters').text
+                chapterStructure = '' if element.find('chapterStructure') is None else element.find('chapterStructure').text
                 shortAbbreviation = '' if element.find('shortAbbreviation') is None else element.find('shortAbbreviation').text
                 SBLAbbreviation = '' if element.find('SBLAbbreviation') is None else element.find('SBLAbbreviation').text
                 OSISAbbreviation = '' if element.find('OSISAbbreviation') is None else element.find('OSISAbbreviation').text
@@ -933,7 +936,7 @@ class BibleBooksCodesConverter:
                     if BBB == referenceAbbreviation:
                         allDerivedAbbreviationsList.append( abbrev )
 
-                newLine = f'{originalLanguageCode}\t{bookName}\t{bookNameEnglishGuide}\t{referenceAbbreviation}\t{intID}\t{intSequenceNumber}\t{expectedChapters}\t{shortAbbreviation}\t' \
+                newLine = f'{originalLanguageCode}\t{bookName}\t{bookNameEnglishGuide}\t{referenceAbbreviation}\t{intID}\t{intSequenceNumber}\t{expectedChapters}\t{chapterStructure}\t{shortAbbreviation}\t' \
                           f'{SBLAbbreviation}\t{OSISAbbreviation}\t{SwordAbbreviation}\t{CCELNumberString}\t{USFMAbbreviation}\t{USFMNumberString}\t{USXNumberString}\t{UnboundCodeString}\t' \
                           f'{BibleditNumberString}\t{LogosNumberString}\t{LogosAbbreviation}\t{NETBibleAbbreviation}\t{DrupalBibleAbbreviation}\t{BibleWorksAbbreviation}\t' \
                           f'{ByzantineAbbreviation}\t{possibleAlternativeAbbreviations}\t{possibleAlternativeBooks}\t{consistsOfBooks}\t{typicalSection}\t{typicalSubsection}\t{",".join(sorted(allDerivedAbbreviationsList))}'
@@ -1,7 +1,7 @@
 use criterion::{Criterion, criterion_group, criterion_main};
 use std::hint::black_box;
 
-use bos_books_codes::{bos_book_code_to_usfm_abbrev, usfm_abbrev_to_bos_book_code, osis_book_code_to_bos_book_code, english_name_to_bos_book_code};
+use bos_books_codes::{bos_book_code_to_usfm_abbrev, usfm_abbrev_to_bos_book_code, english_name_to_bos_book_code};
 
 pub fn criterion_benchmark1(c: &mut Criterion) {
     c.bench_function("BBB to USFM to BBB", |b| {
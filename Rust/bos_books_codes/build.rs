@@ -1,23 +1,872 @@
-use std::process::Command;
+// Build script to create the static tables (BIBLE_BOOKS_CODES_ARRAY and the phf maps)
+//   from sourceXML/BibleBooksCodes.xml (the crate's copy of ../../sourceXML) and the TSV files (VerseCounts_KJV.tsv, PsalmTitles.tsv and VersificationMappings.tsv),
+//   writing them into OUT_DIR where they are included by src/lib.rs.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+const XML_SOURCE: &str = "sourceXML/BibleBooksCodes.xml";
+const RNC_SOURCE: &str = "sourceXML/BibleBooksCodes.rnc";
+const VERSE_COUNTS_TSV_SOURCE: &str = "VerseCounts_KJV.tsv";
+const EXPECTED_VERSE_COUNTS_TSV_HEADER: &str = "BOSReferenceAbbreviation\tverseCounts";
+const PSALM_TITLES_TSV_SOURCE: &str = "PsalmTitles.tsv";
+const EXPECTED_PSALM_TITLES_TSV_HEADER: &str = "BOSReferenceAbbreviation\tnumbering\ttitledChapters\toneVerseTitleChapters\ttwoVerseTitleChapters";
+const VERSIFICATION_MAPPINGS_TSV_SOURCE: &str = "VersificationMappings.tsv";
+const EXPECTED_VERSIFICATION_MAPPINGS_TSV_HEADER: &str = "BOSReferenceAbbreviation\tversifications\tKJVChapters\tKJVVerses\tchapter\tfirstVerse";
+const RUST_OUTPUT: &str = "static_tables.rs";
+const SUMMARY_TEXT_OUTPUT: &str = "results_summary.txt";
+
+/// Derived abbreviations which are ambiguous (or just unhelpful) so never get added
+///     (from BibleBooksCodesConverter.py)
+const BAD_ABBREVIATIONS: &[&str] = &[
+    "SONG OF", "GREEK AD", "GREEKADD", "COMBINED", "PRAYER O", "PRAYEROF", "LETTER O", "LETTEROF", "ECCLESIA", "WISDOM O", "WISDOMOF", "GREEK ES", "EPISTLE", "EPISTLEO",
+    "1 ESDRAS", "2 ESDRAS", "3 ESDRAS", "4 ESDRAS", "5 ESDRAS", "1 EZRA", "2 EZRA", "4 EZRA",
+    "1ESDRAS", "2ESDRAS", "3ESDRAS", "4ESDRAS", "5ESDRAS", "1EZRA", "2EZRA", "4EZRA",
+    "APOCALYP", "2 BARUCH", "REST OF", "MEKABIS", "MEKABISO", "PSALMS B", "PSALMSBO",
+    "ADDITION", "ADDITIONS", "ADDITIONS T", "ADDITIONST", "ADDITIONS TO", "ADDITIONSTO",
+    "AL", "ALT", "ALTE", "ALTER", "ALTERN", "ALTERNA", "ALTERNAT", "ALTERNATI", "ALTERNATIV", "ALTERNATIVE", "ALTERNATIVE P",
+    "ODES", "BEL AND", "BELANDTH", "GOSPEL O", "GOSPELOF",
+    "APOCRYPH", "TESTAMEN", "SHEPHERD", "IGNATIUS", "FRAGMENT", "APOSTROP", "PSALM 15", "PSALM151", "BOOK OF", "BOOKOFTH", "GENERIC", "UNSPECIF",
+    "JOSHUA S", "JOSHUASO",
+    "LAT", "LATI", "LATIN",
+];
+const MULTIPLE_VALUES: &str = "MultipleValues";
+/// The codes from other systems which are intentionally used for more than one of our books (in XML order),
+///     e.g., Paratext uses the same code for Ezra as for Ezra-Nehemiah
+const DECLARED_SHARED_CODES: &[(&str, &str, &[&str])] = &[
+    ("USFMAbbreviation", "Ezr", &["EZR", "EZN"]),
+    ("USFMAbbreviation", "EsG", &["ESG", "ESC"]),
+    ("USFMAbbreviation", "DaG", &["DNG", "DNA", "DNC"]),
+    ("USFMAbbreviation", "LJe", &["LJE", "LJB"]),
+    ("USFMAbbreviation", "2Ba", &["BA2", "LB2"]),
+    ("OSISAbbreviation", "Ezra", &["EZR", "EZN"]),
+    ("OSISAbbreviation", "AddEsth", &["ESA", "ESC"]),
+    ("OSISAbbreviation", "AddDan", &["DNG", "DNA", "DNC"]),
+    ("OSISAbbreviation", "EpJer", &["LJE", "LJB"]),
+    ("SwordAbbreviation", "Ezra", &["EZR", "EZN"]),
+    ("SwordAbbreviation", "AddEsth", &["ESA", "ESC"]),
+    ("SwordAbbreviation", "AddDan", &["DNA", "DNC"]),
+    ("SwordAbbreviation", "EpJer", &["LJE", "LJB"]),
+    ("SwordAbbreviation", "Herm", &["SHE", "SHM", "SHS", "SHV"]),
+];
+
+
+/// A row of a TSV file, with the fields looked up by the column header.
+struct TsvRow<'a> {
+    headers: &'a [&'a str],
+    fields: Vec<&'a str>,
+}
+
+impl<'a> TsvRow<'a> {
+    fn get(&self, header: &str) -> &'a str {
+        let column = self.headers.iter().position(|&h| h == header).unwrap_or_else(|| panic!("No {header} column"));
+        self.fields[column]
+    }
+}
+
+/// Splits the TSV text into the header line and the rows (checking the number of columns),
+///     skipping any comment lines starting with #.
+fn read_tsv<'a>(filename: &str, text: &'a str, expected_header: &str, headers: &'a mut Vec<&'a str>) -> Vec<TsvRow<'a>> {
+    let mut lines = text.trim_start_matches('\u{feff}').lines().enumerate().filter(|(_, line)| !line.starts_with('#'));
+    let header_line = lines.next().map_or("", |(_, line)| line.trim());
+    assert_eq!(header_line, expected_header, "Unexpected header line in {filename}");
+    headers.extend(header_line.split('\t'));
+    let headers: &'a [&'a str] = headers;
+    lines
+        .map(|(n, line)| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            assert_eq!(fields.len(), headers.len(), "{filename} line {} has {} columns instead of {}", n + 1, fields.len(), headers.len());
+            TsvRow { headers, fields }
+        })
+        .collect()
+}
+
+/// Parses a range like "3-9" (or a single number like "92") from one of the TSV files.
+fn parse_range(filename: &str, what: &str, text: &str) -> (u16, u16) {
+    let parse = |number: &str| number.parse::<u16>().unwrap_or_else(|e| panic!("{what} {number:?} in {filename}: {e}"));
+    let (first, last) = text.split_once('-').map_or_else(|| (parse(text), parse(text)), |(first, last)| (parse(first), parse(last)));
+    assert!(first >= 1 && first <= last, "{what} {text:?} should be a number or an increasing range in {filename}");
+    (first, last)
+}
+
+/// Parses a (possibly empty) comma-separated list of chapters and chapter ranges like "3-9,11-32,92",
+///     checking that they're in order and within the book's chapters.
+fn parse_chapter_ranges(filename: &str, what: &str, text: &str, max_chapters: u16) -> Vec<(u16, u16)> {
+    let mut chapter_ranges: Vec<(u16, u16)> = Vec::new();
+    for chapters in text.split(',').filter(|chapters| !chapters.is_empty()) {
+        let (first, last) = parse_range(filename, what, chapters);
+        assert!(
+            last <= max_chapters && chapter_ranges.last().is_none_or(|&(_, previous_last)| first > previous_last),
+            "{what} {chapters:?} should be in order (and within the {max_chapters} expected chapters) in {filename}"
+        );
+        chapter_ranges.push((first, last));
+    }
+    chapter_ranges
+}
+
+/// Formats the ranges as a Rust slice, e.g., &[(3,9),(11,32)].
+fn format_ranges(ranges: &[(u16, u16)]) -> String {
+    format!("&[{}]", ranges.iter().map(|(first, last)| format!("({first},{last})")).collect::<Vec<_>>().join(","))
+}
+
+
+/// What's allowed in the text of an element (from BibleBooksCodes.rnc).
+enum ContentRule {
+    Text { min_length: usize, max_length: usize },
+    PositiveInteger { min: u32, max: u32 },
+    Choice(Vec<String>),
+}
+
+/// One of the child elements of BibleBookCodes (in the order given in BibleBooksCodes.rnc).
+struct ElementRule {
+    name: String,
+    optional: bool,
+    content: ContentRule,
+}
+
+/// Reads the (simple) structure of the BibleBookCodes element out of the Relax NG Compact schema,
+///     i.e., lines like `element SBLAbbreviation { xsd:string { minLength="2" maxLength="8" } }?,`
+fn read_rnc_entry_rules(rnc_text: &str) -> Vec<ElementRule> {
+    let mut lines = rnc_text.lines().skip_while(|line| !line.starts_with("BibleBookCodes = element BibleBookCodes"));
+    assert!(lines.next().is_some(), "No BibleBookCodes definition in {RNC_SOURCE}");
+    let mut rules = Vec::new();
+    for line in lines {
+        let line = line.trim();
+        if line == "}" {
+            break;
+        }
+        let line = line.trim_start_matches('{').trim().trim_end_matches(',');
+        let (line, optional) = match line.strip_suffix('?') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let (name, body) = line
+            .strip_prefix("element ")
+            .and_then(|rest| rest.split_once(' '))
+            .unwrap_or_else(|| panic!("Unexpected line in {RNC_SOURCE}: {line:?}"));
+        let body = body.trim().strip_prefix('{').and_then(|body| body.strip_suffix('}')).map(str::trim)
+            .unwrap_or_else(|| panic!("Unexpected {name} element in {RNC_SOURCE}: {line:?}"));
+        let content = if body.starts_with('"') {
+            ContentRule::Choice(body.split('|').map(|choice| choice.trim().trim_matches('"').to_string()).collect())
+        } else {
+            let (data_type, facets_text) = body.split_once(' ').unwrap_or((body, ""));
+            let facets: HashMap<&str, &str> = facets_text
+                .trim()
+                .trim_start_matches('{')
+                .trim_end_matches('}')
+                .split_whitespace()
+                .map(|facet| facet.split_once('=').map(|(key, value)| (key, value.trim_matches('"'))).unwrap_or_else(|| panic!("Unexpected {name} facet {facet:?}")))
+                .collect();
+            let facet = |key: &str| facets.get(key).map(|value| value.parse::<u32>().unwrap_or_else(|e| panic!("{name} {key}={value:?}: {e}")));
+            match data_type {
+                "xsd:string" => ContentRule::Text {
+                    min_length: facet("length").or(facet("minLength")).unwrap_or(0) as usize,
+                    max_length: facet("length").or(facet("maxLength")).unwrap_or(u32::MAX) as usize,
+                },
+                "xsd:positiveInteger" => ContentRule::PositiveInteger { min: facet("minInclusive").unwrap_or(1), max: facet("maxInclusive").unwrap_or(u32::MAX) },
+                _ => panic!("Unexpected {name} type {data_type:?} in {RNC_SOURCE}"),
+            }
+        };
+        rules.push(ElementRule { name: name.to_string(), optional, content });
+    }
+    assert!(!rules.is_empty(), "No BibleBookCodes elements in {RNC_SOURCE}");
+    rules
+}
+
+/// Checks the text of an element against its rule, returning a description of any problem.
+fn check_content(rule: &ElementRule, text: &str) -> Option<String> {
+    match &rule.content {
+        ContentRule::Text { min_length, max_length } => {
+            let length = text.chars().count();
+            (length < *min_length || length > *max_length).then(|| format!("{} {text:?} should be {min_length}..={max_length} characters", rule.name))
+        }
+        ContentRule::PositiveInteger { min, max } => match text.parse::<u32>() {
+            Ok(number) if (*min..=*max).contains(&number) => None,
+            _ => Some(format!("{} {text:?} should be a number {min}..={max}", rule.name)),
+        },
+        ContentRule::Choice(choices) => (!choices.iter().any(|choice| choice == text)).then(|| format!("{} {text:?} should be one of {choices:?}", rule.name)),
+    }
+}
+
+
+/// One BibleBookCodes entry from the XML, with the fields (and their line numbers) keyed by the XML element name.
+struct BookEntry {
+    line_number: u32,
+    fields: BTreeMap<String, (u32, String)>,
+}
+
+impl BookEntry {
+    /// Returns the field, or an empty string if the optional element wasn't given.
+    fn get(&self, element_name: &str) -> &str {
+        self.fields.get(element_name).map_or("", |(_, text)| text.as_str())
+    }
+
+    /// Returns the XML line number of the field (or of the entry if the element wasn't given).
+    fn line_number(&self, element_name: &str) -> u32 {
+        self.fields.get(element_name).map_or(self.line_number, |&(line_number, _)| line_number)
+    }
+
+    /// Formats an optional text field as Rust, e.g., Some("Gen") or None.
+    fn optional_str(&self, element_name: &str) -> String {
+        match self.get(element_name) {
+            "" => "None".to_string(),
+            value => format!("Some(\"{value}\")"),
+        }
+    }
+
+    /// Formats an optional number field as Rust, e.g., Some(1) or None.
+    fn optional_number(&self, element_name: &str) -> String {
+        match self.get(element_name) {
+            "" => "None".to_string(),
+            value => format!("Some({value})"),
+        }
+    }
+}
+
+/// Loads the BibleBookCodes entries from the XML, checking each one against the rules from the schema.
+///
+/// Returns the entries along with the version and date from the header.
+fn read_xml(xml_text: &str, rules: &[ElementRule]) -> (Vec<BookEntry>, String) {
+    let options = roxmltree::ParsingOptions { allow_dtd: true, ..roxmltree::ParsingOptions::default() };
+    let document = roxmltree::Document::parse_with_options(xml_text, options).unwrap_or_else(|e| panic!("Couldn't parse {XML_SOURCE}: {e}"));
+    let line_number = |node: roxmltree::Node| document.text_pos_at(node.range().start).row;
+    let root = document.root_element();
+    assert_eq!(root.tag_name().name(), "BibleBooksCodes", "Unexpected root element in {XML_SOURCE}");
+
+    let mut children = root.children().filter(roxmltree::Node::is_element);
+    let header = children.next().filter(|node| node.tag_name().name() == "header").unwrap_or_else(|| panic!("{XML_SOURCE} should start with a header"));
+    let work_field = |element_name: &str| {
+        header
+            .descendants()
+            .find(|node| node.tag_name().name() == "work")
+            .and_then(|work| work.children().find(|node| node.tag_name().name() == element_name))
+            .and_then(|node| node.text())
+            .unwrap_or_else(|| panic!("No {element_name} in the {XML_SOURCE} header"))
+    };
+    let version = format!("version {} dated {}", work_field("version"), work_field("date"));
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for entry_node in children {
+        if entry_node.tag_name().name() != "BibleBookCodes" {
+            errors.push(format!("line {}: unexpected {} element", line_number(entry_node), entry_node.tag_name().name()));
+            continue;
+        }
+        let mut fields = BTreeMap::new();
+        let mut rules_iter = rules.iter();
+        for node in entry_node.children().filter(roxmltree::Node::is_element) {
+            let name = node.tag_name().name();
+            // Elements must be in the schema order, so skip over any (optional) rules before this one
+            let Some(rule) = rules_iter.by_ref().find(|rule| {
+                if rule.name != name && !rule.optional && !fields.contains_key(&rule.name) {
+                    errors.push(format!("line {}: missing {} element before {name}", line_number(node), rule.name));
+                }
+                rule.name == name
+            }) else {
+                errors.push(format!("line {}: unexpected or out-of-order {name} element", line_number(node)));
+                break;
+            };
+            let text = node.text().unwrap_or_default();
+            if let Some(problem) = check_content(rule, text) {
+                errors.push(format!("line {}: {problem}", line_number(node)));
+            }
+            fields.insert(name.to_string(), (line_number(node), text.to_string()));
+        }
+        for rule in rules_iter.filter(|rule| !rule.optional) {
+            errors.push(format!("line {}: missing {} element", line_number(entry_node), rule.name));
+        }
+        entries.push(BookEntry { line_number: line_number(entry_node), fields });
+    }
+    assert!(errors.is_empty(), "{XML_SOURCE} doesn't match {RNC_SOURCE}:\n  {}", errors.join("\n  "));
+    assert!(!entries.is_empty(), "No BibleBookCodes entries in {XML_SOURCE}");
+    (entries, version)
+}
+
+/// Checks the things that the schema can't, i.e., the relationships between the entries,
+///     returning a list of problems (with the XML line numbers).
+fn check_integrity(entries: &[BookEntry]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // The BOS book codes, reference numbers and sequence numbers must all be unique
+    for element_name in ["referenceAbbreviation", "referenceNumber", "sequenceNumber"] {
+        let mut first_line_numbers = HashMap::new();
+        for entry in entries {
+            let value = entry.get(element_name);
+            if let Some(first_line_number) = first_line_numbers.insert(value, entry.line_number(element_name)) {
+                errors.push(format!("line {}: {element_name} {value:?} was already used on line {first_line_number}", entry.line_number(element_name)));
+            }
+        }
+    }
+    let is_bos_book_code = |code: &str| entries.iter().any(|entry| entry.get("referenceAbbreviation") == code);
+    for entry in entries {
+        let bbb = entry.get("referenceAbbreviation");
+        let mut chars = bbb.chars();
+        if bbb.len() != 3 || !chars.next().is_some_and(|c| c.is_ascii_uppercase()) || !chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
+            errors.push(format!("line {}: BOS book code {bbb:?} should be three uppercase characters starting with a letter", entry.line_number("referenceAbbreviation")));
+        }
+
+        // Books can only be made of (or confused with) books that we know about
+        for element_name in ["consistsOfBooks", "possibleAlternativeBooks"] {
+            for code in entry.get(element_name).split(',').filter(|code| !code.is_empty()) {
+                if !is_bos_book_code(code) {
+                    errors.push(format!("line {}: {bbb} {element_name} contains unknown BOS book code {code:?}", entry.line_number(element_name)));
+                }
+            }
+        }
+
+        // e.g., 1Sam for SA1 (the number must match and the letters must start with the code's first letter)
+        let four_character_abbreviation = entry.get("fourCharacterAbbreviation");
+        if !four_character_abbreviation.is_empty() {
+            let number = bbb.trim_start_matches(|c: char| c.is_ascii_uppercase());
+            let letters = four_character_abbreviation.strip_prefix(number).unwrap_or_default();
+            if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) || !letters.starts_with(&bbb[..1]) {
+                errors.push(format!(
+                    "line {}: {bbb} fourCharacterAbbreviation {four_character_abbreviation:?} should be {number:?} followed by letters starting with {:?}",
+                    entry.line_number("fourCharacterAbbreviation"),
+                    &bbb[..1]
+                ));
+            }
+        }
+
+        let expected_chapters = entry.get("expectedChapters");
+        if !expected_chapters.is_empty() {
+            let numbers: Vec<&str> = expected_chapters.split(',').collect();
+            if numbers.len() > 2 || numbers.iter().any(|number| number.parse::<u16>().is_err()) {
+                errors.push(format!("line {}: {bbb} expectedChapters {expected_chapters:?} should be one number or two comma-separated numbers", entry.line_number("expectedChapters")));
+            }
+        }
+    }
+
+    // Codes from other systems can only be shared between books if that's been declared
+    for element_name in ["USFMAbbreviation", "OSISAbbreviation", "SwordAbbreviation"] {
+        let mut books_by_code: BTreeMap<&str, Vec<&BookEntry>> = BTreeMap::new();
+        for entry in entries.iter().filter(|entry| !entry.get(element_name).is_empty()) {
+            books_by_code.entry(entry.get(element_name)).or_default().push(entry);
+        }
+        let declared = |code: &str| DECLARED_SHARED_CODES.iter().find(|&&(name, c, _)| name == element_name && c == code);
+        for (code, books) in books_by_code {
+            let bbbs: Vec<&str> = books.iter().map(|entry| entry.get("referenceAbbreviation")).collect();
+            match declared(code) {
+                Some((_, _, declared_bbbs)) if *declared_bbbs == bbbs.as_slice() => {}
+                Some((_, _, declared_bbbs)) => errors.push(format!(
+                    "line {}: {element_name} {code:?} is used by {bbbs:?} but is declared as shared by {declared_bbbs:?}",
+                    books[0].line_number(element_name)
+                )),
+                None if bbbs.len() > 1 => errors.push(format!(
+                    "line {}: {element_name} {code:?} is used by {bbbs:?} (on lines {}) -- add it to DECLARED_SHARED_CODES in build.rs if that's intended",
+                    books[0].line_number(element_name),
+                    books.iter().map(|entry| entry.line_number(element_name).to_string()).collect::<Vec<_>>().join(",")
+                )),
+                None => {}
+            }
+        }
+    }
+    errors
+}
+
+
+/// A string map which remembers the order that the keys were first inserted (like a Python dict).
+#[derive(Default)]
+struct InsertionOrderedMap {
+    keys: Vec<String>,
+    values: HashMap<String, String>,
+}
+
+impl InsertionOrderedMap {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    fn insert(&mut self, key: &str, value: &str) {
+        if self.values.insert(key.to_string(), value.to_string()).is_none() {
+            self.keys.push(key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if self.values.remove(key).is_some() {
+            self.keys.retain(|k| k != key);
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.keys.iter().map(|key| (key.as_str(), self.values[key].as_str()))
+    }
+}
+
+/// Works out the (uppercase) English abbreviations and names which unambiguously identify each book,
+///     returning a sorted list for each BOS book code.
+/// NOTE: This follows importDataToPythonIfNecessary() in BibleBooksCodesConverter.py
+///     so that we get the same allEnglishDerivedAbbreviations as the BibleOrgSys tables.
+fn derive_all_english_abbreviations(entries: &[BookEntry]) -> HashMap<String, Vec<String>> {
+    // Start with the abbreviations from the various systems (marking any that are used for more than one book)
+    let mut initial = InsertionOrderedMap::default();
+    let mut english_names = InsertionOrderedMap::default();
+    let mut possible_alternative_abbreviations = InsertionOrderedMap::default();
+    for entry in entries {
+        let bbb = entry.get("referenceAbbreviation");
+        for element_name in ["shortAbbreviation", "SBLAbbreviation", "OSISAbbreviation", "SwordAbbreviation", "USFMAbbreviation",
+                             "LogosAbbreviation", "NETBibleAbbreviation", "DrupalBibleAbbreviation", "BibleWorksAbbreviation", "ByzantineAbbreviation"] {
+            let abbreviation = entry.get(element_name).to_uppercase();
+            if abbreviation.is_empty() {
+                continue;
+            }
+            let value = match initial.get(&abbreviation) {
+                Some(existing) if existing != bbb => MULTIPLE_VALUES,
+                _ => bbb,
+            };
+            initial.insert(&abbreviation, value);
+        }
+        let english_name = entry.get("bookNameEnglishGuide").to_uppercase();
+        if !english_names.contains_key(&english_name) {
+            english_names.insert(&english_name, bbb);
+        }
+        for abbreviation in entry.get("possibleAlternativeAbbreviations").split(',').filter(|abbreviation| !abbreviation.is_empty()) {
+            assert_eq!(abbreviation.to_uppercase(), abbreviation, "{bbb} possible alternative abbreviation should be UPPERCASE");
+            possible_alternative_abbreviations.insert(abbreviation, bbb);
+        }
+    }
+
+    // Ensure that all BOS book codes are included
+    let is_bos_book_code = |abbreviation: &str| entries.iter().any(|entry| entry.get("referenceAbbreviation") == abbreviation);
+    for entry in entries {
+        let bbb = entry.get("referenceAbbreviation");
+        match initial.get(bbb) {
+            Some(existing) if existing != MULTIPLE_VALUES => assert_eq!(existing, bbb, "{bbb} is another book's abbreviation"),
+            _ => initial.insert(bbb, bbb),
+        }
+    }
+
+    // Add the possible alternative abbreviations (also without spaces) and then remove bad entries
+    for (abbreviation, bbb) in possible_alternative_abbreviations.iter() {
+        for abbreviation in [abbreviation.to_string(), abbreviation.replace(' ', "")] {
+            if !is_bos_book_code(&abbreviation) && !BAD_ABBREVIATIONS.contains(&abbreviation.as_str()) {
+                initial.insert(&abbreviation, bbb);
+            }
+        }
+    }
+    for bad_abbreviation in BAD_ABBREVIATIONS {
+        initial.remove(bad_abbreviation);
+    }
+
+    // Add the English book names (also without spaces and parentheses) if they're not ambiguous
+    for (names, bbb) in english_names.iter() {
+        for name in names.split(" / ") {
+            let shortened_name = name.replace([' ', '(', ')'], "");
+            for name in [name, shortened_name.as_str()] {
+                if !BAD_ABBREVIATIONS.contains(&name) && !initial.contains_key(name) && !is_bos_book_code(name) {
+                    initial.insert(name, bbb);
+                }
+            }
+        }
+    }
+
+    // Now remove the ambiguous entries (except for a couple where we pick the most common book)
+    let mut adjusted = HashMap::new();
+    for (abbreviation, bbb) in initial.iter() {
+        let bbb = match (abbreviation, bbb) {
+            ("LJE" | "EP JER" | "EPJER", MULTIPLE_VALUES) => "LJE",
+            ("EZR", MULTIPLE_VALUES) => "EZR",
+            (_, MULTIPLE_VALUES) => continue,
+            _ => bbb,
+        };
+        adjusted.insert(abbreviation.to_string(), bbb);
+    }
+
+    // Now add any unambiguous shortened entries (by dropping letters off the end)
+    let mut to_be_removed = HashSet::new();
+    for (abbreviation, bbb) in initial.iter().filter(|&(_, bbb)| bbb != MULTIPLE_VALUES) {
+        let mut shortened_abbreviation = abbreviation.to_string();
+        loop {
+            shortened_abbreviation.pop();
+            shortened_abbreviation.truncate(shortened_abbreviation.trim_end().len());
+            if shortened_abbreviation.matches('(').count() != shortened_abbreviation.matches(')').count() {
+                shortened_abbreviation = shortened_abbreviation.replace('(', "").trim_end().to_string();
+            }
+            if shortened_abbreviation.is_empty() {
+                break;
+            }
+            if to_be_removed.contains(&shortened_abbreviation) {
+                continue;
+            }
+            match adjusted.get(shortened_abbreviation.as_str()) {
+                Some(&existing) if existing != bbb && !initial.contains_key(&shortened_abbreviation) => {
+                    // It's one that we just added, so it's ambiguous
+                    to_be_removed.insert(shortened_abbreviation.clone());
+                }
+                Some(_) => {}
+                None if !BAD_ABBREVIATIONS.contains(&shortened_abbreviation.as_str()) => {
+                    adjusted.insert(shortened_abbreviation.clone(), bbb);
+                }
+                None => {}
+            }
+        }
+    }
+    for abbreviation in &to_be_removed {
+        adjusted.remove(abbreviation);
+    }
+
+    let mut all_english_abbreviations: HashMap<String, Vec<String>> = HashMap::new();
+    for (abbreviation, bbb) in adjusted {
+        all_english_abbreviations.entry(bbb.to_string()).or_default().push(abbreviation);
+    }
+    for entry in entries {
+        let bbb = entry.get("referenceAbbreviation");
+        let abbreviations = all_english_abbreviations.get_mut(bbb).unwrap_or_else(|| panic!("No English abbreviations for {bbb}"));
+        assert!(abbreviations.iter().any(|abbreviation| abbreviation == bbb), "{bbb} isn't one of its own abbreviations");
+        abbreviations.sort();
+    }
+    all_english_abbreviations
+}
+
+/// NOTE: This must match normalise_original_language_name() in src/lib.rs
+fn normalise_original_language_name(name: &str) -> String {
+    let unpointed_name: String = name.nfd().filter(|&c| !is_combining_mark(c)).collect();
+    unpointed_name.to_lowercase().replace('ς', "σ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
 
-// Script to build the src/lib.rs file.
 fn main() {
-    // Tell Cargo that if the given file changes, to rerun this build script.
-    // NOTE: none of these println outputs usually appear in the displayed build outputs.
-    //  Change println! to panic! if you really want to see if it's running
-    println!("cargo::rerun-if-changed=build_static_tables.py");
-    println!("cargo::rerun-if-changed=lib.src.rs");
-    println!("cargo::rerun-if-changed=BibleBooksCodes_Tables.tsv");
-
-    // Run the python command to build the file src/include.rs
-    let output = Command::new("python3")
-        .arg("build_static_tables.py")
-        .output();
-        // .expect("Failed to execute command"); 
-
-        println!("{:?}", output);
-    // assert_eq!(b"SUCCESSFUL!\n", output.unwrap().stdout.as_slice());
-    if !output.unwrap().stdout.contains( &36 ) { // $ Ensure we find out if the Python script failed
-        panic!("Python build script failed!!!")
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed={XML_SOURCE}");
+    println!("cargo::rerun-if-changed={RNC_SOURCE}");
+    println!("cargo::rerun-if-changed={VERSE_COUNTS_TSV_SOURCE}");
+    println!("cargo::rerun-if-changed={PSALM_TITLES_TSV_SOURCE}");
+    println!("cargo::rerun-if-changed={VERSIFICATION_MAPPINGS_TSV_SOURCE}");
+
+    let rnc_text = fs::read_to_string(RNC_SOURCE).unwrap_or_else(|e| panic!("Couldn't read {RNC_SOURCE}: {e}"));
+    let rules = read_rnc_entry_rules(&rnc_text);
+    let xml_text = fs::read_to_string(XML_SOURCE).unwrap_or_else(|e| panic!("Couldn't read {XML_SOURCE}: {e}"));
+    let (entries, version) = read_xml(&xml_text, &rules);
+    let errors = check_integrity(&entries);
+    assert!(errors.is_empty(), "{XML_SOURCE} failed the integrity checks:\n  {}", errors.join("\n  "));
+    let all_english_abbreviations = derive_all_english_abbreviations(&entries);
+    let mut summary_text = format!("bos_books_codes build.rs\nLoaded {} BibleBookCodes entries from BibleBooksCodes.xml ({version}).", entries.len());
+
+    // Go through the entries making the array entries and the (array index) entries for the maps
+    let mut full_array_entries = Vec::new();
+    let mut ref_abbrev_entries: Vec<&str> = Vec::new();
+    let mut english_name_entries = Vec::new();
+    // For these ones, there may be duplicates, so we always take the first one
+    let mut usfm_dict_entries: Vec<(&str, usize)> = Vec::new();
+    let mut osis_dict_entries: Vec<(&str, usize)> = Vec::new();
+    let mut original_language_name_dict_entries: Vec<(String, usize)> = Vec::new();
+    let mut four_character_entries = Vec::new();
+    for (n, row) in entries.iter().enumerate() {
+        let expected_chapters = row.get("expectedChapters");
+        let expected_num_chapters = match expected_chapters {
+            "" => "OptionalNumberOrTwoNumbers::None".to_string(),
+            _ if expected_chapters.contains(',') => format!("OptionalNumberOrTwoNumbers::TwoNumbers([{expected_chapters}])"),
+            _ => format!("OptionalNumberOrTwoNumbers::Number({expected_chapters})"),
+        };
+        let chapter_structure = match row.get("chapterStructure") {
+            "units" => "Some(ChapterStructure::Units)",
+            "continuous" => "Some(ChapterStructure::Continuous)",
+            "" if expected_chapters.is_empty() => "None",
+            "" => "Some(ChapterStructure::Continuous)", // The default for books with chapters
+            other => panic!("Unexpected chapterStructure {other:?}"),
+        };
+        let quoted_list = |element_name: &str| {
+            let values = row.get(element_name).split(',').filter(|value| !value.is_empty());
+            values.map(|value| format!("\"{value}\"")).collect::<Vec<_>>().join(",")
+        };
+
+        let bos_book_code = row.get("referenceAbbreviation");
+        let all_english_derived_abbreviations = all_english_abbreviations[bos_book_code].join(",");
+        full_array_entries.push(format!(
+            r#"    BibleBooksCodesArrayEntry {{
+        original_language_code: "{}",
+        original_language_book_name: "{}",
+        book_name_English_guide: "{}",
+        BOS_book_code: "{}",
+        BOS_reference_number: {},
+        BOS_sequence_number: {},
+        expected_num_chapters: {},
+        chapter_structure: {},
+        short_abbreviation: {},
+        SBL_abbreviation: {},
+        OSIS_abbreviation: {},
+        Sword_abbreviation: {},
+        CCEL_number: {},
+        USFM_abbreviation: {},
+        USFM_number_str: {},
+        USX_number_str: {},
+        Unbound_Code: {},
+        Bibledit_number: {},
+        Logos_number: {},
+        Logos_abbreviation: {},
+        NET_Bible_abbreviation: {},
+        Drupal_Bible_abbreviation: {},
+        Bible_Works_abbreviation: {},
+        Byzantine_abbreviation: {},
+        possible_alternative_abbreviations: &[{}],
+        possible_alternative_books_codes: &[{}],
+        consists_of_books_codes: {},
+        typical_section: {},
+        typical_subsection: {},
+        all_English_derived_abbreviations: "{}",
+    }},"#,
+            row.get("originalLanguageCode"),
+            row.get("bookName"),
+            row.get("bookNameEnglishGuide"),
+            bos_book_code,
+            row.get("referenceNumber").parse::<u16>().expect("Checked against the schema"),
+            row.get("sequenceNumber").parse::<u16>().expect("Checked against the schema"),
+            expected_num_chapters,
+            chapter_structure,
+            row.optional_str("shortAbbreviation"),
+            row.optional_str("SBLAbbreviation"),
+            row.optional_str("OSISAbbreviation"),
+            row.optional_str("SwordAbbreviation"),
+            row.optional_number("CCELNumber"),
+            row.optional_str("USFMAbbreviation"),
+            row.optional_str("USFMNumber"),
+            row.optional_str("USXNumber"),
+            row.optional_str("UnboundCode"),
+            row.optional_number("BibleditNumber"),
+            row.optional_number("LogosNumber"),
+            row.optional_str("LogosAbbreviation"),
+            row.optional_str("NETBibleAbbreviation"),
+            row.optional_str("DrupalBibleAbbreviation"),
+            row.optional_str("BibleWorksAbbreviation"),
+            row.optional_str("ByzantineAbbreviation"),
+            quoted_list("possibleAlternativeAbbreviations"),
+            quoted_list("possibleAlternativeBooks"),
+            row.optional_str("consistsOfBooks"),
+            row.optional_str("typicalSection"),
+            row.optional_str("typicalSubsection"),
+            all_english_derived_abbreviations,
+        ));
+
+        ref_abbrev_entries.push(bos_book_code);
+        let four_character_abbreviation = row.get("fourCharacterAbbreviation");
+        if !four_character_abbreviation.is_empty() {
+            let number = bos_book_code.trim_start_matches(|c: char| c.is_ascii_uppercase());
+            let letters = four_character_abbreviation.strip_prefix(number).expect("Checked by check_integrity()");
+            four_character_entries.push(format!("\"{bos_book_code}\"=>\"{letters}\""));
+        }
+        for english_name in &all_english_abbreviations[bos_book_code] {
+            english_name_entries.push(format!("\"{english_name}\"=>{n}"));
+        }
+        let usfm_abbreviation = row.get("USFMAbbreviation");
+        if !usfm_abbreviation.is_empty() && !usfm_dict_entries.iter().any(|&(k, _)| k == usfm_abbreviation) {
+            usfm_dict_entries.push((usfm_abbreviation, n));
+        }
+        let osis_abbreviation = row.get("OSISAbbreviation");
+        if !osis_abbreviation.is_empty() && !osis_dict_entries.iter().any(|&(k, _)| k == osis_abbreviation) {
+            osis_dict_entries.push((osis_abbreviation, n));
+        }
+        if !["(None)", "(Unknown)"].contains(&row.get("originalLanguageCode")) && !row.get("bookName").starts_with('(') {
+            let normalised_name = normalise_original_language_name(row.get("bookName"));
+            if !original_language_name_dict_entries.iter().any(|(k, _)| *k == normalised_name) {
+                // e.g., ESC has the same Hebrew name as EST
+                original_language_name_dict_entries.push((normalised_name, n));
+            }
+        }
+    }
+
+    // Now the verse counts for each chapter (only used if the verse-counts feature is enabled)
+    let verse_counts_tsv_text =
+        fs::read_to_string(VERSE_COUNTS_TSV_SOURCE).unwrap_or_else(|e| panic!("Couldn't read {VERSE_COUNTS_TSV_SOURCE}: {e}"));
+    let mut verse_counts_tsv_headers = Vec::new();
+    let mut verse_counts_entries = Vec::new();
+    let mut kjv_verse_counts = HashMap::new();
+    for verse_counts_row in read_tsv(VERSE_COUNTS_TSV_SOURCE, &verse_counts_tsv_text, EXPECTED_VERSE_COUNTS_TSV_HEADER, &mut verse_counts_tsv_headers) {
+        let bbb = verse_counts_row.get("BOSReferenceAbbreviation");
+        let verse_counts: Vec<u16> = verse_counts_row
+            .get("verseCounts")
+            .split(',')
+            .map(|verse_count| verse_count.parse().unwrap_or_else(|e| panic!("{bbb} verse count {verse_count:?}: {e}")))
+            .collect();
+        let row = entries
+            .iter()
+            .find(|row| row.get("referenceAbbreviation") == bbb)
+            .unwrap_or_else(|| panic!("Unknown book code {bbb} in {VERSE_COUNTS_TSV_SOURCE}"));
+        let expected_chapters = row.get("expectedChapters");
+        assert!(
+            expected_chapters.split(',').any(|num_chapters| num_chapters == verse_counts.len().to_string()),
+            "{bbb} has {} chapters in {VERSE_COUNTS_TSV_SOURCE} but expectedChapters={expected_chapters:?}",
+            verse_counts.len()
+        );
+        assert!(verse_counts.iter().all(|&verse_count| verse_count > 0), "{bbb} verse_counts={verse_counts:?}");
+        let verse_counts_text: Vec<String> = verse_counts.iter().map(u16::to_string).collect();
+        verse_counts_entries.push(format!("\"{bbb}\"=>&[{}]", verse_counts_text.join(",")));
+        assert!(kjv_verse_counts.insert(bbb, verse_counts).is_none(), "Duplicate book code {bbb} in {VERSE_COUNTS_TSV_SOURCE}");
+    }
+
+    // Now the titled Psalms for each book and numbering
+    let psalm_titles_tsv_text =
+        fs::read_to_string(PSALM_TITLES_TSV_SOURCE).unwrap_or_else(|e| panic!("Couldn't read {PSALM_TITLES_TSV_SOURCE}: {e}"));
+    let mut psalm_titles_tsv_headers = Vec::new();
+    let mut psalm_title_entries = Vec::new();
+    let mut psalm_title_books = HashSet::new();
+    for psalm_titles_row in read_tsv(PSALM_TITLES_TSV_SOURCE, &psalm_titles_tsv_text, EXPECTED_PSALM_TITLES_TSV_HEADER, &mut psalm_titles_tsv_headers) {
+        let bbb = psalm_titles_row.get("BOSReferenceAbbreviation");
+        let numbering = psalm_titles_row.get("numbering");
+        assert!(["Hebrew", "Greek"].contains(&numbering), "{bbb} has unexpected numbering {numbering:?} in {PSALM_TITLES_TSV_SOURCE}");
+        assert!(psalm_title_books.insert((bbb, numbering)), "Duplicate {bbb} {numbering} in {PSALM_TITLES_TSV_SOURCE}");
+        let row = entries
+            .iter()
+            .find(|row| row.get("referenceAbbreviation") == bbb)
+            .unwrap_or_else(|| panic!("Unknown book code {bbb} in {PSALM_TITLES_TSV_SOURCE}"));
+        let max_chapters: u16 = row.get("expectedChapters").split(',').filter_map(|num_chapters| num_chapters.parse().ok()).max().unwrap_or(0);
+        let chapter_ranges = |header: &str| {
+            parse_chapter_ranges(PSALM_TITLES_TSV_SOURCE, &format!("{bbb} {numbering} {header}"), psalm_titles_row.get(header), max_chapters)
+        };
+        let titled_chapters = chapter_ranges("titledChapters");
+        let one_verse_title_chapters = chapter_ranges("oneVerseTitleChapters");
+        let two_verse_title_chapters = chapter_ranges("twoVerseTitleChapters");
+        let contains = |chapter_ranges: &[(u16, u16)], chapter| chapter_ranges.iter().any(|&(first, last)| (first..=last).contains(&chapter));
+        for chapter in 1..=max_chapters {
+            let is_numbered = contains(&one_verse_title_chapters, chapter) || contains(&two_verse_title_chapters, chapter);
+            assert!(
+                !is_numbered || (contains(&titled_chapters, chapter) && !(contains(&one_verse_title_chapters, chapter) && contains(&two_verse_title_chapters, chapter))),
+                "{bbb} {numbering} chapter {chapter} has a numbered title so should be titled (with either one or two title verses) in {PSALM_TITLES_TSV_SOURCE}"
+            );
+        }
+        psalm_title_entries.push(format!(
+            "psalm_titles::PsalmTitleEntry {{ bos_book_code: \"{bbb}\", numbering: PsalmNumbering::{numbering}, titled_chapters: {}, one_verse_title_chapters: {}, two_verse_title_chapters: {} }}",
+            format_ranges(&titled_chapters),
+            format_ranges(&one_verse_title_chapters),
+            format_ranges(&two_verse_title_chapters),
+        ));
     }
+
+    // Now where the KJV chapters and verses are found in the other versifications
+    let versification_mappings_tsv_text =
+        fs::read_to_string(VERSIFICATION_MAPPINGS_TSV_SOURCE).unwrap_or_else(|e| panic!("Couldn't read {VERSIFICATION_MAPPINGS_TSV_SOURCE}: {e}"));
+    let mut versification_mappings_tsv_headers = Vec::new();
+    let mut versification_mapping_entries = Vec::new();
+    for mapping_row in read_tsv(
+        VERSIFICATION_MAPPINGS_TSV_SOURCE,
+        &versification_mappings_tsv_text,
+        EXPECTED_VERSIFICATION_MAPPINGS_TSV_HEADER,
+        &mut versification_mappings_tsv_headers,
+    ) {
+        let bbb = mapping_row.get("BOSReferenceAbbreviation");
+        assert!(entries.iter().any(|row| row.get("referenceAbbreviation") == bbb), "Unknown book code {bbb} in {VERSIFICATION_MAPPINGS_TSV_SOURCE}");
+        let versifications: Vec<&str> = mapping_row
+            .get("versifications")
+            .split(',')
+            .map(|name| match name {
+                "Original" => "Versification::Original",
+                "LXX" => "Versification::Lxx",
+                "Vulgate" => "Versification::Vulgate",
+                _ => panic!("{bbb} has unexpected versification {name:?} in {VERSIFICATION_MAPPINGS_TSV_SOURCE}"),
+            })
+            .collect();
+        let (first_kjv_chapter, last_kjv_chapter) = parse_range(VERSIFICATION_MAPPINGS_TSV_SOURCE, &format!("{bbb} KJVChapters"), mapping_row.get("KJVChapters"));
+        let chapter = parse_range(VERSIFICATION_MAPPINGS_TSV_SOURCE, &format!("{bbb} chapter"), mapping_row.get("chapter")).0;
+        // The KJV chapters and verses must be in the KJV (if we have its verse counts for the book)
+        let verse_counts = kjv_verse_counts.get(bbb);
+        assert!(
+            verse_counts.is_none_or(|verse_counts| usize::from(last_kjv_chapter) <= verse_counts.len()),
+            "{bbb} KJV chapter {last_kjv_chapter} isn't in {VERSE_COUNTS_TSV_SOURCE} (in {VERSIFICATION_MAPPINGS_TSV_SOURCE})"
+        );
+        let (kjv_verses, first_verse) = match (mapping_row.get("KJVVerses"), mapping_row.get("firstVerse")) {
+            ("", "") => ("None".to_string(), 0),
+            (kjv_verses, first_verse) => {
+                assert_eq!(first_kjv_chapter, last_kjv_chapter, "{bbb} KJVVerses should only be given for one KJV chapter in {VERSIFICATION_MAPPINGS_TSV_SOURCE}");
+                let (first, last) = parse_range(VERSIFICATION_MAPPINGS_TSV_SOURCE, &format!("{bbb} {first_kjv_chapter} KJVVerses"), kjv_verses);
+                assert!(
+                    verse_counts.is_none_or(|verse_counts| last <= verse_counts[usize::from(first_kjv_chapter) - 1]),
+                    "{bbb} KJV verse {first_kjv_chapter}:{last} isn't in {VERSE_COUNTS_TSV_SOURCE} (in {VERSIFICATION_MAPPINGS_TSV_SOURCE})"
+                );
+                (format!("Some(({first},{last}))"), parse_range(VERSIFICATION_MAPPINGS_TSV_SOURCE, &format!("{bbb} firstVerse"), first_verse).0)
+            }
+        };
+        versification_mapping_entries.push(format!(
+            "versification::VersificationMapping {{ bos_book_code: \"{bbb}\", versifications: &[{}], kjv_chapters: ({first_kjv_chapter},{last_kjv_chapter}), kjv_verses: {kjv_verses}, chapter: {chapter}, first_verse: {first_verse} }}",
+            versifications.join(","),
+        ));
+    }
+
+    let map_entries = |entries: &[(&str, usize)], uppercase: bool| {
+        entries
+            .iter()
+            .map(|&(k, n)| format!("\"{}\"=>{n},", if uppercase { k.to_uppercase() } else { k.to_string() }))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut rust_text = format!(
+        "// THESE VARIOUS STATIC ARRAYS AND HASHMAPS WERE CREATED AUTOMATICALLY BY build.rs
+//   from the data in sourceXML/BibleBooksCodes.xml ({version}), {VERSE_COUNTS_TSV_SOURCE}, {PSALM_TITLES_TSV_SOURCE} and {VERSIFICATION_MAPPINGS_TSV_SOURCE}
+
+pub static BIBLE_BOOKS_CODES_ARRAY: [BibleBooksCodesArrayEntry; {}] = [
+{}
+];
+",
+        full_array_entries.len(),
+        full_array_entries.join("\n")
+    );
+    let _ = write!(
+        rust_text,
+        "
+// NOTE: The following perfect_hash_function maps contain the array index of the entry in the above BIBLE_BOOKS_CODES_ARRAY
+static REFERENCE_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! {{ {} }};
+static USFM_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! {{ {} }};
+static UPPERCASE_USFM_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! {{ {} }};
+static OSIS_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! {{ {} }};
+static ENGLISH_NAME_MAP: phf::Map<&'static str, usize> = phf_map! {{ {} }};
+// NOTE: The keys of this map have had their vowel points, accents, etc. removed by normalise_original_language_name()
+static ORIGINAL_LANGUAGE_NAME_MAP: phf::Map<&'static str, usize> = phf_map! {{ {} }};
+// NOTE: This map contains the letters of the fourCharacterAbbreviation (without any leading number), e.g., \"Sam\" for SA1
+static FOUR_CHARACTER_ABBREVIATION_MAP: phf::Map<&'static str, &'static str> = phf_map! {{ {} }};
+// NOTE: This map contains the number of verses in each chapter (in the KJV versification) from {VERSE_COUNTS_TSV_SOURCE}
+#[cfg(feature = \"verse-counts\")]
+static VERSE_COUNTS_MAP: phf::Map<&'static str, &'static [u16]> = phf_map! {{ {} }};
+// NOTE: This table contains the titled Psalms (as inclusive chapter ranges) for each book and numbering from {PSALM_TITLES_TSV_SOURCE}
+static PSALM_TITLE_TABLE: &[psalm_titles::PsalmTitleEntry] = &[{}];
+// NOTE: This table contains where the KJV chapters (or verses) are found in the other versifications from {VERSIFICATION_MAPPINGS_TSV_SOURCE}
+static VERSIFICATION_MAPPINGS: &[versification::VersificationMapping] = &[{}];
+",
+        ref_abbrev_entries.iter().enumerate().map(|(i, v)| format!("\"{v}\"=>{i}")).collect::<Vec<_>>().join(","),
+        map_entries(&usfm_dict_entries, false),
+        map_entries(&usfm_dict_entries, true),
+        map_entries(&osis_dict_entries, false),
+        english_name_entries.join(", "),
+        original_language_name_dict_entries.iter().map(|(k, n)| format!("\"{k}\"=>{n},")).collect::<Vec<_>>().join(" "),
+        four_character_entries.join(", "),
+        verse_counts_entries.join(", "),
+        psalm_title_entries.join(", "),
+        versification_mapping_entries.join(", "),
+    );
+
+    let _ = write!(
+        summary_text,
+        "\nWrote {} full array entries to BIBLE_BOOKS_CODES_ARRAY\
+         \nWrote {} entries to REFERENCE_ABBREVIATION_MAP\
+         \nWrote {} entries to USFM_ABBREVIATION_MAP and UPPERCASE_USFM_ABBREVIATION_MAP\
+         \nWrote {} entries to OSIS_ABBREVIATION_MAP\
+         \nWrote {} entries to ENGLISH_NAME_MAP\
+         \nWrote {} entries to ORIGINAL_LANGUAGE_NAME_MAP\
+         \nWrote {} entries to FOUR_CHARACTER_ABBREVIATION_MAP\
+         \nWrote {} entries to VERSE_COUNTS_MAP\
+         \nWrote {} entries to PSALM_TITLE_TABLE\
+         \nWrote {} entries to VERSIFICATION_MAPPINGS\n",
+        full_array_entries.len(),
+        ref_abbrev_entries.len(),
+        usfm_dict_entries.len(),
+        osis_dict_entries.len(),
+        english_name_entries.len(),
+        original_language_name_dict_entries.len(),
+        four_character_entries.len(),
+        verse_counts_entries.len(),
+        psalm_title_entries.len(),
+        versification_mapping_entries.len(),
+    );
+
+    // Write a summary alongside the output, so we have a build record that we can refer back to
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join(RUST_OUTPUT), rust_text).expect("Couldn't write the static tables");
+    fs::write(Path::new(&out_dir).join(SUMMARY_TEXT_OUTPUT), summary_text).expect("Couldn't write the summary");
 }
@@ -0,0 +1,251 @@
+// Sortable integer keys for book/chapter/verse references
+//   (a port of BCVReferenceToInt and sortBCVReferences from the Python package).
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{BookCode, BIBLE_BOOKS_CODES_ARRAY};
+
+
+/// A book/chapter/verse reference packed into a u64 so that the numeric order is the canonical order.
+///
+/// The layout (from most to least significant bits) is:
+///     bits 48-63: BOS_sequence_number of the book
+///     bits 32-47: chapter (0 can be used for a book introduction)
+///     bits 16-31: verse (0 can be used for a chapter introduction)
+///     bits 8-15:  number of extra verses in a verse span, e.g., 1 for "3-4", 0 for a single verse
+///     bits 0-7:   segment letter: 0 for none, 1 for 'a', 2 for 'b', ... 26 for 'z'
+///
+/// So "3" sorts before "3a" which sorts before "3b" which sorts before "3-4" which sorts before "4".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BcvKey(u64);
+
+#[derive(Debug, PartialEq)]
+pub enum BcvKeyError<'a> {
+    UnknownBook(&'a str),
+    InvalidChapter(&'a str),
+    InvalidVerse(&'a str),
+    InvalidValue(u64),
+}
+
+impl fmt::Display for BcvKeyError<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BcvKeyError::UnknownBook(k) => write!(f, "BOS book code '{}' not found", k),
+            BcvKeyError::InvalidChapter(k) => write!(f, "invalid chapter '{}'", k),
+            BcvKeyError::InvalidVerse(k) => write!(f, "invalid verse '{}' (expected something like '3', '3a' or '3-4')", k),
+            BcvKeyError::InvalidValue(k) => write!(f, "{} is not a valid BCV key value", k),
+        }
+    }
+}
+
+impl Error for BcvKeyError<'_> {}
+
+
+impl BcvKey {
+    #[inline]
+    pub fn new(book: BookCode, chapter: u16, verse: u16) -> Self {
+        BcvKey::from_fields(book.entry().BOS_sequence_number, chapter, verse, 0, 0)
+    }
+
+    #[inline]
+    fn from_fields(sequence_number: u16, chapter: u16, verse: u16, segment: u8, span_length: u8) -> Self {
+        BcvKey((sequence_number as u64) << 48 | (chapter as u64) << 32 | (verse as u64) << 16 | (span_length as u64) << 8 | segment as u64)
+    }
+
+    /// Makes a key from a BOS book code and chapter and verse strings (like the Python BCV tuples).
+    /// The verse string may have a segment letter and/or be a span, e.g., "3", "3a", "3-4" or "3b-5".
+    pub fn from_bcv<'a>(bos_book_code: &'a str, chapter: &'a str, verse: &'a str) -> Result<Self, BcvKeyError<'a>> {
+        let book = BookCode::new(bos_book_code).ok_or(BcvKeyError::UnknownBook(bos_book_code))?;
+        let chapter_number = parse_number(chapter).ok_or(BcvKeyError::InvalidChapter(chapter))?;
+        let (start, end) = match verse.split_once(['-', '–']) {
+            Some((start, end)) => (start, Some(end)),
+            None => (verse, None),
+        };
+
+        let start = start.trim();
+        let (verse_str, segment) = match start.char_indices().last() {
+            Some((i, c)) if c.is_ascii_alphabetic() => (&start[..i], c.to_ascii_lowercase() as u8 - b'a' + 1),
+            _ => (start, 0),
+        };
+        let verse_number = parse_number(verse_str).ok_or(BcvKeyError::InvalidVerse(verse))?;
+        let span_length = match end {
+            Some(end) => parse_number(end)
+                .and_then(|end_number| end_number.checked_sub(verse_number))
+                .and_then(|span_length| u8::try_from(span_length).ok())
+                .filter(|&span_length| span_length > 0)
+                .ok_or(BcvKeyError::InvalidVerse(verse))?,
+            None => 0,
+        };
+
+        Ok(BcvKey::from_fields(book.entry().BOS_sequence_number, chapter_number, verse_number, segment, span_length))
+    }
+
+    /// Makes a key from a BOS book code and chapter, verse and segment strings (like the Python BCVS tuples).
+    pub fn from_bcvs<'a>(bos_book_code: &'a str, chapter: &'a str, verse: &'a str, segment: &'a str) -> Result<Self, BcvKeyError<'a>> {
+        let key = BcvKey::from_bcv(bos_book_code, chapter, verse)?;
+        match segment.as_bytes() {
+            [] => Ok(key),
+            [letter] if letter.is_ascii_alphabetic() && key.segment().is_none() => {
+                Ok(BcvKey(key.0 | (letter.to_ascii_lowercase() - b'a' + 1) as u64))
+            }
+            _ => Err(BcvKeyError::InvalidVerse(segment)),
+        }
+    }
+
+    /// Makes a key from a previously obtained value, checking that it decodes properly.
+    pub fn from_value(value: u64) -> Result<Self, BcvKeyError<'static>> {
+        let key = BcvKey(value);
+        if key.segment_number() > 26
+            || key.verse().checked_add((value >> 8) as u8 as u16).is_none()
+            || book_from_sequence_number(key.sequence_number()).is_none()
+        {
+            return Err(BcvKeyError::InvalidValue(value));
+        }
+        Ok(key)
+    }
+
+    #[inline]
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn sequence_number(self) -> u16 {
+        (self.0 >> 48) as u16
+    }
+
+    #[inline]
+    fn segment_number(self) -> u8 {
+        self.0 as u8
+    }
+
+    pub fn book(self) -> BookCode {
+        book_from_sequence_number(self.sequence_number()).expect("BcvKey values are always checked")
+    }
+
+    #[inline]
+    pub fn chapter(self) -> u16 {
+        (self.0 >> 32) as u16
+    }
+
+    /// Returns the (first) verse number.
+    #[inline]
+    pub fn verse(self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    #[inline]
+    pub fn segment(self) -> Option<char> {
+        match self.segment_number() {
+            0 => None,
+            n => Some((b'a' + n - 1) as char),
+        }
+    }
+
+    /// Returns the last verse number if this is a verse span like "3-4".
+    #[inline]
+    pub fn span_end_verse(self) -> Option<u16> {
+        match (self.0 >> 8) as u8 {
+            0 => None,
+            span_length => Some(self.verse() + span_length as u16),
+        }
+    }
+
+    /// Returns the verse string as accepted by from_bcv(), e.g., "3", "3a" or "3-4".
+    pub fn verse_str(self) -> String {
+        let mut verse_str = self.verse().to_string();
+        if let Some(segment) = self.segment() {
+            verse_str.push(segment);
+        }
+        if let Some(end_verse) = self.span_end_verse() {
+            verse_str.push_str(&format!("-{}", end_verse));
+        }
+        verse_str
+    }
+}
+
+impl fmt::Display for BcvKey {
+    /// Displays the key in the BOS style, e.g., "CO1_13:4a"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}:{}", self.book(), self.chapter(), self.verse_str())
+    }
+}
+
+fn book_from_sequence_number(sequence_number: u16) -> Option<BookCode> {
+    BIBLE_BOOKS_CODES_ARRAY
+        .iter()
+        .find(|entry| entry.BOS_sequence_number == sequence_number)
+        .and_then(|entry| BookCode::new(entry.BOS_book_code))
+}
+
+/// Parses a chapter or verse number (digits only, but zero is allowed for introductions).
+fn parse_number(number_str: &str) -> Option<u16> {
+    let number_str = number_str.trim();
+    if number_str.is_empty() || !number_str.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number_str.parse::<u16>().ok()
+}
+
+/// Sorts (BOS book code, chapter, verse) string tuples into canonical order.
+/// Unlike the Python version, verse spans and segment letters are handled,
+///     and any invalid reference is returned as an error (leaving the list unsorted).
+pub fn sort_bcv_references<'a>(references: &mut [(&'a str, &'a str, &'a str)]) -> Result<(), BcvKeyError<'a>> {
+    for &(book, chapter, verse) in references.iter() {
+        BcvKey::from_bcv(book, chapter, verse)?;
+    }
+    references.sort_by_cached_key(|&(book, chapter, verse)| {
+        BcvKey::from_bcv(book, chapter, verse).expect("References were all checked above")
+    });
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcv_key_round_trip() {
+        let key = BcvKey::from_bcv("CO1", "13", "4b-7").unwrap();
+        assert_eq!(key.book().as_str(), "CO1");
+        assert_eq!(key.chapter(), 13);
+        assert_eq!(key.verse(), 4);
+        assert_eq!(key.segment(), Some('b'));
+        assert_eq!(key.span_end_verse(), Some(7));
+        assert_eq!(key.to_string(), "CO1_13:4b-7");
+        assert_eq!(BcvKey::from_value(key.value()), Ok(key));
+        assert_eq!(BcvKey::from_bcvs("GEN", "1", "3", "a"), BcvKey::from_bcv("GEN", "1", "3a"));
+        assert_eq!(BcvKey::new(BookCode::new("GEN").unwrap(), 1, 3), BcvKey::from_bcv("GEN", "1", "3").unwrap());
+        assert!(BcvKey::from_value(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_bcv_key_errors() {
+        assert_eq!(BcvKey::from_bcv("XyZ", "1", "1"), Err(BcvKeyError::UnknownBook("XyZ")));
+        assert_eq!(BcvKey::from_bcv("GEN", "one", "1"), Err(BcvKeyError::InvalidChapter("one")));
+        assert_eq!(BcvKey::from_bcv("GEN", "1", "5-3"), Err(BcvKeyError::InvalidVerse("5-3")));
+        assert_eq!(BcvKey::from_bcv("GEN", "1", "3ab"), Err(BcvKeyError::InvalidVerse("3ab")));
+        assert_eq!(BcvKey::from_bcvs("GEN", "1", "3a", "b"), Err(BcvKeyError::InvalidVerse("b")));
+    }
+
+    #[test]
+    fn test_sort_bcv_references() {
+        let mut references = vec![
+            ("MAT", "1", "2"),
+            ("MAL", "4", "6"),
+            ("MAT", "1", "1-2"),
+            ("MAT", "1", "1b"),
+            ("MAT", "1", "1"),
+            ("GEN", "50", "26"),
+        ];
+        assert_eq!(sort_bcv_references(&mut references), Ok(()));
+        assert_eq!(
+            references,
+            vec![("GEN", "50", "26"), ("MAL", "4", "6"), ("MAT", "1", "1"), ("MAT", "1", "1b"), ("MAT", "1", "1-2"), ("MAT", "1", "2")]
+        );
+        assert!(sort_bcv_references(&mut [("MAT", "x", "1")]).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+// Lists of books making up the commonly used canons.
+
+use crate::{BookCode, BIBLE_BOOKS_CODES_ARRAY};
+
+
+/// A selection of books, e.g., for comparing against the books found in a project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Canon {
+    /// The 39 books of the Hebrew Bible / Protestant Old Testament (BOS reference numbers 1-39)
+    OldTestament,
+    /// The 27 books of the New Testament (BOS reference numbers 40-66)
+    NewTestament,
+    /// The 66 books of the Protestant Bible (BOS reference numbers 1-66)
+    Protestant,
+    /// Any list of books
+    Custom(Vec<BookCode>),
+}
+
+impl Canon {
+    /// Returns the books in canonical (BOS_sequence_number) order.
+    pub fn books(&self) -> Vec<BookCode> {
+        let mut books: Vec<BookCode> = match self {
+            Canon::Custom(books) => books.clone(),
+            _ => BIBLE_BOOKS_CODES_ARRAY.iter().map(|entry| BookCode(entry.BOS_book_code)).filter(|&book| self.contains(book)).collect(),
+        };
+        books.sort();
+        books.dedup();
+        books
+    }
+
+    #[inline]
+    pub fn contains(&self, book: BookCode) -> bool {
+        let reference_number = book.entry().BOS_reference_number;
+        match self {
+            Canon::OldTestament => (1..=39).contains(&reference_number),
+            Canon::NewTestament => (40..=66).contains(&reference_number),
+            Canon::Protestant => (1..=66).contains(&reference_number),
+            Canon::Custom(books) => books.contains(&book),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canon_books() {
+        let protestant = Canon::Protestant.books();
+        assert_eq!(protestant.len(), 66);
+        assert_eq!(protestant.first().map(|book| book.as_str()), Some("GEN"));
+        assert_eq!(protestant.last().map(|book| book.as_str()), Some("REV"));
+        assert_eq!(Canon::OldTestament.books().len() + Canon::NewTestament.books().len(), 66);
+        assert!(Canon::NewTestament.contains(BookCode::new("JDE").unwrap()));
+        assert!(!Canon::NewTestament.contains(BookCode::new("TOB").unwrap()));
+        assert!(Canon::OldTestament.contains(BookCode::new("MAL").unwrap()));
+        assert!(!Canon::OldTestament.contains(BookCode::new("MAT").unwrap()));
+        assert!(!Canon::Protestant.contains(BookCode::new("PS2").unwrap()));
+        let custom = Canon::Custom(vec![BookCode::new("REV").unwrap(), BookCode::new("TOB").unwrap()]);
+        assert_eq!(custom.books(), vec![BookCode::new("TOB").unwrap(), BookCode::new("REV").unwrap()]);
+    }
+}
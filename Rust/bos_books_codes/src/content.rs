@@ -0,0 +1,204 @@
+// Identifying the book from the start of a file's contents, i.e., from a USFM "\id 1CO ..." line,
+//   a USX <book code="1CO" style="id"> element or an OSIS <div type="book" osisID="1Cor"> element.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::{osis_book_code_to_bos_book_code, split_osis_work, usfm_abbrev_to_bos_book_code, BookCode, CodeSystem};
+
+
+/// We only look at this many bytes from the start of the file
+///     (the \id line or <book> element should be right at the beginning,
+///     and the OSIS book <div> should be straight after the <header>).
+const SNIFF_LIMIT: u64 = 8 * 1024;
+
+/// The book found in a file, plus any description after the code,
+///     e.g., "Matthew - World English Bible" from "\id MAT Matthew - World English Bible".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookIdentification {
+    pub book: BookCode,
+    pub description: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ContentError {
+    Io(io::Error),
+    NoIdFound,
+    /// The USFM (or USX) or OSIS code that was found isn't in our table
+    UnknownCode(CodeSystem, String),
+}
+
+impl fmt::Display for ContentError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentError::Io(e) => write!(f, "couldn't read contents: {}", e),
+            ContentError::NoIdFound => write!(f, "no USFM \\id line, USX <book> element or OSIS book <div> found"),
+            ContentError::UnknownCode(s, k) => write!(f, "{} book code '{}' not found", s, k),
+        }
+    }
+}
+
+impl Error for ContentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ContentError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ContentError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        ContentError::Io(e)
+    }
+}
+
+
+/// Reads the start of the USFM, USX or OSIS file and identifies the book from its \id line, <book> element or book <div>.
+pub fn book_from_file_contents(path: impl AsRef<Path>) -> Result<BookIdentification, ContentError> {
+    book_from_contents(File::open(path)?)
+}
+
+/// Reads the start of the USFM, USX or OSIS contents and identifies the book from its \id line, <book> element or book <div>
+///     (whichever comes first). Only the first few kilobytes are read.
+pub fn book_from_contents(reader: impl Read) -> Result<BookIdentification, ContentError> {
+    let mut start_bytes = Vec::new();
+    reader.take(SNIFF_LIMIT).read_to_end(&mut start_bytes)?;
+    let start_text = String::from_utf8_lossy(&start_bytes);
+    let start_text = start_text.trim_start_matches('\u{feff}');
+
+    let usfm_id = start_text.match_indices("\\id").find_map(|(i, _)| usfm_id_line(&start_text[i + 3..]).map(|id| (i, CodeSystem::Usfm, id)));
+    let usx_book = start_text.match_indices("<book").find_map(|(i, _)| usx_book_element(&start_text[i + 5..]).map(|id| (i, CodeSystem::Usfm, id)));
+    let osis_book = start_text.match_indices("<div").find_map(|(i, _)| osis_book_div(&start_text[i + 4..]).map(|id| (i, CodeSystem::Osis, id)));
+    let (_, system, (code, description)) = [usfm_id, usx_book, osis_book]
+        .into_iter()
+        .flatten()
+        .min_by_key(|&(index, _, _)| index)
+        .ok_or(ContentError::NoIdFound)?;
+
+    let bos_book_code = match system {
+        CodeSystem::Osis => osis_book_code_to_bos_book_code(split_osis_work(code).1),
+        _ => usfm_abbrev_to_bos_book_code(code),
+    };
+    let book = bos_book_code
+        .ok()
+        .and_then(BookCode::new)
+        .ok_or_else(|| ContentError::UnknownCode(system, code.to_string()))?;
+    let description = Some(description.trim()).filter(|description| !description.is_empty()).map(str::to_string);
+    Ok(BookIdentification { book, description })
+}
+
+/// Splits the rest of a USFM \id line into the code and the description.
+fn usfm_id_line(after_marker: &str) -> Option<(&str, &str)> {
+    if !after_marker.starts_with([' ', '\t']) {
+        return None; // e.g., an \ide line
+    }
+    let line = after_marker.lines().next().unwrap_or_default().trim_start();
+    let code_length = line.find(char::is_whitespace).unwrap_or(line.len());
+    (code_length > 0).then(|| line.split_at(code_length))
+}
+
+/// Gets the code attribute and the text content from the rest of a USX <book ...> element.
+fn usx_book_element(after_name: &str) -> Option<(&str, &str)> {
+    if !after_name.starts_with(char::is_whitespace) {
+        return None; // e.g., a <bookGroup> element
+    }
+    let tag_end = after_name.find('>')?;
+    let attributes = &after_name[..tag_end];
+    let code = attribute_value(attributes, "code")?;
+
+    let description = if attributes.ends_with('/') {
+        ""
+    } else {
+        let content = &after_name[tag_end + 1..];
+        &content[..content.find("</book>").unwrap_or(0)]
+    };
+    Some((code, description))
+}
+
+/// Gets the osisID attribute from the rest of an OSIS <div type="book" ...> element
+///     (there's no description because the <title> is usually in a separate element).
+fn osis_book_div(after_name: &str) -> Option<(&str, &str)> {
+    if !after_name.starts_with(char::is_whitespace) {
+        return None; // e.g., a <divineName> element
+    }
+    let attributes = &after_name[..after_name.find('>')?];
+    if attribute_value(attributes, "type")? != "book" {
+        return None; // e.g., a bookGroup or chapter <div>
+    }
+    Some((attribute_value(attributes, "osisID")?, ""))
+}
+
+/// Finds the value of the (single or double quoted) attribute, e.g., code="1CO".
+fn attribute_value<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    ['"', '\''].into_iter().find_map(|quote| {
+        let attribute_start = format!("{name}={quote}");
+        let (name_start, _) = attributes.match_indices(&attribute_start)
+            .find(|&(i, _)| attributes[..i].ends_with(char::is_whitespace))?; // Not, e.g., osisIDWork="..."
+        let value_start = name_start + attribute_start.len();
+        let value_length = attributes[value_start..].find(quote)?;
+        Some(&attributes[value_start..value_start + value_length])
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(bos_book_code: &str) -> BookCode {
+        BookCode::new(bos_book_code).unwrap()
+    }
+
+    #[test]
+    fn test_book_from_usfm_contents() {
+        let identification = book_from_contents("\u{feff}\\id 1CO 46-1CO-web.sfm World English Bible\n\\h 1 Corinthians\n".as_bytes()).unwrap();
+        assert_eq!(identification.book, book("CO1"));
+        assert_eq!(identification.description.as_deref(), Some("46-1CO-web.sfm World English Bible"));
+        let identification = book_from_contents("\\ide UTF-8\r\n\\id MAT\r\n".as_bytes()).unwrap();
+        assert_eq!(identification, BookIdentification { book: book("MAT"), description: None });
+    }
+
+    #[test]
+    fn test_book_from_usx_contents() {
+        let usx = r#"<?xml version="1.0" encoding="utf-8"?>
+<usx version="3.0">
+  <book code="1CO" style="id">Some description</book>"#;
+        assert_eq!(
+            book_from_contents(usx.as_bytes()).unwrap(),
+            BookIdentification { book: book("CO1"), description: Some("Some description".to_string()) }
+        );
+        assert_eq!(book_from_contents("<usx><book style='id' code='GEN'/>".as_bytes()).unwrap().book, book("GEN"));
+    }
+
+    #[test]
+    fn test_book_from_osis_contents() {
+        let osis = r#"<?xml version="1.0" encoding="UTF-8"?>
+<osis xmlns="http://www.bibletechnologies.net/2003/OSIS/namespace">
+  <osisText osisIDWork="KJV" xml:lang="en">
+    <header><work osisWork="KJV"><title>King James Version</title></work></header>
+    <div type="bookGroup">
+      <div type="book" osisID="1Cor" canonical="true">
+        <title type="main">THE FIRST EPISTLE OF PAUL THE APOSTLE TO THE CORINTHIANS</title>
+        <div type="chapter" osisID="1Cor.1">"#;
+        assert_eq!(book_from_contents(osis.as_bytes()).unwrap(), BookIdentification { book: book("CO1"), description: None });
+        let osis = "<osis><div osisID='KJV:Phlm' type='book'><chapter osisID='Phlm.1'/></div></osis>";
+        assert_eq!(book_from_contents(osis.as_bytes()).unwrap().book, book("PHM"));
+        // A <div> without type="book" isn't enough
+        assert!(matches!(book_from_contents(r#"<osis><div type="chapter" osisID="Gen.1">"#.as_bytes()), Err(ContentError::NoIdFound)));
+    }
+
+    #[test]
+    fn test_book_from_contents_errors() {
+        assert!(matches!(book_from_contents("\\c 1\n\\v 1 Hello".as_bytes()), Err(ContentError::NoIdFound)));
+        assert!(matches!(book_from_contents("\\id XYZ\n".as_bytes()), Err(ContentError::UnknownCode(CodeSystem::Usfm, code)) if code == "XYZ"));
+        let osis = r#"<osis><osisText osisIDWork="Test"><div type="book" osisID="Xyz">"#;
+        assert!(matches!(book_from_contents(osis.as_bytes()), Err(ContentError::UnknownCode(CodeSystem::Osis, code)) if code == "Xyz"));
+        assert!(matches!(book_from_file_contents("/no/such/file.usfm"), Err(ContentError::Io(_))));
+    }
+}
@@ -0,0 +1,321 @@
+// Writers which export books codes entries (all of BIBLE_BOOKS_CODES_ARRAY or, e.g., just the books in a canon)
+//   to TSV/CSV (the columns of the Python exportDataToTSV()), JSON (the derivedFormats layout), or a Markdown/HTML reference table.
+
+use std::io::{self, Write};
+
+use crate::{BibleBooksCodesArrayEntry, ChapterStructure, OptionalNumberOrTwoNumbers};
+
+
+/// A column heading and how to get its (unescaped) text for an entry.
+type Column = (&'static str, fn(&BibleBooksCodesArrayEntry<'_>) -> String);
+
+/// The columns written by exportDataToTSV() in BibleBooksCodesConverter.py so that BooksCodesTable::from_tsv() can read them back.
+const TABLE_COLUMNS: [Column; 30] = [
+    ("originalLanguageCode", |entry| entry.original_language_code.to_string()),
+    ("bookName", |entry| entry.original_language_book_name.to_string()),
+    ("bookNameEnglishGuide", |entry| entry.book_name_English_guide.to_string()),
+    ("BOSReferenceAbbreviation", |entry| entry.BOS_book_code.to_string()),
+    ("BOSReferenceNumber", |entry| entry.BOS_reference_number.to_string()),
+    ("BOSSequenceNumber", |entry| entry.BOS_sequence_number.to_string()),
+    ("expectedChapters", |entry| expected_chapters_text(entry.expected_num_chapters).unwrap_or_default()),
+    ("chapterStructure", |entry| chapter_structure_text(entry).to_string()),
+    ("shortAbbreviation", |entry| text(entry.short_abbreviation)),
+    ("SBLAbbreviation", |entry| text(entry.SBL_abbreviation)),
+    ("OSISAbbreviation", |entry| text(entry.OSIS_abbreviation)),
+    ("SwordAbbreviation", |entry| text(entry.Sword_abbreviation)),
+    ("CCELNumber", |entry| number_text(entry.CCEL_number)),
+    ("USFMAbbreviation", |entry| text(entry.USFM_abbreviation)),
+    ("USFMNumber", |entry| text(entry.USFM_number_str)),
+    ("USXNumber", |entry| text(entry.USX_number_str)),
+    ("UnboundCode", |entry| text(entry.Unbound_Code)),
+    ("BibleditNumber", |entry| number_text(entry.Bibledit_number)),
+    ("LogosNumber", |entry| number_text(entry.Logos_number)),
+    ("LogosAbbreviation", |entry| text(entry.Logos_abbreviation)),
+    ("NETBibleAbbreviation", |entry| text(entry.NET_Bible_abbreviation)),
+    ("DrupalBibleAbbreviation", |entry| text(entry.Drupal_Bible_abbreviation)),
+    ("BibleWorksAbbreviation", |entry| text(entry.Bible_Works_abbreviation)),
+    ("ByzantineAbbreviation", |entry| text(entry.Byzantine_abbreviation)),
+    ("possibleAlternativeAbbreviations", |entry| entry.possible_alternative_abbreviations.join(",")),
+    ("possibleAlternativeBooksCodes", |entry| entry.possible_alternative_books_codes.join(",")),
+    ("consistsOfBooks", |entry| text(entry.consists_of_books_codes)),
+    ("typicalSection", |entry| text(entry.typical_section)),
+    ("typicalSubsection", |entry| text(entry.typical_subsection)),
+    ("allEnglishDerivedAbbreviations", |entry| entry.all_English_derived_abbreviations.to_string()),
+];
+
+/// The columns of the Markdown/HTML reference table (like the published BOSBooksCodes page).
+const REFERENCE_COLUMNS: [Column; 12] = [
+    ("BOS", |entry| entry.BOS_book_code.to_string()),
+    ("#", |entry| entry.BOS_reference_number.to_string()),
+    ("English name guide", |entry| entry.book_name_English_guide.to_string()),
+    ("Original name", |entry| entry.original_language_book_name.to_string()),
+    ("Language", |entry| entry.original_language_code.to_string()),
+    ("Chapters", |entry| expected_chapters_text(entry.expected_num_chapters).unwrap_or_default().replace(',', "/")),
+    ("USFM", |entry| text(entry.USFM_abbreviation)),
+    ("OSIS", |entry| text(entry.OSIS_abbreviation)),
+    ("SBL", |entry| text(entry.SBL_abbreviation)),
+    ("Sword", |entry| text(entry.Sword_abbreviation)),
+    ("Section", |entry| text(entry.typical_section)),
+    ("Subsection", |entry| text(entry.typical_subsection)),
+];
+
+
+/// Writes the entries in the layout of the Python exportDataToTSV() (with the header line).
+pub fn write_tsv<'a>(mut writer: impl Write, entries: impl IntoIterator<Item = &'a BibleBooksCodesArrayEntry<'a>>) -> io::Result<()> {
+    writeln!(writer, "{}", TABLE_COLUMNS.map(|(heading, _)| heading).join("\t"))?;
+    for entry in entries {
+        writeln!(writer, "{}", TABLE_COLUMNS.map(|(_, get)| get(entry)).join("\t"))?;
+    }
+    Ok(())
+}
+
+/// Writes the entries with the same columns as write_tsv() but as (RFC 4180) CSV,
+///     i.e., fields containing commas (like allEnglishDerivedAbbreviations) or quotes are quoted.
+pub fn write_csv<'a>(mut writer: impl Write, entries: impl IntoIterator<Item = &'a BibleBooksCodesArrayEntry<'a>>) -> io::Result<()> {
+    write!(writer, "{}\r\n", TABLE_COLUMNS.map(|(heading, _)| heading).join(","))?;
+    for entry in entries {
+        write!(writer, "{}\r\n", TABLE_COLUMNS.map(|(_, get)| csv_field(get(entry))).join(","))?;
+    }
+    Ok(())
+}
+
+/// Writes a Markdown (pipe) table of the main codes for each entry.
+pub fn write_markdown<'a>(mut writer: impl Write, entries: impl IntoIterator<Item = &'a BibleBooksCodesArrayEntry<'a>>) -> io::Result<()> {
+    writeln!(writer, "| {} |", REFERENCE_COLUMNS.map(|(heading, _)| heading).join(" | "))?;
+    writeln!(writer, "|{}", "---|".repeat(REFERENCE_COLUMNS.len()))?;
+    for entry in entries {
+        writeln!(writer, "| {} |", REFERENCE_COLUMNS.map(|(_, get)| get(entry).replace('|', "\\|")).join(" | "))?;
+    }
+    Ok(())
+}
+
+/// Writes an HTML table (without the surrounding document) of the same columns as write_markdown().
+pub fn write_html<'a>(mut writer: impl Write, entries: impl IntoIterator<Item = &'a BibleBooksCodesArrayEntry<'a>>) -> io::Result<()> {
+    writeln!(writer, "<table class=\"BOSBooksCodes\">")?;
+    writeln!(writer, "<thead><tr>{}</tr></thead>", REFERENCE_COLUMNS.map(|(heading, _)| format!("<th>{}</th>", html_escape(heading))).concat())?;
+    writeln!(writer, "<tbody>")?;
+    for entry in entries {
+        writeln!(writer, "<tr>{}</tr>", REFERENCE_COLUMNS.map(|(_, get)| format!("<td>{}</td>", html_escape(&get(entry)))).concat())?;
+    }
+    writeln!(writer, "</tbody>\n</table>")
+}
+
+/// Writes the entries in the layout of derivedFormats/BibleBooksCodes_Tables.json
+///     (as exported by BibleBooksCodesConverter.py, and loaded by the JavaScript package and BooksCodesTable::from_json()).
+/// Codes shared by several books have a list of BOS book codes (with the last book's reference number), like the Python.
+pub fn write_json<'a>(writer: impl Write, entries: impl IntoIterator<Item = &'a BibleBooksCodesArrayEntry<'a>>) -> io::Result<()> {
+    use serde_json::{json, Map, Value};
+
+    let entries: Vec<&BibleBooksCodesArrayEntry> = entries.into_iter().collect();
+
+    let book_fields = |entry: &BibleBooksCodesArrayEntry| {
+        let Value::Object(fields) = json!({
+            "originalLanguageCode": entry.original_language_code,
+            "bookName": entry.original_language_book_name,
+            "referenceNumber": entry.BOS_reference_number,
+            "shortAbbreviation": entry.short_abbreviation,
+            "SBLAbbreviation": entry.SBL_abbreviation,
+            "OSISAbbreviation": entry.OSIS_abbreviation,
+            "SwordAbbreviation": entry.Sword_abbreviation,
+            "CCELNumberString": entry.CCEL_number.map(|number| number.to_string()),
+            "USFMAbbreviation": entry.USFM_abbreviation,
+            "USFMNumberString": entry.USFM_number_str,
+            "USXNumberString": entry.USX_number_str,
+            "UnboundCodeString": entry.Unbound_Code,
+            "BibleditNumberString": entry.Bibledit_number.map(|number| number.to_string()),
+            "LogosNumberString": entry.Logos_number.map(|number| number.to_string()),
+            "NETBibleAbbreviation": entry.NET_Bible_abbreviation,
+            "DrupalBibleAbbreviation": entry.Drupal_Bible_abbreviation,
+            "ByzantineAbbreviation": entry.Byzantine_abbreviation,
+            "numExpectedChapters": expected_chapters_text(entry.expected_num_chapters),
+            "possibleAlternativeBooks": Some(entry.possible_alternative_books_codes).filter(|books| !books.is_empty()),
+            "bookNameEnglishGuide": entry.book_name_English_guide,
+            "typicalSection": entry.typical_section,
+        }) else {
+            unreachable!()
+        };
+        fields
+    };
+
+    let mut reference_number_dict = Map::new();
+    let mut reference_abbreviation_dict = Map::new();
+    for entry in &entries {
+        let mut fields = Map::new();
+        fields.insert("referenceAbbreviation".to_string(), json!(entry.BOS_book_code));
+        fields.extend(book_fields(entry).into_iter().filter(|(key, _)| key != "referenceNumber"));
+        reference_number_dict.insert(entry.BOS_reference_number.to_string(), Value::Object(fields));
+        reference_abbreviation_dict.insert(entry.BOS_book_code.to_string(), Value::Object(book_fields(entry)));
+    }
+    let mut sequence_list = entries.clone();
+    sequence_list.sort_by_key(|entry| entry.BOS_sequence_number);
+
+    // Each code dictionary is keyed by the uppercase code
+    //     and the value has the BOS reference number and book code (and maybe another code), as a list if several books share the code
+    type CodeDict = (&'static str, bool, fn(&BibleBooksCodesArrayEntry) -> Option<String>, Option<for<'e> fn(&BibleBooksCodesArrayEntry<'e>) -> Option<&'e str>>);
+    let code_dicts: [CodeDict; 16] = [
+        ("shortAbbreviationDict", true, |entry| entry.short_abbreviation.map(str::to_uppercase), None),
+        ("SBLAbbreviationDict", true, |entry| entry.SBL_abbreviation.map(str::to_uppercase), None),
+        ("OSISAbbreviationDict", true, |entry| entry.OSIS_abbreviation.map(str::to_uppercase), None),
+        ("SwordAbbreviationDict", true, |entry| entry.Sword_abbreviation.map(str::to_uppercase), None),
+        ("CCELDict", true, |entry| entry.CCEL_number.map(|number| number.to_string()), None),
+        ("USFMAbbreviationDict", true, |entry| entry.USFM_abbreviation.map(str::to_uppercase), Some(|entry| entry.USFM_number_str)),
+        ("USFMNumberDict", true, |entry| entry.USFM_number_str.map(str::to_uppercase), Some(|entry| entry.USFM_abbreviation)),
+        ("USXNumberDict", false, |entry| entry.USX_number_str.map(str::to_uppercase), Some(|entry| entry.USFM_abbreviation)),
+        ("UnboundCodeDict", false, |entry| entry.Unbound_Code.map(str::to_uppercase), Some(|entry| entry.USFM_abbreviation)),
+        ("BibleditNumberDict", false, |entry| entry.Bibledit_number.map(|number| number.to_string()), Some(|entry| entry.USFM_abbreviation)),
+        ("LogosNumberDict", false, |entry| entry.Logos_number.map(|number| number.to_string()), Some(|entry| entry.USFM_abbreviation)),
+        ("NETBibleAbbreviationDict", true, |entry| entry.NET_Bible_abbreviation.map(str::to_uppercase), None),
+        ("DrupalBibleAbbreviationDict", true, |entry| entry.Drupal_Bible_abbreviation.map(str::to_uppercase), None),
+        ("BibleWorksAbbreviationDict", true, |entry| entry.Bible_Works_abbreviation.map(str::to_uppercase), None),
+        ("ByzantineAbbreviationDict", false, |entry| entry.Byzantine_abbreviation.map(str::to_uppercase), None),
+        ("EnglishNameDict", false, |entry| Some(entry.book_name_English_guide.to_uppercase()), None),
+    ];
+
+    let mut tables = Map::new();
+    tables.insert("referenceNumberDict".to_string(), Value::Object(reference_number_dict));
+    tables.insert("referenceAbbreviationDict".to_string(), Value::Object(reference_abbreviation_dict));
+    tables.insert("sequenceList".to_string(), sequence_list.iter().map(|entry| json!(entry.BOS_book_code)).collect());
+    for (dict_name, can_be_shared, key_of, other_code_of) in code_dicts {
+        let mut dict = Map::new();
+        for entry in &entries {
+            let Some(key) = key_of(entry) else { continue };
+            let mut value = vec![json!(entry.BOS_reference_number), json!(entry.BOS_book_code)];
+            value.extend(other_code_of.map(|other_code_of| json!(other_code_of(entry))));
+            match dict.get_mut(&key) {
+                None => {
+                    dict.insert(key, Value::Array(value));
+                }
+                Some(Value::Array(existing)) if can_be_shared => {
+                    existing[0] = value[0].take();
+                    for (existing, value) in existing.iter_mut().zip(value).skip(1) {
+                        match existing {
+                            Value::Array(list) => list.push(value),
+                            _ => *existing = json!([existing.take(), value]),
+                        }
+                    }
+                }
+                Some(_) => {} // The first book keeps it
+            }
+        }
+        tables.insert(dict_name.to_string(), Value::Object(dict));
+    }
+    tables.insert(
+        "allAbbreviationsDict".to_string(),
+        entries
+            .iter()
+            .flat_map(|entry| entry.all_English_derived_abbreviations.split(',').map(|abbreviation| (abbreviation.to_string(), json!(entry.BOS_book_code))))
+            .collect::<Map<_, _>>()
+            .into(),
+    );
+
+    serde_json::to_writer_pretty(writer, &tables).map_err(io::Error::from)
+}
+
+
+#[inline]
+fn text(value: Option<&str>) -> String {
+    value.unwrap_or_default().to_string()
+}
+
+#[inline]
+fn number_text(value: Option<u16>) -> String {
+    value.map(|number| number.to_string()).unwrap_or_default()
+}
+
+/// Returns the expectedChapters text, e.g., "50" or "150,151".
+fn expected_chapters_text(expected_num_chapters: OptionalNumberOrTwoNumbers) -> Option<String> {
+    match expected_num_chapters {
+        OptionalNumberOrTwoNumbers::Number(num_chapters) => Some(num_chapters.to_string()),
+        OptionalNumberOrTwoNumbers::TwoNumbers([usual_num_chapters, alternative_num_chapters]) => {
+            Some(format!("{usual_num_chapters},{alternative_num_chapters}"))
+        }
+        OptionalNumberOrTwoNumbers::None => None,
+    }
+}
+
+/// NOTE: Like the XML, continuous is only given for books without chapters (as it's the default for the others)
+fn chapter_structure_text(entry: &BibleBooksCodesArrayEntry) -> &'static str {
+    match (entry.chapter_structure, entry.expected_num_chapters) {
+        (Some(ChapterStructure::Units), _) => "units",
+        (Some(ChapterStructure::Continuous), OptionalNumberOrTwoNumbers::None) => "continuous",
+        _ => "",
+    }
+}
+
+fn csv_field(field: String) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BookCode, BooksCodesTable, BooksCodesTableEntry, Canon, BIBLE_BOOKS_CODES_ARRAY};
+
+    #[test]
+    fn test_write_tsv() {
+        let mut tsv = Vec::new();
+        write_tsv(&mut tsv, BIBLE_BOOKS_CODES_ARRAY.iter()).unwrap();
+        let tsv = String::from_utf8(tsv).unwrap();
+        let python_tsv = include_str!("../test_data/BibleBooksCodes_Tables_python_output.tsv");
+        assert_eq!(tsv.lines().next(), python_tsv.lines().next());
+        // It reads back as the same table
+        let table = BooksCodesTable::from_tsv(&tsv).unwrap();
+        let builtin_entries: Vec<BooksCodesTableEntry> = BIBLE_BOOKS_CODES_ARRAY.iter().map(BooksCodesTableEntry::from).collect();
+        assert_eq!(table.entries(), builtin_entries.as_slice());
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut csv = Vec::new();
+        write_csv(&mut csv, Canon::NewTestament.books().into_iter().map(BookCode::entry)).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 1 + 27);
+        let matthew = csv.lines().nth(1).unwrap();
+        assert!(matthew.starts_with("el-x-koine,"));
+        assert!(matthew.contains(",MAT,40,"));
+        assert!(matthew.ends_with(",\"MAT,MATH,MATHE,MATHEW,MATT,MATTH,MATTHE,MATTHEW,MT,MTT\""));
+        assert!(csv.ends_with("\r\n"));
+    }
+
+    #[test]
+    fn test_write_markdown_and_html() {
+        let books = || Canon::OldTestament.books().into_iter().map(BookCode::entry);
+        let mut markdown = Vec::new();
+        write_markdown(&mut markdown, books()).unwrap();
+        let markdown = String::from_utf8(markdown).unwrap();
+        assert_eq!(markdown.lines().count(), 2 + 39);
+        assert!(markdown.lines().nth(2).unwrap().starts_with("| GEN | 1 | Genesis / 1 Moses | "));
+        assert!(markdown.contains("| PSA | 19 | ") && markdown.contains(" | 150/151 | "));
+
+        let mut html = Vec::new();
+        write_html(&mut html, books()).unwrap();
+        let html = String::from_utf8(html).unwrap();
+        assert_eq!(html.matches("<tr>").count(), 1 + 39);
+        assert!(html.contains("<td>GEN</td><td>1</td>"));
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut json = Vec::new();
+        write_json(&mut json, BIBLE_BOOKS_CODES_ARRAY.iter()).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json["referenceAbbreviationDict"]["PSA"]["numExpectedChapters"], "150,151");
+        assert_eq!(json["referenceNumberDict"]["1"]["referenceAbbreviation"], "GEN");
+        assert_eq!(json["USFMAbbreviationDict"]["GEN"], serde_json::json!([1, "GEN", "01"]));
+        assert_eq!(json["USFMAbbreviationDict"]["EZR"][1], serde_json::json!(["EZR", "EZN"]));
+        assert_eq!(json["allAbbreviationsDict"]["GENESIS"], "GEN");
+        // It reads back with the same lookups
+        let table = BooksCodesTable::from_json(&json.to_string()).unwrap();
+        assert_eq!(table.len(), BIBLE_BOOKS_CODES_ARRAY.len());
+        assert_eq!(table.usfm_abbrev_to_bos_book_code("1Co"), Ok("CO1"));
+    }
+}
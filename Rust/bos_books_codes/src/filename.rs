@@ -0,0 +1,207 @@
+// Detecting the book from USFM/Paratext/USX style filenames,
+//   e.g., "41MATENG.SFM", "01GENwebbe.usfm", "A0FRT.SFM", "040MAT.usx", "MAT.usfm" or "CO1.usfm",
+//   and building those filenames for a book.
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use crate::{BookCode, CodeSystem, LookupError, BIBLE_BOOKS_CODES_ARRAY, REFERENCE_ABBREVIATION_MAP, UPPERCASE_USFM_ABBREVIATION_MAP};
+
+
+#[derive(Debug, PartialEq)]
+pub enum FilenameError {
+    Unrecognised(String),
+    NumberAbbreviationConflict {
+        filename: String,
+        number_book: BookCode,
+        abbreviation_book: BookCode,
+    },
+}
+
+impl fmt::Display for FilenameError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilenameError::Unrecognised(k) => write!(f, "no book code found in filename '{}'", k),
+            FilenameError::NumberAbbreviationConflict { filename, number_book, abbreviation_book } => write!(
+                f,
+                "filename '{}' has the book number for {} but the book abbreviation for {}",
+                filename, number_book, abbreviation_book
+            ),
+        }
+    }
+}
+
+impl Error for FilenameError {}
+
+
+/// Finds the book from a filename (any folders are ignored) using the common naming patterns:
+///     USX number (three digits) + USFM abbreviation + anything, e.g., "040MAT.usx"
+///     USFM number (two characters) + USFM abbreviation + anything, e.g., "41MATENG.SFM" or "A0FRT.SFM"
+///     USFM abbreviation + anything, e.g., "MAT.usfm"
+///     BOS book code + anything, e.g., "CO1.usfm"
+/// Case is not significant. If the number and the abbreviation refer to different books,
+///     a NumberAbbreviationConflict error is returned.
+pub fn book_from_filename(path: impl AsRef<Path>) -> Result<BookCode, FilenameError> {
+    let path = path.as_ref();
+    let filename = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+    let stem = filename.split('.').next().unwrap_or_default().to_uppercase();
+
+    for (number_length, number_of) in [(3, usx_number_of as fn(BookCode) -> Option<&'static str>), (2, usfm_number_of)] {
+        let (Some(number), Some(abbreviation)) = (stem.get(..number_length), stem.get(number_length..number_length + 3)) else {
+            continue;
+        };
+        let Some(abbreviation_book) = book_from_uppercase_usfm_abbreviation(abbreviation) else {
+            continue;
+        };
+        if number_of(abbreviation_book) == Some(number) {
+            return Ok(abbreviation_book);
+        }
+        if let Some(number_book) = book_codes().find(|&book| number_of(book) == Some(number)) {
+            return Err(FilenameError::NumberAbbreviationConflict { filename: filename.to_string(), number_book, abbreviation_book });
+        }
+    }
+
+    if let Some(start) = stem.get(..3) {
+        if let Some(book) = book_from_uppercase_usfm_abbreviation(start) {
+            return Ok(book);
+        }
+        if let Some(&array_index) = REFERENCE_ABBREVIATION_MAP.get(start) {
+            return Ok(BookCode(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code));
+        }
+    }
+
+    Err(FilenameError::Unrecognised(filename.to_string()))
+}
+
+
+/// The naming conventions that book_filename() can produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilenameConvention {
+    /// USFM number + uppercase USFM abbreviation + project suffix, e.g., "41MATENG.SFM"
+    Paratext,
+    /// USX number + uppercase USFM abbreviation + project suffix, e.g., "040MAT.usx"
+    Usx,
+    /// OSIS abbreviation + project suffix, e.g., "Matt.xml"
+    OsisPerBook,
+    /// BOS book code + project suffix, e.g., "MAT.usfm"
+    Bos,
+}
+
+impl FilenameConvention {
+    pub fn default_extension(self) -> &'static str {
+        match self {
+            FilenameConvention::Paratext => "SFM",
+            FilenameConvention::Usx => "usx",
+            FilenameConvention::OsisPerBook => "xml",
+            FilenameConvention::Bos => "usfm",
+        }
+    }
+}
+
+/// The project-specific parts of a filename.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FilenameParameters<'a> {
+    /// Appended after the book code, e.g., "ENG" in "41MATENG.SFM"
+    pub project_suffix: &'a str,
+    /// Without the dot; None gives the default extension for the convention
+    pub extension: Option<&'a str>,
+}
+
+/// Builds the filename for the book in the given naming convention,
+///     e.g., "41MATENG.SFM" for MAT with FilenameConvention::Paratext and a project suffix of "ENG".
+/// Fails if the book has no code (or number) in the convention.
+pub fn book_filename(book: BookCode, convention: FilenameConvention, parameters: &FilenameParameters<'_>) -> Result<String, LookupError<'static>> {
+    let entry = book.entry();
+    let uppercase_usfm_abbreviation = || {
+        entry.USFM_abbreviation.map(str::to_uppercase).ok_or(LookupError::no_equivalent(CodeSystem::Usfm, entry.BOS_book_code))
+    };
+    let book_part = match convention {
+        FilenameConvention::Paratext => {
+            let usfm_number = entry.USFM_number_str.ok_or(LookupError::no_equivalent(CodeSystem::UsfmNumber, entry.BOS_book_code))?;
+            format!("{}{}", usfm_number, uppercase_usfm_abbreviation()?)
+        }
+        FilenameConvention::Usx => {
+            let usx_number = entry.USX_number_str.ok_or(LookupError::no_equivalent(CodeSystem::UsxNumber, entry.BOS_book_code))?;
+            format!("{}{}", usx_number, uppercase_usfm_abbreviation()?)
+        }
+        FilenameConvention::OsisPerBook => entry.OSIS_abbreviation.ok_or(LookupError::no_equivalent(CodeSystem::Osis, entry.BOS_book_code))?.to_string(),
+        FilenameConvention::Bos => entry.BOS_book_code.to_string(),
+    };
+    let extension = parameters.extension.unwrap_or(convention.default_extension());
+    Ok(format!("{}{}.{}", book_part, parameters.project_suffix, extension))
+}
+
+#[inline]
+fn book_codes() -> impl Iterator<Item = BookCode> {
+    BIBLE_BOOKS_CODES_ARRAY.iter().map(|entry| BookCode(entry.BOS_book_code))
+}
+
+#[inline]
+fn book_from_uppercase_usfm_abbreviation(uppercase_usfm_abbreviation: &str) -> Option<BookCode> {
+    let array_index = *UPPERCASE_USFM_ABBREVIATION_MAP.get(uppercase_usfm_abbreviation)?;
+    Some(BookCode(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code))
+}
+
+#[inline]
+fn usfm_number_of(book: BookCode) -> Option<&'static str> {
+    book.entry().USFM_number_str
+}
+
+#[inline]
+fn usx_number_of(book: BookCode) -> Option<&'static str> {
+    book.entry().USX_number_str
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(bos_book_code: &str) -> BookCode {
+        BookCode::new(bos_book_code).unwrap()
+    }
+
+    #[test]
+    fn test_book_from_filename() {
+        assert_eq!(book_from_filename("41MATENG.SFM"), Ok(book("MAT")));
+        assert_eq!(book_from_filename("/some/folder/01GENwebbe.usfm"), Ok(book("GEN")));
+        assert_eq!(book_from_filename("A0FRT.SFM"), Ok(book("FRT")));
+        assert_eq!(book_from_filename("040MAT.usx"), Ok(book("MAT")));
+        assert_eq!(book_from_filename("MAT.usfm"), Ok(book("MAT")));
+        assert_eq!(book_from_filename("CO1.usfm"), Ok(book("CO1")));
+        assert_eq!(book_from_filename("readme.txt"), Err(FilenameError::Unrecognised("readme.txt".to_string())));
+    }
+
+    #[test]
+    fn test_book_filename() {
+        let eng = FilenameParameters { project_suffix: "ENG", ..Default::default() };
+        assert_eq!(book_filename(book("MAT"), FilenameConvention::Paratext, &eng), Ok("41MATENG.SFM".to_string()));
+        assert_eq!(book_filename(book("MAT"), FilenameConvention::Usx, &FilenameParameters::default()), Ok("040MAT.usx".to_string()));
+        assert_eq!(book_filename(book("MAT"), FilenameConvention::OsisPerBook, &FilenameParameters::default()), Ok("Matt.xml".to_string()));
+        assert_eq!(book_filename(book("MAT"), FilenameConvention::Bos, &FilenameParameters::default()), Ok("MAT.usfm".to_string()));
+        assert_eq!(
+            book_filename(book("CO1"), FilenameConvention::Paratext, &FilenameParameters { project_suffix: "", extension: Some("usfm") }),
+            Ok("471CO.usfm".to_string())
+        );
+        assert_eq!(book_filename(book("SAM"), FilenameConvention::Paratext, &eng), Err(LookupError::no_equivalent(CodeSystem::UsfmNumber, "SAM")));
+        assert_eq!(book_filename(book("PSO"), FilenameConvention::Usx, &eng), Err(LookupError::no_equivalent(CodeSystem::UsxNumber, "PSO")));
+        for convention in [FilenameConvention::Paratext, FilenameConvention::Usx, FilenameConvention::Bos] {
+            let filename = book_filename(book("JN3"), convention, &eng).unwrap();
+            assert_eq!(book_from_filename(&filename), Ok(book("JN3")), "{}", filename);
+        }
+    }
+
+    #[test]
+    fn test_book_from_filename_conflict() {
+        assert_eq!(
+            book_from_filename("41GENENG.SFM"),
+            Err(FilenameError::NumberAbbreviationConflict {
+                filename: "41GENENG.SFM".to_string(),
+                number_book: book("MAT"),
+                abbreviation_book: book("GEN"),
+            })
+        );
+    }
+}
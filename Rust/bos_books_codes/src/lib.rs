@@ -1,8079 +1,260 @@
-// WARNING: DO NOT EDIT THIS FILE!!!
-//   This file was created by build_static_tables.py v0.1.9 invoked by build.rs (both in the folder above this one)
-//      which added in the static data structures.
-//   To change the functions in this library,
-//      edit lib.rs.src (also in the folder above this one)
-
-
 #![allow(non_snake_case)]
 // #![allow(unused)]
 
-use std::error::Error;
+use std::cmp::Ordering;
 use std::fmt;
 
 use phf::phf_map;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
-
-// THESE VARIOUS STATIC ARRAYS AND HASHMAPS WERE CREATED AUTOMATICALLY BY build_static_tables.py
-//   from the data in BibleBooksCodes_Tables.tsv (both files in the folder above this one)
-
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OptionalNumberOrTwoNumbers {
     Number(u16),
     TwoNumbers([u16; 2]),
     None,
 }
 
-//#[derive(Debug)]
-//pub enum OptionalAbbreviationOrListOfAbbreviations<'a> {
-//    Abbreviation(&'a str),
-//    TwoAbbreviations([&'a str; 2]),
-//    ThreeAbbreviations([&'a str; 3]),
-//    FourAbbreviations([&'a str; 4]),
-//    FiveAbbreviations([&'a str; 5]),
-//    SixAbbreviations([&'a str; 6]),
-//    SevenAbbreviations([&'a str; 7]),
-//    TwelveAbbreviations([&'a str; 12]),
-//    ThirteenAbbreviations([&'a str; 13]),
-//    FourteenAbbreviations([&'a str; 14]),
-//    SixteenAbbreviations([&'a str; 16]),
-//    None,
-//}
+/// Whether the chapter divisions of a book are artificial breaks in a continuous text (most books),
+///     or each chapter is an independent unit (like the individual psalms in Psalms).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChapterStructure {
+    Continuous,
+    Units,
+}
 
+/// NOTE: With the serde feature, this can be serialized but not deserialized (because of the &'static lists)
+///     so deserialize into a BooksCodesTableEntry instead (which has the same fields).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BibleBooksCodesArrayEntry<'a> {
-    original_language_code: &'a str,
-    original_language_book_name: &'a str,
-    book_name_English_guide: &'a str,
-    BOS_book_code: &'a str,
-    BOS_reference_number: u16,
-    BOS_sequence_number: u16,
-    expected_num_chapters: OptionalNumberOrTwoNumbers,
-    short_abbreviation: Option<&'a str>,
-    SBL_abbreviation: Option<&'a str>,
-    OSIS_abbreviation: Option<&'a str>,
-    Sword_abbreviation: Option<&'a str>,
-    CCEL_number: Option<u16>,
-    USFM_abbreviation: Option<&'a str>,
-    USFM_number_str: Option<&'a str>,
-    USX_number_str: Option<&'a str>,
-    Unbound_Code: Option<&'a str>,
-    Bibledit_number: Option<u16>,
-    Logos_number: Option<u16>,
-    Logos_abbreviation: Option<&'a str>,
-    NET_Bible_abbreviation: Option<&'a str>,
-    Drupal_Bible_abbreviation: Option<&'a str>,
-    Bible_Works_abbreviation: Option<&'a str>,
-    Byzantine_abbreviation: Option<&'a str>,
-    //possible_alternative_abbreviations: OptionalAbbreviationOrListOfAbbreviations<'a>,
-    possible_alternative_abbreviations: &'static [&'static str],
-    //possible_alternative_books_codes: OptionalAbbreviationOrListOfAbbreviations<'a>,
-    //possible_alternative_books_codes: Option<&'static [&'static str]>,
-    possible_alternative_books_codes: &'static [&'static str],
-    consists_of_books_codes: Option<&'a str>,
-    typical_section: Option<&'a str>,
-    typical_subsection: Option<&'a str>,
-    all_English_derived_abbreviations: &'a str,
+    pub original_language_code: &'a str,
+    pub original_language_book_name: &'a str,
+    pub book_name_English_guide: &'a str,
+    pub BOS_book_code: &'a str,
+    pub BOS_reference_number: u16,
+    pub BOS_sequence_number: u16,
+    pub expected_num_chapters: OptionalNumberOrTwoNumbers,
+    pub chapter_structure: Option<ChapterStructure>,
+    pub short_abbreviation: Option<&'a str>,
+    pub SBL_abbreviation: Option<&'a str>,
+    pub OSIS_abbreviation: Option<&'a str>,
+    pub Sword_abbreviation: Option<&'a str>,
+    pub CCEL_number: Option<u16>,
+    pub USFM_abbreviation: Option<&'a str>,
+    pub USFM_number_str: Option<&'a str>,
+    pub USX_number_str: Option<&'a str>,
+    pub Unbound_Code: Option<&'a str>,
+    pub Bibledit_number: Option<u16>,
+    pub Logos_number: Option<u16>,
+    pub Logos_abbreviation: Option<&'a str>,
+    pub NET_Bible_abbreviation: Option<&'a str>,
+    pub Drupal_Bible_abbreviation: Option<&'a str>,
+    pub Bible_Works_abbreviation: Option<&'a str>,
+    pub Byzantine_abbreviation: Option<&'a str>,
+    pub possible_alternative_abbreviations: &'static [&'static str],
+    pub possible_alternative_books_codes: &'static [&'static str],
+    pub consists_of_books_codes: Option<&'a str>,
+    pub typical_section: Option<&'a str>,
+    pub typical_subsection: Option<&'a str>,
+    pub all_English_derived_abbreviations: &'a str,
 }
 
-pub static BIBLE_BOOKS_CODES_ARRAY: [BibleBooksCodesArrayEntry; 257] = [
-        BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "בְּרֵאשִׁית",
-        book_name_English_guide: "Genesis / 1 Moses",
-        BOS_book_code: "GEN",
-        BOS_reference_number: 1,
-        BOS_sequence_number: 20,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(50),
-        short_abbreviation: Some("Ge"),
-        SBL_abbreviation: Some("Gen"),
-        OSIS_abbreviation: Some("Gen"),
-        Sword_abbreviation: Some("Gen"),
-        CCEL_number: Some(1),
-        USFM_abbreviation: Some("Gen"),
-        USFM_number_str: Some("01"),
-        USX_number_str: Some("001"),
-        Unbound_Code: Some("01O"),
-        Bibledit_number: Some(1),
-        Logos_number: Some(1),
-        Logos_abbreviation: Some("Ge"),
-        NET_Bible_abbreviation: Some("Gen"),
-        Drupal_Bible_abbreviation: Some("Gen"),
-        Bible_Works_abbreviation: Some("GEN"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["GENE","GNSS","1 MSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Pentateuch"),
-        all_English_derived_abbreviations: "1 MO,1 MOS,1 MOSE,1 MOSES,1 MS,1 MSS,1MO,1MOS,1MOSE,1MOSES,1MS,1MSS,GE,GEN,GENE,GENES,GENESI,GENESIS,GN,GNS,GNSS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "שְׁמֹות",
-        book_name_English_guide: "Exodus / 2 Moses",
-        BOS_book_code: "EXO",
-        BOS_reference_number: 2,
-        BOS_sequence_number: 22,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(40),
-        short_abbreviation: Some("Ex"),
-        SBL_abbreviation: Some("Exod"),
-        OSIS_abbreviation: Some("Exod"),
-        Sword_abbreviation: Some("Exod"),
-        CCEL_number: Some(2),
-        USFM_abbreviation: Some("Exo"),
-        USFM_number_str: Some("02"),
-        USX_number_str: Some("002"),
-        Unbound_Code: Some("02O"),
-        Bibledit_number: Some(2),
-        Logos_number: Some(2),
-        Logos_abbreviation: Some("Ex"),
-        NET_Bible_abbreviation: Some("Exo"),
-        Drupal_Bible_abbreviation: Some("Exo"),
-        Bible_Works_abbreviation: Some("EXO"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["EXDS","2 MSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Pentateuch"),
-        all_English_derived_abbreviations: "2 MO,2 MOS,2 MOSE,2 MOSES,2 MS,2 MSS,2MO,2MOS,2MOSE,2MOSES,2MS,2MSS,EX,EXD,EXDS,EXO,EXOD,EXODU,EXODUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "וַיִּקְרָא",
-        book_name_English_guide: "Leviticus / 3 Moses",
-        BOS_book_code: "LEV",
-        BOS_reference_number: 3,
-        BOS_sequence_number: 24,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(27),
-        short_abbreviation: Some("Le"),
-        SBL_abbreviation: Some("Lev"),
-        OSIS_abbreviation: Some("Lev"),
-        Sword_abbreviation: Some("Lev"),
-        CCEL_number: Some(3),
-        USFM_abbreviation: Some("Lev"),
-        USFM_number_str: Some("03"),
-        USX_number_str: Some("003"),
-        Unbound_Code: Some("03O"),
-        Bibledit_number: Some(3),
-        Logos_number: Some(3),
-        Logos_abbreviation: Some("Le"),
-        NET_Bible_abbreviation: Some("Lev"),
-        Drupal_Bible_abbreviation: Some("Lev"),
-        Bible_Works_abbreviation: Some("LEV"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["LVT","3 MSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Pentateuch"),
-        all_English_derived_abbreviations: "3 MO,3 MOS,3 MOSE,3 MOSES,3 MS,3 MSS,3MO,3MOS,3MOSE,3MOSES,3MS,3MSS,LE,LEV,LEVI,LEVIT,LEVITI,LEVITIC,LEVITICU,LEVITICUS,LV,LVT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "בְּמִדְבַּר",
-        book_name_English_guide: "Numbers / 4 Moses",
-        BOS_book_code: "NUM",
-        BOS_reference_number: 4,
-        BOS_sequence_number: 26,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(36),
-        short_abbreviation: Some("Nu"),
-        SBL_abbreviation: Some("Num"),
-        OSIS_abbreviation: Some("Num"),
-        Sword_abbreviation: Some("Num"),
-        CCEL_number: Some(4),
-        USFM_abbreviation: Some("Num"),
-        USFM_number_str: Some("04"),
-        USX_number_str: Some("004"),
-        Unbound_Code: Some("04O"),
-        Bibledit_number: Some(4),
-        Logos_number: Some(4),
-        Logos_abbreviation: Some("Nu"),
-        NET_Bible_abbreviation: Some("Num"),
-        Drupal_Bible_abbreviation: Some("Num"),
-        Bible_Works_abbreviation: Some("NUM"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["NMBRS","4 MSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Pentateuch"),
-        all_English_derived_abbreviations: "4 MO,4 MOS,4 MOSE,4 MOSES,4 MS,4 MSS,4MO,4MOS,4MOSE,4MOSES,4MS,4MSS,NM,NMB,NMBR,NMBRS,NU,NUM,NUMB,NUMBE,NUMBER,NUMBERS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "דְּבָרִים",
-        book_name_English_guide: "Deuteronomy / 5 Moses",
-        BOS_book_code: "DEU",
-        BOS_reference_number: 5,
-        BOS_sequence_number: 28,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(34),
-        short_abbreviation: Some("Dt"),
-        SBL_abbreviation: Some("Deut"),
-        OSIS_abbreviation: Some("Deut"),
-        Sword_abbreviation: Some("Deut"),
-        CCEL_number: Some(5),
-        USFM_abbreviation: Some("Deu"),
-        USFM_number_str: Some("05"),
-        USX_number_str: Some("005"),
-        Unbound_Code: Some("05O"),
-        Bibledit_number: Some(5),
-        Logos_number: Some(5),
-        Logos_abbreviation: Some("Dt"),
-        NET_Bible_abbreviation: Some("Deu"),
-        Drupal_Bible_abbreviation: Some("Deu"),
-        Bible_Works_abbreviation: Some("DEU"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["DTRN","5 MSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Pentateuch"),
-        all_English_derived_abbreviations: "5 MO,5 MOS,5 MOSE,5 MOSES,5 MS,5 MSS,5MO,5MOS,5MOSE,5MOSES,5MS,5MSS,DE,DEU,DEUT,DEUTE,DEUTER,DEUTERO,DEUTERON,DEUTERONO,DEUTERONOM,DEUTERONOMY,DT,DTR,DTRN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "יְהוֹשֻעַ",
-        book_name_English_guide: "Joshua / Joshua son of Nun",
-        BOS_book_code: "JOS",
-        BOS_reference_number: 6,
-        BOS_sequence_number: 30,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(24),
-        short_abbreviation: Some("Jos"),
-        SBL_abbreviation: Some("Josh"),
-        OSIS_abbreviation: Some("Josh"),
-        Sword_abbreviation: Some("Josh"),
-        CCEL_number: Some(6),
-        USFM_abbreviation: Some("Jos"),
-        USFM_number_str: Some("06"),
-        USX_number_str: Some("006"),
-        Unbound_Code: Some("06O"),
-        Bibledit_number: Some(6),
-        Logos_number: Some(6),
-        Logos_abbreviation: Some("Jos"),
-        NET_Bible_abbreviation: Some("Jos"),
-        Drupal_Bible_abbreviation: Some("Jos"),
-        Bible_Works_abbreviation: Some("JOS"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["JS","JSH"],
-        possible_alternative_books_codes: &["JSA"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "JOS,JOSH,JOSHUA,JOSHUA SO,JOSHUA SON,JOSHUA SON O,JOSHUA SON OF,JOSHUA SON OF N,JOSHUA SON OF NU,JOSHUA SON OF NUN,JOSHUAS,JOSHUASON,JOSHUASONO,JOSHUASONOF,JOSHUASONOFN,JOSHUASONOFNU,JOSHUASONOFNUN,JS,JSH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "שֹׁפְטִים",
-        book_name_English_guide: "Judges",
-        BOS_book_code: "JDG",
-        BOS_reference_number: 7,
-        BOS_sequence_number: 32,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(21),
-        short_abbreviation: Some("Jdg"),
-        SBL_abbreviation: Some("Judg"),
-        OSIS_abbreviation: Some("Judg"),
-        Sword_abbreviation: Some("Judg"),
-        CCEL_number: Some(7),
-        USFM_abbreviation: Some("Jdg"),
-        USFM_number_str: Some("07"),
-        USX_number_str: Some("007"),
-        Unbound_Code: Some("07O"),
-        Bibledit_number: Some(7),
-        Logos_number: Some(7),
-        Logos_abbreviation: Some("Jdg"),
-        NET_Bible_abbreviation: Some("Jdg"),
-        Drupal_Bible_abbreviation: Some("Jug"),
-        Bible_Works_abbreviation: Some("JDG"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["JG","JDGS"],
-        possible_alternative_books_codes: &["JGB"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "JDG,JDGS,JG,JUDG,JUDGES,JUG",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "רוּת",
-        book_name_English_guide: "Ruth",
-        BOS_book_code: "RUT",
-        BOS_reference_number: 8,
-        BOS_sequence_number: 34,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(4),
-        short_abbreviation: Some("Ru"),
-        SBL_abbreviation: Some("Ruth"),
-        OSIS_abbreviation: Some("Ruth"),
-        Sword_abbreviation: Some("Ruth"),
-        CCEL_number: Some(8),
-        USFM_abbreviation: Some("Rut"),
-        USFM_number_str: Some("08"),
-        USX_number_str: Some("008"),
-        Unbound_Code: Some("08O"),
-        Bibledit_number: Some(8),
-        Logos_number: Some(8),
-        Logos_abbreviation: Some("Ru"),
-        NET_Bible_abbreviation: Some("Rut"),
-        Drupal_Bible_abbreviation: Some("Rut"),
-        Bible_Works_abbreviation: Some("RUT"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["RTH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "RT,RTH,RU,RUT,RUTH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "שְׁמוּאֵל א",
-        book_name_English_guide: "1 Samuel / 1 Kingdoms / 1 Kings",
-        BOS_book_code: "SA1",
-        BOS_reference_number: 9,
-        BOS_sequence_number: 38,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(31),
-        short_abbreviation: Some("1Sa"),
-        SBL_abbreviation: Some("1 Sam"),
-        OSIS_abbreviation: Some("1Sam"),
-        Sword_abbreviation: Some("1Sam"),
-        CCEL_number: Some(9),
-        USFM_abbreviation: Some("1Sa"),
-        USFM_number_str: Some("09"),
-        USX_number_str: Some("009"),
-        Unbound_Code: Some("09O"),
-        Bibledit_number: Some(9),
-        Logos_number: Some(9),
-        Logos_abbreviation: Some("1Sa"),
-        NET_Bible_abbreviation: Some("1Sa"),
-        Drupal_Bible_abbreviation: Some("1Sm"),
-        Bible_Works_abbreviation: Some("1SA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["1 SML","1 KNGDMS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "1 KINGD,1 KINGDO,1 KINGDOM,1 KINGDOMS,1 KNGD,1 KNGDM,1 KNGDMS,1 S,1 SA,1 SAM,1 SAMU,1 SAMUE,1 SAMUEL,1 SM,1 SML,1KINGD,1KINGDO,1KINGDOM,1KINGDOMS,1KNGD,1KNGDM,1KNGDMS,1S,1SA,1SAM,1SAMU,1SAMUE,1SAMUEL,1SM,1SML,SA1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "שְׁמוּאֵל ב",
-        book_name_English_guide: "2 Samuel / 2 Kingdoms / 2 Kings",
-        BOS_book_code: "SA2",
-        BOS_reference_number: 10,
-        BOS_sequence_number: 40,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(24),
-        short_abbreviation: Some("2Sa"),
-        SBL_abbreviation: Some("2 Sam"),
-        OSIS_abbreviation: Some("2Sam"),
-        Sword_abbreviation: Some("2Sam"),
-        CCEL_number: Some(10),
-        USFM_abbreviation: Some("2Sa"),
-        USFM_number_str: Some("10"),
-        USX_number_str: Some("010"),
-        Unbound_Code: Some("10O"),
-        Bibledit_number: Some(10),
-        Logos_number: Some(10),
-        Logos_abbreviation: Some("2Sa"),
-        NET_Bible_abbreviation: Some("2Sa"),
-        Drupal_Bible_abbreviation: Some("2Sm"),
-        Bible_Works_abbreviation: Some("2SA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["2 SML","2 KNGDMS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "2 KINGD,2 KINGDO,2 KINGDOM,2 KINGDOMS,2 KNGD,2 KNGDM,2 KNGDMS,2 S,2 SA,2 SAM,2 SAMU,2 SAMUE,2 SAMUEL,2 SM,2 SML,2KINGD,2KINGDO,2KINGDOM,2KINGDOMS,2KNGD,2KNGDM,2KNGDMS,2S,2SA,2SAM,2SAMU,2SAMUE,2SAMUEL,2SM,2SML,SA2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "מְלָכִים א",
-        book_name_English_guide: "1 Kings / 3 Kingdoms / 3 Kings",
-        BOS_book_code: "KI1",
-        BOS_reference_number: 11,
-        BOS_sequence_number: 44,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(22),
-        short_abbreviation: Some("1Ki"),
-        SBL_abbreviation: Some("1 Kgs"),
-        OSIS_abbreviation: Some("1Kgs"),
-        Sword_abbreviation: Some("1Kgs"),
-        CCEL_number: Some(11),
-        USFM_abbreviation: Some("1Ki"),
-        USFM_number_str: Some("11"),
-        USX_number_str: Some("011"),
-        Unbound_Code: Some("11O"),
-        Bibledit_number: Some(11),
-        Logos_number: Some(11),
-        Logos_abbreviation: Some("1Ki"),
-        NET_Bible_abbreviation: Some("1Ki"),
-        Drupal_Bible_abbreviation: Some("1Kg"),
-        Bible_Works_abbreviation: Some("1KI"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["1 KINGS","1 KING","1 KIN","1 KNGS","3 KNGDMS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "1 KG,1 KGS,1 KIN,1 KING,1 KINGS,1 KNGS,1KG,1KGS,1KI,1KIN,1KING,1KINGS,1KNGS,3 K,3 KI,3 KIN,3 KING,3 KINGD,3 KINGDO,3 KINGDOM,3 KINGDOMS,3 KINGS,3 KN,3 KNG,3 KNGD,3 KNGDM,3 KNGDMS,3K,3KI,3KIN,3KING,3KINGD,3KINGDO,3KINGDOM,3KINGDOMS,3KINGS,3KN,3KNG,3KNGD,3KNGDM,3KNGDMS,KI1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "מְלָכִים ב",
-        book_name_English_guide: "2 Kings / 4 Kingdoms / 4 Kings",
-        BOS_book_code: "KI2",
-        BOS_reference_number: 12,
-        BOS_sequence_number: 46,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(25),
-        short_abbreviation: Some("2Ki"),
-        SBL_abbreviation: Some("2 Kgs"),
-        OSIS_abbreviation: Some("2Kgs"),
-        Sword_abbreviation: Some("2Kgs"),
-        CCEL_number: Some(12),
-        USFM_abbreviation: Some("2Ki"),
-        USFM_number_str: Some("12"),
-        USX_number_str: Some("012"),
-        Unbound_Code: Some("12O"),
-        Bibledit_number: Some(12),
-        Logos_number: Some(12),
-        Logos_abbreviation: Some("2Ki"),
-        NET_Bible_abbreviation: Some("2Ki"),
-        Drupal_Bible_abbreviation: Some("2Kg"),
-        Bible_Works_abbreviation: Some("2KI"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["2 KINGS","2 KING","2 KIN","2 KNGS","4 KNGDMS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "2 KG,2 KGS,2 KIN,2 KING,2 KINGS,2 KNGS,2KG,2KGS,2KI,2KIN,2KING,2KINGS,2KNGS,4 K,4 KI,4 KIN,4 KING,4 KINGD,4 KINGDO,4 KINGDOM,4 KINGDOMS,4 KINGS,4 KN,4 KNG,4 KNGD,4 KNGDM,4 KNGDMS,4K,4KI,4KIN,4KING,4KINGD,4KINGDO,4KINGDOM,4KINGDOMS,4KINGS,4KN,4KNG,4KNGD,4KNGDM,4KNGDMS,KI2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "דִּבְרֵי הַיָּמִים א",
-        book_name_English_guide: "1 Chronicles / 1 Paralipomenon",
-        BOS_book_code: "CH1",
-        BOS_reference_number: 13,
-        BOS_sequence_number: 50,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(29),
-        short_abbreviation: Some("1Ch"),
-        SBL_abbreviation: Some("1 Chr"),
-        OSIS_abbreviation: Some("1Chr"),
-        Sword_abbreviation: Some("1Chr"),
-        CCEL_number: Some(13),
-        USFM_abbreviation: Some("1Ch"),
-        USFM_number_str: Some("13"),
-        USX_number_str: Some("013"),
-        Unbound_Code: Some("13O"),
-        Bibledit_number: Some(13),
-        Logos_number: Some(13),
-        Logos_abbreviation: Some("1Ch"),
-        NET_Bible_abbreviation: Some("1Ch"),
-        Drupal_Bible_abbreviation: Some("1Ch"),
-        Bible_Works_abbreviation: Some("1CH"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["1 CHRN"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "1 CH,1 CHR,1 CHRN,1 CHRO,1 CHRON,1 CHRONI,1 CHRONIC,1 CHRONICL,1 CHRONICLE,1 CHRONICLES,1 PA,1 PAR,1 PARA,1 PARAL,1 PARALI,1 PARALIP,1 PARALIPO,1 PARALIPOM,1 PARALIPOME,1 PARALIPOMEN,1 PARALIPOMENO,1 PARALIPOMENON,1CH,1CHR,1CHRN,1CHRO,1CHRON,1CHRONI,1CHRONIC,1CHRONICL,1CHRONICLE,1CHRONICLES,1PA,1PAR,1PARA,1PARAL,1PARALI,1PARALIP,1PARALIPO,1PARALIPOM,1PARALIPOME,1PARALIPOMEN,1PARALIPOMENO,1PARALIPOMENON,CH1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "דִּבְרֵי הַיָּמִים ב",
-        book_name_English_guide: "2 Chronicles / 2 Paralipomenon",
-        BOS_book_code: "CH2",
-        BOS_reference_number: 14,
-        BOS_sequence_number: 52,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([36,37]),
-        short_abbreviation: Some("2Ch"),
-        SBL_abbreviation: Some("2 Chr"),
-        OSIS_abbreviation: Some("2Chr"),
-        Sword_abbreviation: Some("2Chr"),
-        CCEL_number: Some(14),
-        USFM_abbreviation: Some("2Ch"),
-        USFM_number_str: Some("14"),
-        USX_number_str: Some("014"),
-        Unbound_Code: Some("14O"),
-        Bibledit_number: Some(14),
-        Logos_number: Some(14),
-        Logos_abbreviation: Some("2Ch"),
-        NET_Bible_abbreviation: Some("2Ch"),
-        Drupal_Bible_abbreviation: Some("2Ch"),
-        Bible_Works_abbreviation: Some("2CH"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["2 CHRN"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "2 CH,2 CHR,2 CHRN,2 CHRO,2 CHRON,2 CHRONI,2 CHRONIC,2 CHRONICL,2 CHRONICLE,2 CHRONICLES,2 PA,2 PAR,2 PARA,2 PARAL,2 PARALI,2 PARALIP,2 PARALIPO,2 PARALIPOM,2 PARALIPOME,2 PARALIPOMEN,2 PARALIPOMENO,2 PARALIPOMENON,2CH,2CHR,2CHRN,2CHRO,2CHRON,2CHRONI,2CHRONIC,2CHRONICL,2CHRONICLE,2CHRONICLES,2PA,2PAR,2PARA,2PARAL,2PARALI,2PARALIP,2PARALIPO,2PARALIPOM,2PARALIPOME,2PARALIPOMEN,2PARALIPOMENO,2PARALIPOMENON,CH2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "עֶזְרָא",
-        book_name_English_guide: "Ezra / 1 Ezra / 1 Esdras",
-        BOS_book_code: "EZR",
-        BOS_reference_number: 15,
-        BOS_sequence_number: 60,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(10),
-        short_abbreviation: Some("Ezr"),
-        SBL_abbreviation: Some("Ezra"),
-        OSIS_abbreviation: Some("Ezra"),
-        Sword_abbreviation: Some("Ezra"),
-        CCEL_number: Some(15),
-        USFM_abbreviation: Some("Ezr"),
-        USFM_number_str: Some("15"),
-        USX_number_str: Some("015"),
-        Unbound_Code: Some("15O"),
-        Bibledit_number: Some(15),
-        Logos_number: Some(15),
-        Logos_abbreviation: Some("Ezr"),
-        NET_Bible_abbreviation: Some("Ezr"),
-        Drupal_Bible_abbreviation: Some("Ezr"),
-        Bible_Works_abbreviation: Some("EZR"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["EZRA"],
-        possible_alternative_books_codes: &["EZN"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "EZR,EZRA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "נְחֶמְיָה",
-        book_name_English_guide: "Nehemiah / 2 Ezra / 2 Esdras",
-        BOS_book_code: "NEH",
-        BOS_reference_number: 16,
-        BOS_sequence_number: 70,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(13),
-        short_abbreviation: Some("Ne"),
-        SBL_abbreviation: Some("Neh"),
-        OSIS_abbreviation: Some("Neh"),
-        Sword_abbreviation: Some("Neh"),
-        CCEL_number: Some(16),
-        USFM_abbreviation: Some("Neh"),
-        USFM_number_str: Some("16"),
-        USX_number_str: Some("016"),
-        Unbound_Code: Some("16O"),
-        Bibledit_number: Some(16),
-        Logos_number: Some(16),
-        Logos_abbreviation: Some("Ne"),
-        NET_Bible_abbreviation: Some("Neh"),
-        Drupal_Bible_abbreviation: Some("Neh"),
-        Bible_Works_abbreviation: Some("NEH"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["NHMH"],
-        possible_alternative_books_codes: &["EZN"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "NE,NEH,NEHE,NEHEM,NEHEMI,NEHEMIA,NEHEMIAH,NHMH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "אֶסְתֵר",
-        book_name_English_guide: "Esther",
-        BOS_book_code: "EST",
-        BOS_reference_number: 17,
-        BOS_sequence_number: 80,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(10),
-        short_abbreviation: Some("Es"),
-        SBL_abbreviation: Some("Esth"),
-        OSIS_abbreviation: Some("Esth"),
-        Sword_abbreviation: Some("Esth"),
-        CCEL_number: Some(17),
-        USFM_abbreviation: Some("Est"),
-        USFM_number_str: Some("17"),
-        USX_number_str: Some("017"),
-        Unbound_Code: Some("17O"),
-        Bibledit_number: Some(17),
-        Logos_number: Some(17),
-        Logos_abbreviation: Some("Es"),
-        NET_Bible_abbreviation: Some("Est"),
-        Drupal_Bible_abbreviation: Some("Est"),
-        Bible_Works_abbreviation: Some("EST"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["ESTHR","ESTER","ESTR"],
-        possible_alternative_books_codes: &["ESG","ESA","ESC"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "ES,EST,ESTE,ESTER,ESTH,ESTHER,ESTHR,ESTR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "אִיּוֹב",
-        book_name_English_guide: "Job",
-        BOS_book_code: "JOB",
-        BOS_reference_number: 18,
-        BOS_sequence_number: 90,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(42),
-        short_abbreviation: Some("Jb"),
-        SBL_abbreviation: Some("Job"),
-        OSIS_abbreviation: Some("Job"),
-        Sword_abbreviation: Some("Job"),
-        CCEL_number: Some(18),
-        USFM_abbreviation: Some("Job"),
-        USFM_number_str: Some("18"),
-        USX_number_str: Some("018"),
-        Unbound_Code: Some("18O"),
-        Bibledit_number: Some(18),
-        Logos_number: Some(18),
-        Logos_abbreviation: Some("Job"),
-        NET_Bible_abbreviation: Some("Job"),
-        Drupal_Bible_abbreviation: Some("Job"),
-        Bible_Works_abbreviation: Some("JOB"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Poetry-Wisdom"),
-        all_English_derived_abbreviations: "JB,JOB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "תְהִלִּים",
-        book_name_English_guide: "Psalms / Gallicanum / Psalms A",
-        BOS_book_code: "PSA",
-        BOS_reference_number: 19,
-        BOS_sequence_number: 100,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([150,151]),
-        short_abbreviation: Some("Ps"),
-        SBL_abbreviation: Some("Ps"),
-        OSIS_abbreviation: Some("Ps"),
-        Sword_abbreviation: Some("Ps"),
-        CCEL_number: Some(19),
-        USFM_abbreviation: Some("Psa"),
-        USFM_number_str: Some("19"),
-        USX_number_str: Some("019"),
-        Unbound_Code: Some("19O"),
-        Bibledit_number: Some(19),
-        Logos_number: Some(19),
-        Logos_abbreviation: Some("Ps"),
-        NET_Bible_abbreviation: Some("Psa"),
-        Drupal_Bible_abbreviation: Some("Ps"),
-        Bible_Works_abbreviation: Some("PSA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["PSAL","PSALM","PSLMS"],
-        possible_alternative_books_codes: &["PB1","PB2","PB3","PB4","PB5"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Poetry-Wisdom"),
-        all_English_derived_abbreviations: "GALL,GALLI,GALLIC,GALLICA,GALLICAN,GALLICANU,GALLICANUM,PS,PSA,PSAL,PSALM,PSALMS,PSALMS A,PSALMSA,PSL,PSLM,PSLMS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "מִשְׁלֵי",
-        book_name_English_guide: "Proverbs",
-        BOS_book_code: "PRO",
-        BOS_reference_number: 20,
-        BOS_sequence_number: 110,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(31),
-        short_abbreviation: Some("Pr"),
-        SBL_abbreviation: Some("Prov"),
-        OSIS_abbreviation: Some("Prov"),
-        Sword_abbreviation: Some("Prov"),
-        CCEL_number: Some(20),
-        USFM_abbreviation: Some("Pro"),
-        USFM_number_str: Some("20"),
-        USX_number_str: Some("020"),
-        Unbound_Code: Some("20O"),
-        Bibledit_number: Some(20),
-        Logos_number: Some(20),
-        Logos_abbreviation: Some("Pr"),
-        NET_Bible_abbreviation: Some("Pro"),
-        Drupal_Bible_abbreviation: Some("Pro"),
-        Bible_Works_abbreviation: Some("PRO"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["PRVB"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Poetry-Wisdom"),
-        all_English_derived_abbreviations: "PR,PRO,PROV,PROVE,PROVER,PROVERB,PROVERBS,PRV,PRVB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "קֹהֶלֶת",
-        book_name_English_guide: "Ecclesiastes / Qoholeth",
-        BOS_book_code: "ECC",
-        BOS_reference_number: 21,
-        BOS_sequence_number: 120,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(12),
-        short_abbreviation: Some("Ec"),
-        SBL_abbreviation: Some("Eccl"),
-        OSIS_abbreviation: Some("Eccl"),
-        Sword_abbreviation: Some("Eccl"),
-        CCEL_number: Some(21),
-        USFM_abbreviation: Some("Ecc"),
-        USFM_number_str: Some("21"),
-        USX_number_str: Some("021"),
-        Unbound_Code: Some("21O"),
-        Bibledit_number: Some(21),
-        Logos_number: Some(21),
-        Logos_abbreviation: Some("Ec"),
-        NET_Bible_abbreviation: Some("Ecc"),
-        Drupal_Bible_abbreviation: Some("Ecc"),
-        Bible_Works_abbreviation: Some("ECC"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["ECCLE","ECCLS","ECCLES","ECCLST","ECCLESS","ECCLESI","ECCLSTS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Poetry-Wisdom"),
-        all_English_derived_abbreviations: "EC,ECC,ECCL,ECCLE,ECCLES,ECCLESI,ECCLESIASTE,ECCLESIASTES,ECCLESS,ECCLS,ECCLST,ECCLSTS,QO,QOH,QOHO,QOHOL,QOHOLE,QOHOLET,QOHOLETH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "שִׁיר הַשִּׁירִים",
-        book_name_English_guide: "Song of Solomon / Song of Songs / Canticles / Canticle of Canticles",
-        BOS_book_code: "SNG",
-        BOS_reference_number: 22,
-        BOS_sequence_number: 130,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(8),
-        short_abbreviation: Some("So"),
-        SBL_abbreviation: Some("Song"),
-        OSIS_abbreviation: Some("Song"),
-        Sword_abbreviation: Some("Song"),
-        CCEL_number: Some(22),
-        USFM_abbreviation: Some("Sng"),
-        USFM_number_str: Some("22"),
-        USX_number_str: Some("022"),
-        Unbound_Code: Some("22O"),
-        Bibledit_number: Some(22),
-        Logos_number: Some(22),
-        Logos_abbreviation: Some("So"),
-        NET_Bible_abbreviation: Some("Sos"),
-        Drupal_Bible_abbreviation: Some("Son"),
-        Bible_Works_abbreviation: Some("SOL"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["SL","SS","CANT","CNT"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Poetry-Wisdom"),
-        all_English_derived_abbreviations: "CAN,CANT,CANTI,CANTIC,CANTICL,CANTICLE,CANTICLE O,CANTICLE OF,CANTICLE OF C,CANTICLE OF CA,CANTICLE OF CAN,CANTICLE OF CANT,CANTICLE OF CANTI,CANTICLE OF CANTIC,CANTICLE OF CANTICL,CANTICLE OF CANTICLE,CANTICLE OF CANTICLES,CANTICLEO,CANTICLEOF,CANTICLEOFC,CANTICLEOFCA,CANTICLEOFCAN,CANTICLEOFCANT,CANTICLEOFCANTI,CANTICLEOFCANTIC,CANTICLEOFCANTICL,CANTICLEOFCANTICLE,CANTICLEOFCANTICLES,CANTICLES,CNT,SL,SN,SNG,SO,SOL,SON,SONG,SONG OF S,SONG OF SO,SONG OF SOL,SONG OF SOLO,SONG OF SOLOM,SONG OF SOLOMO,SONG OF SOLOMON,SONG OF SON,SONG OF SONG,SONG OF SONGS,SONGOFS,SONGOFSO,SONGOFSOL,SONGOFSOLO,SONGOFSOLOM,SONGOFSOLOMO,SONGOFSOLOMON,SONGOFSON,SONGOFSONG,SONGOFSONGS,SOS,SS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "יְשַׁעְיָהוּ",
-        book_name_English_guide: "Isaiah",
-        BOS_book_code: "ISA",
-        BOS_reference_number: 23,
-        BOS_sequence_number: 132,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(66),
-        short_abbreviation: Some("Is"),
-        SBL_abbreviation: Some("Isa"),
-        OSIS_abbreviation: Some("Isa"),
-        Sword_abbreviation: Some("Isa"),
-        CCEL_number: Some(23),
-        USFM_abbreviation: Some("Isa"),
-        USFM_number_str: Some("23"),
-        USX_number_str: Some("023"),
-        Unbound_Code: Some("23O"),
-        Bibledit_number: Some(23),
-        Logos_number: Some(23),
-        Logos_abbreviation: Some("Is"),
-        NET_Bible_abbreviation: Some("Isa"),
-        Drupal_Bible_abbreviation: Some("Isa"),
-        Bible_Works_abbreviation: Some("ISA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "IS,ISA,ISAI,ISAIA,ISAIAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "יִרְמְיָהוּ",
-        book_name_English_guide: "Jeremiah",
-        BOS_book_code: "JER",
-        BOS_reference_number: 24,
-        BOS_sequence_number: 134,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(52),
-        short_abbreviation: Some("Je"),
-        SBL_abbreviation: Some("Jer"),
-        OSIS_abbreviation: Some("Jer"),
-        Sword_abbreviation: Some("Jer"),
-        CCEL_number: Some(24),
-        USFM_abbreviation: Some("Jer"),
-        USFM_number_str: Some("24"),
-        USX_number_str: Some("024"),
-        Unbound_Code: Some("24O"),
-        Bibledit_number: Some(24),
-        Logos_number: Some(24),
-        Logos_abbreviation: Some("Je"),
-        NET_Bible_abbreviation: Some("Jer"),
-        Drupal_Bible_abbreviation: Some("Jer"),
-        Bible_Works_abbreviation: Some("JER"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["JRMH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "JE,JER,JERE,JEREM,JEREMI,JEREMIA,JEREMIAH,JRM,JRMH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "אֵיכָה",
-        book_name_English_guide: "Lamentations / Lamentations of Jeremiah",
-        BOS_book_code: "LAM",
-        BOS_reference_number: 25,
-        BOS_sequence_number: 136,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(5),
-        short_abbreviation: Some("La"),
-        SBL_abbreviation: Some("Lam"),
-        OSIS_abbreviation: Some("Lam"),
-        Sword_abbreviation: Some("Lam"),
-        CCEL_number: Some(25),
-        USFM_abbreviation: Some("Lam"),
-        USFM_number_str: Some("25"),
-        USX_number_str: Some("025"),
-        Unbound_Code: Some("25O"),
-        Bibledit_number: Some(25),
-        Logos_number: Some(25),
-        Logos_abbreviation: Some("La"),
-        NET_Bible_abbreviation: Some("Lam"),
-        Drupal_Bible_abbreviation: Some("Lam"),
-        Bible_Works_abbreviation: Some("LAM"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["LMTTS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Poetry-Wisdom"),
-        all_English_derived_abbreviations: "LA,LAM,LAME,LAMEN,LAMENT,LAMENTA,LAMENTAT,LAMENTATI,LAMENTATIO,LAMENTATION,LAMENTATIONS,LAMENTATIONS O,LAMENTATIONS OF,LAMENTATIONS OF J,LAMENTATIONS OF JE,LAMENTATIONS OF JER,LAMENTATIONS OF JERE,LAMENTATIONS OF JEREM,LAMENTATIONS OF JEREMI,LAMENTATIONS OF JEREMIA,LAMENTATIONS OF JEREMIAH,LAMENTATIONSO,LAMENTATIONSOF,LAMENTATIONSOFJ,LAMENTATIONSOFJE,LAMENTATIONSOFJER,LAMENTATIONSOFJERE,LAMENTATIONSOFJEREM,LAMENTATIONSOFJEREMI,LAMENTATIONSOFJEREMIA,LAMENTATIONSOFJEREMIAH,LM,LMT,LMTT,LMTTS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "יְחֶזְקֵאל",
-        book_name_English_guide: "Ezekiel",
-        BOS_book_code: "EZE",
-        BOS_reference_number: 26,
-        BOS_sequence_number: 138,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(48),
-        short_abbreviation: Some("Eze"),
-        SBL_abbreviation: Some("Ezek"),
-        OSIS_abbreviation: Some("Ezek"),
-        Sword_abbreviation: Some("Ezek"),
-        CCEL_number: Some(26),
-        USFM_abbreviation: Some("Ezk"),
-        USFM_number_str: Some("26"),
-        USX_number_str: Some("026"),
-        Unbound_Code: Some("26O"),
-        Bibledit_number: Some(26),
-        Logos_number: Some(26),
-        Logos_abbreviation: Some("Eze"),
-        NET_Bible_abbreviation: Some("Eze"),
-        Drupal_Bible_abbreviation: Some("Eze"),
-        Bible_Works_abbreviation: Some("EZE"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["EZKL"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "EZE,EZEK,EZEKIEL,EZK,EZKL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "דָּנִיֵּאל",
-        book_name_English_guide: "Daniel",
-        BOS_book_code: "DAN",
-        BOS_reference_number: 27,
-        BOS_sequence_number: 140,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([12,14]),
-        short_abbreviation: Some("Da"),
-        SBL_abbreviation: Some("Dan"),
-        OSIS_abbreviation: Some("Dan"),
-        Sword_abbreviation: Some("Dan"),
-        CCEL_number: Some(27),
-        USFM_abbreviation: Some("Dan"),
-        USFM_number_str: Some("27"),
-        USX_number_str: Some("027"),
-        Unbound_Code: Some("27O"),
-        Bibledit_number: Some(27),
-        Logos_number: Some(27),
-        Logos_abbreviation: Some("Da"),
-        NET_Bible_abbreviation: Some("Dan"),
-        Drupal_Bible_abbreviation: Some("Dan"),
-        Bible_Works_abbreviation: Some("DAN"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["DN","DNL"],
-        possible_alternative_books_codes: &["DNG","DNA","DNC","DNT"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "DA,DAN,DANIEL,DN,DNL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "הוֹשֵׁעַ",
-        book_name_English_guide: "Hosea",
-        BOS_book_code: "HOS",
-        BOS_reference_number: 28,
-        BOS_sequence_number: 152,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: Some("Ho"),
-        SBL_abbreviation: Some("Hos"),
-        OSIS_abbreviation: Some("Hos"),
-        Sword_abbreviation: Some("Hos"),
-        CCEL_number: Some(28),
-        USFM_abbreviation: Some("Hos"),
-        USFM_number_str: Some("28"),
-        USX_number_str: Some("028"),
-        Unbound_Code: Some("28O"),
-        Bibledit_number: Some(28),
-        Logos_number: Some(28),
-        Logos_abbreviation: Some("Ho"),
-        NET_Bible_abbreviation: Some("Hos"),
-        Drupal_Bible_abbreviation: Some("Hos"),
-        Bible_Works_abbreviation: Some("HOS"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["HS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "HO,HOS,HOSE,HOSEA,HS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "יוֹאֵל",
-        book_name_English_guide: "Joel",
-        BOS_book_code: "JOL",
-        BOS_reference_number: 29,
-        BOS_sequence_number: 154,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([3,4]),
-        short_abbreviation: Some("Jol"),
-        SBL_abbreviation: Some("Joel"),
-        OSIS_abbreviation: Some("Joel"),
-        Sword_abbreviation: Some("Joel"),
-        CCEL_number: Some(29),
-        USFM_abbreviation: Some("Jol"),
-        USFM_number_str: Some("29"),
-        USX_number_str: Some("029"),
-        Unbound_Code: Some("29O"),
-        Bibledit_number: Some(29),
-        Logos_number: Some(29),
-        Logos_abbreviation: Some("Joe"),
-        NET_Bible_abbreviation: Some("Joe"),
-        Drupal_Bible_abbreviation: Some("Joe"),
-        Bible_Works_abbreviation: Some("JOE"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["JL"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "JL,JOE,JOEL,JOL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "עָמוֹס",
-        book_name_English_guide: "Amos",
-        BOS_book_code: "AMO",
-        BOS_reference_number: 30,
-        BOS_sequence_number: 156,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(9),
-        short_abbreviation: Some("Am"),
-        SBL_abbreviation: Some("Amos"),
-        OSIS_abbreviation: Some("Amos"),
-        Sword_abbreviation: Some("Amos"),
-        CCEL_number: Some(30),
-        USFM_abbreviation: Some("Amo"),
-        USFM_number_str: Some("30"),
-        USX_number_str: Some("030"),
-        Unbound_Code: Some("30O"),
-        Bibledit_number: Some(30),
-        Logos_number: Some(30),
-        Logos_abbreviation: Some("Am"),
-        NET_Bible_abbreviation: Some("Amo"),
-        Drupal_Bible_abbreviation: Some("Amo"),
-        Bible_Works_abbreviation: Some("AMO"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["AMS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "AM,AMO,AMOS,AMS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "עֹבַדְיָה",
-        book_name_English_guide: "Obadiah",
-        BOS_book_code: "OBA",
-        BOS_reference_number: 31,
-        BOS_sequence_number: 158,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("Ob"),
-        SBL_abbreviation: Some("Obad"),
-        OSIS_abbreviation: Some("Obad"),
-        Sword_abbreviation: Some("Obad"),
-        CCEL_number: Some(31),
-        USFM_abbreviation: Some("Oba"),
-        USFM_number_str: Some("31"),
-        USX_number_str: Some("031"),
-        Unbound_Code: Some("31O"),
-        Bibledit_number: Some(31),
-        Logos_number: Some(31),
-        Logos_abbreviation: Some("Ob"),
-        NET_Bible_abbreviation: Some("Oba"),
-        Drupal_Bible_abbreviation: Some("Oba"),
-        Bible_Works_abbreviation: Some("OBA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["OBDH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "OB,OBA,OBAD,OBADI,OBADIA,OBADIAH,OBD,OBDH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "יוֹנָה",
-        book_name_English_guide: "Jonah",
-        BOS_book_code: "JNA",
-        BOS_reference_number: 32,
-        BOS_sequence_number: 160,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(4),
-        short_abbreviation: Some("Jon"),
-        SBL_abbreviation: Some("Jonah"),
-        OSIS_abbreviation: Some("Jonah"),
-        Sword_abbreviation: Some("Jonah"),
-        CCEL_number: Some(32),
-        USFM_abbreviation: Some("Jon"),
-        USFM_number_str: Some("32"),
-        USX_number_str: Some("032"),
-        Unbound_Code: Some("32O"),
-        Bibledit_number: Some(32),
-        Logos_number: Some(32),
-        Logos_abbreviation: Some("Jon"),
-        NET_Bible_abbreviation: Some("Jon"),
-        Drupal_Bible_abbreviation: Some("Jon"),
-        Bible_Works_abbreviation: Some("JON"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["JNH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "JNA,JNH,JON,JONA,JONAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "מִיכָה",
-        book_name_English_guide: "Micah",
-        BOS_book_code: "MIC",
-        BOS_reference_number: 33,
-        BOS_sequence_number: 162,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(7),
-        short_abbreviation: Some("Mi"),
-        SBL_abbreviation: Some("Mic"),
-        OSIS_abbreviation: Some("Mic"),
-        Sword_abbreviation: Some("Mic"),
-        CCEL_number: Some(33),
-        USFM_abbreviation: Some("Mic"),
-        USFM_number_str: Some("33"),
-        USX_number_str: Some("033"),
-        Unbound_Code: Some("33O"),
-        Bibledit_number: Some(33),
-        Logos_number: Some(33),
-        Logos_abbreviation: Some("Mic"),
-        NET_Bible_abbreviation: Some("Mic"),
-        Drupal_Bible_abbreviation: Some("Mic"),
-        Bible_Works_abbreviation: Some("MIC"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["MCH","MICH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "MC,MCH,MI,MIC,MICA,MICAH,MICH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "נַחוּם",
-        book_name_English_guide: "Nahum",
-        BOS_book_code: "NAH",
-        BOS_reference_number: 34,
-        BOS_sequence_number: 164,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(3),
-        short_abbreviation: Some("Na"),
-        SBL_abbreviation: Some("Nah"),
-        OSIS_abbreviation: Some("Nah"),
-        Sword_abbreviation: Some("Nah"),
-        CCEL_number: Some(34),
-        USFM_abbreviation: Some("Nam"),
-        USFM_number_str: Some("34"),
-        USX_number_str: Some("034"),
-        Unbound_Code: Some("34O"),
-        Bibledit_number: Some(34),
-        Logos_number: Some(34),
-        Logos_abbreviation: Some("Na"),
-        NET_Bible_abbreviation: Some("Nah"),
-        Drupal_Bible_abbreviation: Some("Nah"),
-        Bible_Works_abbreviation: Some("NAH"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["NHM"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "NA,NAH,NAHU,NAHUM,NAM,NHM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "חֲבַקּוּק",
-        book_name_English_guide: "Habakkuk",
-        BOS_book_code: "HAB",
-        BOS_reference_number: 35,
-        BOS_sequence_number: 166,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(3),
-        short_abbreviation: Some("Hab"),
-        SBL_abbreviation: Some("Hab"),
-        OSIS_abbreviation: Some("Hab"),
-        Sword_abbreviation: Some("Hab"),
-        CCEL_number: Some(35),
-        USFM_abbreviation: Some("Hab"),
-        USFM_number_str: Some("35"),
-        USX_number_str: Some("035"),
-        Unbound_Code: Some("35O"),
-        Bibledit_number: Some(35),
-        Logos_number: Some(35),
-        Logos_abbreviation: Some("Hab"),
-        NET_Bible_abbreviation: Some("Hab"),
-        Drupal_Bible_abbreviation: Some("Hab"),
-        Bible_Works_abbreviation: Some("HAB"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["HBKK"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "HAB,HABA,HABAK,HABAKK,HABAKKU,HABAKKUK,HBK,HBKK",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "צְפַנְיָה",
-        book_name_English_guide: "Zephaniah",
-        BOS_book_code: "ZEP",
-        BOS_reference_number: 36,
-        BOS_sequence_number: 168,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(3),
-        short_abbreviation: Some("Zep"),
-        SBL_abbreviation: Some("Zeph"),
-        OSIS_abbreviation: Some("Zeph"),
-        Sword_abbreviation: Some("Zeph"),
-        CCEL_number: Some(36),
-        USFM_abbreviation: Some("Zep"),
-        USFM_number_str: Some("36"),
-        USX_number_str: Some("036"),
-        Unbound_Code: Some("36O"),
-        Bibledit_number: Some(36),
-        Logos_number: Some(36),
-        Logos_abbreviation: Some("Zep"),
-        NET_Bible_abbreviation: Some("Zep"),
-        Drupal_Bible_abbreviation: Some("Zep"),
-        Bible_Works_abbreviation: Some("ZEP"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["ZPH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "ZEP,ZEPH,ZEPHA,ZEPHAN,ZEPHANI,ZEPHANIA,ZEPHANIAH,ZP,ZPH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "חַגַּי",
-        book_name_English_guide: "Haggai",
-        BOS_book_code: "HAG",
-        BOS_reference_number: 37,
-        BOS_sequence_number: 170,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(2),
-        short_abbreviation: Some("Hag"),
-        SBL_abbreviation: Some("Hag"),
-        OSIS_abbreviation: Some("Hag"),
-        Sword_abbreviation: Some("Hag"),
-        CCEL_number: Some(37),
-        USFM_abbreviation: Some("Hag"),
-        USFM_number_str: Some("37"),
-        USX_number_str: Some("037"),
-        Unbound_Code: Some("37O"),
-        Bibledit_number: Some(37),
-        Logos_number: Some(37),
-        Logos_abbreviation: Some("Hag"),
-        NET_Bible_abbreviation: Some("Hag"),
-        Drupal_Bible_abbreviation: Some("Hag"),
-        Bible_Works_abbreviation: Some("HAG"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["HGG"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "HAG,HAGG,HAGGA,HAGGAI,HG,HGG",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "זְכַרְיָה",
-        book_name_English_guide: "Zechariah",
-        BOS_book_code: "ZEC",
-        BOS_reference_number: 38,
-        BOS_sequence_number: 172,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: Some("Zec"),
-        SBL_abbreviation: Some("Zech"),
-        OSIS_abbreviation: Some("Zech"),
-        Sword_abbreviation: Some("Zech"),
-        CCEL_number: Some(38),
-        USFM_abbreviation: Some("Zec"),
-        USFM_number_str: Some("38"),
-        USX_number_str: Some("038"),
-        Unbound_Code: Some("38O"),
-        Bibledit_number: Some(38),
-        Logos_number: Some(38),
-        Logos_abbreviation: Some("Zec"),
-        NET_Bible_abbreviation: Some("Zec"),
-        Drupal_Bible_abbreviation: Some("Zec"),
-        Bible_Works_abbreviation: Some("ZEC"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["ZCRH"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "ZC,ZCR,ZCRH,ZEC,ZECH,ZECHA,ZECHAR,ZECHARI,ZECHARIA,ZECHARIAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "מַלְאָכִי",
-        book_name_English_guide: "Malachi",
-        BOS_book_code: "MAL",
-        BOS_reference_number: 39,
-        BOS_sequence_number: 174,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([4,3]),
-        short_abbreviation: Some("Mal"),
-        SBL_abbreviation: Some("Mal"),
-        OSIS_abbreviation: Some("Mal"),
-        Sword_abbreviation: Some("Mal"),
-        CCEL_number: Some(39),
-        USFM_abbreviation: Some("Mal"),
-        USFM_number_str: Some("39"),
-        USX_number_str: Some("039"),
-        Unbound_Code: Some("39O"),
-        Bibledit_number: Some(39),
-        Logos_number: Some(39),
-        Logos_abbreviation: Some("Mal"),
-        NET_Bible_abbreviation: Some("Mal"),
-        Drupal_Bible_abbreviation: Some("Mal"),
-        Bible_Works_abbreviation: Some("MAL"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["MLC"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "MAL,MALA,MALAC,MALACH,MALACHI,ML,MLC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Κατὰ Μαθθαῖον",
-        book_name_English_guide: "Matthew",
-        BOS_book_code: "MAT",
-        BOS_reference_number: 40,
-        BOS_sequence_number: 600,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(28),
-        short_abbreviation: Some("Mt"),
-        SBL_abbreviation: Some("Matt"),
-        OSIS_abbreviation: Some("Matt"),
-        Sword_abbreviation: Some("Matt"),
-        CCEL_number: Some(40),
-        USFM_abbreviation: Some("Mat"),
-        USFM_number_str: Some("41"),
-        USX_number_str: Some("040"),
-        Unbound_Code: Some("40N"),
-        Bibledit_number: Some(40),
-        Logos_number: Some(61),
-        Logos_abbreviation: Some("Mt"),
-        NET_Bible_abbreviation: Some("Mat"),
-        Drupal_Bible_abbreviation: Some("Mat"),
-        Bible_Works_abbreviation: Some("MAT"),
-        Byzantine_abbreviation: Some("MT"),
-        possible_alternative_abbreviations: &["MTT","MATHEW"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Gospels"),
-        all_English_derived_abbreviations: "MAT,MATH,MATHE,MATHEW,MATT,MATTH,MATTHE,MATTHEW,MT,MTT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Κατὰ Μᾶρκον",
-        book_name_English_guide: "Mark",
-        BOS_book_code: "MRK",
-        BOS_reference_number: 41,
-        BOS_sequence_number: 602,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: Some("Mk"),
-        SBL_abbreviation: Some("Mark"),
-        OSIS_abbreviation: Some("Mark"),
-        Sword_abbreviation: Some("Mark"),
-        CCEL_number: Some(41),
-        USFM_abbreviation: Some("Mrk"),
-        USFM_number_str: Some("42"),
-        USX_number_str: Some("041"),
-        Unbound_Code: Some("41N"),
-        Bibledit_number: Some(41),
-        Logos_number: Some(62),
-        Logos_abbreviation: Some("Mk"),
-        NET_Bible_abbreviation: Some("Mar"),
-        Drupal_Bible_abbreviation: Some("Mak"),
-        Bible_Works_abbreviation: Some("MAR"),
-        Byzantine_abbreviation: Some("MR"),
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Gospels"),
-        all_English_derived_abbreviations: "MAK,MAR,MARK,MK,MR,MRK",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Κατὰ Λουκᾶν",
-        book_name_English_guide: "Luke",
-        BOS_book_code: "LUK",
-        BOS_reference_number: 42,
-        BOS_sequence_number: 604,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(24),
-        short_abbreviation: Some("Lk"),
-        SBL_abbreviation: Some("Luke"),
-        OSIS_abbreviation: Some("Luke"),
-        Sword_abbreviation: Some("Luke"),
-        CCEL_number: Some(42),
-        USFM_abbreviation: Some("Luk"),
-        USFM_number_str: Some("43"),
-        USX_number_str: Some("042"),
-        Unbound_Code: Some("42N"),
-        Bibledit_number: Some(42),
-        Logos_number: Some(63),
-        Logos_abbreviation: Some("Lk"),
-        NET_Bible_abbreviation: Some("Luk"),
-        Drupal_Bible_abbreviation: Some("Luk"),
-        Bible_Works_abbreviation: Some("LUK"),
-        Byzantine_abbreviation: Some("LU"),
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LKA"],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Gospels"),
-        all_English_derived_abbreviations: "LK,LU,LUK,LUKE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Κατὰ Ἰωάννην",
-        book_name_English_guide: "John",
-        BOS_book_code: "JHN",
-        BOS_reference_number: 43,
-        BOS_sequence_number: 606,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(21),
-        short_abbreviation: Some("Jn"),
-        SBL_abbreviation: Some("John"),
-        OSIS_abbreviation: Some("John"),
-        Sword_abbreviation: Some("John"),
-        CCEL_number: Some(43),
-        USFM_abbreviation: Some("Jhn"),
-        USFM_number_str: Some("44"),
-        USX_number_str: Some("043"),
-        Unbound_Code: Some("43N"),
-        Bibledit_number: Some(43),
-        Logos_number: Some(64),
-        Logos_abbreviation: Some("Jn"),
-        NET_Bible_abbreviation: Some("Joh"),
-        Drupal_Bible_abbreviation: Some("Jhn"),
-        Bible_Works_abbreviation: Some("JOH"),
-        Byzantine_abbreviation: Some("JOH"),
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Gospels"),
-        all_English_derived_abbreviations: "JH,JHN,JN,JOH,JOHN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πράξεις Ἀποστόλων",
-        book_name_English_guide: "Acts / Acts of the Apostles",
-        BOS_book_code: "ACT",
-        BOS_reference_number: 44,
-        BOS_sequence_number: 610,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(28),
-        short_abbreviation: Some("Ac"),
-        SBL_abbreviation: Some("Acts"),
-        OSIS_abbreviation: Some("Acts"),
-        Sword_abbreviation: Some("Acts"),
-        CCEL_number: Some(44),
-        USFM_abbreviation: Some("Act"),
-        USFM_number_str: Some("45"),
-        USX_number_str: Some("044"),
-        Unbound_Code: Some("44N"),
-        Bibledit_number: Some(44),
-        Logos_number: Some(65),
-        Logos_abbreviation: Some("Ac"),
-        NET_Bible_abbreviation: Some("Act"),
-        Drupal_Bible_abbreviation: Some("Act"),
-        Bible_Works_abbreviation: Some("ACT"),
-        Byzantine_abbreviation: Some("AC"),
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LKA"],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Acts"),
-        all_English_derived_abbreviations: "AC,ACT,ACTS,ACTS O,ACTS OF,ACTS OF T,ACTS OF TH,ACTS OF THE,ACTS OF THE A,ACTS OF THE AP,ACTS OF THE APO,ACTS OF THE APOS,ACTS OF THE APOST,ACTS OF THE APOSTL,ACTS OF THE APOSTLE,ACTS OF THE APOSTLES,ACTSO,ACTSOF,ACTSOFT,ACTSOFTH,ACTSOFTHE,ACTSOFTHEA,ACTSOFTHEAP,ACTSOFTHEAPO,ACTSOFTHEAPOS,ACTSOFTHEAPOST,ACTSOFTHEAPOSTL,ACTSOFTHEAPOSTLE,ACTSOFTHEAPOSTLES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Ῥωμαίους",
-        book_name_English_guide: "Romans",
-        BOS_book_code: "ROM",
-        BOS_reference_number: 45,
-        BOS_sequence_number: 612,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: Some("Ro"),
-        SBL_abbreviation: Some("Rom"),
-        OSIS_abbreviation: Some("Rom"),
-        Sword_abbreviation: Some("Rom"),
-        CCEL_number: Some(45),
-        USFM_abbreviation: Some("Rom"),
-        USFM_number_str: Some("46"),
-        USX_number_str: Some("045"),
-        Unbound_Code: Some("45N"),
-        Bibledit_number: Some(45),
-        Logos_number: Some(66),
-        Logos_abbreviation: Some("Ro"),
-        NET_Bible_abbreviation: Some("Rom"),
-        Drupal_Bible_abbreviation: Some("Rom"),
-        Bible_Works_abbreviation: Some("ROM"),
-        Byzantine_abbreviation: Some("RO"),
-        possible_alternative_abbreviations: &["RMNS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "RM,RMN,RMNS,RO,ROM,ROMA,ROMAN,ROMANS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Κορινθίους α",
-        book_name_English_guide: "1 Corinthians",
-        BOS_book_code: "CO1",
-        BOS_reference_number: 46,
-        BOS_sequence_number: 614,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: Some("1Co"),
-        SBL_abbreviation: Some("1 Cor"),
-        OSIS_abbreviation: Some("1Cor"),
-        Sword_abbreviation: Some("1Cor"),
-        CCEL_number: Some(46),
-        USFM_abbreviation: Some("1Co"),
-        USFM_number_str: Some("47"),
-        USX_number_str: Some("046"),
-        Unbound_Code: Some("46N"),
-        Bibledit_number: Some(46),
-        Logos_number: Some(67),
-        Logos_abbreviation: Some("1Co"),
-        NET_Bible_abbreviation: Some("1Co"),
-        Drupal_Bible_abbreviation: Some("1Co"),
-        Bible_Works_abbreviation: Some("1CO"),
-        Byzantine_abbreviation: Some("1CO"),
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "1 CO,1 COR,1 CORI,1 CORIN,1 CORINT,1 CORINTH,1 CORINTHI,1 CORINTHIA,1 CORINTHIAN,1 CORINTHIANS,1CO,1COR,1CORI,1CORIN,1CORINT,1CORINTH,1CORINTHI,1CORINTHIA,1CORINTHIAN,1CORINTHIANS,CO1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Κορινθίους β",
-        book_name_English_guide: "2 Corinthians",
-        BOS_book_code: "CO2",
-        BOS_reference_number: 47,
-        BOS_sequence_number: 616,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(13),
-        short_abbreviation: Some("2Co"),
-        SBL_abbreviation: Some("2 Cor"),
-        OSIS_abbreviation: Some("2Cor"),
-        Sword_abbreviation: Some("2Cor"),
-        CCEL_number: Some(47),
-        USFM_abbreviation: Some("2Co"),
-        USFM_number_str: Some("48"),
-        USX_number_str: Some("047"),
-        Unbound_Code: Some("47N"),
-        Bibledit_number: Some(47),
-        Logos_number: Some(68),
-        Logos_abbreviation: Some("2Co"),
-        NET_Bible_abbreviation: Some("2Co"),
-        Drupal_Bible_abbreviation: Some("2Co"),
-        Bible_Works_abbreviation: Some("2CO"),
-        Byzantine_abbreviation: Some("2CO"),
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "2 CO,2 COR,2 CORI,2 CORIN,2 CORINT,2 CORINTH,2 CORINTHI,2 CORINTHIA,2 CORINTHIAN,2 CORINTHIANS,2CO,2COR,2CORI,2CORIN,2CORINT,2CORINTH,2CORINTHI,2CORINTHIA,2CORINTHIAN,2CORINTHIANS,CO2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Γαλάτας",
-        book_name_English_guide: "Galatians",
-        BOS_book_code: "GAL",
-        BOS_reference_number: 48,
-        BOS_sequence_number: 618,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(6),
-        short_abbreviation: Some("Ga"),
-        SBL_abbreviation: Some("Gal"),
-        OSIS_abbreviation: Some("Gal"),
-        Sword_abbreviation: Some("Gal"),
-        CCEL_number: Some(48),
-        USFM_abbreviation: Some("Gal"),
-        USFM_number_str: Some("49"),
-        USX_number_str: Some("048"),
-        Unbound_Code: Some("48N"),
-        Bibledit_number: Some(48),
-        Logos_number: Some(69),
-        Logos_abbreviation: Some("Ga"),
-        NET_Bible_abbreviation: Some("Gal"),
-        Drupal_Bible_abbreviation: Some("Gal"),
-        Bible_Works_abbreviation: Some("GAL"),
-        Byzantine_abbreviation: Some("GA"),
-        possible_alternative_abbreviations: &["GL","GLT"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "GA,GAL,GALA,GALAT,GALATI,GALATIA,GALATIAN,GALATIANS,GL,GLT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Ἐφεσίους",
-        book_name_English_guide: "Ephesians",
-        BOS_book_code: "EPH",
-        BOS_reference_number: 49,
-        BOS_sequence_number: 620,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(6),
-        short_abbreviation: Some("Eph"),
-        SBL_abbreviation: Some("Eph"),
-        OSIS_abbreviation: Some("Eph"),
-        Sword_abbreviation: Some("Eph"),
-        CCEL_number: Some(49),
-        USFM_abbreviation: Some("Eph"),
-        USFM_number_str: Some("50"),
-        USX_number_str: Some("049"),
-        Unbound_Code: Some("49N"),
-        Bibledit_number: Some(49),
-        Logos_number: Some(70),
-        Logos_abbreviation: Some("Eph"),
-        NET_Bible_abbreviation: Some("Eph"),
-        Drupal_Bible_abbreviation: Some("Eph"),
-        Bible_Works_abbreviation: Some("EPH"),
-        Byzantine_abbreviation: Some("EPH"),
-        possible_alternative_abbreviations: &["EPHS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "EPH,EPHE,EPHES,EPHESI,EPHESIA,EPHESIAN,EPHESIANS,EPHS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Φιλιππησίους",
-        book_name_English_guide: "Philippians",
-        BOS_book_code: "PHP",
-        BOS_reference_number: 50,
-        BOS_sequence_number: 622,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(4),
-        short_abbreviation: Some("Php"),
-        SBL_abbreviation: Some("Phil"),
-        OSIS_abbreviation: Some("Phil"),
-        Sword_abbreviation: Some("Phil"),
-        CCEL_number: Some(50),
-        USFM_abbreviation: Some("Php"),
-        USFM_number_str: Some("51"),
-        USX_number_str: Some("050"),
-        Unbound_Code: Some("50N"),
-        Bibledit_number: Some(50),
-        Logos_number: Some(71),
-        Logos_abbreviation: Some("Php"),
-        NET_Bible_abbreviation: Some("Phi"),
-        Drupal_Bible_abbreviation: Some("Phl"),
-        Bible_Works_abbreviation: Some("PHI"),
-        Byzantine_abbreviation: Some("PHP"),
-        possible_alternative_abbreviations: &["PPS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "PHI,PHIL,PHILI,PHILIP,PHILIPP,PHILIPPI,PHILIPPIA,PHILIPPIAN,PHILIPPIANS,PHL,PHP,PP,PPS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Κολοσσαεῖς",
-        book_name_English_guide: "Colossians",
-        BOS_book_code: "COL",
-        BOS_reference_number: 51,
-        BOS_sequence_number: 624,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(4),
-        short_abbreviation: Some("Col"),
-        SBL_abbreviation: Some("Col"),
-        OSIS_abbreviation: Some("Col"),
-        Sword_abbreviation: Some("Col"),
-        CCEL_number: Some(51),
-        USFM_abbreviation: Some("Col"),
-        USFM_number_str: Some("52"),
-        USX_number_str: Some("051"),
-        Unbound_Code: Some("51N"),
-        Bibledit_number: Some(51),
-        Logos_number: Some(72),
-        Logos_abbreviation: Some("Col"),
-        NET_Bible_abbreviation: Some("Col"),
-        Drupal_Bible_abbreviation: Some("Col"),
-        Bible_Works_abbreviation: Some("COL"),
-        Byzantine_abbreviation: Some("COL"),
-        possible_alternative_abbreviations: &["CLS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "CLS,COL,COLO,COLOS,COLOSS,COLOSSI,COLOSSIA,COLOSSIAN,COLOSSIANS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Θεσσαλονικεῖς α",
-        book_name_English_guide: "1 Thessalonians",
-        BOS_book_code: "TH1",
-        BOS_reference_number: 52,
-        BOS_sequence_number: 626,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(5),
-        short_abbreviation: Some("1Th"),
-        SBL_abbreviation: Some("1 Thess"),
-        OSIS_abbreviation: Some("1Thess"),
-        Sword_abbreviation: Some("1Thess"),
-        CCEL_number: Some(52),
-        USFM_abbreviation: Some("1Th"),
-        USFM_number_str: Some("53"),
-        USX_number_str: Some("052"),
-        Unbound_Code: Some("52N"),
-        Bibledit_number: Some(52),
-        Logos_number: Some(73),
-        Logos_abbreviation: Some("1Th"),
-        NET_Bible_abbreviation: Some("1Th"),
-        Drupal_Bible_abbreviation: Some("1Ts"),
-        Bible_Works_abbreviation: Some("1TH"),
-        Byzantine_abbreviation: Some("1TH"),
-        possible_alternative_abbreviations: &["1 THSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "1 TH,1 THE,1 THES,1 THESS,1 THESSA,1 THESSAL,1 THESSALO,1 THESSALON,1 THESSALONI,1 THESSALONIA,1 THESSALONIAN,1 THESSALONIANS,1 THS,1 THSS,1TH,1THE,1THES,1THESS,1THESSA,1THESSAL,1THESSALO,1THESSALON,1THESSALONI,1THESSALONIA,1THESSALONIAN,1THESSALONIANS,1THS,1THSS,1TS,TH1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Θεσσαλονικεῖς β",
-        book_name_English_guide: "2 Thessalonians",
-        BOS_book_code: "TH2",
-        BOS_reference_number: 53,
-        BOS_sequence_number: 628,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(3),
-        short_abbreviation: Some("2Th"),
-        SBL_abbreviation: Some("2 Thess"),
-        OSIS_abbreviation: Some("2Thess"),
-        Sword_abbreviation: Some("2Thess"),
-        CCEL_number: Some(53),
-        USFM_abbreviation: Some("2Th"),
-        USFM_number_str: Some("54"),
-        USX_number_str: Some("053"),
-        Unbound_Code: Some("53N"),
-        Bibledit_number: Some(53),
-        Logos_number: Some(74),
-        Logos_abbreviation: Some("2Th"),
-        NET_Bible_abbreviation: Some("2Th"),
-        Drupal_Bible_abbreviation: Some("2Ts"),
-        Bible_Works_abbreviation: Some("2TH"),
-        Byzantine_abbreviation: Some("2TH"),
-        possible_alternative_abbreviations: &["2 THSS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "2 TH,2 THE,2 THES,2 THESS,2 THESSA,2 THESSAL,2 THESSALO,2 THESSALON,2 THESSALONI,2 THESSALONIA,2 THESSALONIAN,2 THESSALONIANS,2 THS,2 THSS,2TH,2THE,2THES,2THESS,2THESSA,2THESSAL,2THESSALO,2THESSALON,2THESSALONI,2THESSALONIA,2THESSALONIAN,2THESSALONIANS,2THS,2THSS,2TS,TH2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Τιμόθεον α",
-        book_name_English_guide: "1 Timothy",
-        BOS_book_code: "TI1",
-        BOS_reference_number: 54,
-        BOS_sequence_number: 630,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(6),
-        short_abbreviation: Some("1Ti"),
-        SBL_abbreviation: Some("1 Tim"),
-        OSIS_abbreviation: Some("1Tim"),
-        Sword_abbreviation: Some("1Tim"),
-        CCEL_number: Some(54),
-        USFM_abbreviation: Some("1Ti"),
-        USFM_number_str: Some("55"),
-        USX_number_str: Some("054"),
-        Unbound_Code: Some("54N"),
-        Bibledit_number: Some(54),
-        Logos_number: Some(75),
-        Logos_abbreviation: Some("1Ti"),
-        NET_Bible_abbreviation: Some("1Ti"),
-        Drupal_Bible_abbreviation: Some("1Tm"),
-        Bible_Works_abbreviation: Some("1TI"),
-        Byzantine_abbreviation: Some("1TI"),
-        possible_alternative_abbreviations: &["1 TMT"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "1 TI,1 TIM,1 TIMO,1 TIMOT,1 TIMOTH,1 TIMOTHY,1 TM,1 TMT,1TI,1TIM,1TIMO,1TIMOT,1TIMOTH,1TIMOTHY,1TM,1TMT,TI1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Τιμόθεον β",
-        book_name_English_guide: "2 Timothy",
-        BOS_book_code: "TI2",
-        BOS_reference_number: 55,
-        BOS_sequence_number: 632,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(4),
-        short_abbreviation: Some("2Ti"),
-        SBL_abbreviation: Some("2 Tim"),
-        OSIS_abbreviation: Some("2Tim"),
-        Sword_abbreviation: Some("2Tim"),
-        CCEL_number: Some(55),
-        USFM_abbreviation: Some("2Ti"),
-        USFM_number_str: Some("56"),
-        USX_number_str: Some("055"),
-        Unbound_Code: Some("55N"),
-        Bibledit_number: Some(55),
-        Logos_number: Some(76),
-        Logos_abbreviation: Some("2Ti"),
-        NET_Bible_abbreviation: Some("2Ti"),
-        Drupal_Bible_abbreviation: Some("2Tm"),
-        Bible_Works_abbreviation: Some("2TI"),
-        Byzantine_abbreviation: Some("2TI"),
-        possible_alternative_abbreviations: &["2 TMT"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "2 TI,2 TIM,2 TIMO,2 TIMOT,2 TIMOTH,2 TIMOTHY,2 TM,2 TMT,2TI,2TIM,2TIMO,2TIMOT,2TIMOTH,2TIMOTHY,2TM,2TMT,TI2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Τίτον",
-        book_name_English_guide: "Titus",
-        BOS_book_code: "TIT",
-        BOS_reference_number: 56,
-        BOS_sequence_number: 634,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(3),
-        short_abbreviation: Some("Tit"),
-        SBL_abbreviation: Some("Titus"),
-        OSIS_abbreviation: Some("Titus"),
-        Sword_abbreviation: Some("Titus"),
-        CCEL_number: Some(56),
-        USFM_abbreviation: Some("Tit"),
-        USFM_number_str: Some("57"),
-        USX_number_str: Some("056"),
-        Unbound_Code: Some("56N"),
-        Bibledit_number: Some(56),
-        Logos_number: Some(77),
-        Logos_abbreviation: Some("Tt"),
-        NET_Bible_abbreviation: Some("Tit"),
-        Drupal_Bible_abbreviation: Some("Tit"),
-        Bible_Works_abbreviation: Some("TIT"),
-        Byzantine_abbreviation: Some("TIT"),
-        possible_alternative_abbreviations: &["TTS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "TIT,TITU,TITUS,TT,TTS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Φιλήμονα",
-        book_name_English_guide: "Philemon",
-        BOS_book_code: "PHM",
-        BOS_reference_number: 57,
-        BOS_sequence_number: 636,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("Phm"),
-        SBL_abbreviation: Some("Phlm"),
-        OSIS_abbreviation: Some("Phlm"),
-        Sword_abbreviation: Some("Phlm"),
-        CCEL_number: Some(57),
-        USFM_abbreviation: Some("Phm"),
-        USFM_number_str: Some("58"),
-        USX_number_str: Some("057"),
-        Unbound_Code: Some("57N"),
-        Bibledit_number: Some(57),
-        Logos_number: Some(78),
-        Logos_abbreviation: Some("Phm"),
-        NET_Bible_abbreviation: Some("Phm"),
-        Drupal_Bible_abbreviation: Some("Phm"),
-        Bible_Works_abbreviation: Some("PHM"),
-        Byzantine_abbreviation: Some("PHM"),
-        possible_alternative_abbreviations: &["PHLMN"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Paul's Letters"),
-        all_English_derived_abbreviations: "PHILE,PHILEM,PHILEMO,PHILEMON,PHLM,PHLMN,PHM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πρὸς Ἑβραίους",
-        book_name_English_guide: "Hebrews",
-        BOS_book_code: "HEB",
-        BOS_reference_number: 58,
-        BOS_sequence_number: 638,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(13),
-        short_abbreviation: Some("Heb"),
-        SBL_abbreviation: Some("Heb"),
-        OSIS_abbreviation: Some("Heb"),
-        Sword_abbreviation: Some("Heb"),
-        CCEL_number: Some(58),
-        USFM_abbreviation: Some("Heb"),
-        USFM_number_str: Some("59"),
-        USX_number_str: Some("058"),
-        Unbound_Code: Some("58N"),
-        Bibledit_number: Some(58),
-        Logos_number: Some(79),
-        Logos_abbreviation: Some("Heb"),
-        NET_Bible_abbreviation: Some("Heb"),
-        Drupal_Bible_abbreviation: Some("Heb"),
-        Bible_Works_abbreviation: Some("HEB"),
-        Byzantine_abbreviation: Some("HEB"),
-        possible_alternative_abbreviations: &["HBRWS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "HBR,HBRW,HBRWS,HE,HEB,HEBR,HEBRE,HEBREW,HEBREWS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Ἰακώβου",
-        book_name_English_guide: "James / Jacob",
-        BOS_book_code: "JAM",
-        BOS_reference_number: 59,
-        BOS_sequence_number: 640,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(5),
-        short_abbreviation: Some("Jas"),
-        SBL_abbreviation: Some("Jas"),
-        OSIS_abbreviation: Some("Jas"),
-        Sword_abbreviation: Some("Jas"),
-        CCEL_number: Some(59),
-        USFM_abbreviation: Some("Jas"),
-        USFM_number_str: Some("60"),
-        USX_number_str: Some("059"),
-        Unbound_Code: Some("59N"),
-        Bibledit_number: Some(59),
-        Logos_number: Some(80),
-        Logos_abbreviation: Some("Jas"),
-        NET_Bible_abbreviation: Some("Jam"),
-        Drupal_Bible_abbreviation: Some("Jam"),
-        Bible_Works_abbreviation: Some("JAM"),
-        Byzantine_abbreviation: Some("JAS"),
-        possible_alternative_abbreviations: &["JMS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "JA,JAC,JACO,JACOB,JAM,JAME,JAMES,JAS,JM,JMS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πέτρου α",
-        book_name_English_guide: "1 Peter",
-        BOS_book_code: "PE1",
-        BOS_reference_number: 60,
-        BOS_sequence_number: 642,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(5),
-        short_abbreviation: Some("1Pe"),
-        SBL_abbreviation: Some("1 Pet"),
-        OSIS_abbreviation: Some("1Pet"),
-        Sword_abbreviation: Some("1Pet"),
-        CCEL_number: Some(60),
-        USFM_abbreviation: Some("1Pe"),
-        USFM_number_str: Some("61"),
-        USX_number_str: Some("060"),
-        Unbound_Code: Some("60N"),
-        Bibledit_number: Some(60),
-        Logos_number: Some(81),
-        Logos_abbreviation: Some("1Pe"),
-        NET_Bible_abbreviation: Some("1Pe"),
-        Drupal_Bible_abbreviation: Some("1Pe"),
-        Bible_Works_abbreviation: Some("1PE"),
-        Byzantine_abbreviation: Some("1PE"),
-        possible_alternative_abbreviations: &["1 PTR"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "1 PE,1 PET,1 PETE,1 PETER,1 PT,1 PTR,1PE,1PET,1PETE,1PETER,1PT,1PTR,PE1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Πέτρου β",
-        book_name_English_guide: "2 Peter",
-        BOS_book_code: "PE2",
-        BOS_reference_number: 61,
-        BOS_sequence_number: 644,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(3),
-        short_abbreviation: Some("2Pe"),
-        SBL_abbreviation: Some("2 Pet"),
-        OSIS_abbreviation: Some("2Pet"),
-        Sword_abbreviation: Some("2Pet"),
-        CCEL_number: Some(61),
-        USFM_abbreviation: Some("2Pe"),
-        USFM_number_str: Some("62"),
-        USX_number_str: Some("061"),
-        Unbound_Code: Some("61N"),
-        Bibledit_number: Some(61),
-        Logos_number: Some(82),
-        Logos_abbreviation: Some("2Pe"),
-        NET_Bible_abbreviation: Some("2Pe"),
-        Drupal_Bible_abbreviation: Some("2Pe"),
-        Bible_Works_abbreviation: Some("2PE"),
-        Byzantine_abbreviation: Some("2PE"),
-        possible_alternative_abbreviations: &["2 PTR"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "2 PE,2 PET,2 PETE,2 PETER,2 PT,2 PTR,2PE,2PET,2PETE,2PETER,2PT,2PTR,PE2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Ἰωάννου α",
-        book_name_English_guide: "1 John",
-        BOS_book_code: "JN1",
-        BOS_reference_number: 62,
-        BOS_sequence_number: 646,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(5),
-        short_abbreviation: Some("1Jn"),
-        SBL_abbreviation: Some("1 John"),
-        OSIS_abbreviation: Some("1John"),
-        Sword_abbreviation: Some("1John"),
-        CCEL_number: Some(62),
-        USFM_abbreviation: Some("1Jn"),
-        USFM_number_str: Some("63"),
-        USX_number_str: Some("062"),
-        Unbound_Code: Some("62N"),
-        Bibledit_number: Some(62),
-        Logos_number: Some(83),
-        Logos_abbreviation: Some("1Jn"),
-        NET_Bible_abbreviation: Some("1Jo"),
-        Drupal_Bible_abbreviation: Some("1Jn"),
-        Bible_Works_abbreviation: Some("1JO"),
-        Byzantine_abbreviation: Some("1JO"),
-        possible_alternative_abbreviations: &["1 JHN"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "1 J,1 JH,1 JHN,1 JO,1 JOH,1 JOHN,1J,1JH,1JHN,1JN,1JO,1JOH,1JOHN,JN1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Ἰωάννου β",
-        book_name_English_guide: "2 John",
-        BOS_book_code: "JN2",
-        BOS_reference_number: 63,
-        BOS_sequence_number: 648,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("2Jn"),
-        SBL_abbreviation: Some("2 John"),
-        OSIS_abbreviation: Some("2John"),
-        Sword_abbreviation: Some("2John"),
-        CCEL_number: Some(63),
-        USFM_abbreviation: Some("2Jn"),
-        USFM_number_str: Some("64"),
-        USX_number_str: Some("063"),
-        Unbound_Code: Some("63N"),
-        Bibledit_number: Some(63),
-        Logos_number: Some(84),
-        Logos_abbreviation: Some("2Jn"),
-        NET_Bible_abbreviation: Some("2Jo"),
-        Drupal_Bible_abbreviation: Some("2Jn"),
-        Bible_Works_abbreviation: Some("2JO"),
-        Byzantine_abbreviation: Some("2JO"),
-        possible_alternative_abbreviations: &["2 JHN"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "2 J,2 JH,2 JHN,2 JO,2 JOH,2 JOHN,2J,2JH,2JHN,2JN,2JO,2JOH,2JOHN,JN2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Ἰωάννου γ",
-        book_name_English_guide: "3 John",
-        BOS_book_code: "JN3",
-        BOS_reference_number: 64,
-        BOS_sequence_number: 650,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("3Jn"),
-        SBL_abbreviation: Some("3 John"),
-        OSIS_abbreviation: Some("3John"),
-        Sword_abbreviation: Some("3John"),
-        CCEL_number: Some(64),
-        USFM_abbreviation: Some("3Jn"),
-        USFM_number_str: Some("65"),
-        USX_number_str: Some("064"),
-        Unbound_Code: Some("64N"),
-        Bibledit_number: Some(64),
-        Logos_number: Some(85),
-        Logos_abbreviation: Some("3Jn"),
-        NET_Bible_abbreviation: Some("3Jo"),
-        Drupal_Bible_abbreviation: Some("3Jn"),
-        Bible_Works_abbreviation: Some("3JO"),
-        Byzantine_abbreviation: Some("3JO"),
-        possible_alternative_abbreviations: &["3 JHN"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "3 J,3 JH,3 JHN,3 JO,3 JOH,3 JOHN,3J,3JH,3JHN,3JN,3JO,3JOH,3JOHN,JN3",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Ἰούδας",
-        book_name_English_guide: "Jude / Judas",
-        BOS_book_code: "JDE",
-        BOS_reference_number: 65,
-        BOS_sequence_number: 652,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("Jud"),
-        SBL_abbreviation: Some("Jude"),
-        OSIS_abbreviation: Some("Jude"),
-        Sword_abbreviation: Some("Jude"),
-        CCEL_number: Some(65),
-        USFM_abbreviation: Some("Jud"),
-        USFM_number_str: Some("66"),
-        USX_number_str: Some("065"),
-        Unbound_Code: Some("65N"),
-        Bibledit_number: Some(65),
-        Logos_number: Some(86),
-        Logos_abbreviation: Some("Jud"),
-        NET_Bible_abbreviation: Some("Jud"),
-        Drupal_Bible_abbreviation: Some("Jud"),
-        Bible_Works_abbreviation: Some("JUD"),
-        Byzantine_abbreviation: Some("JUDE"),
-        possible_alternative_abbreviations: &["JD"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("General Letters"),
-        all_English_derived_abbreviations: "JD,JDE,JUD,JUDA,JUDAS,JUDE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "Ἀποκάλυψις Ἰωάννου",
-        book_name_English_guide: "Revelation / Revelation of John / Apocalypse of St. John",
-        BOS_book_code: "REV",
-        BOS_reference_number: 66,
-        BOS_sequence_number: 654,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(22),
-        short_abbreviation: Some("Re"),
-        SBL_abbreviation: Some("Rev"),
-        OSIS_abbreviation: Some("Rev"),
-        Sword_abbreviation: Some("Rev"),
-        CCEL_number: Some(66),
-        USFM_abbreviation: Some("Rev"),
-        USFM_number_str: Some("67"),
-        USX_number_str: Some("066"),
-        Unbound_Code: Some("66N"),
-        Bibledit_number: Some(66),
-        Logos_number: Some(87),
-        Logos_abbreviation: Some("Re"),
-        NET_Bible_abbreviation: Some("Rev"),
-        Drupal_Bible_abbreviation: Some("Rev"),
-        Bible_Works_abbreviation: Some("REV"),
-        Byzantine_abbreviation: Some("RE"),
-        possible_alternative_abbreviations: &["RVL"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Revelation"),
-        all_English_derived_abbreviations: "APOCALYPSE OF S,APOCALYPSE OF ST,APOCALYPSE OF ST.,APOCALYPSE OF ST. J,APOCALYPSE OF ST. JO,APOCALYPSE OF ST. JOH,APOCALYPSE OF ST. JOHN,APOCALYPSEOFS,APOCALYPSEOFST,APOCALYPSEOFST.,APOCALYPSEOFST.J,APOCALYPSEOFST.JO,APOCALYPSEOFST.JOH,APOCALYPSEOFST.JOHN,RE,REV,REVELATION,REVELATION OF J,REVELATION OF JO,REVELATION OF JOH,REVELATION OF JOHN,REVELATIONOFJ,REVELATIONOFJO,REVELATIONOFJOH,REVELATIONOFJOHN,RVL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Greek Esther / Esther (Greek)",
-        BOS_book_code: "ESG",
-        BOS_reference_number: 70,
-        BOS_sequence_number: 204,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(10),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Add Esth"),
-        OSIS_abbreviation: Some("EsthGr"),
-        Sword_abbreviation: Some("EsthGr"),
-        CCEL_number: Some(95),
-        USFM_abbreviation: Some("EsG"),
-        USFM_number_str: Some("70"),
-        USX_number_str: Some("069"),
-        Unbound_Code: Some("69A"),
-        Bibledit_number: Some(72),
-        Logos_number: Some(42),
-        Logos_abbreviation: Some("GkEs"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["ADD ESTHER"],
-        possible_alternative_books_codes: &["EST","ESA","ESC"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "ADD E,ADD ES,ADD EST,ADD ESTH,ADD ESTHE,ADD ESTHER,ADDE,ADDES,ADDEST,ADDESTH,ADDESTHE,ADDESTHER,ESG,ESTHER (GREEK),ESTHER G,ESTHER GR,ESTHER GRE,ESTHER GREE,ESTHER GREEK,ESTHERG,ESTHERGR,ESTHERGRE,ESTHERGREE,ESTHERGREEK,ESTHG,ESTHGR,GK,GKE,GKES,GREEK EST,GREEK ESTH,GREEK ESTHE,GREEK ESTHER,GREEKEST,GREEKESTH,GREEKESTHE,GREEKESTHER",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Greek additions to Esther / Esther (additions)",
-        BOS_book_code: "ESA",
-        BOS_reference_number: 71,
-        BOS_sequence_number: 84,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(10),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Add Esth"),
-        OSIS_abbreviation: Some("AddEsth"),
-        Sword_abbreviation: Some("AddEsth"),
-        CCEL_number: Some(95),
-        USFM_abbreviation: Some("ADE"),
-        USFM_number_str: Some("70"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("ESG"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["EST","ESG","ESC"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "ADE,ESA,ESTHER (ADDITIONS),ESTHER A,ESTHER AD,ESTHER ADD,ESTHER ADDI,ESTHER ADDIT,ESTHER ADDITI,ESTHER ADDITIO,ESTHER ADDITION,ESTHER ADDITIONS,ESTHERA,ESTHERAD,ESTHERADD,ESTHERADDI,ESTHERADDIT,ESTHERADDITI,ESTHERADDITIO,ESTHERADDITION,ESTHERADDITIONS,GREEK ADDITIONS TO E,GREEK ADDITIONS TO ES,GREEK ADDITIONS TO EST,GREEK ADDITIONS TO ESTH,GREEK ADDITIONS TO ESTHE,GREEK ADDITIONS TO ESTHER,GREEKADDITIONSTOE,GREEKADDITIONSTOES,GREEKADDITIONSTOEST,GREEKADDITIONSTOESTH,GREEKADDITIONSTOESTHE,GREEKADDITIONSTOESTHER",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo,el-x-koine",
-        original_language_book_name: "אֶסְתֵר",
-        book_name_English_guide: "Combined Hebrew/Greek Esther / Esther (combined)",
-        BOS_book_code: "ESC",
-        BOS_reference_number: 72,
-        BOS_sequence_number: 86,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(10),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Add Esth"),
-        OSIS_abbreviation: Some("AddEsth"),
-        Sword_abbreviation: Some("AddEsth"),
-        CCEL_number: Some(95),
-        USFM_abbreviation: Some("EsG"),
-        USFM_number_str: Some("70"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["EST","ESG","ESA"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "COMBINED HEBREW/GREEK E,COMBINED HEBREW/GREEK ES,COMBINED HEBREW/GREEK EST,COMBINED HEBREW/GREEK ESTH,COMBINED HEBREW/GREEK ESTHE,COMBINED HEBREW/GREEK ESTHER,COMBINEDHEBREW/GREEKE,COMBINEDHEBREW/GREEKES,COMBINEDHEBREW/GREEKEST,COMBINEDHEBREW/GREEKESTH,COMBINEDHEBREW/GREEKESTHE,COMBINEDHEBREW/GREEKESTHER,ESC,ESTHER (COMBINED),ESTHER C,ESTHER CO,ESTHER COM,ESTHER COMB,ESTHER COMBI,ESTHER COMBIN,ESTHER COMBINE,ESTHER COMBINED,ESTHERC,ESTHERCO,ESTHERCOM,ESTHERCOMB,ESTHERCOMBI,ESTHERCOMBIN,ESTHERCOMBINE,ESTHERCOMBINED",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Greek Daniel / Daniel (Greek)",
-        BOS_book_code: "DNG",
-        BOS_reference_number: 73,
-        BOS_sequence_number: 142,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Add Dan"),
-        OSIS_abbreviation: Some("AddDan"),
-        Sword_abbreviation: Some("DanGr"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("DaG"),
-        USFM_number_str: Some("B2"),
-        USX_number_str: Some("112"),
-        Unbound_Code: None,
-        Bibledit_number: Some(88),
-        Logos_number: Some(98),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("DNG"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["DAN","DNA","DNC","DNT"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "DANG,DANGR,DANIEL (GREEK),DANIEL G,DANIEL GR,DANIEL GRE,DANIEL GREE,DANIEL GREEK,DANIELG,DANIELGR,DANIELGRE,DANIELGREE,DANIELGREEK,DNG,GREEK D,GREEK DA,GREEK DAN,GREEK DANI,GREEK DANIE,GREEK DANIEL,GREEKD,GREEKDA,GREEKDAN,GREEKDANI,GREEKDANIE,GREEKDANIEL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Greek additions to Daniel / Daniel (additions)",
-        BOS_book_code: "DNA",
-        BOS_reference_number: 74,
-        BOS_sequence_number: 144,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Add Dan"),
-        OSIS_abbreviation: Some("AddDan"),
-        Sword_abbreviation: Some("AddDan"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("DaG"),
-        USFM_number_str: Some("B2"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["DAN","DNG","DNC","DNT"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "DANIEL (ADDITIONS),DANIEL A,DANIEL AD,DANIEL ADD,DANIEL ADDI,DANIEL ADDIT,DANIEL ADDITI,DANIEL ADDITIO,DANIEL ADDITION,DANIEL ADDITIONS,DANIELA,DANIELAD,DANIELADD,DANIELADDI,DANIELADDIT,DANIELADDITI,DANIELADDITIO,DANIELADDITION,DANIELADDITIONS,DNA,GREEK ADDITIONS TO D,GREEK ADDITIONS TO DA,GREEK ADDITIONS TO DAN,GREEK ADDITIONS TO DANI,GREEK ADDITIONS TO DANIE,GREEK ADDITIONS TO DANIEL,GREEKADDITIONSTOD,GREEKADDITIONSTODA,GREEKADDITIONSTODAN,GREEKADDITIONSTODANI,GREEKADDITIONSTODANIE,GREEKADDITIONSTODANIEL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo,el-x-koine",
-        original_language_book_name: "דָּנִיֵּאל",
-        book_name_English_guide: "Combined Hebrew/Greek Daniel / Daniel (combined)",
-        BOS_book_code: "DNC",
-        BOS_reference_number: 75,
-        BOS_sequence_number: 146,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Add Dan"),
-        OSIS_abbreviation: Some("AddDan"),
-        Sword_abbreviation: Some("AddDan"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("DaG"),
-        USFM_number_str: Some("B2"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["DAN","DNG","DNA","DNT"],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: Some("Major Prophets"),
-        all_English_derived_abbreviations: "COMBINED HEBREW/GREEK D,COMBINED HEBREW/GREEK DA,COMBINED HEBREW/GREEK DAN,COMBINED HEBREW/GREEK DANI,COMBINED HEBREW/GREEK DANIE,COMBINED HEBREW/GREEK DANIEL,COMBINEDHEBREW/GREEKD,COMBINEDHEBREW/GREEKDA,COMBINEDHEBREW/GREEKDAN,COMBINEDHEBREW/GREEKDANI,COMBINEDHEBREW/GREEKDANIE,COMBINEDHEBREW/GREEKDANIEL,DANIEL (COMBINED),DANIEL C,DANIEL CO,DANIEL COM,DANIEL COMB,DANIEL COMBI,DANIEL COMBIN,DANIEL COMBINE,DANIEL COMBINED,DANIELC,DANIELCO,DANIELCOM,DANIELCOMB,DANIELCOMBI,DANIELCOMBIN,DANIELCOMBINE,DANIELCOMBINED,DNC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Bel and the dragon",
-        BOS_book_code: "BEL",
-        BOS_reference_number: 80,
-        BOS_sequence_number: 262,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("Bel"),
-        SBL_abbreviation: Some("Bel"),
-        OSIS_abbreviation: Some("Bel"),
-        Sword_abbreviation: Some("Bel"),
-        CCEL_number: Some(78),
-        USFM_abbreviation: Some("Bel"),
-        USFM_number_str: Some("77"),
-        USX_number_str: Some("076"),
-        Unbound_Code: Some("76A"),
-        Bibledit_number: Some(79),
-        Logos_number: Some(49),
-        Logos_abbreviation: Some("Bel"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("BEL"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["BLT"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BEL,BEL AND THE DRAGON,BELANDTHEDRAGON",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Susanna",
-        BOS_book_code: "SUS",
-        BOS_reference_number: 81,
-        BOS_sequence_number: 260,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("Sus"),
-        SBL_abbreviation: Some("Sus"),
-        OSIS_abbreviation: Some("Sus"),
-        Sword_abbreviation: Some("Sus"),
-        CCEL_number: Some(77),
-        USFM_abbreviation: Some("Sus"),
-        USFM_number_str: Some("76"),
-        USX_number_str: Some("075"),
-        Unbound_Code: Some("75A"),
-        Bibledit_number: Some(78),
-        Logos_number: Some(48),
-        Logos_abbreviation: Some("Sus"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("SUS"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["SST"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SUS,SUSANNA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Judith",
-        BOS_book_code: "JDT",
-        BOS_reference_number: 82,
-        BOS_sequence_number: 202,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: Some("Jdt"),
-        SBL_abbreviation: Some("Jdt"),
-        OSIS_abbreviation: Some("Jdt"),
-        Sword_abbreviation: Some("Jdt"),
-        CCEL_number: Some(70),
-        USFM_abbreviation: Some("Jdt"),
-        USFM_number_str: Some("69"),
-        USX_number_str: Some("068"),
-        Unbound_Code: Some("68A"),
-        Bibledit_number: Some(71),
-        Logos_number: Some(41),
-        Logos_abbreviation: Some("Jdt"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("JDT"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JDT,JUDI,JUDIT,JUDITH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Prayer of Manasses / Prayer of Manasseh",
-        BOS_book_code: "MAN",
-        BOS_reference_number: 83,
-        BOS_sequence_number: 294,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Pr Man"),
-        OSIS_abbreviation: Some("PrMan"),
-        Sword_abbreviation: Some("PrMan"),
-        CCEL_number: Some(82),
-        USFM_abbreviation: Some("Man"),
-        USFM_number_str: Some("84"),
-        USX_number_str: Some("083"),
-        Unbound_Code: Some("83A"),
-        Bibledit_number: Some(83),
-        Logos_number: Some(53),
-        Logos_abbreviation: Some("PrMan"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("PRM"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "MAN,PR M,PR MA,PR MAN,PRAYER OF M,PRAYER OF MA,PRAYER OF MAN,PRAYER OF MANA,PRAYER OF MANAS,PRAYER OF MANASS,PRAYER OF MANASSE,PRAYER OF MANASSEH,PRAYER OF MANASSES,PRAYEROFM,PRAYEROFMA,PRAYEROFMAN,PRAYEROFMANA,PRAYEROFMANAS,PRAYEROFMANASS,PRAYEROFMANASSE,PRAYEROFMANASSEH,PRAYEROFMANASSES,PRM,PRMA,PRMAN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Sirach / Ecclesiasticus / Wisdom of Ben Sira / Wisdom of Jesus son of Sirach",
-        BOS_book_code: "SIR",
-        BOS_reference_number: 84,
-        BOS_sequence_number: 208,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([51,52]),
-        short_abbreviation: Some("Sir"),
-        SBL_abbreviation: Some("Sir"),
-        OSIS_abbreviation: Some("Sir"),
-        Sword_abbreviation: Some("Sir"),
-        CCEL_number: Some(94),
-        USFM_abbreviation: Some("Sir"),
-        USFM_number_str: Some("72"),
-        USX_number_str: Some("071"),
-        Unbound_Code: Some("71A"),
-        Bibledit_number: Some(74),
-        Logos_number: Some(44),
-        Logos_abbreviation: Some("Sir"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("SIR"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["ECCLU","ECCLUS","ECCLSTU","ECCLSTC","ECCLSTCU","ECCLSTCS","ECCLSTCUS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ECCLESIASTI,ECCLESIASTIC,ECCLESIASTICU,ECCLESIASTICUS,ECCLSTC,ECCLSTCS,ECCLSTCU,ECCLSTCUS,ECCLSTU,ECCLU,ECCLUS,SIR,SIRACH,WISDOM OF B,WISDOM OF BE,WISDOM OF BEN,WISDOM OF BEN S,WISDOM OF BEN SI,WISDOM OF BEN SIR,WISDOM OF BEN SIRA,WISDOM OF JESUS S,WISDOM OF JESUS SO,WISDOM OF JESUS SON,WISDOM OF JESUS SON O,WISDOM OF JESUS SON OF,WISDOM OF JESUS SON OF S,WISDOM OF JESUS SON OF SI,WISDOM OF JESUS SON OF SIR,WISDOM OF JESUS SON OF SIRA,WISDOM OF JESUS SON OF SIRAC,WISDOM OF JESUS SON OF SIRACH,WISDOMOFB,WISDOMOFBE,WISDOMOFBEN,WISDOMOFBENS,WISDOMOFBENSI,WISDOMOFBENSIR,WISDOMOFBENSIRA,WISDOMOFJESUSS,WISDOMOFJESUSSO,WISDOMOFJESUSSON,WISDOMOFJESUSSONO,WISDOMOFJESUSSONOF,WISDOMOFJESUSSONOFS,WISDOMOFJESUSSONOFSI,WISDOMOFJESUSSONOFSIR,WISDOMOFJESUSSONOFSIRA,WISDOMOFJESUSSONOFSIRAC,WISDOMOFJESUSSONOFSIRACH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Sirach Prologue",
-        BOS_book_code: "SIP",
-        BOS_reference_number: 85,
-        BOS_sequence_number: 207,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("SirP"),
-        Sword_abbreviation: Some("SirP"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("SIP"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SIP,SIRACH P,SIRACH PR,SIRACH PRO,SIRACH PROL,SIRACH PROLO,SIRACH PROLOG,SIRACH PROLOGU,SIRACH PROLOGUE,SIRACHP,SIRACHPR,SIRACHPRO,SIRACHPROL,SIRACHPROLO,SIRACHPROLOG,SIRACHPROLOGU,SIRACHPROLOGUE,SIRP",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Tobit / Tobias",
-        BOS_book_code: "TOB",
-        BOS_reference_number: 86,
-        BOS_sequence_number: 200,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: Some("Tob"),
-        SBL_abbreviation: Some("Tob"),
-        OSIS_abbreviation: Some("Tob"),
-        Sword_abbreviation: Some("Tob"),
-        CCEL_number: Some(69),
-        USFM_abbreviation: Some("Tob"),
-        USFM_number_str: Some("68"),
-        USX_number_str: Some("067"),
-        Unbound_Code: Some("67A"),
-        Bibledit_number: Some(70),
-        Logos_number: Some(40),
-        Logos_abbreviation: Some("Tob"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("TOB"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["TBT","TOBI"],
-        possible_alternative_books_codes: &["TBS"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TBT,TOB,TOBI,TOBIA,TOBIAS,TOBIT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Wisdom / Wisdom of Solomon",
-        BOS_book_code: "WIS",
-        BOS_reference_number: 87,
-        BOS_sequence_number: 206,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(19),
-        short_abbreviation: Some("Wis"),
-        SBL_abbreviation: Some("Wis"),
-        OSIS_abbreviation: Some("Wis"),
-        Sword_abbreviation: Some("Wis"),
-        CCEL_number: Some(72),
-        USFM_abbreviation: Some("Wis"),
-        USFM_number_str: Some("71"),
-        USX_number_str: Some("070"),
-        Unbound_Code: Some("70A"),
-        Bibledit_number: Some(73),
-        Logos_number: Some(43),
-        Logos_abbreviation: Some("Wis"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("WIS"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["WISD","WISDO","WISDM"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "WIS,WISD,WISDM,WISDO,WISDOM,WISDOM OF S,WISDOM OF SO,WISDOM OF SOL,WISDOM OF SOLO,WISDOM OF SOLOM,WISDOM OF SOLOMO,WISDOM OF SOLOMON,WISDOMOFS,WISDOMOFSO,WISDOMOFSOL,WISDOMOFSOLO,WISDOMOFSOLOM,WISDOMOFSOLOMO,WISDOMOFSOLOMON",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Prayer of Azariah and the Song of the Three Young Men / Prayer of Azariah / Song of the Three Young Men / Song of the Three Children",
-        BOS_book_code: "PAZ",
-        BOS_reference_number: 88,
-        BOS_sequence_number: 250,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("Az"),
-        SBL_abbreviation: Some("Pr Azar"),
-        OSIS_abbreviation: Some("PrAzar"),
-        Sword_abbreviation: Some("PrAzar"),
-        CCEL_number: Some(76),
-        USFM_abbreviation: Some("S3Y"),
-        USFM_number_str: Some("75"),
-        USX_number_str: Some("074"),
-        Unbound_Code: Some("74A"),
-        Bibledit_number: Some(77),
-        Logos_number: Some(47),
-        Logos_abbreviation: Some("SongThr"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("PRA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["PRAZ"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "AZ,PAZ,PR A,PR AZ,PR AZA,PR AZAR,PRA,PRAYER OF A,PRAYER OF AZ,PRAYER OF AZA,PRAYER OF AZAR,PRAYER OF AZARI,PRAYER OF AZARIA,PRAYER OF AZARIAH,PRAYER OF AZARIAH A,PRAYER OF AZARIAH AN,PRAYER OF AZARIAH AND,PRAYER OF AZARIAH AND T,PRAYER OF AZARIAH AND TH,PRAYER OF AZARIAH AND THE,PRAYER OF AZARIAH AND THE S,PRAYER OF AZARIAH AND THE SO,PRAYER OF AZARIAH AND THE SON,PRAYER OF AZARIAH AND THE SONG,PRAYER OF AZARIAH AND THE SONG O,PRAYER OF AZARIAH AND THE SONG OF,PRAYER OF AZARIAH AND THE SONG OF T,PRAYER OF AZARIAH AND THE SONG OF TH,PRAYER OF AZARIAH AND THE SONG OF THE,PRAYER OF AZARIAH AND THE SONG OF THE T,PRAYER OF AZARIAH AND THE SONG OF THE TH,PRAYER OF AZARIAH AND THE SONG OF THE THR,PRAYER OF AZARIAH AND THE SONG OF THE THRE,PRAYER OF AZARIAH AND THE SONG OF THE THREE,PRAYER OF AZARIAH AND THE SONG OF THE THREE Y,PRAYER OF AZARIAH AND THE SONG OF THE THREE YO,PRAYER OF AZARIAH AND THE SONG OF THE THREE YOU,PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUN,PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG,PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG M,PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG ME,PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG MEN,PRAYEROFA,PRAYEROFAZ,PRAYEROFAZA,PRAYEROFAZAR,PRAYEROFAZARI,PRAYEROFAZARIA,PRAYEROFAZARIAH,PRAYEROFAZARIAHA,PRAYEROFAZARIAHAN,PRAYEROFAZARIAHAND,PRAYEROFAZARIAHANDT,PRAYEROFAZARIAHANDTH,PRAYEROFAZARIAHANDTHE,PRAYEROFAZARIAHANDTHES,PRAYEROFAZARIAHANDTHESO,PRAYEROFAZARIAHANDTHESON,PRAYEROFAZARIAHANDTHESONG,PRAYEROFAZARIAHANDTHESONGO,PRAYEROFAZARIAHANDTHESONGOF,PRAYEROFAZARIAHANDTHESONGOFT,PRAYEROFAZARIAHANDTHESONGOFTH,PRAYEROFAZARIAHANDTHESONGOFTHE,PRAYEROFAZARIAHANDTHESONGOFTHET,PRAYEROFAZARIAHANDTHESONGOFTHETH,PRAYEROFAZARIAHANDTHESONGOFTHETHR,PRAYEROFAZARIAHANDTHESONGOFTHETHRE,PRAYEROFAZARIAHANDTHESONGOFTHETHREE,PRAYEROFAZARIAHANDTHESONGOFTHETHREEY,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYO,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOU,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUN,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNG,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNGM,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNGME,PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNGMEN,PRAZ,PRAZA,PRAZAR,S3,S3Y,SONG OF T,SONG OF TH,SONG OF THE,SONG OF THE T,SONG OF THE TH,SONG OF THE THR,SONG OF THE THRE,SONG OF THE THREE,SONG OF THE THREE C,SONG OF THE THREE CH,SONG OF THE THREE CHI,SONG OF THE THREE CHIL,SONG OF THE THREE CHILD,SONG OF THE THREE CHILDR,SONG OF THE THREE CHILDRE,SONG OF THE THREE CHILDREN,SONG OF THE THREE Y,SONG OF THE THREE YO,SONG OF THE THREE YOU,SONG OF THE THREE YOUN,SONG OF THE THREE YOUNG,SONG OF THE THREE YOUNG M,SONG OF THE THREE YOUNG ME,SONG OF THE THREE YOUNG MEN,SONGOFT,SONGOFTH,SONGOFTHE,SONGOFTHET,SONGOFTHETH,SONGOFTHETHR,SONGOFTHETHRE,SONGOFTHETHREE,SONGOFTHETHREEC,SONGOFTHETHREECH,SONGOFTHETHREECHI,SONGOFTHETHREECHIL,SONGOFTHETHREECHILD,SONGOFTHETHREECHILDR,SONGOFTHETHREECHILDRE,SONGOFTHETHREECHILDREN,SONGOFTHETHREEY,SONGOFTHETHREEYO,SONGOFTHETHREEYOU,SONGOFTHETHREEYOUN,SONGOFTHETHREEYOUNG,SONGOFTHETHREEYOUNGM,SONGOFTHETHREEYOUNGME,SONGOFTHETHREEYOUNGMEN,SONGT,SONGTH,SONGTHR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Greek Esdras / Greek Ezra / 1 Esdras / 1 Ezra / 2 Ezra / 3 Ezra / 3 Esdras",
-        BOS_book_code: "GES",
-        BOS_reference_number: 90,
-        BOS_sequence_number: 282,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(9),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("1 Esd"),
-        OSIS_abbreviation: Some("1Esd"),
-        Sword_abbreviation: Some("1Esd"),
-        CCEL_number: Some(81),
-        USFM_abbreviation: Some("1Es"),
-        USFM_number_str: Some("82"),
-        USX_number_str: Some("081"),
-        Unbound_Code: Some("81A"),
-        Bibledit_number: Some(82),
-        Logos_number: Some(52),
-        Logos_abbreviation: Some("1Esd"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("1ES"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LES"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "1 ES,1 ESD,1ES,1ESD,3 E,3 EZ,3 EZR,3 EZRA,3EZ,3EZR,3EZRA,GES,GREEK ESD,GREEK ESDR,GREEK ESDRA,GREEK ESDRAS,GREEK EZ,GREEK EZR,GREEK EZRA,GREEKESD,GREEKESDR,GREEKESDRA,GREEKESDRAS,GREEKEZ,GREEKEZR,GREEKEZRA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "lat",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Latin Esdras / 2 Esdras / 3 Esdras / 4 Esdras / 4 Ezra",
-        BOS_book_code: "LES",
-        BOS_reference_number: 91,
-        BOS_sequence_number: 284,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("2 Esd"),
-        OSIS_abbreviation: Some("2Esd"),
-        Sword_abbreviation: Some("2Esd"),
-        CCEL_number: Some(83),
-        USFM_abbreviation: Some("2Es"),
-        USFM_number_str: Some("83"),
-        USX_number_str: Some("082"),
-        Unbound_Code: Some("82A"),
-        Bibledit_number: Some(86),
-        Logos_number: Some(56),
-        Logos_abbreviation: Some("2Esd"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("4ES"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["4ESDR","3ESDR","2ESDR"],
-        possible_alternative_books_codes: &["GES"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2 ES,2 ESD,2ES,2ESD,2ESDR,4E,4ES,4ESD,4ESDR,LATIN E,LATIN ES,LATIN ESD,LATIN ESDR,LATIN ESDRA,LATIN ESDRAS,LATINE,LATINES,LATINESD,LATINESDR,LATINESDRA,LATINESDRAS,LES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apocalypse of Ezra / Ezra Apocalypse / 4 Ezra / Ezra Shealtiel",
-        BOS_book_code: "EZA",
-        BOS_reference_number: 92,
-        BOS_sequence_number: 286,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([12,13]),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("4 Ezra"),
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("4Ezra"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("EzA"),
-        USFM_number_str: Some("A4"),
-        USX_number_str: Some("104"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(100),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "APOCALYPSE OF E,APOCALYPSE OF EZ,APOCALYPSE OF EZR,APOCALYPSE OF EZRA,APOCALYPSEOFE,APOCALYPSEOFEZ,APOCALYPSEOFEZR,APOCALYPSEOFEZRA,EZA,EZRA A,EZRA AP,EZRA APO,EZRA APOC,EZRA APOCA,EZRA APOCAL,EZRA APOCALY,EZRA APOCALYP,EZRA APOCALYPS,EZRA APOCALYPSE,EZRA S,EZRA SH,EZRA SHE,EZRA SHEA,EZRA SHEAL,EZRA SHEALT,EZRA SHEALTI,EZRA SHEALTIE,EZRA SHEALTIEL,EZRAA,EZRAAP,EZRAAPO,EZRAAPOC,EZRAAPOCA,EZRAAPOCAL,EZRAAPOCALY,EZRAAPOCALYP,EZRAAPOCALYPS,EZRAAPOCALYPSE,EZRAS,EZRASH,EZRASHE,EZRASHEA,EZRASHEAL,EZRASHEALT,EZRASHEALTI,EZRASHEALTIE,EZRASHEALTIEL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "lat",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "5 Ezra / 3 Esdras / 5 Esdras",
-        BOS_book_code: "EZ5",
-        BOS_reference_number: 93,
-        BOS_sequence_number: 288,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(2),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("5 Ezra"),
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("5Ezra"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("5Ez"),
-        USFM_number_str: Some("A5"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(200),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["5ESDR","3ESDR"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "3ES,3ESD,3ESDR,5 E,5 EZ,5 EZR,5 EZRA,5E,5ES,5ESD,5ESDR,5EZ,5EZR,5EZRA,EZ5",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "lat",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "6 Ezra / 5 Esdras / 6 Esdras",
-        BOS_book_code: "EZ6",
-        BOS_reference_number: 94,
-        BOS_sequence_number: 290,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(2),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("6 Ezra"),
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("6Ezra"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("6Ez"),
-        USFM_number_str: Some("A6"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(201),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "6,6 E,6 ES,6 ESD,6 ESDR,6 ESDRA,6 ESDRAS,6 EZ,6 EZR,6 EZRA,6E,6ES,6ESD,6ESDR,6ESDRA,6ESDRAS,6EZ,6EZR,6EZRA,EZ6",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Revelation of Ezra",
-        BOS_book_code: "RVE",
-        BOS_reference_number: 95,
-        BOS_sequence_number: 292,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("RevEzra"),
-        Sword_abbreviation: Some("RevEzra"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "REVELATION OF E,REVELATION OF EZ,REVELATION OF EZR,REVELATION OF EZRA,REVELATIONOFE,REVELATIONOFEZ,REVELATIONOFEZR,REVELATIONOFEZRA,REVEZ,REVEZR,REVEZRA,RVE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Vison of Ezra",
-        BOS_book_code: "VSE",
-        BOS_reference_number: 96,
-        BOS_sequence_number: 293,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("VisEzra"),
-        Sword_abbreviation: Some("VisEzra"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "VISE,VISEZ,VISEZR,VISEZRA,VISO,VISON,VISON O,VISON OF,VISON OF E,VISON OF EZ,VISON OF EZR,VISON OF EZRA,VISONO,VISONOF,VISONOFE,VISONOFEZ,VISONOFEZR,VISONOFEZRA,VS,VSE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter of Jeremiah / Epistle of Jeremiah",
-        BOS_book_code: "LJE",
-        BOS_reference_number: 100,
-        BOS_sequence_number: 240,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: Some("LJe"),
-        SBL_abbreviation: Some("Ep Jer"),
-        OSIS_abbreviation: Some("EpJer"),
-        Sword_abbreviation: Some("EpJer"),
-        CCEL_number: Some(75),
-        USFM_abbreviation: Some("LJe"),
-        USFM_number_str: Some("74"),
-        USX_number_str: Some("073"),
-        Unbound_Code: Some("73A"),
-        Bibledit_number: Some(76),
-        Logos_number: Some(46),
-        Logos_abbreviation: Some("LetJer"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("EPJ"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LJB"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EP JER,EPISTLE OF JEREMIAH,EPISTLEOFJEREMIAH,EPJ,EPJER,LETJ,LETJE,LETJER,LETTER OF JEREMIAH,LETTEROFJEREMIAH,LJE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Baruch / 1 Baruch",
-        BOS_book_code: "BAR",
-        BOS_reference_number: 101,
-        BOS_sequence_number: 220,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(5),
-        short_abbreviation: Some("Bar"),
-        SBL_abbreviation: Some("Bar"),
-        OSIS_abbreviation: Some("Bar"),
-        Sword_abbreviation: Some("Bar"),
-        CCEL_number: Some(74),
-        USFM_abbreviation: Some("Bar"),
-        USFM_number_str: Some("73"),
-        USX_number_str: Some("072"),
-        Unbound_Code: Some("72A"),
-        Bibledit_number: Some(75),
-        Logos_number: Some(45),
-        Logos_abbreviation: Some("Bar"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("BAR"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LJB"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "1 B,1 BA,1 BAR,1 BARU,1 BARUC,1 BARUCH,1B,1BA,1BAR,1BARU,1BARUC,1BARUCH,BAR,BARU,BARUC,BARUCH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter of Jeremiah with Baruch / Epistle of Jeremiah with Baruch",
-        BOS_book_code: "LJB",
-        BOS_reference_number: 102,
-        BOS_sequence_number: 222,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(6),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Ep Jer"),
-        OSIS_abbreviation: Some("EpJer"),
-        Sword_abbreviation: Some("EpJer"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("LJe"),
-        USFM_number_str: Some("74"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["BAR"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EPISTLE OF JEREMIAH W,EPISTLE OF JEREMIAH WI,EPISTLE OF JEREMIAH WIT,EPISTLE OF JEREMIAH WITH,EPISTLE OF JEREMIAH WITH B,EPISTLE OF JEREMIAH WITH BA,EPISTLE OF JEREMIAH WITH BAR,EPISTLE OF JEREMIAH WITH BARU,EPISTLE OF JEREMIAH WITH BARUC,EPISTLE OF JEREMIAH WITH BARUCH,EPISTLEOFJEREMIAHW,EPISTLEOFJEREMIAHWI,EPISTLEOFJEREMIAHWIT,EPISTLEOFJEREMIAHWITH,EPISTLEOFJEREMIAHWITHB,EPISTLEOFJEREMIAHWITHBA,EPISTLEOFJEREMIAHWITHBAR,EPISTLEOFJEREMIAHWITHBARU,EPISTLEOFJEREMIAHWITHBARUC,EPISTLEOFJEREMIAHWITHBARUCH,LETTER OF JEREMIAH W,LETTER OF JEREMIAH WI,LETTER OF JEREMIAH WIT,LETTER OF JEREMIAH WITH,LETTER OF JEREMIAH WITH B,LETTER OF JEREMIAH WITH BA,LETTER OF JEREMIAH WITH BAR,LETTER OF JEREMIAH WITH BARU,LETTER OF JEREMIAH WITH BARUC,LETTER OF JEREMIAH WITH BARUCH,LETTEROFJEREMIAHW,LETTEROFJEREMIAHWI,LETTEROFJEREMIAHWIT,LETTEROFJEREMIAHWITH,LETTEROFJEREMIAHWITHB,LETTEROFJEREMIAHWITHBA,LETTEROFJEREMIAHWITHBAR,LETTEROFJEREMIAHWITHBARU,LETTEROFJEREMIAHWITHBARUC,LETTEROFJEREMIAHWITHBARUCH,LJB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "2 Baruch / Apocalypse of Baruch",
-        BOS_book_code: "BA2",
-        BOS_reference_number: 103,
-        BOS_sequence_number: 228,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(77),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("2Bar"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("2Ba"),
-        USFM_number_str: Some("B4"),
-        USX_number_str: Some("114"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(96),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LB2"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2BA,2BAR,2BARUCH,APOCALYPSE OF B,APOCALYPSE OF BA,APOCALYPSE OF BAR,APOCALYPSE OF BARU,APOCALYPSE OF BARUC,APOCALYPSE OF BARUCH,APOCALYPSEOFB,APOCALYPSEOFBA,APOCALYPSEOFBAR,APOCALYPSEOFBARU,APOCALYPSEOFBARUC,APOCALYPSEOFBARUCH,BA2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter of Baruch / Epistle of Baruch",
-        BOS_book_code: "LBA",
-        BOS_reference_number: 104,
-        BOS_sequence_number: 224,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(9),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("EpBar"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("LBa"),
-        USFM_number_str: Some("B5"),
-        USX_number_str: Some("115"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(97),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LB2"],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EPB,EPBA,EPBAR,EPISTLE OF BARU,EPISTLE OF BARUC,EPISTLE OF BARUCH,EPISTLEOFBARU,EPISTLEOFBARUC,EPISTLEOFBARUCH,LBA,LETTER OF BARU,LETTER OF BARUC,LETTER OF BARUCH,LETTEROFBARU,LETTEROFBARUC,LETTEROFBARUCH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "2 Baruch with the Letter of Baruch",
-        BOS_book_code: "LB2",
-        BOS_reference_number: 105,
-        BOS_sequence_number: 226,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(86),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("2Ba"),
-        USFM_number_str: Some("B4"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(99),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["BA2","LBA"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2 B,2 BA,2 BAR,2 BARU,2 BARUC,2 BARUCH W,2 BARUCH WI,2 BARUCH WIT,2 BARUCH WITH,2 BARUCH WITH T,2 BARUCH WITH TH,2 BARUCH WITH THE,2 BARUCH WITH THE L,2 BARUCH WITH THE LE,2 BARUCH WITH THE LET,2 BARUCH WITH THE LETT,2 BARUCH WITH THE LETTE,2 BARUCH WITH THE LETTER,2 BARUCH WITH THE LETTER O,2 BARUCH WITH THE LETTER OF,2 BARUCH WITH THE LETTER OF B,2 BARUCH WITH THE LETTER OF BA,2 BARUCH WITH THE LETTER OF BAR,2 BARUCH WITH THE LETTER OF BARU,2 BARUCH WITH THE LETTER OF BARUC,2 BARUCH WITH THE LETTER OF BARUCH,2BARUCHW,2BARUCHWI,2BARUCHWIT,2BARUCHWITH,2BARUCHWITHT,2BARUCHWITHTH,2BARUCHWITHTHE,2BARUCHWITHTHEL,2BARUCHWITHTHELE,2BARUCHWITHTHELET,2BARUCHWITHTHELETT,2BARUCHWITHTHELETTE,2BARUCHWITHTHELETTER,2BARUCHWITHTHELETTERO,2BARUCHWITHTHELETTEROF,2BARUCHWITHTHELETTEROFB,2BARUCHWITHTHELETTEROFBA,2BARUCHWITHTHELETTEROFBAR,2BARUCHWITHTHELETTEROFBARU,2BARUCHWITHTHELETTEROFBARUC,2BARUCHWITHTHELETTEROFBARUCH,LB2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "3 Baruch",
-        BOS_book_code: "BA3",
-        BOS_reference_number: 106,
-        BOS_sequence_number: 230,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("3Bar"),
-        Sword_abbreviation: Some("3Bar"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "3 B,3 BA,3 BAR,3 BARU,3 BARUC,3 BARUCH,3B,3BA,3BAR,3BARU,3BARUC,3BARUCH,BA3",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "4 Baruch / Paralipomenon of Jeremiah / Paraleipomena Ieremiou / Rest of the Words of Baruch",
-        BOS_book_code: "BA4",
-        BOS_reference_number: 107,
-        BOS_sequence_number: 232,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([6,5]),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("4Bar"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("4Ba"),
-        USFM_number_str: Some("C2"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(208),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "4 B,4 BA,4 BAR,4 BARU,4 BARUC,4 BARUCH,4B,4BA,4BAR,4BARU,4BARUC,4BARUCH,BA4,PAR,PARA,PARAL,PARALE,PARALEI,PARALEIP,PARALEIPO,PARALEIPOM,PARALEIPOME,PARALEIPOMEN,PARALEIPOMENA,PARALEIPOMENA I,PARALEIPOMENA IE,PARALEIPOMENA IER,PARALEIPOMENA IERE,PARALEIPOMENA IEREM,PARALEIPOMENA IEREMI,PARALEIPOMENA IEREMIO,PARALEIPOMENA IEREMIOU,PARALEIPOMENAI,PARALEIPOMENAIE,PARALEIPOMENAIER,PARALEIPOMENAIERE,PARALEIPOMENAIEREM,PARALEIPOMENAIEREMI,PARALEIPOMENAIEREMIO,PARALEIPOMENAIEREMIOU,PARALI,PARALIP,PARALIPO,PARALIPOM,PARALIPOME,PARALIPOMEN,PARALIPOMENO,PARALIPOMENON,PARALIPOMENON O,PARALIPOMENON OF,PARALIPOMENON OF J,PARALIPOMENON OF JE,PARALIPOMENON OF JER,PARALIPOMENON OF JERE,PARALIPOMENON OF JEREM,PARALIPOMENON OF JEREMI,PARALIPOMENON OF JEREMIA,PARALIPOMENON OF JEREMIAH,PARALIPOMENONO,PARALIPOMENONOF,PARALIPOMENONOFJ,PARALIPOMENONOFJE,PARALIPOMENONOFJER,PARALIPOMENONOFJERE,PARALIPOMENONOFJEREM,PARALIPOMENONOFJEREMI,PARALIPOMENONOFJEREMIA,PARALIPOMENONOFJEREMIAH,REST OF T,REST OF TH,REST OF THE,REST OF THE W,REST OF THE WO,REST OF THE WOR,REST OF THE WORD,REST OF THE WORDS,REST OF THE WORDS O,REST OF THE WORDS OF,REST OF THE WORDS OF B,REST OF THE WORDS OF BA,REST OF THE WORDS OF BAR,REST OF THE WORDS OF BARU,REST OF THE WORDS OF BARUC,REST OF THE WORDS OF BARUCH,RESTOFT,RESTOFTH,RESTOFTHE,RESTOFTHEW,RESTOFTHEWO,RESTOFTHEWOR,RESTOFTHEWORD,RESTOFTHEWORDS,RESTOFTHEWORDSO,RESTOFTHEWORDSOF,RESTOFTHEWORDSOFB,RESTOFTHEWORDSOFBA,RESTOFTHEWORDSOFBAR,RESTOFTHEWORDSOFBARU,RESTOFTHEWORDSOFBARUC,RESTOFTHEWORDSOFBARUCH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Additions to Jeremiah / Rest of Jeremiah",
-        BOS_book_code: "JRA",
-        BOS_reference_number: 108,
-        BOS_sequence_number: 233,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("AddJer"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ADDITIONS TO J,ADDITIONS TO JE,ADDITIONS TO JER,ADDITIONS TO JERE,ADDITIONS TO JEREM,ADDITIONS TO JEREMI,ADDITIONS TO JEREMIA,ADDITIONS TO JEREMIAH,ADDITIONSTOJ,ADDITIONSTOJE,ADDITIONSTOJER,ADDITIONSTOJERE,ADDITIONSTOJEREM,ADDITIONSTOJEREMI,ADDITIONSTOJEREMIA,ADDITIONSTOJEREMIAH,ADDJ,ADDJE,ADDJER,JRA,REST OF J,REST OF JE,REST OF JER,REST OF JERE,REST OF JEREM,REST OF JEREMI,REST OF JEREMIA,REST OF JEREMIAH,RESTOFJ,RESTOFJE,RESTOFJER,RESTOFJERE,RESTOFJEREM,RESTOFJEREMI,RESTOFJEREMIA,RESTOFJEREMIAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Pseudo-Josephus",
-        BOS_book_code: "PSJ",
-        BOS_reference_number: 109,
-        BOS_sequence_number: 234,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PsJos"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PSEUDO-J,PSEUDO-JO,PSEUDO-JOS,PSEUDO-JOSE,PSEUDO-JOSEP,PSEUDO-JOSEPH,PSEUDO-JOSEPHU,PSEUDO-JOSEPHUS,PSJ,PSJO,PSJOS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Maccabees",
-        BOS_book_code: "MAC",
-        BOS_reference_number: 110,
-        BOS_sequence_number: 270,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(31),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "MAC,MACC,MACCA,MACCAB,MACCABE,MACCABEE,MACCABEES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "1 Maccabees",
-        BOS_book_code: "MA1",
-        BOS_reference_number: 111,
-        BOS_sequence_number: 272,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: Some("1Ma"),
-        SBL_abbreviation: Some("1 Macc"),
-        OSIS_abbreviation: Some("1Macc"),
-        Sword_abbreviation: Some("1Macc"),
-        CCEL_number: Some(79),
-        USFM_abbreviation: Some("1Ma"),
-        USFM_number_str: Some("78"),
-        USX_number_str: Some("077"),
-        Unbound_Code: Some("77A"),
-        Bibledit_number: Some(80),
-        Logos_number: Some(50),
-        Logos_abbreviation: Some("1Mac"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("1MA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["1 MCB"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "1 MAC,1 MACC,1 MACCA,1 MACCAB,1 MACCABE,1 MACCABEE,1 MACCABEES,1 MC,1 MCB,1MA,1MAC,1MACC,1MACCA,1MACCAB,1MACCABE,1MACCABEE,1MACCABEES,1MC,1MCB,MA1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "2 Maccabees",
-        BOS_book_code: "MA2",
-        BOS_reference_number: 112,
-        BOS_sequence_number: 274,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(15),
-        short_abbreviation: Some("2Ma"),
-        SBL_abbreviation: Some("2 Macc"),
-        OSIS_abbreviation: Some("2Macc"),
-        Sword_abbreviation: Some("2Macc"),
-        CCEL_number: Some(80),
-        USFM_abbreviation: Some("2Ma"),
-        USFM_number_str: Some("79"),
-        USX_number_str: Some("078"),
-        Unbound_Code: Some("78A"),
-        Bibledit_number: Some(81),
-        Logos_number: Some(51),
-        Logos_abbreviation: Some("2Mac"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("2MA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["2 MCB"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2 MAC,2 MACC,2 MACCA,2 MACCAB,2 MACCABE,2 MACCABEE,2 MACCABEES,2 MC,2 MCB,2MA,2MAC,2MACC,2MACCA,2MACCAB,2MACCABE,2MACCABEE,2MACCABEES,2MC,2MCB,MA2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "3 Maccabees",
-        BOS_book_code: "MA3",
-        BOS_reference_number: 113,
-        BOS_sequence_number: 276,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(7),
-        short_abbreviation: Some("3Ma"),
-        SBL_abbreviation: Some("3 Macc"),
-        OSIS_abbreviation: Some("3Macc"),
-        Sword_abbreviation: Some("3Macc"),
-        CCEL_number: Some(84),
-        USFM_abbreviation: Some("3Ma"),
-        USFM_number_str: Some("80"),
-        USX_number_str: Some("079"),
-        Unbound_Code: Some("79A"),
-        Bibledit_number: Some(85),
-        Logos_number: Some(55),
-        Logos_abbreviation: Some("3Mac"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("3MA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["3 MCB"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "3 MAC,3 MACC,3 MACCA,3 MACCAB,3 MACCABE,3 MACCABEE,3 MACCABEES,3 MC,3 MCB,3MA,3MAC,3MACC,3MACCA,3MACCAB,3MACCABE,3MACCABEE,3MACCABEES,3MC,3MCB,MA3",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "4 Maccabees",
-        BOS_book_code: "MA4",
-        BOS_reference_number: 114,
-        BOS_sequence_number: 278,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(18),
-        short_abbreviation: Some("4Ma"),
-        SBL_abbreviation: Some("4 Macc"),
-        OSIS_abbreviation: Some("4Macc"),
-        Sword_abbreviation: Some("4Macc"),
-        CCEL_number: Some(85),
-        USFM_abbreviation: Some("4Ma"),
-        USFM_number_str: Some("81"),
-        USX_number_str: Some("080"),
-        Unbound_Code: Some("80A"),
-        Bibledit_number: Some(87),
-        Logos_number: Some(57),
-        Logos_abbreviation: Some("4Mac"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("4MA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["4 MCB"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "4 MA,4 MAC,4 MACC,4 MACCA,4 MACCAB,4 MACCABE,4 MACCABEE,4 MACCABEES,4 MC,4 MCB,4MA,4MAC,4MACC,4MACCA,4MACCAB,4MACCABE,4MACCABEE,4MACCABEES,4MC,4MCB,MA4",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "5 Maccabees",
-        BOS_book_code: "MA5",
-        BOS_reference_number: 115,
-        BOS_sequence_number: 280,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: Some("5Ma"),
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("5Macc"),
-        Sword_abbreviation: Some("5Macc"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["5 MCB"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "5 MA,5 MAC,5 MACC,5 MACCA,5 MACCAB,5 MACCABE,5 MACCABEE,5 MACCABEES,5 MC,5 MCB,5MA,5MAC,5MACC,5MACCA,5MACCAB,5MACCABE,5MACCABEE,5MACCABEES,5MC,5MCB,MA5",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "1 Meqabyan / 1 Makabian / Mekabis of Benjamin",
-        BOS_book_code: "MQ1",
-        BOS_reference_number: 116,
-        BOS_sequence_number: 500,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(36),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("1Meq"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("1Mq"),
-        USFM_number_str: Some("B8"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(204),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "1 MAK,1 MAKA,1 MAKAB,1 MAKABI,1 MAKABIA,1 MAKABIAN,1 ME,1 MEQ,1 MEQA,1 MEQAB,1 MEQABY,1 MEQABYA,1 MEQABYAN,1MAK,1MAKA,1MAKAB,1MAKABI,1MAKABIA,1MAKABIAN,1ME,1MEQ,1MEQA,1MEQAB,1MEQABY,1MEQABYA,1MEQABYAN,1MQ,MEKABIS OF B,MEKABIS OF BE,MEKABIS OF BEN,MEKABIS OF BENJ,MEKABIS OF BENJA,MEKABIS OF BENJAM,MEKABIS OF BENJAMI,MEKABIS OF BENJAMIN,MEKABISOFB,MEKABISOFBE,MEKABISOFBEN,MEKABISOFBENJ,MEKABISOFBENJA,MEKABISOFBENJAM,MEKABISOFBENJAMI,MEKABISOFBENJAMIN,MQ1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "2 Meqabyan / 2 Makabian / Mekabis of Moab",
-        BOS_book_code: "MQ2",
-        BOS_reference_number: 117,
-        BOS_sequence_number: 502,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([20,21]),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("2Meq"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("2Mq"),
-        USFM_number_str: Some("B9"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(205),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2 MAK,2 MAKA,2 MAKAB,2 MAKABI,2 MAKABIA,2 MAKABIAN,2 ME,2 MEQ,2 MEQA,2 MEQAB,2 MEQABY,2 MEQABYA,2 MEQABYAN,2MAK,2MAKA,2MAKAB,2MAKABI,2MAKABIA,2MAKABIAN,2ME,2MEQ,2MEQA,2MEQAB,2MEQABY,2MEQABYA,2MEQABYAN,2MQ,MEKABIS OF M,MEKABIS OF MO,MEKABIS OF MOA,MEKABIS OF MOAB,MEKABISOFM,MEKABISOFMO,MEKABISOFMOA,MEKABISOFMOAB,MQ2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "3 Meqabyan / 3 Makabian",
-        BOS_book_code: "MQ3",
-        BOS_reference_number: 118,
-        BOS_sequence_number: 506,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(10),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("3Meq"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("3Mq"),
-        USFM_number_str: Some("C0"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(206),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "3 MAK,3 MAKA,3 MAKAB,3 MAKABI,3 MAKABIA,3 MAKABIAN,3 ME,3 MEQ,3 MEQA,3 MEQAB,3 MEQABY,3 MEQABYA,3 MEQABYAN,3MAK,3MAKA,3MAKAB,3MAKABI,3MAKABIA,3MAKABIAN,3ME,3MEQ,3MEQA,3MEQAB,3MEQABY,3MEQABYA,3MEQABYAN,3MQ,MQ3",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalms Book 1 / Psalms Book I",
-        BOS_book_code: "PB1",
-        BOS_reference_number: 120,
-        BOS_sequence_number: 101,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(41),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PB1,PSALMS BOOK 1,PSALMS BOOK I,PSALMSBOOK1,PSALMSBOOKI",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalms Book 2 / Psalms Book II",
-        BOS_book_code: "PB2",
-        BOS_reference_number: 121,
-        BOS_sequence_number: 102,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(31),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PB2,PSALMS BOOK 2,PSALMS BOOK II,PSALMSBOOK2,PSALMSBOOKII",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalms Book 3 / Psalms Book III",
-        BOS_book_code: "PB3",
-        BOS_reference_number: 122,
-        BOS_sequence_number: 103,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(17),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PB3,PSALMS BOOK 3,PSALMS BOOK III,PSALMSBOOK3,PSALMSBOOKIII",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalms Book 4 / Psalms Book IV",
-        BOS_book_code: "PB4",
-        BOS_reference_number: 123,
-        BOS_sequence_number: 104,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(17),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PB4,PSALMS BOOK 4,PSALMS BOOK IV,PSALMSBOOK4,PSALMSBOOKIV",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalms Book 5 / Psalms Book V",
-        BOS_book_code: "PB5",
-        BOS_reference_number: 124,
-        BOS_sequence_number: 105,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(44),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PB5,PSALMS BOOK 5,PSALMS BOOK V,PSALMSBOOK5,PSALMSBOOKV",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalms B / Liturgical Psalms / Metrical Psalms / Alternative Psalms / Grail Psalms",
-        BOS_book_code: "PSB",
-        BOS_reference_number: 125,
-        BOS_sequence_number: 106,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(150),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PsMet"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("PsB"),
-        USFM_number_str: Some("88"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ALTERNATIVE PS,ALTERNATIVE PSA,ALTERNATIVE PSAL,ALTERNATIVE PSALM,ALTERNATIVE PSALMS,ALTERNATIVEP,ALTERNATIVEPS,ALTERNATIVEPSA,ALTERNATIVEPSAL,ALTERNATIVEPSALM,ALTERNATIVEPSALMS,GRA,GRAI,GRAIL,GRAIL P,GRAIL PS,GRAIL PSA,GRAIL PSAL,GRAIL PSALM,GRAIL PSALMS,GRAILP,GRAILPS,GRAILPSA,GRAILPSAL,GRAILPSALM,GRAILPSALMS,LIT,LITU,LITUR,LITURG,LITURGI,LITURGIC,LITURGICA,LITURGICAL,LITURGICAL P,LITURGICAL PS,LITURGICAL PSA,LITURGICAL PSAL,LITURGICAL PSALM,LITURGICAL PSALMS,LITURGICALP,LITURGICALPS,LITURGICALPSA,LITURGICALPSAL,LITURGICALPSALM,LITURGICALPSALMS,MET,METR,METRI,METRIC,METRICA,METRICAL,METRICAL P,METRICAL PS,METRICAL PSA,METRICAL PSAL,METRICAL PSALM,METRICAL PSALMS,METRICALP,METRICALPS,METRICALPSA,METRICALPSAL,METRICALPSALM,METRICALPSALMS,PSALMSB,PSB,PSM,PSME,PSMET",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalm 151",
-        BOS_book_code: "PS2",
-        BOS_reference_number: 126,
-        BOS_sequence_number: 107,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("AddPs"),
-        Sword_abbreviation: Some("AddPs"),
-        CCEL_number: Some(86),
-        USFM_abbreviation: Some("Ps2"),
-        USFM_number_str: Some("85"),
-        USX_number_str: Some("084"),
-        Unbound_Code: Some("84A"),
-        Bibledit_number: Some(84),
-        Logos_number: Some(54),
-        Logos_abbreviation: Some("Ps151"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("PSX"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("OT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ADDP,ADDPS,PS1,PS15,PS151,PS2,PSALM 151,PSX",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Syriac Psalms 152-155 / Five apocryphal Syriac Psalms / Additional Syriac Psalms",
-        BOS_book_code: "PS3",
-        BOS_reference_number: 127,
-        BOS_sequence_number: 108,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::TwoNumbers([5,4]),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("5ApocSyrPss"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Ps3"),
-        USFM_number_str: Some("B3"),
-        USX_number_str: Some("113"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(202),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "5A,5AP,5APO,5APOC,5APOCS,5APOCSY,5APOCSYR,5APOCSYRP,5APOCSYRPS,5APOCSYRPSS,ADDITIONAL S,ADDITIONAL SY,ADDITIONAL SYR,ADDITIONAL SYRI,ADDITIONAL SYRIA,ADDITIONAL SYRIAC,ADDITIONAL SYRIAC P,ADDITIONAL SYRIAC PS,ADDITIONAL SYRIAC PSA,ADDITIONAL SYRIAC PSAL,ADDITIONAL SYRIAC PSALM,ADDITIONAL SYRIAC PSALMS,ADDITIONALS,ADDITIONALSY,ADDITIONALSYR,ADDITIONALSYRI,ADDITIONALSYRIA,ADDITIONALSYRIAC,ADDITIONALSYRIACP,ADDITIONALSYRIACPS,ADDITIONALSYRIACPSA,ADDITIONALSYRIACPSAL,ADDITIONALSYRIACPSALM,ADDITIONALSYRIACPSALMS,FI,FIV,FIVE,FIVE A,FIVE AP,FIVE APO,FIVE APOC,FIVE APOCR,FIVE APOCRY,FIVE APOCRYP,FIVE APOCRYPH,FIVE APOCRYPHA,FIVE APOCRYPHAL,FIVE APOCRYPHAL S,FIVE APOCRYPHAL SY,FIVE APOCRYPHAL SYR,FIVE APOCRYPHAL SYRI,FIVE APOCRYPHAL SYRIA,FIVE APOCRYPHAL SYRIAC,FIVE APOCRYPHAL SYRIAC P,FIVE APOCRYPHAL SYRIAC PS,FIVE APOCRYPHAL SYRIAC PSA,FIVE APOCRYPHAL SYRIAC PSAL,FIVE APOCRYPHAL SYRIAC PSALM,FIVE APOCRYPHAL SYRIAC PSALMS,FIVEA,FIVEAP,FIVEAPO,FIVEAPOC,FIVEAPOCR,FIVEAPOCRY,FIVEAPOCRYP,FIVEAPOCRYPH,FIVEAPOCRYPHA,FIVEAPOCRYPHAL,FIVEAPOCRYPHALS,FIVEAPOCRYPHALSY,FIVEAPOCRYPHALSYR,FIVEAPOCRYPHALSYRI,FIVEAPOCRYPHALSYRIA,FIVEAPOCRYPHALSYRIAC,FIVEAPOCRYPHALSYRIACP,FIVEAPOCRYPHALSYRIACPS,FIVEAPOCRYPHALSYRIACPSA,FIVEAPOCRYPHALSYRIACPSAL,FIVEAPOCRYPHALSYRIACPSALM,FIVEAPOCRYPHALSYRIACPSALMS,PS3,SY,SYR,SYRI,SYRIA,SYRIAC,SYRIAC P,SYRIAC PS,SYRIAC PSA,SYRIAC PSAL,SYRIAC PSALM,SYRIAC PSALMS,SYRIAC PSALMS 1,SYRIAC PSALMS 15,SYRIAC PSALMS 152,SYRIAC PSALMS 152-,SYRIAC PSALMS 152-1,SYRIAC PSALMS 152-15,SYRIAC PSALMS 152-155,SYRIACP,SYRIACPS,SYRIACPSA,SYRIACPSAL,SYRIACPSALM,SYRIACPSALMS,SYRIACPSALMS1,SYRIACPSALMS15,SYRIACPSALMS152,SYRIACPSALMS152-,SYRIACPSALMS152-1,SYRIACPSALMS152-15,SYRIACPSALMS152-155",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Additional Psalms of Solomon",
-        BOS_book_code: "PSS",
-        BOS_reference_number: 128,
-        BOS_sequence_number: 109,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(18),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PssSol"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("PsS"),
-        USFM_number_str: Some("87"),
-        USX_number_str: Some("086"),
-        Unbound_Code: Some("85A"),
-        Bibledit_number: None,
-        Logos_number: Some(59),
-        Logos_abbreviation: Some("PsSol"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("PSS"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ADDITIONAL P,ADDITIONAL PS,ADDITIONAL PSA,ADDITIONAL PSAL,ADDITIONAL PSALM,ADDITIONAL PSALMS,ADDITIONAL PSALMS O,ADDITIONAL PSALMS OF,ADDITIONAL PSALMS OF S,ADDITIONAL PSALMS OF SO,ADDITIONAL PSALMS OF SOL,ADDITIONAL PSALMS OF SOLO,ADDITIONAL PSALMS OF SOLOM,ADDITIONAL PSALMS OF SOLOMO,ADDITIONAL PSALMS OF SOLOMON,ADDITIONALP,ADDITIONALPS,ADDITIONALPSA,ADDITIONALPSAL,ADDITIONALPSALM,ADDITIONALPSALMS,ADDITIONALPSALMSO,ADDITIONALPSALMSOF,ADDITIONALPSALMSOFS,ADDITIONALPSALMSOFSO,ADDITIONALPSALMSOFSOL,ADDITIONALPSALMSOFSOLO,ADDITIONALPSALMSOFSOLOM,ADDITIONALPSALMSOFSOLOMO,ADDITIONALPSALMSOFSOLOMON,PSS,PSSO,PSSOL,PSSS,PSSSO,PSSSOL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter to the Laodiceans",
-        BOS_book_code: "LAO",
-        BOS_reference_number: 130,
-        BOS_sequence_number: 660,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("EpLao"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Lao"),
-        USFM_number_str: Some("C3"),
-        USX_number_str: Some("123"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(60),
-        Logos_abbreviation: Some("Laod"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("LAO"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EPLA,EPLAO,LAO,LAOD,LETTER T,LETTER TO,LETTER TO T,LETTER TO TH,LETTER TO THE,LETTER TO THE L,LETTER TO THE LA,LETTER TO THE LAO,LETTER TO THE LAOD,LETTER TO THE LAODI,LETTER TO THE LAODIC,LETTER TO THE LAODICE,LETTER TO THE LAODICEA,LETTER TO THE LAODICEAN,LETTER TO THE LAODICEANS,LETTERT,LETTERTO,LETTERTOT,LETTERTOTH,LETTERTOTHE,LETTERTOTHEL,LETTERTOTHELA,LETTERTOTHELAO,LETTERTOTHELAOD,LETTERTOTHELAODI,LETTERTOTHELAODIC,LETTERTOTHELAODICE,LETTERTOTHELAODICEA,LETTERTOTHELAODICEAN,LETTERTOTHELAODICEANS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Odes / Odae",
-        BOS_book_code: "ODE",
-        BOS_reference_number: 131,
-        BOS_sequence_number: 300,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Odes"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Oda"),
-        USFM_number_str: Some("86"),
-        USX_number_str: Some("085"),
-        Unbound_Code: Some("86A"),
-        Bibledit_number: None,
-        Logos_number: Some(58),
-        Logos_abbreviation: Some("Ode"),
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("ODE"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "OD,ODA,ODAE,ODE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Prayer of Solomon",
-        BOS_book_code: "PSO",
-        BOS_reference_number: 132,
-        BOS_sequence_number: 310,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PrSol"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("PSo"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["PRS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PRAYER OF S,PRAYER OF SO,PRAYER OF SOL,PRAYER OF SOLO,PRAYER OF SOLOM,PRAYER OF SOLOMO,PRAYER OF SOLOMON,PRAYEROFS,PRAYEROFSO,PRAYEROFSOL,PRAYEROFSOLO,PRAYEROFSOLOM,PRAYEROFSOLOMO,PRAYEROFSOLOMON,PRS,PRSO,PRSOL,PSO",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Prayer of Jeremiah",
-        BOS_book_code: "PJE",
-        BOS_reference_number: 133,
-        BOS_sequence_number: 312,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PrJer"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("PJe"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["PRJ"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PJ,PJE,PRAYER OF J,PRAYER OF JE,PRAYER OF JER,PRAYER OF JERE,PRAYER OF JEREM,PRAYER OF JEREMI,PRAYER OF JEREMIA,PRAYER OF JEREMIAH,PRAYEROFJ,PRAYEROFJE,PRAYEROFJER,PRAYEROFJERE,PRAYEROFJEREM,PRAYEROFJEREMI,PRAYEROFJEREMIA,PRAYEROFJEREMIAH,PRJ,PRJE,PRJER",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Words of Sirach",
-        BOS_book_code: "WSI",
-        BOS_reference_number: 134,
-        BOS_sequence_number: 320,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("WSir"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("WSi"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "WORDS,WORDS O,WORDS OF,WORDS OF S,WORDS OF SI,WORDS OF SIR,WORDS OF SIRA,WORDS OF SIRAC,WORDS OF SIRACH,WORDSO,WORDSOF,WORDSOFS,WORDSOFSI,WORDSOFSIR,WORDSOFSIRA,WORDSOFSIRAC,WORDSOFSIRACH,WS,WSI,WSIR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter of the Corinthians to Paul / Epistle of the Corinthians to Paul",
-        BOS_book_code: "COP",
-        BOS_reference_number: 135,
-        BOS_sequence_number: 662,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("EpCorPaul"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("CoP"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "COP,EPC,EPCO,EPCOR,EPCORP,EPCORPA,EPCORPAU,EPCORPAUL,EPISTLE OF T,EPISTLE OF TH,EPISTLE OF THE,EPISTLE OF THE C,EPISTLE OF THE CO,EPISTLE OF THE COR,EPISTLE OF THE CORI,EPISTLE OF THE CORIN,EPISTLE OF THE CORINT,EPISTLE OF THE CORINTH,EPISTLE OF THE CORINTHI,EPISTLE OF THE CORINTHIA,EPISTLE OF THE CORINTHIAN,EPISTLE OF THE CORINTHIANS,EPISTLE OF THE CORINTHIANS T,EPISTLE OF THE CORINTHIANS TO,EPISTLE OF THE CORINTHIANS TO P,EPISTLE OF THE CORINTHIANS TO PA,EPISTLE OF THE CORINTHIANS TO PAU,EPISTLE OF THE CORINTHIANS TO PAUL,EPISTLEOFT,EPISTLEOFTH,EPISTLEOFTHE,EPISTLEOFTHEC,EPISTLEOFTHECO,EPISTLEOFTHECOR,EPISTLEOFTHECORI,EPISTLEOFTHECORIN,EPISTLEOFTHECORINT,EPISTLEOFTHECORINTH,EPISTLEOFTHECORINTHI,EPISTLEOFTHECORINTHIA,EPISTLEOFTHECORINTHIAN,EPISTLEOFTHECORINTHIANS,EPISTLEOFTHECORINTHIANST,EPISTLEOFTHECORINTHIANSTO,EPISTLEOFTHECORINTHIANSTOP,EPISTLEOFTHECORINTHIANSTOPA,EPISTLEOFTHECORINTHIANSTOPAU,EPISTLEOFTHECORINTHIANSTOPAUL,LETTER OF T,LETTER OF TH,LETTER OF THE,LETTER OF THE C,LETTER OF THE CO,LETTER OF THE COR,LETTER OF THE CORI,LETTER OF THE CORIN,LETTER OF THE CORINT,LETTER OF THE CORINTH,LETTER OF THE CORINTHI,LETTER OF THE CORINTHIA,LETTER OF THE CORINTHIAN,LETTER OF THE CORINTHIANS,LETTER OF THE CORINTHIANS T,LETTER OF THE CORINTHIANS TO,LETTER OF THE CORINTHIANS TO P,LETTER OF THE CORINTHIANS TO PA,LETTER OF THE CORINTHIANS TO PAU,LETTER OF THE CORINTHIANS TO PAUL,LETTEROFT,LETTEROFTH,LETTEROFTHE,LETTEROFTHEC,LETTEROFTHECO,LETTEROFTHECOR,LETTEROFTHECORI,LETTEROFTHECORIN,LETTEROFTHECORINT,LETTEROFTHECORINTH,LETTEROFTHECORINTHI,LETTEROFTHECORINTHIA,LETTEROFTHECORINTHIAN,LETTEROFTHECORINTHIANS,LETTEROFTHECORINTHIANST,LETTEROFTHECORINTHIANSTO,LETTEROFTHECORINTHIANSTOP,LETTEROFTHECORINTHIANSTOPA,LETTEROFTHECORINTHIANSTOPAU,LETTEROFTHECORINTHIANSTOPAUL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "3 Corinthians",
-        BOS_book_code: "CO3",
-        BOS_reference_number: 136,
-        BOS_sequence_number: 664,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("3Cor"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("3Co"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "3 C,3 CO,3 COR,3 CORI,3 CORIN,3 CORINT,3 CORINTH,3 CORINTHI,3 CORINTHIA,3 CORINTHIAN,3 CORINTHIANS,3C,3CO,3COR,3CORI,3CORIN,3CORINT,3CORINTH,3CORINTHI,3CORINTHIA,3CORINTHIAN,3CORINTHIANS,CO3",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Prayer of Euthalius",
-        BOS_book_code: "EUT",
-        BOS_reference_number: 137,
-        BOS_sequence_number: 670,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PrEuth"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Eut"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["PRE"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EUT,PRAYER OF E,PRAYER OF EU,PRAYER OF EUT,PRAYER OF EUTH,PRAYER OF EUTHA,PRAYER OF EUTHAL,PRAYER OF EUTHALI,PRAYER OF EUTHALIU,PRAYER OF EUTHALIUS,PRAYEROFE,PRAYEROFEU,PRAYEROFEUT,PRAYEROFEUTH,PRAYEROFEUTHA,PRAYEROFEUTHAL,PRAYEROFEUTHALI,PRAYEROFEUTHALIU,PRAYEROFEUTHALIUS,PRE,PREU,PREUT,PREUTH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Dormition of John",
-        BOS_book_code: "DOJ",
-        BOS_reference_number: 138,
-        BOS_sequence_number: 680,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("DormJohn"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("DoJ"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("NT+"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "DO,DOJ,DOR,DORM,DORMI,DORMIT,DORMITI,DORMITIO,DORMITION,DORMITION O,DORMITION OF,DORMITION OF J,DORMITION OF JO,DORMITION OF JOH,DORMITION OF JOHN,DORMITIONO,DORMITIONOF,DORMITIONOFJ,DORMITIONOFJO,DORMITIONOFJOH,DORMITIONOFJOHN,DORMJ,DORMJO,DORMJOH,DORMJOHN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Jubilees",
-        BOS_book_code: "JUB",
-        BOS_reference_number: 140,
-        BOS_sequence_number: 340,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(34),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Jub"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Jub"),
-        USFM_number_str: Some("B6"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(203),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DC"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JUB,JUBI,JUBIL,JUBILE,JUBILEE,JUBILEES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Enoch / 1 Enoch / Ethiopic Apocalypse of Enoch",
-        BOS_book_code: "ENO",
-        BOS_reference_number: 141,
-        BOS_sequence_number: 342,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(42),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("1En"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Eno"),
-        USFM_number_str: Some("B7"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(88),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "1 EN,1 ENO,1 ENOC,1 ENOCH,1EN,1ENO,1ENOC,1ENOCH,ENO,ENOC,ENOCH,ET,ETH,ETHI,ETHIO,ETHIOP,ETHIOPI,ETHIOPIC,ETHIOPIC A,ETHIOPIC AP,ETHIOPIC APO,ETHIOPIC APOC,ETHIOPIC APOCA,ETHIOPIC APOCAL,ETHIOPIC APOCALY,ETHIOPIC APOCALYP,ETHIOPIC APOCALYPS,ETHIOPIC APOCALYPSE,ETHIOPIC APOCALYPSE O,ETHIOPIC APOCALYPSE OF,ETHIOPIC APOCALYPSE OF E,ETHIOPIC APOCALYPSE OF EN,ETHIOPIC APOCALYPSE OF ENO,ETHIOPIC APOCALYPSE OF ENOC,ETHIOPIC APOCALYPSE OF ENOCH,ETHIOPICA,ETHIOPICAP,ETHIOPICAPO,ETHIOPICAPOC,ETHIOPICAPOCA,ETHIOPICAPOCAL,ETHIOPICAPOCALY,ETHIOPICAPOCALYP,ETHIOPICAPOCALYPS,ETHIOPICAPOCALYPSE,ETHIOPICAPOCALYPSEO,ETHIOPICAPOCALYPSEOF,ETHIOPICAPOCALYPSEOFE,ETHIOPICAPOCALYPSEOFEN,ETHIOPICAPOCALYPSEOFENO,ETHIOPICAPOCALYPSEOFENOC,ETHIOPICAPOCALYPSEOFENOCH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "2 Enoch",
-        BOS_book_code: "EN2",
-        BOS_reference_number: 142,
-        BOS_sequence_number: 344,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: Some("2En"),
-        Sword_abbreviation: Some("2En"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2 EN,2 ENO,2 ENOC,2 ENOCH,2EN,2ENO,2ENOC,2ENOCH,EN2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Reproof / Tegsas / Tegsats / Taagsas",
-        BOS_book_code: "REP",
-        BOS_reference_number: 143,
-        BOS_sequence_number: 350,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(6),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Rep"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Rep"),
-        USFM_number_str: Some("C1"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(207),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "REP,REPR,REPRO,REPROO,REPROOF,TAA,TAAG,TAAGS,TAAGSA,TAAGSAS,TEG,TEGS,TEGSA,TEGSAS,TEGSAT,TEGSATS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter of Barnabas / Epistle of Barnabas / Barnabas",
-        BOS_book_code: "LBB",
-        BOS_reference_number: 144,
-        BOS_sequence_number: 690,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Barn"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BARN,BARNA,BARNAB,BARNABA,BARNABAS,EPISTLE OF BARN,EPISTLE OF BARNA,EPISTLE OF BARNAB,EPISTLE OF BARNABA,EPISTLE OF BARNABAS,EPISTLEOFBARN,EPISTLEOFBARNA,EPISTLEOFBARNAB,EPISTLEOFBARNABA,EPISTLEOFBARNABAS,LBB,LETTER OF BARN,LETTER OF BARNA,LETTER OF BARNAB,LETTER OF BARNABA,LETTER OF BARNABAS,LETTEROFBARN,LETTEROFBARNA,LETTEROFBARNAB,LETTEROFBARNABA,LETTEROFBARNABAS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "1 Clement",
-        BOS_book_code: "CL1",
-        BOS_reference_number: 150,
-        BOS_sequence_number: 360,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(65),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("1Clem"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("1Cl"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "1 CL,1 CLE,1 CLEM,1 CLEME,1 CLEMEN,1 CLEMENT,1CL,1CLE,1CLEM,1CLEME,1CLEMEN,1CLEMENT,CL1",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "2 Clement",
-        BOS_book_code: "CL2",
-        BOS_reference_number: 151,
-        BOS_sequence_number: 362,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(20),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("2Clem"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("2Cl"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "2 CL,2 CLE,2 CLEM,2 CLEME,2 CLEMEN,2 CLEMENT,2CL,2CLE,2CLEM,2CLEME,2CLEMEN,2CLEMENT,CL2",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Didache",
-        BOS_book_code: "DID",
-        BOS_reference_number: 153,
-        BOS_sequence_number: 366,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(16),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Did"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Did"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "DID,DIDA,DIDAC,DIDACH,DIDACHE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Joshua A",
-        BOS_book_code: "JSA",
-        BOS_reference_number: 154,
-        BOS_sequence_number: 370,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(24),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("JoshA"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("JsA"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("JSA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["JOS"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JOSHA,JOSHUA A,JOSHUAA,JSA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Joshua B",
-        BOS_book_code: "JSB",
-        BOS_reference_number: 155,
-        BOS_sequence_number: 371,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(24),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("JoshB"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("JsB"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("JSB"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["JOS"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JOSHB,JOSHUA B,JOSHUAB,JSB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Judges A",
-        BOS_book_code: "JGA",
-        BOS_reference_number: 156,
-        BOS_sequence_number: 372,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(21),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("JudgA"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("JdA"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("JDA"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["JDG"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JDA,JGA,JUDGA,JUDGES A,JUDGESA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Judges B",
-        BOS_book_code: "JGB",
-        BOS_reference_number: 157,
-        BOS_sequence_number: 373,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(21),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("JudgB"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("JdB"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("JDB"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["JDG"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JDB,JGB,JUDGB,JUDGES B,JUDGESB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Tobit S",
-        BOS_book_code: "TBS",
-        BOS_reference_number: 158,
-        BOS_sequence_number: 374,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(14),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("TobS"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("TbS"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("TBS"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["TOB"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TBS,TOBIT S,TOBITS,TOBS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Susanna θ / Susanna Th",
-        BOS_book_code: "SST",
-        BOS_reference_number: 159,
-        BOS_sequence_number: 375,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("SusTh"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("SsT"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("SUT"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["SUS"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SST,SUSANNA T,SUSANNA TH,SUSANNA Θ,SUSANNAT,SUSANNATH,SUSANNAΘ,SUST,SUSTH,SUT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Daniel θ / Daniel Th",
-        BOS_book_code: "DNT",
-        BOS_reference_number: 160,
-        BOS_sequence_number: 376,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(12),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("DanTh"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("DnT"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("DAT"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["DAN","DNG","DNA","DNC"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "DANIEL T,DANIEL TH,DANIEL Θ,DANIELT,DANIELTH,DANIELΘ,DANT,DANTH,DAT,DNT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Bel and the dragon θ / Bel and the dragon Th",
-        BOS_book_code: "BLT",
-        BOS_reference_number: 161,
-        BOS_sequence_number: 377,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(1),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("BelTh"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("BlT"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: Some("BET"),
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["BEL"],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BEL AND THE DRAGON T,BEL AND THE DRAGON TH,BEL AND THE DRAGON Θ,BELANDTHEDRAGONT,BELANDTHEDRAGONTH,BELANDTHEDRAGONΘ,BELT,BELTH,BET,BL,BLT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of the Hebrews",
-        BOS_book_code: "GHE",
-        BOS_reference_number: 170,
-        BOS_sequence_number: 700,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GH,GHE,GOSPEL OF THE H,GOSPEL OF THE HE,GOSPEL OF THE HEB,GOSPEL OF THE HEBR,GOSPEL OF THE HEBRE,GOSPEL OF THE HEBREW,GOSPEL OF THE HEBREWS,GOSPELOFTHEH,GOSPELOFTHEHE,GOSPELOFTHEHEB,GOSPELOFTHEHEBR,GOSPELOFTHEHEBRE,GOSPELOFTHEHEBREW,GOSPELOFTHEHEBREWS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Truth",
-        BOS_book_code: "GTR",
-        BOS_reference_number: 171,
-        BOS_sequence_number: 702,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GOSPEL OF TR,GOSPEL OF TRU,GOSPEL OF TRUT,GOSPEL OF TRUTH,GOSPELOFTR,GOSPELOFTRU,GOSPELOFTRUT,GOSPELOFTRUTH,GTR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Mary",
-        BOS_book_code: "GMA",
-        BOS_reference_number: 172,
-        BOS_sequence_number: 704,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GMA,GOSPEL OF MARY,GOSPELOFMARY",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Marcion",
-        BOS_book_code: "GMC",
-        BOS_reference_number: 173,
-        BOS_sequence_number: 706,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GMC,GOSPEL OF MARC,GOSPEL OF MARCI,GOSPEL OF MARCIO,GOSPEL OF MARCION,GOSPELOFMARC,GOSPELOFMARCI,GOSPELOFMARCIO,GOSPELOFMARCION",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of the Twelve",
-        BOS_book_code: "GTW",
-        BOS_reference_number: 174,
-        BOS_sequence_number: 708,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GOSPEL OF THE T,GOSPEL OF THE TW,GOSPEL OF THE TWE,GOSPEL OF THE TWEL,GOSPEL OF THE TWELV,GOSPEL OF THE TWELVE,GOSPELOFTHET,GOSPELOFTHETW,GOSPELOFTHETWE,GOSPELOFTHETWEL,GOSPELOFTHETWELV,GOSPELOFTHETWELVE,GTW",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Philip",
-        BOS_book_code: "GPH",
-        BOS_reference_number: 175,
-        BOS_sequence_number: 710,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GOSPEL OF P,GOSPEL OF PH,GOSPEL OF PHI,GOSPEL OF PHIL,GOSPEL OF PHILI,GOSPEL OF PHILIP,GOSPELOFP,GOSPELOFPH,GOSPELOFPHI,GOSPELOFPHIL,GOSPELOFPHILI,GOSPELOFPHILIP,GP,GPH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Thomas",
-        BOS_book_code: "GTH",
-        BOS_reference_number: 176,
-        BOS_sequence_number: 712,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GOSPEL OF THO,GOSPEL OF THOM,GOSPEL OF THOMA,GOSPEL OF THOMAS,GOSPELOFTHO,GOSPELOFTHOM,GOSPELOFTHOMA,GOSPELOFTHOMAS,GTH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Judas",
-        BOS_book_code: "GJU",
-        BOS_reference_number: 177,
-        BOS_sequence_number: 714,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GJ,GJU,GOSPEL OF J,GOSPEL OF JU,GOSPEL OF JUD,GOSPEL OF JUDA,GOSPEL OF JUDAS,GOSPELOFJ,GOSPELOFJU,GOSPELOFJUD,GOSPELOFJUDA,GOSPELOFJUDAS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Gospel of Bartholomew",
-        BOS_book_code: "GBA",
-        BOS_reference_number: 178,
-        BOS_sequence_number: 716,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GB,GBA,GOSPEL OF B,GOSPEL OF BA,GOSPEL OF BAR,GOSPEL OF BART,GOSPEL OF BARTH,GOSPEL OF BARTHO,GOSPEL OF BARTHOL,GOSPEL OF BARTHOLO,GOSPEL OF BARTHOLOM,GOSPEL OF BARTHOLOME,GOSPEL OF BARTHOLOMEW,GOSPELOFB,GOSPELOFBA,GOSPELOFBAR,GOSPELOFBART,GOSPELOFBARTH,GOSPELOFBARTHO,GOSPELOFBARTHOL,GOSPELOFBARTHOLO,GOSPELOFBARTHOLOM,GOSPELOFBARTHOLOME,GOSPELOFBARTHOLOMEW",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apocrypha of James / Apocryphal James",
-        BOS_book_code: "AJA",
-        BOS_reference_number: 179,
-        BOS_sequence_number: 718,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "AJ,AJA,APOCRYPHA OF J,APOCRYPHA OF JA,APOCRYPHA OF JAM,APOCRYPHA OF JAME,APOCRYPHA OF JAMES,APOCRYPHAL J,APOCRYPHAL JA,APOCRYPHAL JAM,APOCRYPHAL JAME,APOCRYPHAL JAMES,APOCRYPHALJ,APOCRYPHALJA,APOCRYPHALJAM,APOCRYPHALJAME,APOCRYPHALJAMES,APOCRYPHAOFJ,APOCRYPHAOFJA,APOCRYPHAOFJAM,APOCRYPHAOFJAME,APOCRYPHAOFJAMES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Wisdom of Jesus Christ",
-        BOS_book_code: "WJC",
-        BOS_reference_number: 180,
-        BOS_sequence_number: 720,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "WISDOM OF JESUS C,WISDOM OF JESUS CH,WISDOM OF JESUS CHR,WISDOM OF JESUS CHRI,WISDOM OF JESUS CHRIS,WISDOM OF JESUS CHRIST,WISDOMOFJESUSC,WISDOMOFJESUSCH,WISDOMOFJESUSCHR,WISDOMOFJESUSCHRI,WISDOMOFJESUSCHRIS,WISDOMOFJESUSCHRIST,WJ,WJC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Adam",
-        BOS_book_code: "TAD",
-        BOS_reference_number: 190,
-        BOS_sequence_number: 400,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TAD,TESTAMENT OF AD,TESTAMENT OF ADA,TESTAMENT OF ADAM,TESTAMENTOFAD,TESTAMENTOFADA,TESTAMENTOFADAM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Life of Adam and Eve",
-        BOS_book_code: "LAE",
-        BOS_reference_number: 191,
-        BOS_sequence_number: 402,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "LAE,LIF,LIFE,LIFE O,LIFE OF,LIFE OF A,LIFE OF AD,LIFE OF ADA,LIFE OF ADAM,LIFE OF ADAM A,LIFE OF ADAM AN,LIFE OF ADAM AND,LIFE OF ADAM AND E,LIFE OF ADAM AND EV,LIFE OF ADAM AND EVE,LIFEO,LIFEOF,LIFEOFA,LIFEOFAD,LIFEOFADA,LIFEOFADAM,LIFEOFADAMA,LIFEOFADAMAN,LIFEOFADAMAND,LIFEOFADAMANDE,LIFEOFADAMANDEV,LIFEOFADAMANDEVE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Assumption of Moses / Testament of Moses",
-        BOS_book_code: "ASS",
-        BOS_reference_number: 192,
-        BOS_sequence_number: 404,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "AS,ASS,ASSU,ASSUM,ASSUMP,ASSUMPT,ASSUMPTI,ASSUMPTIO,ASSUMPTION,ASSUMPTION O,ASSUMPTION OF,ASSUMPTION OF M,ASSUMPTION OF MO,ASSUMPTION OF MOS,ASSUMPTION OF MOSE,ASSUMPTION OF MOSES,ASSUMPTIONO,ASSUMPTIONOF,ASSUMPTIONOFM,ASSUMPTIONOFMO,ASSUMPTIONOFMOS,ASSUMPTIONOFMOSE,ASSUMPTIONOFMOSES,TESTAMENT OF M,TESTAMENT OF MO,TESTAMENT OF MOS,TESTAMENT OF MOSE,TESTAMENT OF MOSES,TESTAMENTOFM,TESTAMENTOFMO,TESTAMENTOFMOS,TESTAMENTOFMOSE,TESTAMENTOFMOSES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Solomon",
-        BOS_book_code: "TSO",
-        BOS_reference_number: 193,
-        BOS_sequence_number: 406,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TESTAMENT OF SO,TESTAMENT OF SOL,TESTAMENT OF SOLO,TESTAMENT OF SOLOM,TESTAMENT OF SOLOMO,TESTAMENT OF SOLOMON,TESTAMENTOFSO,TESTAMENTOFSOL,TESTAMENTOFSOLO,TESTAMENTOFSOLOM,TESTAMENTOFSOLOMO,TESTAMENTOFSOLOMON,TSO",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Job",
-        BOS_book_code: "TJB",
-        BOS_reference_number: 194,
-        BOS_sequence_number: 408,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TESTAMENT OF JOB,TESTAMENTOFJOB,TJ,TJB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Martyrdom and Ascension of Isaiah",
-        BOS_book_code: "MAI",
-        BOS_reference_number: 195,
-        BOS_sequence_number: 410,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "MAI,MARTYR,MARTYRD,MARTYRDO,MARTYRDOM,MARTYRDOM A,MARTYRDOM AN,MARTYRDOM AND,MARTYRDOM AND A,MARTYRDOM AND AS,MARTYRDOM AND ASC,MARTYRDOM AND ASCE,MARTYRDOM AND ASCEN,MARTYRDOM AND ASCENS,MARTYRDOM AND ASCENSI,MARTYRDOM AND ASCENSIO,MARTYRDOM AND ASCENSION,MARTYRDOM AND ASCENSION O,MARTYRDOM AND ASCENSION OF,MARTYRDOM AND ASCENSION OF I,MARTYRDOM AND ASCENSION OF IS,MARTYRDOM AND ASCENSION OF ISA,MARTYRDOM AND ASCENSION OF ISAI,MARTYRDOM AND ASCENSION OF ISAIA,MARTYRDOM AND ASCENSION OF ISAIAH,MARTYRDOMA,MARTYRDOMAN,MARTYRDOMAND,MARTYRDOMANDA,MARTYRDOMANDAS,MARTYRDOMANDASC,MARTYRDOMANDASCE,MARTYRDOMANDASCEN,MARTYRDOMANDASCENS,MARTYRDOMANDASCENSI,MARTYRDOMANDASCENSIO,MARTYRDOMANDASCENSION,MARTYRDOMANDASCENSIONO,MARTYRDOMANDASCENSIONOF,MARTYRDOMANDASCENSIONOFI,MARTYRDOMANDASCENSIONOFIS,MARTYRDOMANDASCENSIONOFISA,MARTYRDOMANDASCENSIONOFISAI,MARTYRDOMANDASCENSIONOFISAIA,MARTYRDOMANDASCENSIONOFISAIAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apocrypha of Ezekiel / Apocryphon of Ezekiel",
-        BOS_book_code: "AEZ",
-        BOS_reference_number: 196,
-        BOS_sequence_number: 412,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "AE,AEZ,APOCRYPHA OF E,APOCRYPHA OF EZ,APOCRYPHA OF EZE,APOCRYPHA OF EZEK,APOCRYPHA OF EZEKI,APOCRYPHA OF EZEKIE,APOCRYPHA OF EZEKIEL,APOCRYPHAOFE,APOCRYPHAOFEZ,APOCRYPHAOFEZE,APOCRYPHAOFEZEK,APOCRYPHAOFEZEKI,APOCRYPHAOFEZEKIE,APOCRYPHAOFEZEKIEL,APOCRYPHO,APOCRYPHON,APOCRYPHON O,APOCRYPHON OF,APOCRYPHON OF E,APOCRYPHON OF EZ,APOCRYPHON OF EZE,APOCRYPHON OF EZEK,APOCRYPHON OF EZEKI,APOCRYPHON OF EZEKIE,APOCRYPHON OF EZEKIEL,APOCRYPHONO,APOCRYPHONOF,APOCRYPHONOFE,APOCRYPHONOFEZ,APOCRYPHONOFEZE,APOCRYPHONOFEZEK,APOCRYPHONOFEZEKI,APOCRYPHONOFEZEKIE,APOCRYPHONOFEZEKIEL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ezekiel the Tragedian",
-        BOS_book_code: "EZT",
-        BOS_reference_number: 197,
-        BOS_sequence_number: 414,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EZEKIEL T,EZEKIEL TH,EZEKIEL THE,EZEKIEL THE T,EZEKIEL THE TR,EZEKIEL THE TRA,EZEKIEL THE TRAG,EZEKIEL THE TRAGE,EZEKIEL THE TRAGED,EZEKIEL THE TRAGEDI,EZEKIEL THE TRAGEDIA,EZEKIEL THE TRAGEDIAN,EZEKIELT,EZEKIELTH,EZEKIELTHE,EZEKIELTHET,EZEKIELTHETR,EZEKIELTHETRA,EZEKIELTHETRAG,EZEKIELTHETRAGE,EZEKIELTHETRAGED,EZEKIELTHETRAGEDI,EZEKIELTHETRAGEDIA,EZEKIELTHETRAGEDIAN,EZT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Eupolemus",
-        BOS_book_code: "EUP",
-        BOS_reference_number: 198,
-        BOS_sequence_number: 416,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EUP,EUPO,EUPOL,EUPOLE,EUPOLEM,EUPOLEMU,EUPOLEMUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Pseudo of Eupolemus / Pseudo-Eupolemus",
-        BOS_book_code: "PEU",
-        BOS_reference_number: 199,
-        BOS_sequence_number: 418,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PEU,PSEUDO O,PSEUDO OF,PSEUDO OF E,PSEUDO OF EU,PSEUDO OF EUP,PSEUDO OF EUPO,PSEUDO OF EUPOL,PSEUDO OF EUPOLE,PSEUDO OF EUPOLEM,PSEUDO OF EUPOLEMU,PSEUDO OF EUPOLEMUS,PSEUDO-E,PSEUDO-EU,PSEUDO-EUP,PSEUDO-EUPO,PSEUDO-EUPOL,PSEUDO-EUPOLE,PSEUDO-EUPOLEM,PSEUDO-EUPOLEMU,PSEUDO-EUPOLEMUS,PSEUDOO,PSEUDOOF,PSEUDOOFE,PSEUDOOFEU,PSEUDOOFEUP,PSEUDOOFEUPO,PSEUDOOFEUPOL,PSEUDOOFEUPOLE,PSEUDOOFEUPOLEM,PSEUDOOFEUPOLEMU,PSEUDOOFEUPOLEMUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Visions of Amram",
-        BOS_book_code: "VAM",
-        BOS_reference_number: 200,
-        BOS_sequence_number: 420,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "VA,VAM,VISI,VISIO,VISION,VISIONS,VISIONS O,VISIONS OF,VISIONS OF A,VISIONS OF AM,VISIONS OF AMR,VISIONS OF AMRA,VISIONS OF AMRAM,VISIONSO,VISIONSOF,VISIONSOFA,VISIONSOFAM,VISIONSOFAMR,VISIONSOFAMRA,VISIONSOFAMRAM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Letter of Aristeas / Epistle of Aristeas",
-        BOS_book_code: "LAR",
-        BOS_reference_number: 201,
-        BOS_sequence_number: 422,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EPISTLE OF A,EPISTLE OF AR,EPISTLE OF ARI,EPISTLE OF ARIS,EPISTLE OF ARIST,EPISTLE OF ARISTE,EPISTLE OF ARISTEA,EPISTLE OF ARISTEAS,EPISTLEOFA,EPISTLEOFAR,EPISTLEOFARI,EPISTLEOFARIS,EPISTLEOFARIST,EPISTLEOFARISTE,EPISTLEOFARISTEA,EPISTLEOFARISTEAS,LAR,LETTER OF A,LETTER OF AR,LETTER OF ARI,LETTER OF ARIS,LETTER OF ARIST,LETTER OF ARISTE,LETTER OF ARISTEA,LETTER OF ARISTEAS,LETTEROFA,LETTEROFAR,LETTEROFARI,LETTEROFARIS,LETTEROFARIST,LETTEROFARISTE,LETTEROFARISTEA,LETTEROFARISTEAS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Aristeas the Exegete",
-        BOS_book_code: "ARI",
-        BOS_reference_number: 202,
-        BOS_sequence_number: 424,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ARI,ARISTE,ARISTEA,ARISTEAS,ARISTEAS T,ARISTEAS TH,ARISTEAS THE,ARISTEAS THE E,ARISTEAS THE EX,ARISTEAS THE EXE,ARISTEAS THE EXEG,ARISTEAS THE EXEGE,ARISTEAS THE EXEGET,ARISTEAS THE EXEGETE,ARISTEAST,ARISTEASTH,ARISTEASTHE,ARISTEASTHEE,ARISTEASTHEEX,ARISTEASTHEEXE,ARISTEASTHEEXEG,ARISTEASTHEEXEGE,ARISTEASTHEEXEGET,ARISTEASTHEEXEGETE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Aristobulus",
-        BOS_book_code: "ABL",
-        BOS_reference_number: 203,
-        BOS_sequence_number: 426,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "AB,ABL,ARISTO,ARISTOB,ARISTOBU,ARISTOBUL,ARISTOBULU,ARISTOBULUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Artapanus",
-        BOS_book_code: "ART",
-        BOS_reference_number: 204,
-        BOS_sequence_number: 428,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ART,ARTA,ARTAP,ARTAPA,ARTAPAN,ARTAPANU,ARTAPANUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Cleodemus Malchus",
-        BOS_book_code: "CLM",
-        BOS_reference_number: 205,
-        BOS_sequence_number: 430,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "CLE,CLEO,CLEOD,CLEODE,CLEODEM,CLEODEMU,CLEODEMUS,CLEODEMUS M,CLEODEMUS MA,CLEODEMUS MAL,CLEODEMUS MALC,CLEODEMUS MALCH,CLEODEMUS MALCHU,CLEODEMUS MALCHUS,CLEODEMUSM,CLEODEMUSMA,CLEODEMUSMAL,CLEODEMUSMALC,CLEODEMUSMALCH,CLEODEMUSMALCHU,CLEODEMUSMALCHUS,CLM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Eldad and Modad",
-        BOS_book_code: "ELM",
-        BOS_reference_number: 206,
-        BOS_sequence_number: 432,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EL,ELD,ELDA,ELDAD,ELDAD A,ELDAD AN,ELDAD AND,ELDAD AND M,ELDAD AND MO,ELDAD AND MOD,ELDAD AND MODA,ELDAD AND MODAD,ELDADA,ELDADAN,ELDADAND,ELDADANDM,ELDADANDMO,ELDADANDMOD,ELDADANDMODA,ELDADANDMODAD,ELM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "History of the Rechabites",
-        BOS_book_code: "HRE",
-        BOS_reference_number: 207,
-        BOS_sequence_number: 434,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "HI,HIS,HIST,HISTO,HISTOR,HISTORY,HISTORY O,HISTORY OF,HISTORY OF T,HISTORY OF TH,HISTORY OF THE,HISTORY OF THE R,HISTORY OF THE RE,HISTORY OF THE REC,HISTORY OF THE RECH,HISTORY OF THE RECHA,HISTORY OF THE RECHAB,HISTORY OF THE RECHABI,HISTORY OF THE RECHABIT,HISTORY OF THE RECHABITE,HISTORY OF THE RECHABITES,HISTORYO,HISTORYOF,HISTORYOFT,HISTORYOFTH,HISTORYOFTHE,HISTORYOFTHER,HISTORYOFTHERE,HISTORYOFTHEREC,HISTORYOFTHERECH,HISTORYOFTHERECHA,HISTORYOFTHERECHAB,HISTORYOFTHERECHABI,HISTORYOFTHERECHABIT,HISTORYOFTHERECHABITE,HISTORYOFTHERECHABITES,HR,HRE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Lives of the Prophets",
-        BOS_book_code: "LPR",
-        BOS_reference_number: 208,
-        BOS_sequence_number: 436,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "LIV,LIVE,LIVES,LIVES O,LIVES OF,LIVES OF T,LIVES OF TH,LIVES OF THE,LIVES OF THE P,LIVES OF THE PR,LIVES OF THE PRO,LIVES OF THE PROP,LIVES OF THE PROPH,LIVES OF THE PROPHE,LIVES OF THE PROPHET,LIVES OF THE PROPHETS,LIVESO,LIVESOF,LIVESOFT,LIVESOFTH,LIVESOFTHE,LIVESOFTHEP,LIVESOFTHEPR,LIVESOFTHEPRO,LIVESOFTHEPROP,LIVESOFTHEPROPH,LIVESOFTHEPROPHE,LIVESOFTHEPROPHET,LIVESOFTHEPROPHETS,LP,LPR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Philo the Epic Poet",
-        BOS_book_code: "EPL",
-        BOS_reference_number: 209,
-        BOS_sequence_number: 438,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EPL,PHILO,PHILO T,PHILO TH,PHILO THE,PHILO THE E,PHILO THE EP,PHILO THE EPI,PHILO THE EPIC,PHILO THE EPIC P,PHILO THE EPIC PO,PHILO THE EPIC POE,PHILO THE EPIC POET,PHILOT,PHILOTH,PHILOTHE,PHILOTHEE,PHILOTHEEP,PHILOTHEEPI,PHILOTHEEPIC,PHILOTHEEPICP,PHILOTHEEPICPO,PHILOTHEEPICPOE,PHILOTHEEPICPOET",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Theodotus",
-        BOS_book_code: "TEO",
-        BOS_reference_number: 210,
-        BOS_sequence_number: 440,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TEO,THEO,THEOD,THEODO,THEODOT,THEODOTU,THEODOTUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testaments of the Twelve Patriarchs",
-        BOS_book_code: "TTP",
-        BOS_reference_number: 250,
-        BOS_sequence_number: 380,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR,TESTAMENTS,TESTAMENTS O,TESTAMENTS OF,TESTAMENTS OF T,TESTAMENTS OF TH,TESTAMENTS OF THE,TESTAMENTS OF THE T,TESTAMENTS OF THE TW,TESTAMENTS OF THE TWE,TESTAMENTS OF THE TWEL,TESTAMENTS OF THE TWELV,TESTAMENTS OF THE TWELVE,TESTAMENTS OF THE TWELVE P,TESTAMENTS OF THE TWELVE PA,TESTAMENTS OF THE TWELVE PAT,TESTAMENTS OF THE TWELVE PATR,TESTAMENTS OF THE TWELVE PATRI,TESTAMENTS OF THE TWELVE PATRIA,TESTAMENTS OF THE TWELVE PATRIAR,TESTAMENTS OF THE TWELVE PATRIARC,TESTAMENTS OF THE TWELVE PATRIARCH,TESTAMENTS OF THE TWELVE PATRIARCHS,TESTAMENTSO,TESTAMENTSOF,TESTAMENTSOFT,TESTAMENTSOFTH,TESTAMENTSOFTHE,TESTAMENTSOFTHET,TESTAMENTSOFTHETW,TESTAMENTSOFTHETWE,TESTAMENTSOFTHETWEL,TESTAMENTSOFTHETWELV,TESTAMENTSOFTHETWELVE,TESTAMENTSOFTHETWELVEP,TESTAMENTSOFTHETWELVEPA,TESTAMENTSOFTHETWELVEPAT,TESTAMENTSOFTHETWELVEPATR,TESTAMENTSOFTHETWELVEPATRI,TESTAMENTSOFTHETWELVEPATRIA,TESTAMENTSOFTHETWELVEPATRIAR,TESTAMENTSOFTHETWELVEPATRIARC,TESTAMENTSOFTHETWELVEPATRIARCH,TESTAMENTSOFTHETWELVEPATRIARCHS,TTP",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Asher",
-        BOS_book_code: "TSA",
-        BOS_reference_number: 251,
-        BOS_sequence_number: 381,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TAsh"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TA,T12PATR.TAS,T12PATR.TASH,TESTAMENT OF AS,TESTAMENT OF ASH,TESTAMENT OF ASHE,TESTAMENT OF ASHER,TESTAMENTOFAS,TESTAMENTOFASH,TESTAMENTOFASHE,TESTAMENTOFASHER,TSA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Benjamin",
-        BOS_book_code: "TSB",
-        BOS_reference_number: 252,
-        BOS_sequence_number: 382,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TBenj"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TB,T12PATR.TBE,T12PATR.TBEN,T12PATR.TBENJ,TESTAMENT OF B,TESTAMENT OF BE,TESTAMENT OF BEN,TESTAMENT OF BENJ,TESTAMENT OF BENJA,TESTAMENT OF BENJAM,TESTAMENT OF BENJAMI,TESTAMENT OF BENJAMIN,TESTAMENTOFB,TESTAMENTOFBE,TESTAMENTOFBEN,TESTAMENTOFBENJ,TESTAMENTOFBENJA,TESTAMENTOFBENJAM,TESTAMENTOFBENJAMI,TESTAMENTOFBENJAMIN,TSB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Dan",
-        BOS_book_code: "TSD",
-        BOS_reference_number: 253,
-        BOS_sequence_number: 383,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TDan"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TD,T12PATR.TDA,T12PATR.TDAN,TESTAMENT OF D,TESTAMENT OF DA,TESTAMENT OF DAN,TESTAMENTOFD,TESTAMENTOFDA,TESTAMENTOFDAN,TSD",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Gad",
-        BOS_book_code: "TSG",
-        BOS_reference_number: 254,
-        BOS_sequence_number: 384,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TGad"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TG,T12PATR.TGA,T12PATR.TGAD,TESTAMENT OF G,TESTAMENT OF GA,TESTAMENT OF GAD,TESTAMENTOFG,TESTAMENTOFGA,TESTAMENTOFGAD,TSG",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Issachar",
-        BOS_book_code: "TSI",
-        BOS_reference_number: 255,
-        BOS_sequence_number: 385,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TIss"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TI,T12PATR.TIS,T12PATR.TISS,TESTAMENT OF I,TESTAMENT OF IS,TESTAMENT OF ISS,TESTAMENT OF ISSA,TESTAMENT OF ISSAC,TESTAMENT OF ISSACH,TESTAMENT OF ISSACHA,TESTAMENT OF ISSACHAR,TESTAMENTOFI,TESTAMENTOFIS,TESTAMENTOFISS,TESTAMENTOFISSA,TESTAMENTOFISSAC,TESTAMENTOFISSACH,TESTAMENTOFISSACHA,TESTAMENTOFISSACHAR,TSI",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Joseph",
-        BOS_book_code: "TSJ",
-        BOS_reference_number: 256,
-        BOS_sequence_number: 386,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TJos"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TJO,T12PATR.TJOS,TESTAMENT OF JOS,TESTAMENT OF JOSE,TESTAMENT OF JOSEP,TESTAMENT OF JOSEPH,TESTAMENTOFJOS,TESTAMENTOFJOSE,TESTAMENTOFJOSEP,TESTAMENTOFJOSEPH,TSJ",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Judah",
-        BOS_book_code: "TSY",
-        BOS_reference_number: 257,
-        BOS_sequence_number: 387,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TJud"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TJU,T12PATR.TJUD,TESTAMENT OF JU,TESTAMENT OF JUD,TESTAMENT OF JUDA,TESTAMENT OF JUDAH,TESTAMENTOFJU,TESTAMENTOFJUD,TESTAMENTOFJUDA,TESTAMENTOFJUDAH,TSY",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Levi",
-        BOS_book_code: "TSL",
-        BOS_reference_number: 258,
-        BOS_sequence_number: 388,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TLevi"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TL,T12PATR.TLE,T12PATR.TLEV,T12PATR.TLEVI,TESTAMENT OF L,TESTAMENT OF LE,TESTAMENT OF LEV,TESTAMENT OF LEVI,TESTAMENTOFL,TESTAMENTOFLE,TESTAMENTOFLEV,TESTAMENTOFLEVI,TSL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Naphtali",
-        BOS_book_code: "TSN",
-        BOS_reference_number: 259,
-        BOS_sequence_number: 389,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TNaph"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TN,T12PATR.TNA,T12PATR.TNAP,T12PATR.TNAPH,TESTAMENT OF N,TESTAMENT OF NA,TESTAMENT OF NAP,TESTAMENT OF NAPH,TESTAMENT OF NAPHT,TESTAMENT OF NAPHTA,TESTAMENT OF NAPHTAL,TESTAMENT OF NAPHTALI,TESTAMENTOFN,TESTAMENTOFNA,TESTAMENTOFNAP,TESTAMENTOFNAPH,TESTAMENTOFNAPHT,TESTAMENTOFNAPHTA,TESTAMENTOFNAPHTAL,TESTAMENTOFNAPHTALI,TSN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Reuben",
-        BOS_book_code: "TSR",
-        BOS_reference_number: 260,
-        BOS_sequence_number: 390,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TReu"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TR,T12PATR.TRE,T12PATR.TREU,TESTAMENT OF R,TESTAMENT OF RE,TESTAMENT OF REU,TESTAMENT OF REUB,TESTAMENT OF REUBE,TESTAMENT OF REUBEN,TESTAMENTOFR,TESTAMENTOFRE,TESTAMENTOFREU,TESTAMENTOFREUB,TESTAMENTOFREUBE,TESTAMENTOFREUBEN,TSR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Simeon",
-        BOS_book_code: "TSS",
-        BOS_reference_number: 261,
-        BOS_sequence_number: 391,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TSim"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TS,T12PATR.TSI,T12PATR.TSIM,TESTAMENT OF SI,TESTAMENT OF SIM,TESTAMENT OF SIME,TESTAMENT OF SIMEO,TESTAMENT OF SIMEON,TESTAMENTOFSI,TESTAMENTOFSIM,TESTAMENTOFSIME,TESTAMENTOFSIMEO,TESTAMENTOFSIMEON,TSS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Testament of Zebulun",
-        BOS_book_code: "TSZ",
-        BOS_reference_number: 262,
-        BOS_sequence_number: 392,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("T12Patr.TZeb"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "T12PATR.TZ,T12PATR.TZE,T12PATR.TZEB,TESTAMENT OF Z,TESTAMENT OF ZE,TESTAMENT OF ZEB,TESTAMENT OF ZEBU,TESTAMENT OF ZEBUL,TESTAMENT OF ZEBULU,TESTAMENT OF ZEBULUN,TESTAMENTOFZ,TESTAMENTOFZE,TESTAMENTOFZEB,TESTAMENTOFZEBU,TESTAMENTOFZEBUL,TESTAMENTOFZEBULU,TESTAMENTOFZEBULUN,TSZ",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Josephus Jewish War VI",
-        BOS_book_code: "JJW",
-        BOS_reference_number: 270,
-        BOS_sequence_number: 378,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("JosephusJWvi"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "JJ,JJW,JOSE,JOSEP,JOSEPH,JOSEPHU,JOSEPHUS,JOSEPHUS J,JOSEPHUS JE,JOSEPHUS JEW,JOSEPHUS JEWI,JOSEPHUS JEWIS,JOSEPHUS JEWISH,JOSEPHUS JEWISH W,JOSEPHUS JEWISH WA,JOSEPHUS JEWISH WAR,JOSEPHUS JEWISH WAR V,JOSEPHUS JEWISH WAR VI,JOSEPHUSJ,JOSEPHUSJE,JOSEPHUSJEW,JOSEPHUSJEWI,JOSEPHUSJEWIS,JOSEPHUSJEWISH,JOSEPHUSJEWISHW,JOSEPHUSJEWISHWA,JOSEPHUSJEWISHWAR,JOSEPHUSJEWISHWARV,JOSEPHUSJEWISHWARVI,JOSEPHUSJW,JOSEPHUSJWV,JOSEPHUSJWVI",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Shepherd of Hermas",
-        BOS_book_code: "SHE",
-        BOS_reference_number: 280,
-        BOS_sequence_number: 394,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Herm"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("She"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SHE,SHEPHERD OF HERMAS,SHEPHERDOFHERMAS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Shepherd of Hermas Mandates",
-        BOS_book_code: "SHM",
-        BOS_reference_number: 281,
-        BOS_sequence_number: 395,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Herm"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SHEPHERD OF HERMAS M,SHEPHERD OF HERMAS MA,SHEPHERD OF HERMAS MAN,SHEPHERD OF HERMAS MAND,SHEPHERD OF HERMAS MANDA,SHEPHERD OF HERMAS MANDAT,SHEPHERD OF HERMAS MANDATE,SHEPHERD OF HERMAS MANDATES,SHEPHERDOFHERMASM,SHEPHERDOFHERMASMA,SHEPHERDOFHERMASMAN,SHEPHERDOFHERMASMAND,SHEPHERDOFHERMASMANDA,SHEPHERDOFHERMASMANDAT,SHEPHERDOFHERMASMANDATE,SHEPHERDOFHERMASMANDATES,SHM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Shepherd of Hermas Similitudes",
-        BOS_book_code: "SHS",
-        BOS_reference_number: 282,
-        BOS_sequence_number: 396,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Herm"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SHEPHERD OF HERMAS S,SHEPHERD OF HERMAS SI,SHEPHERD OF HERMAS SIM,SHEPHERD OF HERMAS SIMI,SHEPHERD OF HERMAS SIMIL,SHEPHERD OF HERMAS SIMILI,SHEPHERD OF HERMAS SIMILIT,SHEPHERD OF HERMAS SIMILITU,SHEPHERD OF HERMAS SIMILITUD,SHEPHERD OF HERMAS SIMILITUDE,SHEPHERD OF HERMAS SIMILITUDES,SHEPHERDOFHERMASS,SHEPHERDOFHERMASSI,SHEPHERDOFHERMASSIM,SHEPHERDOFHERMASSIMI,SHEPHERDOFHERMASSIMIL,SHEPHERDOFHERMASSIMILI,SHEPHERDOFHERMASSIMILIT,SHEPHERDOFHERMASSIMILITU,SHEPHERDOFHERMASSIMILITUD,SHEPHERDOFHERMASSIMILITUDE,SHEPHERDOFHERMASSIMILITUDES,SHS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Shepherd of Hermas Visions",
-        BOS_book_code: "SHV",
-        BOS_reference_number: 283,
-        BOS_sequence_number: 397,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Herm"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SHEPHERD OF HERMAS V,SHEPHERD OF HERMAS VI,SHEPHERD OF HERMAS VIS,SHEPHERD OF HERMAS VISI,SHEPHERD OF HERMAS VISIO,SHEPHERD OF HERMAS VISION,SHEPHERD OF HERMAS VISIONS,SHEPHERDOFHERMASV,SHEPHERDOFHERMASVI,SHEPHERDOFHERMASVIS,SHEPHERDOFHERMASVISI,SHEPHERDOFHERMASVISIO,SHEPHERDOFHERMASVISION,SHEPHERDOFHERMASVISIONS,SHV",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to the Ephesians",
-        BOS_book_code: "IGE",
-        BOS_reference_number: 290,
-        BOS_sequence_number: 730,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnEph"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGE,IGNATIUS TO THE E,IGNATIUS TO THE EP,IGNATIUS TO THE EPH,IGNATIUS TO THE EPHE,IGNATIUS TO THE EPHES,IGNATIUS TO THE EPHESI,IGNATIUS TO THE EPHESIA,IGNATIUS TO THE EPHESIAN,IGNATIUS TO THE EPHESIANS,IGNATIUSTOTHEE,IGNATIUSTOTHEEP,IGNATIUSTOTHEEPH,IGNATIUSTOTHEEPHE,IGNATIUSTOTHEEPHES,IGNATIUSTOTHEEPHESI,IGNATIUSTOTHEEPHESIA,IGNATIUSTOTHEEPHESIAN,IGNATIUSTOTHEEPHESIANS,IGNE,IGNEP,IGNEPH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to the Magnesians",
-        BOS_book_code: "IGM",
-        BOS_reference_number: 291,
-        BOS_sequence_number: 731,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnMagn"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGM,IGNATIUS TO THE M,IGNATIUS TO THE MA,IGNATIUS TO THE MAG,IGNATIUS TO THE MAGN,IGNATIUS TO THE MAGNE,IGNATIUS TO THE MAGNES,IGNATIUS TO THE MAGNESI,IGNATIUS TO THE MAGNESIA,IGNATIUS TO THE MAGNESIAN,IGNATIUS TO THE MAGNESIANS,IGNATIUSTOTHEM,IGNATIUSTOTHEMA,IGNATIUSTOTHEMAG,IGNATIUSTOTHEMAGN,IGNATIUSTOTHEMAGNE,IGNATIUSTOTHEMAGNES,IGNATIUSTOTHEMAGNESI,IGNATIUSTOTHEMAGNESIA,IGNATIUSTOTHEMAGNESIAN,IGNATIUSTOTHEMAGNESIANS,IGNM,IGNMA,IGNMAG,IGNMAGN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to the Trallians",
-        BOS_book_code: "IGT",
-        BOS_reference_number: 292,
-        BOS_sequence_number: 732,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnTrall"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGNATIUS TO THE T,IGNATIUS TO THE TR,IGNATIUS TO THE TRA,IGNATIUS TO THE TRAL,IGNATIUS TO THE TRALL,IGNATIUS TO THE TRALLI,IGNATIUS TO THE TRALLIA,IGNATIUS TO THE TRALLIAN,IGNATIUS TO THE TRALLIANS,IGNATIUSTOTHET,IGNATIUSTOTHETR,IGNATIUSTOTHETRA,IGNATIUSTOTHETRAL,IGNATIUSTOTHETRALL,IGNATIUSTOTHETRALLI,IGNATIUSTOTHETRALLIA,IGNATIUSTOTHETRALLIAN,IGNATIUSTOTHETRALLIANS,IGNT,IGNTR,IGNTRA,IGNTRAL,IGNTRALL,IGT",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to the Romans",
-        BOS_book_code: "IGR",
-        BOS_reference_number: 293,
-        BOS_sequence_number: 733,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnRom"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGNATIUS TO THE R,IGNATIUS TO THE RO,IGNATIUS TO THE ROM,IGNATIUS TO THE ROMA,IGNATIUS TO THE ROMAN,IGNATIUS TO THE ROMANS,IGNATIUSTOTHER,IGNATIUSTOTHERO,IGNATIUSTOTHEROM,IGNATIUSTOTHEROMA,IGNATIUSTOTHEROMAN,IGNATIUSTOTHEROMANS,IGNR,IGNRO,IGNROM,IGR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to the Philadelphians",
-        BOS_book_code: "IGP",
-        BOS_reference_number: 294,
-        BOS_sequence_number: 734,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnPhld"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGNATIUS TO THE P,IGNATIUS TO THE PH,IGNATIUS TO THE PHI,IGNATIUS TO THE PHIL,IGNATIUS TO THE PHILA,IGNATIUS TO THE PHILAD,IGNATIUS TO THE PHILADE,IGNATIUS TO THE PHILADEL,IGNATIUS TO THE PHILADELP,IGNATIUS TO THE PHILADELPH,IGNATIUS TO THE PHILADELPHI,IGNATIUS TO THE PHILADELPHIA,IGNATIUS TO THE PHILADELPHIAN,IGNATIUS TO THE PHILADELPHIANS,IGNATIUSTOTHEP,IGNATIUSTOTHEPH,IGNATIUSTOTHEPHI,IGNATIUSTOTHEPHIL,IGNATIUSTOTHEPHILA,IGNATIUSTOTHEPHILAD,IGNATIUSTOTHEPHILADE,IGNATIUSTOTHEPHILADEL,IGNATIUSTOTHEPHILADELP,IGNATIUSTOTHEPHILADELPH,IGNATIUSTOTHEPHILADELPHI,IGNATIUSTOTHEPHILADELPHIA,IGNATIUSTOTHEPHILADELPHIAN,IGNATIUSTOTHEPHILADELPHIANS,IGNPH,IGNPHL,IGNPHLD,IGP",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to the Smyrnaeans",
-        BOS_book_code: "IGS",
-        BOS_reference_number: 295,
-        BOS_sequence_number: 735,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnSmyrn"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGNATIUS TO THE S,IGNATIUS TO THE SM,IGNATIUS TO THE SMY,IGNATIUS TO THE SMYR,IGNATIUS TO THE SMYRN,IGNATIUS TO THE SMYRNA,IGNATIUS TO THE SMYRNAE,IGNATIUS TO THE SMYRNAEA,IGNATIUS TO THE SMYRNAEAN,IGNATIUS TO THE SMYRNAEANS,IGNATIUSTOTHES,IGNATIUSTOTHESM,IGNATIUSTOTHESMY,IGNATIUSTOTHESMYR,IGNATIUSTOTHESMYRN,IGNATIUSTOTHESMYRNA,IGNATIUSTOTHESMYRNAE,IGNATIUSTOTHESMYRNAEA,IGNATIUSTOTHESMYRNAEAN,IGNATIUSTOTHESMYRNAEANS,IGNS,IGNSM,IGNSMY,IGNSMYR,IGNSMYRN,IGS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ignatius to Polycarp",
-        BOS_book_code: "IGC",
-        BOS_reference_number: 296,
-        BOS_sequence_number: 736,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("IgnPol"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IGC,IGNATIUS TO P,IGNATIUS TO PO,IGNATIUS TO POL,IGNATIUS TO POLY,IGNATIUS TO POLYC,IGNATIUS TO POLYCA,IGNATIUS TO POLYCAR,IGNATIUS TO POLYCARP,IGNATIUSTOP,IGNATIUSTOPO,IGNATIUSTOPOL,IGNATIUSTOPOLY,IGNATIUSTOPOLYC,IGNATIUSTOPOLYCA,IGNATIUSTOPOLYCAR,IGNATIUSTOPOLYCARP,IGNPO,IGNPOL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Polycarp to the Philippians",
-        BOS_book_code: "PCP",
-        BOS_reference_number: 297,
-        BOS_sequence_number: 740,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PolPhil"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PC,PCP,PO,POL,POLP,POLPH,POLPHI,POLPHIL,POLY,POLYC,POLYCA,POLYCAR,POLYCARP,POLYCARP T,POLYCARP TO,POLYCARP TO T,POLYCARP TO TH,POLYCARP TO THE,POLYCARP TO THE P,POLYCARP TO THE PH,POLYCARP TO THE PHI,POLYCARP TO THE PHIL,POLYCARP TO THE PHILI,POLYCARP TO THE PHILIP,POLYCARP TO THE PHILIPP,POLYCARP TO THE PHILIPPI,POLYCARP TO THE PHILIPPIA,POLYCARP TO THE PHILIPPIAN,POLYCARP TO THE PHILIPPIANS,POLYCARPT,POLYCARPTO,POLYCARPTOT,POLYCARPTOTH,POLYCARPTOTHE,POLYCARPTOTHEP,POLYCARPTOTHEPH,POLYCARPTOTHEPHI,POLYCARPTOTHEPHIL,POLYCARPTOTHEPHILI,POLYCARPTOTHEPHILIP,POLYCARPTOTHEPHILIPP,POLYCARPTOTHEPHILIPPI,POLYCARPTOTHEPHILIPPIA,POLYCARPTOTHEPHILIPPIAN,POLYCARPTOTHEPHILIPPIANS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Martydom of Polycarp",
-        BOS_book_code: "MPC",
-        BOS_reference_number: 298,
-        BOS_sequence_number: 741,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("MartPol"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "MARTP,MARTPO,MARTPOL,MARTYD,MARTYDO,MARTYDOM,MARTYDOM O,MARTYDOM OF,MARTYDOM OF P,MARTYDOM OF PO,MARTYDOM OF POL,MARTYDOM OF POLY,MARTYDOM OF POLYC,MARTYDOM OF POLYCA,MARTYDOM OF POLYCAR,MARTYDOM OF POLYCARP,MARTYDOMO,MARTYDOMOF,MARTYDOMOFP,MARTYDOMOFPO,MARTYDOMOFPOL,MARTYDOMOFPOLY,MARTYDOMOFPOLYC,MARTYDOMOFPOLYCA,MARTYDOMOFPOLYCAR,MARTYDOMOFPOLYCARP,MP,MPC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Diognetus",
-        BOS_book_code: "DIO",
-        BOS_reference_number: 300,
-        BOS_sequence_number: 750,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: Some("Dio"),
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("Diogn"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "DIO,DIOG,DIOGN,DIOGNE,DIOGNET,DIOGNETU,DIOGNETUS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apostles' Creed",
-        BOS_book_code: "APC",
-        BOS_reference_number: 301,
-        BOS_sequence_number: 751,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("AposCreed"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "APC,APOSC,APOSCR,APOSCRE,APOSCREE,APOSCREED,APOSTL,APOSTLE,APOSTLES,APOSTLES',APOSTLES' C,APOSTLES' CR,APOSTLES' CRE,APOSTLES' CREE,APOSTLES' CREED,APOSTLES'C,APOSTLES'CR,APOSTLES'CRE,APOSTLES'CREE,APOSTLES'CREED",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Fragments of Papias",
-        BOS_book_code: "FOP",
-        BOS_reference_number: 302,
-        BOS_sequence_number: 752,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("PapFrag"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "FOP,FRAGMENTS,FRAGMENTS O,FRAGMENTS OF,FRAGMENTS OF P,FRAGMENTS OF PA,FRAGMENTS OF PAP,FRAGMENTS OF PAPI,FRAGMENTS OF PAPIA,FRAGMENTS OF PAPIAS,FRAGMENTSO,FRAGMENTSOF,FRAGMENTSOFP,FRAGMENTSOFPA,FRAGMENTSOFPAP,FRAGMENTSOFPAPI,FRAGMENTSOFPAPIA,FRAGMENTSOFPAPIAS,PAP,PAPF,PAPFR,PAPFRA,PAPFRAG",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Reliques of the Elders",
-        BOS_book_code: "ROE",
-        BOS_reference_number: 303,
-        BOS_sequence_number: 753,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("RelElders"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "REL,RELE,RELEL,RELELD,RELELDE,RELELDER,RELELDERS,RELI,RELIQ,RELIQU,RELIQUE,RELIQUES,RELIQUES O,RELIQUES OF,RELIQUES OF T,RELIQUES OF TH,RELIQUES OF THE,RELIQUES OF THE E,RELIQUES OF THE EL,RELIQUES OF THE ELD,RELIQUES OF THE ELDE,RELIQUES OF THE ELDER,RELIQUES OF THE ELDERS,RELIQUESO,RELIQUESOF,RELIQUESOFT,RELIQUESOFTH,RELIQUESOFTHE,RELIQUESOFTHEE,RELIQUESOFTHEEL,RELIQUESOFTHEELD,RELIQUESOFTHEELDE,RELIQUESOFTHEELDER,RELIQUESOFTHEELDERS,ROE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Fragment of Quadratus",
-        BOS_book_code: "FOQ",
-        BOS_reference_number: 304,
-        BOS_sequence_number: 754,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("QuadFrag"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "FOQ,FRAGMENT O,FRAGMENT OF,FRAGMENT OF Q,FRAGMENT OF QU,FRAGMENT OF QUA,FRAGMENT OF QUAD,FRAGMENT OF QUADR,FRAGMENT OF QUADRA,FRAGMENT OF QUADRAT,FRAGMENT OF QUADRATU,FRAGMENT OF QUADRATUS,FRAGMENTO,FRAGMENTOF,FRAGMENTOFQ,FRAGMENTOFQU,FRAGMENTOFQUA,FRAGMENTOFQUAD,FRAGMENTOFQUADR,FRAGMENTOFQUADRA,FRAGMENTOFQUADRAT,FRAGMENTOFQUADRATU,FRAGMENTOFQUADRATUS,QU,QUA,QUAD,QUADF,QUADFR,QUADFRA,QUADFRAG",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Tatian / Tatian's Diatessaron / Diatessaron",
-        BOS_book_code: "TAT",
-        BOS_reference_number: 310,
-        BOS_sequence_number: 760,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: Some("TatDiat"),
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("PS"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "DIA,DIAT,DIATE,DIATES,DIATESS,DIATESSA,DIATESSAR,DIATESSARO,DIATESSARON,TAT,TATD,TATDI,TATDIA,TATDIAT,TATI,TATIA,TATIAN,TATIAN',TATIAN'S,TATIAN'S D,TATIAN'S DI,TATIAN'S DIA,TATIAN'S DIAT,TATIAN'S DIATE,TATIAN'S DIATES,TATIAN'S DIATESS,TATIAN'S DIATESSA,TATIAN'S DIATESSAR,TATIAN'S DIATESSARO,TATIAN'S DIATESSARON,TATIAN'SD,TATIAN'SDI,TATIAN'SDIA,TATIAN'SDIAT,TATIAN'SDIATE,TATIAN'SDIATES,TATIAN'SDIATESS,TATIAN'SDIATESSA,TATIAN'SDIATESSAR,TATIAN'SDIATESSARO,TATIAN'SDIATESSARON",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Plea for Deliverance",
-        BOS_book_code: "P4D",
-        BOS_reference_number: 400,
-        BOS_sequence_number: 770,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(89),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "P4,P4D,PLE,PLEA,PLEA F,PLEA FO,PLEA FOR,PLEA FOR D,PLEA FOR DE,PLEA FOR DEL,PLEA FOR DELI,PLEA FOR DELIV,PLEA FOR DELIVE,PLEA FOR DELIVER,PLEA FOR DELIVERA,PLEA FOR DELIVERAN,PLEA FOR DELIVERANC,PLEA FOR DELIVERANCE,PLEAF,PLEAFO,PLEAFOR,PLEAFORD,PLEAFORDE,PLEAFORDEL,PLEAFORDELI,PLEAFORDELIV,PLEAFORDELIVE,PLEAFORDELIVER,PLEAFORDELIVERA,PLEAFORDELIVERAN,PLEAFORDELIVERANC,PLEAFORDELIVERANCE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apostrophe to Zion",
-        BOS_book_code: "A2Z",
-        BOS_reference_number: 401,
-        BOS_sequence_number: 771,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(90),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "A2Z,APOSTROPHE TO Z,APOSTROPHE TO ZI,APOSTROPHE TO ZIO,APOSTROPHE TO ZION,APOSTROPHETOZ,APOSTROPHETOZI,APOSTROPHETOZIO,APOSTROPHETOZION",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Eschatalogical Hymn",
-        BOS_book_code: "EHY",
-        BOS_reference_number: 402,
-        BOS_sequence_number: 772,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(91),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "EH,EHY,ESCH,ESCHA,ESCHAT,ESCHATA,ESCHATAL,ESCHATALO,ESCHATALOG,ESCHATALOGI,ESCHATALOGIC,ESCHATALOGICA,ESCHATALOGICAL,ESCHATALOGICAL H,ESCHATALOGICAL HY,ESCHATALOGICAL HYM,ESCHATALOGICAL HYMN,ESCHATALOGICALH,ESCHATALOGICALHY,ESCHATALOGICALHYM,ESCHATALOGICALHYMN",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apostrophe to Judah",
-        BOS_book_code: "A2J",
-        BOS_reference_number: 403,
-        BOS_sequence_number: 773,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(92),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "A2J,APOSTROPHE TO J,APOSTROPHE TO JU,APOSTROPHE TO JUD,APOSTROPHE TO JUDA,APOSTROPHE TO JUDAH,APOSTROPHETOJ,APOSTROPHETOJU,APOSTROPHETOJUD,APOSTROPHETOJUDA,APOSTROPHETOJUDAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Hymn to the Creator",
-        BOS_book_code: "H2C",
-        BOS_reference_number: 404,
-        BOS_sequence_number: 774,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(93),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "H2,H2C,HYM,HYMN,HYMN T,HYMN TO,HYMN TO T,HYMN TO TH,HYMN TO THE,HYMN TO THE C,HYMN TO THE CR,HYMN TO THE CRE,HYMN TO THE CREA,HYMN TO THE CREAT,HYMN TO THE CREATO,HYMN TO THE CREATOR,HYMNT,HYMNTO,HYMNTOT,HYMNTOTH,HYMNTOTHE,HYMNTOTHEC,HYMNTOTHECR,HYMNTOTHECRE,HYMNTOTHECREA,HYMNTOTHECREAT,HYMNTOTHECREATO,HYMNTOTHECREATOR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "David's Compositions",
-        BOS_book_code: "CMP",
-        BOS_reference_number: 405,
-        BOS_sequence_number: 775,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(94),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "CM,CMP,DAV,DAVI,DAVID,DAVID',DAVID'S,DAVID'S C,DAVID'S CO,DAVID'S COM,DAVID'S COMP,DAVID'S COMPO,DAVID'S COMPOS,DAVID'S COMPOSI,DAVID'S COMPOSIT,DAVID'S COMPOSITI,DAVID'S COMPOSITIO,DAVID'S COMPOSITION,DAVID'S COMPOSITIONS,DAVID'SC,DAVID'SCO,DAVID'SCOM,DAVID'SCOMP,DAVID'SCOMPO,DAVID'SCOMPOS,DAVID'SCOMPOSI,DAVID'SCOMPOSIT,DAVID'SCOMPOSITI,DAVID'SCOMPOSITIO,DAVID'SCOMPOSITION,DAVID'SCOMPOSITIONS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Apocryphal Psalms",
-        BOS_book_code: "APS",
-        BOS_reference_number: 406,
-        BOS_sequence_number: 776,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(95),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "APOCRYPHAL P,APOCRYPHAL PS,APOCRYPHAL PSA,APOCRYPHAL PSAL,APOCRYPHAL PSALM,APOCRYPHAL PSALMS,APOCRYPHALP,APOCRYPHALPS,APOCRYPHALPSA,APOCRYPHALPSAL,APOCRYPHALPSALM,APOCRYPHALPSALMS,APS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Catena",
-        BOS_book_code: "CAT",
-        BOS_reference_number: 407,
-        BOS_sequence_number: 777,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(101),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "CAT,CATE,CATEN,CATENA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalm 151A",
-        BOS_book_code: "P1A",
-        BOS_reference_number: 408,
-        BOS_sequence_number: 778,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(102),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "P1A,PSALM 151A,PSALM151A",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Psalm 151B",
-        BOS_book_code: "P1B",
-        BOS_reference_number: 409,
-        BOS_sequence_number: 779,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: Some(103),
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("DSS5"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "P1B,PSALM 151B,PSALM151B",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Book of the Pentateuch / Pentateuch / Torah / Books of Moses",
-        BOS_book_code: "MOS",
-        BOS_reference_number: 600,
-        BOS_sequence_number: 18,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(187),
-        short_abbreviation: Some("Mos"),
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["GEN","EXO","LEV","NUM","DEU"],
-        consists_of_books_codes: Some("GEN,EXO,LEV,NUM,DEU"),
-        typical_section: Some("OT"),
-        typical_subsection: Some("Pentateuch"),
-        all_English_derived_abbreviations: "BOOK OF THE PE,BOOK OF THE PEN,BOOK OF THE PENT,BOOK OF THE PENTA,BOOK OF THE PENTAT,BOOK OF THE PENTATE,BOOK OF THE PENTATEU,BOOK OF THE PENTATEUC,BOOK OF THE PENTATEUCH,BOOKOFTHEPE,BOOKOFTHEPEN,BOOKOFTHEPENT,BOOKOFTHEPENTA,BOOKOFTHEPENTAT,BOOKOFTHEPENTATE,BOOKOFTHEPENTATEU,BOOKOFTHEPENTATEUC,BOOKOFTHEPENTATEUCH,BOOKS,BOOKS O,BOOKS OF,BOOKS OF M,BOOKS OF MO,BOOKS OF MOS,BOOKS OF MOSE,BOOKS OF MOSES,BOOKSO,BOOKSOF,BOOKSOFM,BOOKSOFMO,BOOKSOFMOS,BOOKSOFMOSE,BOOKSOFMOSES,MO,MOS,PEN,PENT,PENTA,PENTAT,PENTATE,PENTATEU,PENTATEUC,PENTATEUCH,TOR,TORA,TORAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "שְׁמוּאֵל",
-        book_name_English_guide: "Samuel",
-        BOS_book_code: "SAM",
-        BOS_reference_number: 605,
-        BOS_sequence_number: 36,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(55),
-        short_abbreviation: Some("Sam"),
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["SA1","SA2"],
-        consists_of_books_codes: Some("SA1,SA2"),
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "SAM,SAMUEL",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "מְלָכִים",
-        book_name_English_guide: "Kings",
-        BOS_book_code: "KGS",
-        BOS_reference_number: 610,
-        BOS_sequence_number: 42,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(47),
-        short_abbreviation: Some("Ki"),
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["KI1","KI2"],
-        consists_of_books_codes: Some("KI1,KI2"),
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "KG,KGS,KI,KIN,KING,KINGS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Samuel-Kings",
-        BOS_book_code: "SMK",
-        BOS_reference_number: 615,
-        BOS_sequence_number: 43,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(102),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["SAM","KGS","SA1","SA2","KI1","KI2"],
-        consists_of_books_codes: None,
-        typical_section: Some("NT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "SAMUEL-,SAMUEL-K,SAMUEL-KI,SAMUEL-KIN,SAMUEL-KING,SAMUEL-KINGS,SMK",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "דִּבְרֵי הַיָּמִים",
-        book_name_English_guide: "Chronicles",
-        BOS_book_code: "CHR",
-        BOS_reference_number: 620,
-        BOS_sequence_number: 48,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(65),
-        short_abbreviation: Some("Chr"),
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["CH1","CH2"],
-        consists_of_books_codes: Some("CH1,CH2"),
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "CHR,CHRO,CHRON,CHRONI,CHRONIC,CHRONICL,CHRONICLE,CHRONICLES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(Unknown)",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Ezra-Nehemiah / 1 Esdras",
-        BOS_book_code: "EZN",
-        BOS_reference_number: 625,
-        BOS_sequence_number: 65,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(26),
-        short_abbreviation: None,
-        SBL_abbreviation: Some("Ezra"),
-        OSIS_abbreviation: Some("Ezra"),
-        Sword_abbreviation: Some("Ezra"),
-        CCEL_number: None,
-        USFM_abbreviation: Some("Ezr"),
-        USFM_number_str: Some("15"),
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: Some("Ezr"),
-        Drupal_Bible_abbreviation: Some("Ezr"),
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["EZR","NEH"],
-        consists_of_books_codes: Some("EZR,NEH"),
-        typical_section: Some("OT"),
-        typical_subsection: Some("Historical"),
-        all_English_derived_abbreviations: "EZN,EZRA-,EZRA-N,EZRA-NE,EZRA-NEH,EZRA-NEHE,EZRA-NEHEM,EZRA-NEHEMI,EZRA-NEHEMIA,EZRA-NEHEMIAH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Twelve Prophets",
-        BOS_book_code: "P12",
-        BOS_reference_number: 630,
-        BOS_sequence_number: 150,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["SPR","HOS","JOL","AMO","OBA","JNA","MIC","NAH","HAB","ZEP","HAG","ZEC","MAL"],
-        consists_of_books_codes: Some("HOS,JOL,AMO,OBA,JNA,MIC,NAH,HAB,ZEP,HAG,ZEC,MAL"),
-        typical_section: Some("OT"),
-        typical_subsection: Some("Minor Prophets"),
-        all_English_derived_abbreviations: "P12,TW,TWE,TWEL,TWELV,TWELVE,TWELVE P,TWELVE PR,TWELVE PRO,TWELVE PROP,TWELVE PROPH,TWELVE PROPHE,TWELVE PROPHET,TWELVE PROPHETS,TWELVEP,TWELVEPR,TWELVEPRO,TWELVEPROP,TWELVEPROPH,TWELVEPROPHE,TWELVEPROPHET,TWELVEPROPHETS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Book of Women",
-        BOS_book_code: "WOM",
-        BOS_reference_number: 640,
-        BOS_sequence_number: 33,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["RUT","SUS","EST","JDT"],
-        consists_of_books_codes: Some("RUT,SUS,EST,JDT"),
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BOOK OF W,BOOK OF WO,BOOK OF WOM,BOOK OF WOME,BOOK OF WOMEN,BOOKOFW,BOOKOFWO,BOOKOFWOM,BOOKOFWOME,BOOKOFWOMEN,WOM",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Book of Sessions",
-        BOS_book_code: "SES",
-        BOS_reference_number: 645,
-        BOS_sequence_number: 31,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["JOS","JDG","SA1","SA2","KI1","KI2","PRO","ECC","RUT","SNG","SIR","JOB"],
-        consists_of_books_codes: Some("JOS,JDG,SA1,SA2,KI1,KI2,PRO,ECC,RUT,SNG,SIR,JOB"),
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BOOK OF S,BOOK OF SE,BOOK OF SES,BOOK OF SESS,BOOK OF SESSI,BOOK OF SESSIO,BOOK OF SESSION,BOOK OF SESSIONS,BOOKOFS,BOOKOFSE,BOOKOFSES,BOOKOFSESS,BOOKOFSESSI,BOOKOFSESSIO,BOOKOFSESSION,BOOKOFSESSIONS,SES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Book of the Prophets",
-        BOS_book_code: "SPR",
-        BOS_reference_number: 650,
-        BOS_sequence_number: 155,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["P12","ISA","JER","LAM","HOS","JOL","AMO","OBA","JNA","MIC","NAH","HAB","ZEP","HAG","ZEC","MAL"],
-        consists_of_books_codes: Some("ISA,JER,LAM,HOS,JOL,AMO,OBA,JNA,MIC,NAH,HAB,ZEP,HAG,ZEC,MAL"),
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BOOK OF THE PR,BOOK OF THE PRO,BOOK OF THE PROP,BOOK OF THE PROPH,BOOK OF THE PROPHE,BOOK OF THE PROPHET,BOOK OF THE PROPHETS,BOOKOFTHEPR,BOOKOFTHEPRO,BOOKOFTHEPROP,BOOKOFTHEPROPH,BOOKOFTHEPROPHE,BOOKOFTHEPROPHET,BOOKOFTHEPROPHETS,SPR",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "hbo",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Book of the Maccabees",
-        BOS_book_code: "SMC",
-        BOS_reference_number: 655,
-        BOS_sequence_number: 180,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["MA1","MA2","MA3","CH1","CH2","EZR","NEH","WIS","JDT","EST","SUS","LJE","LBA","BAR"],
-        consists_of_books_codes: Some("MA1,MA2,MA3,CH1,CH2,EZR,NEH,WIS,JDT,EST,SUS,LJE,LBA,BAR"),
-        typical_section: Some("OT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BOOK OF THE M,BOOK OF THE MA,BOOK OF THE MAC,BOOK OF THE MACC,BOOK OF THE MACCA,BOOK OF THE MACCAB,BOOK OF THE MACCABE,BOOK OF THE MACCABEE,BOOK OF THE MACCABEES,BOOKOFTHEM,BOOKOFTHEMA,BOOKOFTHEMAC,BOOKOFTHEMACC,BOOKOFTHEMACCA,BOOKOFTHEMACCAB,BOOKOFTHEMACCABE,BOOKOFTHEMACCABEE,BOOKOFTHEMACCABEES,SMC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "el-x-koine",
-        original_language_book_name: "(Unknown)",
-        book_name_English_guide: "Luke-Acts",
-        BOS_book_code: "LKA",
-        BOS_reference_number: 690,
-        BOS_sequence_number: 608,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(52),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &["LUK","ACT"],
-        consists_of_books_codes: Some("LUK,ACT"),
-        typical_section: Some("NT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "LKA,LUKE-,LUKE-A,LUKE-AC,LUKE-ACT,LUKE-ACTS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Generic front matter)",
-        book_name_English_guide: "Generic front matter",
-        BOS_book_code: "FRT",
-        BOS_reference_number: 700,
-        BOS_sequence_number: 5,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("Frt"),
-        USFM_number_str: Some("A0"),
-        USX_number_str: Some("100"),
-        Unbound_Code: None,
-        Bibledit_number: Some(67),
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("FRT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "FRT,GENERIC F,GENERIC FR,GENERIC FRO,GENERIC FRON,GENERIC FRONT,GENERIC FRONT M,GENERIC FRONT MA,GENERIC FRONT MAT,GENERIC FRONT MATT,GENERIC FRONT MATTE,GENERIC FRONT MATTER,GENERICF,GENERICFR,GENERICFRO,GENERICFRON,GENERICFRONT,GENERICFRONTM,GENERICFRONTMA,GENERICFRONTMAT,GENERICFRONTMATT,GENERICFRONTMATTE,GENERICFRONTMATTER",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Preface",
-        book_name_English_guide: "Preface",
-        BOS_book_code: "PRF",
-        BOS_reference_number: 705,
-        BOS_sequence_number: 1,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("FRT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "PREF,PREFA,PREFAC,PREFACE,PRF",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Acknowledgements",
-        book_name_English_guide: "Acknowledgements",
-        BOS_book_code: "ACK",
-        BOS_reference_number: 710,
-        BOS_sequence_number: 2,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("FRT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ACK,ACKN,ACKNO,ACKNOW,ACKNOWL,ACKNOWLE,ACKNOWLED,ACKNOWLEDG,ACKNOWLEDGE,ACKNOWLEDGEM,ACKNOWLEDGEME,ACKNOWLEDGEMEN,ACKNOWLEDGEMENT,ACKNOWLEDGEMENTS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Introduction",
-        book_name_English_guide: "Introduction",
-        BOS_book_code: "INT",
-        BOS_reference_number: 715,
-        BOS_sequence_number: 3,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("Int"),
-        USFM_number_str: None,
-        USX_number_str: Some("107"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("FRT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IN,INT,INTR,INTRO,INTROD,INTRODU,INTRODUC,INTRODUCT,INTRODUCTI,INTRODUCTIO,INTRODUCTION",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Table of Contents)",
-        book_name_English_guide: "Table of Contents",
-        BOS_book_code: "TOC",
-        BOS_reference_number: 720,
-        BOS_sequence_number: 8,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("FRT"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TAB,TABL,TABLE,TABLE O,TABLE OF,TABLE OF C,TABLE OF CO,TABLE OF CON,TABLE OF CONT,TABLE OF CONTE,TABLE OF CONTEN,TABLE OF CONTENT,TABLE OF CONTENTS,TABLEO,TABLEOF,TABLEOFC,TABLEOFCO,TABLEOFCON,TABLEOFCONT,TABLEOFCONTE,TABLEOFCONTEN,TABLEOFCONTENT,TABLEOFCONTENTS,TOC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Glossary",
-        book_name_English_guide: "Glossary",
-        BOS_book_code: "GLS",
-        BOS_reference_number: 725,
-        BOS_sequence_number: 800,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("Glo"),
-        USFM_number_str: Some("A9"),
-        USX_number_str: Some("109"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "GLO,GLOS,GLOSS,GLOSSA,GLOSSAR,GLOSSARY,GLS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Concordance",
-        book_name_English_guide: "Concordance",
-        BOS_book_code: "CNC",
-        BOS_reference_number: 730,
-        BOS_sequence_number: 810,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("Cnc"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "CNC,CON,CONC,CONCO,CONCOR,CONCORD,CONCORDA,CONCORDAN,CONCORDANC,CONCORDANCE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Topical Concordance)",
-        book_name_English_guide: "Topical Concordance",
-        BOS_book_code: "TPC",
-        BOS_reference_number: 735,
-        BOS_sequence_number: 820,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TOP,TOPI,TOPIC,TOPICA,TOPICAL,TOPICAL C,TOPICAL CO,TOPICAL CON,TOPICAL CONC,TOPICAL CONCO,TOPICAL CONCOR,TOPICAL CONCORD,TOPICAL CONCORDA,TOPICAL CONCORDAN,TOPICAL CONCORDANC,TOPICAL CONCORDANCE,TOPICALC,TOPICALCO,TOPICALCON,TOPICALCONC,TOPICALCONCO,TOPICALCONCOR,TOPICALCONCORD,TOPICALCONCORDA,TOPICALCONCORDAN,TOPICALCONCORDANC,TOPICALCONCORDANCE,TP,TPC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Names Index)",
-        book_name_English_guide: "Names Index",
-        BOS_book_code: "IXN",
-        BOS_reference_number: 740,
-        BOS_sequence_number: 830,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("NDX"),
-        USFM_number_str: None,
-        USX_number_str: Some("111"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IXN,NAME,NAMES,NAMES I,NAMES IN,NAMES IND,NAMES INDE,NAMES INDEX,NAMESI,NAMESIN,NAMESIND,NAMESINDE,NAMESINDEX,ND,NDX",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Places Index)",
-        book_name_English_guide: "Places Index",
-        BOS_book_code: "IXP",
-        BOS_reference_number: 745,
-        BOS_sequence_number: 840,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IXP,PLA,PLAC,PLACE,PLACES,PLACES I,PLACES IN,PLACES IND,PLACES INDE,PLACES INDEX,PLACESI,PLACESIN,PLACESIND,PLACESINDE,PLACESINDEX",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Maps",
-        book_name_English_guide: "Maps",
-        BOS_book_code: "MAP",
-        BOS_reference_number: 750,
-        BOS_sequence_number: 890,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "MAP,MAPS",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Thematic Index)",
-        book_name_English_guide: "Thematic Index",
-        BOS_book_code: "IXT",
-        BOS_reference_number: 755,
-        BOS_sequence_number: 850,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("TDX"),
-        USFM_number_str: None,
-        USX_number_str: Some("110"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "IXT,TD,TDX,THEM,THEMA,THEMAT,THEMATI,THEMATIC,THEMATIC I,THEMATIC IN,THEMATIC IND,THEMATIC INDE,THEMATIC INDEX,THEMATICI,THEMATICIN,THEMATICIND,THEMATICINDE,THEMATICINDEX",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Generic back matter)",
-        book_name_English_guide: "Generic back matter",
-        BOS_book_code: "BAK",
-        BOS_reference_number: 760,
-        BOS_sequence_number: 860,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("Bak"),
-        USFM_number_str: None,
-        USX_number_str: Some("101"),
-        Unbound_Code: None,
-        Bibledit_number: Some(68),
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "BAK,GENERIC B,GENERIC BA,GENERIC BAC,GENERIC BACK,GENERIC BACK M,GENERIC BACK MA,GENERIC BACK MAT,GENERIC BACK MATT,GENERIC BACK MATTE,GENERIC BACK MATTER,GENERICB,GENERICBA,GENERICBAC,GENERICBACK,GENERICBACKM,GENERICBACKMA,GENERICBACKMAT,GENERICBACKMATT,GENERICBACKMATTE,GENERICBACKMATTER",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(OTHER)",
-        book_name_English_guide: "Unspecified other OTH",
-        BOS_book_code: "OTH",
-        BOS_reference_number: 870,
-        BOS_sequence_number: 970,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("Oth"),
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "OT,OTH,UNSPECIFIED O,UNSPECIFIED OT,UNSPECIFIED OTH,UNSPECIFIED OTHE,UNSPECIFIED OTHER,UNSPECIFIED OTHER O,UNSPECIFIED OTHER OT,UNSPECIFIED OTHER OTH,UNSPECIFIEDO,UNSPECIFIEDOT,UNSPECIFIEDOTH,UNSPECIFIEDOTHE,UNSPECIFIEDOTHER,UNSPECIFIEDOTHERO,UNSPECIFIEDOTHEROT,UNSPECIFIEDOTHEROTH",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Spelling dictionary / word list)",
-        book_name_English_guide: "Spelling dictionary / word list",
-        BOS_book_code: "WRD",
-        BOS_reference_number: 880,
-        BOS_sequence_number: 980,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SPE,SPEL,SPELL,SPELLI,SPELLIN,SPELLING,SPELLING D,SPELLING DI,SPELLING DIC,SPELLING DICT,SPELLING DICTI,SPELLING DICTIO,SPELLING DICTION,SPELLING DICTIONA,SPELLING DICTIONAR,SPELLING DICTIONARY,SPELLINGD,SPELLINGDI,SPELLINGDIC,SPELLINGDICT,SPELLINGDICTI,SPELLINGDICTIO,SPELLINGDICTION,SPELLINGDICTIONA,SPELLINGDICTIONAR,SPELLINGDICTIONARY,WORD L,WORD LI,WORD LIS,WORD LIST,WORDL,WORDLI,WORDLIS,WORDLIST,WR,WRD",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Word definitions dictionary)",
-        book_name_English_guide: "Word definitions dictionary",
-        BOS_book_code: "WDF",
-        BOS_reference_number: 882,
-        BOS_sequence_number: 982,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "WD,WDF,WORD D,WORD DE,WORD DEF,WORD DEFI,WORD DEFIN,WORD DEFINI,WORD DEFINIT,WORD DEFINITI,WORD DEFINITIO,WORD DEFINITION,WORD DEFINITIONS,WORD DEFINITIONS D,WORD DEFINITIONS DI,WORD DEFINITIONS DIC,WORD DEFINITIONS DICT,WORD DEFINITIONS DICTI,WORD DEFINITIONS DICTIO,WORD DEFINITIONS DICTION,WORD DEFINITIONS DICTIONA,WORD DEFINITIONS DICTIONAR,WORD DEFINITIONS DICTIONARY,WORDD,WORDDE,WORDDEF,WORDDEFI,WORDDEFIN,WORDDEFINI,WORDDEFINIT,WORDDEFINITI,WORDDEFINITIO,WORDDEFINITION,WORDDEFINITIONS,WORDDEFINITIONSD,WORDDEFINITIONSDI,WORDDEFINITIONSDIC,WORDDEFINITIONSDICT,WORDDEFINITIONSDICTI,WORDDEFINITIONSDICTIO,WORDDEFINITIONSDICTION,WORDDEFINITIONSDICTIONA,WORDDEFINITIONSDICTIONAR,WORDDEFINITIONSDICTIONARY",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Hyphenation dictionary)",
-        book_name_English_guide: "Hyphenation dictionary",
-        BOS_book_code: "HYP",
-        BOS_reference_number: 884,
-        BOS_sequence_number: 984,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "HYP,HYPH,HYPHE,HYPHEN,HYPHENA,HYPHENAT,HYPHENATI,HYPHENATIO,HYPHENATION,HYPHENATION D,HYPHENATION DI,HYPHENATION DIC,HYPHENATION DICT,HYPHENATION DICTI,HYPHENATION DICTIO,HYPHENATION DICTION,HYPHENATION DICTIONA,HYPHENATION DICTIONAR,HYPHENATION DICTIONARY,HYPHENATIOND,HYPHENATIONDI,HYPHENATIONDIC,HYPHENATIONDICT,HYPHENATIONDICTI,HYPHENATIONDICTIO,HYPHENATIONDICTION,HYPHENATIONDICTIONA,HYPHENATIONDICTIONAR,HYPHENATIONDICTIONARY",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Strong's Database)",
-        book_name_English_guide: "Strongs Database / Strong's Database",
-        BOS_book_code: "STR",
-        BOS_reference_number: 888,
-        BOS_sequence_number: 988,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &["STRNGS"],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "ST,STR,STRN,STRNG,STRNGS,STRO,STRON,STRONG,STRONG',STRONG'S,STRONG'S D,STRONG'S DA,STRONG'S DAT,STRONG'S DATA,STRONG'S DATAB,STRONG'S DATABA,STRONG'S DATABAS,STRONG'S DATABASE,STRONG'SD,STRONG'SDA,STRONG'SDAT,STRONG'SDATA,STRONG'SDATAB,STRONG'SDATABA,STRONG'SDATABAS,STRONG'SDATABASE,STRONGS,STRONGS D,STRONGS DA,STRONGS DAT,STRONGS DATA,STRONGS DATAB,STRONGS DATABA,STRONGS DATABAS,STRONGS DATABASE,STRONGSD,STRONGSDA,STRONGSDAT,STRONGSDATA,STRONGSDATAB,STRONGSDATABA,STRONGSDATABAS,STRONGSDATABASE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(Semantic Database)",
-        book_name_English_guide: "Semantic Database",
-        BOS_book_code: "SEM",
-        BOS_reference_number: 890,
-        BOS_sequence_number: 990,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "SEM,SEMA,SEMAN,SEMANT,SEMANTI,SEMANTIC,SEMANTIC D,SEMANTIC DA,SEMANTIC DAT,SEMANTIC DATA,SEMANTIC DATAB,SEMANTIC DATABA,SEMANTIC DATABAS,SEMANTIC DATABASE,SEMANTICD,SEMANTICDA,SEMANTICDAT,SEMANTICDATA,SEMANTICDATAB,SEMANTICDATABA,SEMANTICDATABAS,SEMANTICDATABASE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXA)",
-        book_name_English_guide: "Unspecified extra XXA",
-        BOS_book_code: "XXA",
-        BOS_reference_number: 800,
-        BOS_sequence_number: 900,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXA"),
-        USFM_number_str: Some("94"),
-        USX_number_str: Some("093"),
-        Unbound_Code: None,
-        Bibledit_number: Some(69),
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXA,UNSPECIFIEDEXTRAXXA,XXA",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXB)",
-        book_name_English_guide: "Unspecified extra XXB",
-        BOS_book_code: "XXB",
-        BOS_reference_number: 810,
-        BOS_sequence_number: 910,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXB"),
-        USFM_number_str: Some("95"),
-        USX_number_str: Some("094"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXB,UNSPECIFIEDEXTRAXXB,XXB",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXC)",
-        book_name_English_guide: "Unspecified extra XXC",
-        BOS_book_code: "XXC",
-        BOS_reference_number: 820,
-        BOS_sequence_number: 920,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXC"),
-        USFM_number_str: Some("96"),
-        USX_number_str: Some("095"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXC,UNSPECIFIEDEXTRAXXC,XXC",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXD)",
-        book_name_English_guide: "Unspecified extra XXD",
-        BOS_book_code: "XXD",
-        BOS_reference_number: 830,
-        BOS_sequence_number: 930,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXD"),
-        USFM_number_str: Some("97"),
-        USX_number_str: Some("096"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXD,UNSPECIFIEDEXTRAXXD,XXD",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXE)",
-        book_name_English_guide: "Unspecified extra XXE",
-        BOS_book_code: "XXE",
-        BOS_reference_number: 840,
-        BOS_sequence_number: 940,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXE"),
-        USFM_number_str: Some("98"),
-        USX_number_str: Some("097"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXE,UNSPECIFIEDEXTRAXXE,XXE",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXF)",
-        book_name_English_guide: "Unspecified extra XXF",
-        BOS_book_code: "XXF",
-        BOS_reference_number: 850,
-        BOS_sequence_number: 950,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXF"),
-        USFM_number_str: Some("99"),
-        USX_number_str: Some("098"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXF,UNSPECIFIEDEXTRAXXF,XXF",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(XXG)",
-        book_name_English_guide: "Unspecified extra XXG",
-        BOS_book_code: "XXG",
-        BOS_reference_number: 860,
-        BOS_sequence_number: 960,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: Some("XXG"),
-        USFM_number_str: None,
-        USX_number_str: Some("099"),
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("BAK"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNSPECIFIED EXTRA XXG,UNSPECIFIEDEXTRAXXG,XXG",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "Open Bible Stories",
-        book_name_English_guide: "Open Bible Stories",
-        BOS_book_code: "OBS",
-        BOS_reference_number: 997,
-        BOS_sequence_number: 997,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::Number(50),
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "OBS,OP,OPE,OPEN,OPEN B,OPEN BI,OPEN BIB,OPEN BIBL,OPEN BIBLE,OPEN BIBLE S,OPEN BIBLE ST,OPEN BIBLE STO,OPEN BIBLE STOR,OPEN BIBLE STORI,OPEN BIBLE STORIE,OPEN BIBLE STORIES,OPENB,OPENBI,OPENBIB,OPENBIBL,OPENBIBLE,OPENBIBLES,OPENBIBLEST,OPENBIBLESTO,OPENBIBLESTOR,OPENBIBLESTORI,OPENBIBLESTORIE,OPENBIBLESTORIES",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(TEST)",
-        book_name_English_guide: "TEST",
-        BOS_book_code: "TST",
-        BOS_reference_number: 998,
-        BOS_sequence_number: 998,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "TEST,TST",
-    },
-    BibleBooksCodesArrayEntry {
-        original_language_code: "(None)",
-        original_language_book_name: "(UNKNOWN)",
-        book_name_English_guide: "UNKNOWN",
-        BOS_book_code: "UNK",
-        BOS_reference_number: 999,
-        BOS_sequence_number: 999,
-        expected_num_chapters: OptionalNumberOrTwoNumbers::None,
-        short_abbreviation: None,
-        SBL_abbreviation: None,
-        OSIS_abbreviation: None,
-        Sword_abbreviation: None,
-        CCEL_number: None,
-        USFM_abbreviation: None,
-        USFM_number_str: None,
-        USX_number_str: None,
-        Unbound_Code: None,
-        Bibledit_number: None,
-        Logos_number: None,
-        Logos_abbreviation: None,
-        NET_Bible_abbreviation: None,
-        Drupal_Bible_abbreviation: None,
-        Bible_Works_abbreviation: None,
-        Byzantine_abbreviation: None,
-        possible_alternative_abbreviations: &[""],
-        possible_alternative_books_codes: &[],
-        consists_of_books_codes: None,
-        typical_section: Some("???"),
-        typical_subsection: None,
-        all_English_derived_abbreviations: "UNK,UNKN,UNKNO,UNKNOW,UNKNOWN",
-    },
-];
+// The static BIBLE_BOOKS_CODES_ARRAY and its phf maps are created by build.rs (from sourceXML/BibleBooksCodes.xml)
+include!(concat!(env!("OUT_DIR"), "/static_tables.rs"));
+
+
+mod reference;
+pub use reference::{parse_reference, RefRange, Reference, ReferenceParseError};
+mod reference_format;
+pub use reference_format::{BookNameForm, ReferenceStyle};
+mod osis;
+pub use osis::{parse_osis_id, parse_osis_ids, parse_osis_ref, split_osis_work};
+mod bcv_key;
+pub use bcv_key::{sort_bcv_references, BcvKey, BcvKeyError};
+mod filename;
+pub use filename::{book_filename, book_from_filename, FilenameConvention, FilenameError, FilenameParameters};
+mod content;
+pub use content::{book_from_contents, book_from_file_contents, BookIdentification, ContentError};
+mod canon;
+pub use canon::Canon;
+mod scan;
+pub use scan::{scan_folder, FolderScan};
+mod tidy;
+pub use tidy::{tidy_bos_book_code, tidy_bos_book_codes, TidyOptions};
+mod psalm_titles;
+pub use psalm_titles::{greek_to_hebrew_psalm, has_psalm_title, has_psalm_title_numbered, psalm_title_verses, PsalmNumbering};
+mod validation;
+pub use validation::{validate_chapter, ChapterValidity};
+#[cfg(feature = "verse-counts")]
+mod verse_counts;
+#[cfg(feature = "verse-counts")]
+pub use verse_counts::{validate_reference, verses_in_chapter, ReferenceValidity, VERSE_COUNTS_VERSIFICATION};
+mod versification;
+pub use versification::Versification;
+mod table;
+pub use table::{BooksCodesTable, BooksCodesTableEntry, TableLoadError};
+mod lookup_error;
+pub use lookup_error::{Conversion, LookupError};
+#[cfg(feature = "serde")]
+pub mod serde_book_code;
+pub mod export;
+#[cfg(test)]
+mod test_support;
 
-// NOTE: The following perfect_hash_function maps contain the array index of the entry in the above BIBLE_BOOKS_CODES_ARRAY
-//static REFERENCE_ABBREVIATION_ARRAY: [&'static str; 257] = ["GEN","EXO","LEV","NUM","DEU","JOS","JDG","RUT","SA1","SA2","KI1","KI2","CH1","CH2","EZR","NEH","EST","JOB","PSA","PRO","ECC","SNG","ISA","JER","LAM","EZE","DAN","HOS","JOL","AMO","OBA","JNA","MIC","NAH","HAB","ZEP","HAG","ZEC","MAL","MAT","MRK","LUK","JHN","ACT","ROM","CO1","CO2","GAL","EPH","PHP","COL","TH1","TH2","TI1","TI2","TIT","PHM","HEB","JAM","PE1","PE2","JN1","JN2","JN3","JDE","REV","ESG","ESA","ESC","DNG","DNA","DNC","BEL","SUS","JDT","MAN","SIR","SIP","TOB","WIS","PAZ","GES","LES","EZA","EZ5","EZ6","RVE","VSE","LJE","BAR","LJB","BA2","LBA","LB2","BA3","BA4","JRA","PSJ","MAC","MA1","MA2","MA3","MA4","MA5","MQ1","MQ2","MQ3","PB1","PB2","PB3","PB4","PB5","PSB","PS2","PS3","PSS","LAO","ODE","PSO","PJE","WSI","COP","CO3","EUT","DOJ","JUB","ENO","EN2","REP","LBB","CL1","CL2","DID","JSA","JSB","JGA","JGB","TBS","SST","DNT","BLT","GHE","GTR","GMA","GMC","GTW","GPH","GTH","GJU","GBA","AJA","WJC","TAD","LAE","ASS","TSO","TJB","MAI","AEZ","EZT","EUP","PEU","VAM","LAR","ARI","ABL","ART","CLM","ELM","HRE","LPR","EPL","TEO","TTP","TSA","TSB","TSD","TSG","TSI","TSJ","TSY","TSL","TSN","TSR","TSS","TSZ","JJW","SHE","SHM","SHS","SHV","IGE","IGM","IGT","IGR","IGP","IGS","IGC","PCP","MPC","DIO","APC","FOP","ROE","FOQ","TAT","P4D","A2Z","EHY","A2J","H2C","CMP","APS","CAT","P1A","P1B","MOS","SAM","KGS","SMK","CHR","EZN","P12","WOM","SES","SPR","SMC","LKA","FRT","PRF","ACK","INT","TOC","GLS","CNC","TPC","IXN","IXP","MAP","IXT","BAK","OTH","WRD","WDF","HYP","STR","SEM","XXA","XXB","XXC","XXD","XXE","XXF","XXG","OBS","TST","UNK"]; // The array index matches the BIBLE_BOOKS_CODES_ARRAY index
-static REFERENCE_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! { "GEN"=>0,"EXO"=>1,"LEV"=>2,"NUM"=>3,"DEU"=>4,"JOS"=>5,"JDG"=>6,"RUT"=>7,"SA1"=>8,"SA2"=>9,"KI1"=>10,"KI2"=>11,"CH1"=>12,"CH2"=>13,"EZR"=>14,"NEH"=>15,"EST"=>16,"JOB"=>17,"PSA"=>18,"PRO"=>19,"ECC"=>20,"SNG"=>21,"ISA"=>22,"JER"=>23,"LAM"=>24,"EZE"=>25,"DAN"=>26,"HOS"=>27,"JOL"=>28,"AMO"=>29,"OBA"=>30,"JNA"=>31,"MIC"=>32,"NAH"=>33,"HAB"=>34,"ZEP"=>35,"HAG"=>36,"ZEC"=>37,"MAL"=>38,"MAT"=>39,"MRK"=>40,"LUK"=>41,"JHN"=>42,"ACT"=>43,"ROM"=>44,"CO1"=>45,"CO2"=>46,"GAL"=>47,"EPH"=>48,"PHP"=>49,"COL"=>50,"TH1"=>51,"TH2"=>52,"TI1"=>53,"TI2"=>54,"TIT"=>55,"PHM"=>56,"HEB"=>57,"JAM"=>58,"PE1"=>59,"PE2"=>60,"JN1"=>61,"JN2"=>62,"JN3"=>63,"JDE"=>64,"REV"=>65,"ESG"=>66,"ESA"=>67,"ESC"=>68,"DNG"=>69,"DNA"=>70,"DNC"=>71,"BEL"=>72,"SUS"=>73,"JDT"=>74,"MAN"=>75,"SIR"=>76,"SIP"=>77,"TOB"=>78,"WIS"=>79,"PAZ"=>80,"GES"=>81,"LES"=>82,"EZA"=>83,"EZ5"=>84,"EZ6"=>85,"RVE"=>86,"VSE"=>87,"LJE"=>88,"BAR"=>89,"LJB"=>90,"BA2"=>91,"LBA"=>92,"LB2"=>93,"BA3"=>94,"BA4"=>95,"JRA"=>96,"PSJ"=>97,"MAC"=>98,"MA1"=>99,"MA2"=>100,"MA3"=>101,"MA4"=>102,"MA5"=>103,"MQ1"=>104,"MQ2"=>105,"MQ3"=>106,"PB1"=>107,"PB2"=>108,"PB3"=>109,"PB4"=>110,"PB5"=>111,"PSB"=>112,"PS2"=>113,"PS3"=>114,"PSS"=>115,"LAO"=>116,"ODE"=>117,"PSO"=>118,"PJE"=>119,"WSI"=>120,"COP"=>121,"CO3"=>122,"EUT"=>123,"DOJ"=>124,"JUB"=>125,"ENO"=>126,"EN2"=>127,"REP"=>128,"LBB"=>129,"CL1"=>130,"CL2"=>131,"DID"=>132,"JSA"=>133,"JSB"=>134,"JGA"=>135,"JGB"=>136,"TBS"=>137,"SST"=>138,"DNT"=>139,"BLT"=>140,"GHE"=>141,"GTR"=>142,"GMA"=>143,"GMC"=>144,"GTW"=>145,"GPH"=>146,"GTH"=>147,"GJU"=>148,"GBA"=>149,"AJA"=>150,"WJC"=>151,"TAD"=>152,"LAE"=>153,"ASS"=>154,"TSO"=>155,"TJB"=>156,"MAI"=>157,"AEZ"=>158,"EZT"=>159,"EUP"=>160,"PEU"=>161,"VAM"=>162,"LAR"=>163,"ARI"=>164,"ABL"=>165,"ART"=>166,"CLM"=>167,"ELM"=>168,"HRE"=>169,"LPR"=>170,"EPL"=>171,"TEO"=>172,"TTP"=>173,"TSA"=>174,"TSB"=>175,"TSD"=>176,"TSG"=>177,"TSI"=>178,"TSJ"=>179,"TSY"=>180,"TSL"=>181,"TSN"=>182,"TSR"=>183,"TSS"=>184,"TSZ"=>185,"JJW"=>186,"SHE"=>187,"SHM"=>188,"SHS"=>189,"SHV"=>190,"IGE"=>191,"IGM"=>192,"IGT"=>193,"IGR"=>194,"IGP"=>195,"IGS"=>196,"IGC"=>197,"PCP"=>198,"MPC"=>199,"DIO"=>200,"APC"=>201,"FOP"=>202,"ROE"=>203,"FOQ"=>204,"TAT"=>205,"P4D"=>206,"A2Z"=>207,"EHY"=>208,"A2J"=>209,"H2C"=>210,"CMP"=>211,"APS"=>212,"CAT"=>213,"P1A"=>214,"P1B"=>215,"MOS"=>216,"SAM"=>217,"KGS"=>218,"SMK"=>219,"CHR"=>220,"EZN"=>221,"P12"=>222,"WOM"=>223,"SES"=>224,"SPR"=>225,"SMC"=>226,"LKA"=>227,"FRT"=>228,"PRF"=>229,"ACK"=>230,"INT"=>231,"TOC"=>232,"GLS"=>233,"CNC"=>234,"TPC"=>235,"IXN"=>236,"IXP"=>237,"MAP"=>238,"IXT"=>239,"BAK"=>240,"OTH"=>241,"WRD"=>242,"WDF"=>243,"HYP"=>244,"STR"=>245,"SEM"=>246,"XXA"=>247,"XXB"=>248,"XXC"=>249,"XXD"=>250,"XXE"=>251,"XXF"=>252,"XXG"=>253,"OBS"=>254,"TST"=>255,"UNK"=>256 };
-static USFM_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! { "Gen"=>0, "Exo"=>1, "Lev"=>2, "Num"=>3, "Deu"=>4, "Jos"=>5, "Jdg"=>6, "Rut"=>7, "1Sa"=>8, "2Sa"=>9, "1Ki"=>10, "2Ki"=>11, "1Ch"=>12, "2Ch"=>13, "Ezr"=>14, "Neh"=>15, "Est"=>16, "Job"=>17, "Psa"=>18, "Pro"=>19, "Ecc"=>20, "Sng"=>21, "Isa"=>22, "Jer"=>23, "Lam"=>24, "Ezk"=>25, "Dan"=>26, "Hos"=>27, "Jol"=>28, "Amo"=>29, "Oba"=>30, "Jon"=>31, "Mic"=>32, "Nam"=>33, "Hab"=>34, "Zep"=>35, "Hag"=>36, "Zec"=>37, "Mal"=>38, "Mat"=>39, "Mrk"=>40, "Luk"=>41, "Jhn"=>42, "Act"=>43, "Rom"=>44, "1Co"=>45, "2Co"=>46, "Gal"=>47, "Eph"=>48, "Php"=>49, "Col"=>50, "1Th"=>51, "2Th"=>52, "1Ti"=>53, "2Ti"=>54, "Tit"=>55, "Phm"=>56, "Heb"=>57, "Jas"=>58, "1Pe"=>59, "2Pe"=>60, "1Jn"=>61, "2Jn"=>62, "3Jn"=>63, "Jud"=>64, "Rev"=>65, "EsG"=>66, "ADE"=>67, "DaG"=>69, "Bel"=>72, "Sus"=>73, "Jdt"=>74, "Man"=>75, "Sir"=>76, "Tob"=>78, "Wis"=>79, "S3Y"=>80, "1Es"=>81, "2Es"=>82, "EzA"=>83, "5Ez"=>84, "6Ez"=>85, "LJe"=>88, "Bar"=>89, "2Ba"=>91, "LBa"=>92, "4Ba"=>95, "1Ma"=>99, "2Ma"=>100, "3Ma"=>101, "4Ma"=>102, "1Mq"=>104, "2Mq"=>105, "3Mq"=>106, "PsB"=>112, "Ps2"=>113, "Ps3"=>114, "PsS"=>115, "Lao"=>116, "Oda"=>117, "PSo"=>118, "PJe"=>119, "WSi"=>120, "CoP"=>121, "3Co"=>122, "Eut"=>123, "DoJ"=>124, "Jub"=>125, "Eno"=>126, "Rep"=>128, "1Cl"=>130, "2Cl"=>131, "Did"=>132, "JsA"=>133, "JsB"=>134, "JdA"=>135, "JdB"=>136, "TbS"=>137, "SsT"=>138, "DnT"=>139, "BlT"=>140, "She"=>187, "Frt"=>228, "Int"=>231, "Glo"=>233, "Cnc"=>234, "NDX"=>236, "TDX"=>239, "Bak"=>240, "Oth"=>241, "XXA"=>247, "XXB"=>248, "XXC"=>249, "XXD"=>250, "XXE"=>251, "XXF"=>252, "XXG"=>253, };
-static UPPERCASE_USFM_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! { "GEN"=>0, "EXO"=>1, "LEV"=>2, "NUM"=>3, "DEU"=>4, "JOS"=>5, "JDG"=>6, "RUT"=>7, "1SA"=>8, "2SA"=>9, "1KI"=>10, "2KI"=>11, "1CH"=>12, "2CH"=>13, "EZR"=>14, "NEH"=>15, "EST"=>16, "JOB"=>17, "PSA"=>18, "PRO"=>19, "ECC"=>20, "SNG"=>21, "ISA"=>22, "JER"=>23, "LAM"=>24, "EZK"=>25, "DAN"=>26, "HOS"=>27, "JOL"=>28, "AMO"=>29, "OBA"=>30, "JON"=>31, "MIC"=>32, "NAM"=>33, "HAB"=>34, "ZEP"=>35, "HAG"=>36, "ZEC"=>37, "MAL"=>38, "MAT"=>39, "MRK"=>40, "LUK"=>41, "JHN"=>42, "ACT"=>43, "ROM"=>44, "1CO"=>45, "2CO"=>46, "GAL"=>47, "EPH"=>48, "PHP"=>49, "COL"=>50, "1TH"=>51, "2TH"=>52, "1TI"=>53, "2TI"=>54, "TIT"=>55, "PHM"=>56, "HEB"=>57, "JAS"=>58, "1PE"=>59, "2PE"=>60, "1JN"=>61, "2JN"=>62, "3JN"=>63, "JUD"=>64, "REV"=>65, "ESG"=>66, "ADE"=>67, "DAG"=>69, "BEL"=>72, "SUS"=>73, "JDT"=>74, "MAN"=>75, "SIR"=>76, "TOB"=>78, "WIS"=>79, "S3Y"=>80, "1ES"=>81, "2ES"=>82, "EZA"=>83, "5EZ"=>84, "6EZ"=>85, "LJE"=>88, "BAR"=>89, "2BA"=>91, "LBA"=>92, "4BA"=>95, "1MA"=>99, "2MA"=>100, "3MA"=>101, "4MA"=>102, "1MQ"=>104, "2MQ"=>105, "3MQ"=>106, "PSB"=>112, "PS2"=>113, "PS3"=>114, "PSS"=>115, "LAO"=>116, "ODA"=>117, "PSO"=>118, "PJE"=>119, "WSI"=>120, "COP"=>121, "3CO"=>122, "EUT"=>123, "DOJ"=>124, "JUB"=>125, "ENO"=>126, "REP"=>128, "1CL"=>130, "2CL"=>131, "DID"=>132, "JSA"=>133, "JSB"=>134, "JDA"=>135, "JDB"=>136, "TBS"=>137, "SST"=>138, "DNT"=>139, "BLT"=>140, "SHE"=>187, "FRT"=>228, "INT"=>231, "GLO"=>233, "CNC"=>234, "NDX"=>236, "TDX"=>239, "BAK"=>240, "OTH"=>241, "XXA"=>247, "XXB"=>248, "XXC"=>249, "XXD"=>250, "XXE"=>251, "XXF"=>252, "XXG"=>253, };
-static OSIS_ABBREVIATION_MAP: phf::Map<&'static str, usize> = phf_map! { "Gen"=>0, "Exod"=>1, "Lev"=>2, "Num"=>3, "Deut"=>4, "Josh"=>5, "Judg"=>6, "Ruth"=>7, "1Sam"=>8, "2Sam"=>9, "1Kgs"=>10, "2Kgs"=>11, "1Chr"=>12, "2Chr"=>13, "Ezra"=>14, "Neh"=>15, "Esth"=>16, "Job"=>17, "Ps"=>18, "Prov"=>19, "Eccl"=>20, "Song"=>21, "Isa"=>22, "Jer"=>23, "Lam"=>24, "Ezek"=>25, "Dan"=>26, "Hos"=>27, "Joel"=>28, "Amos"=>29, "Obad"=>30, "Jonah"=>31, "Mic"=>32, "Nah"=>33, "Hab"=>34, "Zeph"=>35, "Hag"=>36, "Zech"=>37, "Mal"=>38, "Matt"=>39, "Mark"=>40, "Luke"=>41, "John"=>42, "Acts"=>43, "Rom"=>44, "1Cor"=>45, "2Cor"=>46, "Gal"=>47, "Eph"=>48, "Phil"=>49, "Col"=>50, "1Thess"=>51, "2Thess"=>52, "1Tim"=>53, "2Tim"=>54, "Titus"=>55, "Phlm"=>56, "Heb"=>57, "Jas"=>58, "1Pet"=>59, "2Pet"=>60, "1John"=>61, "2John"=>62, "3John"=>63, "Jude"=>64, "Rev"=>65, "EsthGr"=>66, "AddEsth"=>67, "AddDan"=>69, "Bel"=>72, "Sus"=>73, "Jdt"=>74, "PrMan"=>75, "Sir"=>76, "SirP"=>77, "Tob"=>78, "Wis"=>79, "PrAzar"=>80, "1Esd"=>81, "2Esd"=>82, "RevEzra"=>86, "VisEzra"=>87, "EpJer"=>88, "Bar"=>89, "3Bar"=>94, "1Macc"=>99, "2Macc"=>100, "3Macc"=>101, "4Macc"=>102, "5Macc"=>103, "AddPs"=>113, "2En"=>127, };
-static ENGLISH_NAME_MAP: phf::Map<&'static str, usize> = phf_map! { "1 MO"=>0, "1 MOS"=>0, "1 MOSE"=>0, "1 MOSES"=>0, "1 MS"=>0, "1 MSS"=>0, "1MO"=>0, "1MOS"=>0, "1MOSE"=>0, "1MOSES"=>0, "1MS"=>0, "1MSS"=>0, "GE"=>0, "GEN"=>0, "GENE"=>0, "GENES"=>0, "GENESI"=>0, "GENESIS"=>0, "GN"=>0, "GNS"=>0, "GNSS"=>0, "2 MO"=>1, "2 MOS"=>1, "2 MOSE"=>1, "2 MOSES"=>1, "2 MS"=>1, "2 MSS"=>1, "2MO"=>1, "2MOS"=>1, "2MOSE"=>1, "2MOSES"=>1, "2MS"=>1, "2MSS"=>1, "EX"=>1, "EXD"=>1, "EXDS"=>1, "EXO"=>1, "EXOD"=>1, "EXODU"=>1, "EXODUS"=>1, "3 MO"=>2, "3 MOS"=>2, "3 MOSE"=>2, "3 MOSES"=>2, "3 MS"=>2, "3 MSS"=>2, "3MO"=>2, "3MOS"=>2, "3MOSE"=>2, "3MOSES"=>2, "3MS"=>2, "3MSS"=>2, "LE"=>2, "LEV"=>2, "LEVI"=>2, "LEVIT"=>2, "LEVITI"=>2, "LEVITIC"=>2, "LEVITICU"=>2, "LEVITICUS"=>2, "LV"=>2, "LVT"=>2, "4 MO"=>3, "4 MOS"=>3, "4 MOSE"=>3, "4 MOSES"=>3, "4 MS"=>3, "4 MSS"=>3, "4MO"=>3, "4MOS"=>3, "4MOSE"=>3, "4MOSES"=>3, "4MS"=>3, "4MSS"=>3, "NM"=>3, "NMB"=>3, "NMBR"=>3, "NMBRS"=>3, "NU"=>3, "NUM"=>3, "NUMB"=>3, "NUMBE"=>3, "NUMBER"=>3, "NUMBERS"=>3, "5 MO"=>4, "5 MOS"=>4, "5 MOSE"=>4, "5 MOSES"=>4, "5 MS"=>4, "5 MSS"=>4, "5MO"=>4, "5MOS"=>4, "5MOSE"=>4, "5MOSES"=>4, "5MS"=>4, "5MSS"=>4, "DE"=>4, "DEU"=>4, "DEUT"=>4, "DEUTE"=>4, "DEUTER"=>4, "DEUTERO"=>4, "DEUTERON"=>4, "DEUTERONO"=>4, "DEUTERONOM"=>4, "DEUTERONOMY"=>4, "DT"=>4, "DTR"=>4, "DTRN"=>4, "JOS"=>5, "JOSH"=>5, "JOSHUA"=>5, "JOSHUA SO"=>5, "JOSHUA SON"=>5, "JOSHUA SON O"=>5, "JOSHUA SON OF"=>5, "JOSHUA SON OF N"=>5, "JOSHUA SON OF NU"=>5, "JOSHUA SON OF NUN"=>5, "JOSHUAS"=>5, "JOSHUASON"=>5, "JOSHUASONO"=>5, "JOSHUASONOF"=>5, "JOSHUASONOFN"=>5, "JOSHUASONOFNU"=>5, "JOSHUASONOFNUN"=>5, "JS"=>5, "JSH"=>5, "JDG"=>6, "JDGS"=>6, "JG"=>6, "JUDG"=>6, "JUDGES"=>6, "JUG"=>6, "RT"=>7, "RTH"=>7, "RU"=>7, "RUT"=>7, "RUTH"=>7, "1 KINGD"=>8, "1 KINGDO"=>8, "1 KINGDOM"=>8, "1 KINGDOMS"=>8, "1 KNGD"=>8, "1 KNGDM"=>8, "1 KNGDMS"=>8, "1 S"=>8, "1 SA"=>8, "1 SAM"=>8, "1 SAMU"=>8, "1 SAMUE"=>8, "1 SAMUEL"=>8, "1 SM"=>8, "1 SML"=>8, "1KINGD"=>8, "1KINGDO"=>8, "1KINGDOM"=>8, "1KINGDOMS"=>8, "1KNGD"=>8, "1KNGDM"=>8, "1KNGDMS"=>8, "1S"=>8, "1SA"=>8, "1SAM"=>8, "1SAMU"=>8, "1SAMUE"=>8, "1SAMUEL"=>8, "1SM"=>8, "1SML"=>8, "SA1"=>8, "2 KINGD"=>9, "2 KINGDO"=>9, "2 KINGDOM"=>9, "2 KINGDOMS"=>9, "2 KNGD"=>9, "2 KNGDM"=>9, "2 KNGDMS"=>9, "2 S"=>9, "2 SA"=>9, "2 SAM"=>9, "2 SAMU"=>9, "2 SAMUE"=>9, "2 SAMUEL"=>9, "2 SM"=>9, "2 SML"=>9, "2KINGD"=>9, "2KINGDO"=>9, "2KINGDOM"=>9, "2KINGDOMS"=>9, "2KNGD"=>9, "2KNGDM"=>9, "2KNGDMS"=>9, "2S"=>9, "2SA"=>9, "2SAM"=>9, "2SAMU"=>9, "2SAMUE"=>9, "2SAMUEL"=>9, "2SM"=>9, "2SML"=>9, "SA2"=>9, "1 KG"=>10, "1 KGS"=>10, "1 KIN"=>10, "1 KING"=>10, "1 KINGS"=>10, "1 KNGS"=>10, "1KG"=>10, "1KGS"=>10, "1KI"=>10, "1KIN"=>10, "1KING"=>10, "1KINGS"=>10, "1KNGS"=>10, "3 K"=>10, "3 KI"=>10, "3 KIN"=>10, "3 KING"=>10, "3 KINGD"=>10, "3 KINGDO"=>10, "3 KINGDOM"=>10, "3 KINGDOMS"=>10, "3 KINGS"=>10, "3 KN"=>10, "3 KNG"=>10, "3 KNGD"=>10, "3 KNGDM"=>10, "3 KNGDMS"=>10, "3K"=>10, "3KI"=>10, "3KIN"=>10, "3KING"=>10, "3KINGD"=>10, "3KINGDO"=>10, "3KINGDOM"=>10, "3KINGDOMS"=>10, "3KINGS"=>10, "3KN"=>10, "3KNG"=>10, "3KNGD"=>10, "3KNGDM"=>10, "3KNGDMS"=>10, "KI1"=>10, "2 KG"=>11, "2 KGS"=>11, "2 KIN"=>11, "2 KING"=>11, "2 KINGS"=>11, "2 KNGS"=>11, "2KG"=>11, "2KGS"=>11, "2KI"=>11, "2KIN"=>11, "2KING"=>11, "2KINGS"=>11, "2KNGS"=>11, "4 K"=>11, "4 KI"=>11, "4 KIN"=>11, "4 KING"=>11, "4 KINGD"=>11, "4 KINGDO"=>11, "4 KINGDOM"=>11, "4 KINGDOMS"=>11, "4 KINGS"=>11, "4 KN"=>11, "4 KNG"=>11, "4 KNGD"=>11, "4 KNGDM"=>11, "4 KNGDMS"=>11, "4K"=>11, "4KI"=>11, "4KIN"=>11, "4KING"=>11, "4KINGD"=>11, "4KINGDO"=>11, "4KINGDOM"=>11, "4KINGDOMS"=>11, "4KINGS"=>11, "4KN"=>11, "4KNG"=>11, "4KNGD"=>11, "4KNGDM"=>11, "4KNGDMS"=>11, "KI2"=>11, "1 CH"=>12, "1 CHR"=>12, "1 CHRN"=>12, "1 CHRO"=>12, "1 CHRON"=>12, "1 CHRONI"=>12, "1 CHRONIC"=>12, "1 CHRONICL"=>12, "1 CHRONICLE"=>12, "1 CHRONICLES"=>12, "1 PA"=>12, "1 PAR"=>12, "1 PARA"=>12, "1 PARAL"=>12, "1 PARALI"=>12, "1 PARALIP"=>12, "1 PARALIPO"=>12, "1 PARALIPOM"=>12, "1 PARALIPOME"=>12, "1 PARALIPOMEN"=>12, "1 PARALIPOMENO"=>12, "1 PARALIPOMENON"=>12, "1CH"=>12, "1CHR"=>12, "1CHRN"=>12, "1CHRO"=>12, "1CHRON"=>12, "1CHRONI"=>12, "1CHRONIC"=>12, "1CHRONICL"=>12, "1CHRONICLE"=>12, "1CHRONICLES"=>12, "1PA"=>12, "1PAR"=>12, "1PARA"=>12, "1PARAL"=>12, "1PARALI"=>12, "1PARALIP"=>12, "1PARALIPO"=>12, "1PARALIPOM"=>12, "1PARALIPOME"=>12, "1PARALIPOMEN"=>12, "1PARALIPOMENO"=>12, "1PARALIPOMENON"=>12, "CH1"=>12, "2 CH"=>13, "2 CHR"=>13, "2 CHRN"=>13, "2 CHRO"=>13, "2 CHRON"=>13, "2 CHRONI"=>13, "2 CHRONIC"=>13, "2 CHRONICL"=>13, "2 CHRONICLE"=>13, "2 CHRONICLES"=>13, "2 PA"=>13, "2 PAR"=>13, "2 PARA"=>13, "2 PARAL"=>13, "2 PARALI"=>13, "2 PARALIP"=>13, "2 PARALIPO"=>13, "2 PARALIPOM"=>13, "2 PARALIPOME"=>13, "2 PARALIPOMEN"=>13, "2 PARALIPOMENO"=>13, "2 PARALIPOMENON"=>13, "2CH"=>13, "2CHR"=>13, "2CHRN"=>13, "2CHRO"=>13, "2CHRON"=>13, "2CHRONI"=>13, "2CHRONIC"=>13, "2CHRONICL"=>13, "2CHRONICLE"=>13, "2CHRONICLES"=>13, "2PA"=>13, "2PAR"=>13, "2PARA"=>13, "2PARAL"=>13, "2PARALI"=>13, "2PARALIP"=>13, "2PARALIPO"=>13, "2PARALIPOM"=>13, "2PARALIPOME"=>13, "2PARALIPOMEN"=>13, "2PARALIPOMENO"=>13, "2PARALIPOMENON"=>13, "CH2"=>13, "EZR"=>14, "EZRA"=>14, "NE"=>15, "NEH"=>15, "NEHE"=>15, "NEHEM"=>15, "NEHEMI"=>15, "NEHEMIA"=>15, "NEHEMIAH"=>15, "NHMH"=>15, "ES"=>16, "EST"=>16, "ESTE"=>16, "ESTER"=>16, "ESTH"=>16, "ESTHER"=>16, "ESTHR"=>16, "ESTR"=>16, "JB"=>17, "JOB"=>17, "GALL"=>18, "GALLI"=>18, "GALLIC"=>18, "GALLICA"=>18, "GALLICAN"=>18, "GALLICANU"=>18, "GALLICANUM"=>18, "PS"=>18, "PSA"=>18, "PSAL"=>18, "PSALM"=>18, "PSALMS"=>18, "PSALMS A"=>18, "PSALMSA"=>18, "PSL"=>18, "PSLM"=>18, "PSLMS"=>18, "PR"=>19, "PRO"=>19, "PROV"=>19, "PROVE"=>19, "PROVER"=>19, "PROVERB"=>19, "PROVERBS"=>19, "PRV"=>19, "PRVB"=>19, "EC"=>20, "ECC"=>20, "ECCL"=>20, "ECCLE"=>20, "ECCLES"=>20, "ECCLESI"=>20, "ECCLESIASTE"=>20, "ECCLESIASTES"=>20, "ECCLESS"=>20, "ECCLS"=>20, "ECCLST"=>20, "ECCLSTS"=>20, "QO"=>20, "QOH"=>20, "QOHO"=>20, "QOHOL"=>20, "QOHOLE"=>20, "QOHOLET"=>20, "QOHOLETH"=>20, "CAN"=>21, "CANT"=>21, "CANTI"=>21, "CANTIC"=>21, "CANTICL"=>21, "CANTICLE"=>21, "CANTICLE O"=>21, "CANTICLE OF"=>21, "CANTICLE OF C"=>21, "CANTICLE OF CA"=>21, "CANTICLE OF CAN"=>21, "CANTICLE OF CANT"=>21, "CANTICLE OF CANTI"=>21, "CANTICLE OF CANTIC"=>21, "CANTICLE OF CANTICL"=>21, "CANTICLE OF CANTICLE"=>21, "CANTICLE OF CANTICLES"=>21, "CANTICLEO"=>21, "CANTICLEOF"=>21, "CANTICLEOFC"=>21, "CANTICLEOFCA"=>21, "CANTICLEOFCAN"=>21, "CANTICLEOFCANT"=>21, "CANTICLEOFCANTI"=>21, "CANTICLEOFCANTIC"=>21, "CANTICLEOFCANTICL"=>21, "CANTICLEOFCANTICLE"=>21, "CANTICLEOFCANTICLES"=>21, "CANTICLES"=>21, "CNT"=>21, "SL"=>21, "SN"=>21, "SNG"=>21, "SO"=>21, "SOL"=>21, "SON"=>21, "SONG"=>21, "SONG OF S"=>21, "SONG OF SO"=>21, "SONG OF SOL"=>21, "SONG OF SOLO"=>21, "SONG OF SOLOM"=>21, "SONG OF SOLOMO"=>21, "SONG OF SOLOMON"=>21, "SONG OF SON"=>21, "SONG OF SONG"=>21, "SONG OF SONGS"=>21, "SONGOFS"=>21, "SONGOFSO"=>21, "SONGOFSOL"=>21, "SONGOFSOLO"=>21, "SONGOFSOLOM"=>21, "SONGOFSOLOMO"=>21, "SONGOFSOLOMON"=>21, "SONGOFSON"=>21, "SONGOFSONG"=>21, "SONGOFSONGS"=>21, "SOS"=>21, "SS"=>21, "IS"=>22, "ISA"=>22, "ISAI"=>22, "ISAIA"=>22, "ISAIAH"=>22, "JE"=>23, "JER"=>23, "JERE"=>23, "JEREM"=>23, "JEREMI"=>23, "JEREMIA"=>23, "JEREMIAH"=>23, "JRM"=>23, "JRMH"=>23, "LA"=>24, "LAM"=>24, "LAME"=>24, "LAMEN"=>24, "LAMENT"=>24, "LAMENTA"=>24, "LAMENTAT"=>24, "LAMENTATI"=>24, "LAMENTATIO"=>24, "LAMENTATION"=>24, "LAMENTATIONS"=>24, "LAMENTATIONS O"=>24, "LAMENTATIONS OF"=>24, "LAMENTATIONS OF J"=>24, "LAMENTATIONS OF JE"=>24, "LAMENTATIONS OF JER"=>24, "LAMENTATIONS OF JERE"=>24, "LAMENTATIONS OF JEREM"=>24, "LAMENTATIONS OF JEREMI"=>24, "LAMENTATIONS OF JEREMIA"=>24, "LAMENTATIONS OF JEREMIAH"=>24, "LAMENTATIONSO"=>24, "LAMENTATIONSOF"=>24, "LAMENTATIONSOFJ"=>24, "LAMENTATIONSOFJE"=>24, "LAMENTATIONSOFJER"=>24, "LAMENTATIONSOFJERE"=>24, "LAMENTATIONSOFJEREM"=>24, "LAMENTATIONSOFJEREMI"=>24, "LAMENTATIONSOFJEREMIA"=>24, "LAMENTATIONSOFJEREMIAH"=>24, "LM"=>24, "LMT"=>24, "LMTT"=>24, "LMTTS"=>24, "EZE"=>25, "EZEK"=>25, "EZEKIEL"=>25, "EZK"=>25, "EZKL"=>25, "DA"=>26, "DAN"=>26, "DANIEL"=>26, "DN"=>26, "DNL"=>26, "HO"=>27, "HOS"=>27, "HOSE"=>27, "HOSEA"=>27, "HS"=>27, "JL"=>28, "JOE"=>28, "JOEL"=>28, "JOL"=>28, "AM"=>29, "AMO"=>29, "AMOS"=>29, "AMS"=>29, "OB"=>30, "OBA"=>30, "OBAD"=>30, "OBADI"=>30, "OBADIA"=>30, "OBADIAH"=>30, "OBD"=>30, "OBDH"=>30, "JNA"=>31, "JNH"=>31, "JON"=>31, "JONA"=>31, "JONAH"=>31, "MC"=>32, "MCH"=>32, "MI"=>32, "MIC"=>32, "MICA"=>32, "MICAH"=>32, "MICH"=>32, "NA"=>33, "NAH"=>33, "NAHU"=>33, "NAHUM"=>33, "NAM"=>33, "NHM"=>33, "HAB"=>34, "HABA"=>34, "HABAK"=>34, "HABAKK"=>34, "HABAKKU"=>34, "HABAKKUK"=>34, "HBK"=>34, "HBKK"=>34, "ZEP"=>35, "ZEPH"=>35, "ZEPHA"=>35, "ZEPHAN"=>35, "ZEPHANI"=>35, "ZEPHANIA"=>35, "ZEPHANIAH"=>35, "ZP"=>35, "ZPH"=>35, "HAG"=>36, "HAGG"=>36, "HAGGA"=>36, "HAGGAI"=>36, "HG"=>36, "HGG"=>36, "ZC"=>37, "ZCR"=>37, "ZCRH"=>37, "ZEC"=>37, "ZECH"=>37, "ZECHA"=>37, "ZECHAR"=>37, "ZECHARI"=>37, "ZECHARIA"=>37, "ZECHARIAH"=>37, "MAL"=>38, "MALA"=>38, "MALAC"=>38, "MALACH"=>38, "MALACHI"=>38, "ML"=>38, "MLC"=>38, "MAT"=>39, "MATH"=>39, "MATHE"=>39, "MATHEW"=>39, "MATT"=>39, "MATTH"=>39, "MATTHE"=>39, "MATTHEW"=>39, "MT"=>39, "MTT"=>39, "MAK"=>40, "MAR"=>40, "MARK"=>40, "MK"=>40, "MR"=>40, "MRK"=>40, "LK"=>41, "LU"=>41, "LUK"=>41, "LUKE"=>41, "JH"=>42, "JHN"=>42, "JN"=>42, "JOH"=>42, "JOHN"=>42, "AC"=>43, "ACT"=>43, "ACTS"=>43, "ACTS O"=>43, "ACTS OF"=>43, "ACTS OF T"=>43, "ACTS OF TH"=>43, "ACTS OF THE"=>43, "ACTS OF THE A"=>43, "ACTS OF THE AP"=>43, "ACTS OF THE APO"=>43, "ACTS OF THE APOS"=>43, "ACTS OF THE APOST"=>43, "ACTS OF THE APOSTL"=>43, "ACTS OF THE APOSTLE"=>43, "ACTS OF THE APOSTLES"=>43, "ACTSO"=>43, "ACTSOF"=>43, "ACTSOFT"=>43, "ACTSOFTH"=>43, "ACTSOFTHE"=>43, "ACTSOFTHEA"=>43, "ACTSOFTHEAP"=>43, "ACTSOFTHEAPO"=>43, "ACTSOFTHEAPOS"=>43, "ACTSOFTHEAPOST"=>43, "ACTSOFTHEAPOSTL"=>43, "ACTSOFTHEAPOSTLE"=>43, "ACTSOFTHEAPOSTLES"=>43, "RM"=>44, "RMN"=>44, "RMNS"=>44, "RO"=>44, "ROM"=>44, "ROMA"=>44, "ROMAN"=>44, "ROMANS"=>44, "1 CO"=>45, "1 COR"=>45, "1 CORI"=>45, "1 CORIN"=>45, "1 CORINT"=>45, "1 CORINTH"=>45, "1 CORINTHI"=>45, "1 CORINTHIA"=>45, "1 CORINTHIAN"=>45, "1 CORINTHIANS"=>45, "1CO"=>45, "1COR"=>45, "1CORI"=>45, "1CORIN"=>45, "1CORINT"=>45, "1CORINTH"=>45, "1CORINTHI"=>45, "1CORINTHIA"=>45, "1CORINTHIAN"=>45, "1CORINTHIANS"=>45, "CO1"=>45, "2 CO"=>46, "2 COR"=>46, "2 CORI"=>46, "2 CORIN"=>46, "2 CORINT"=>46, "2 CORINTH"=>46, "2 CORINTHI"=>46, "2 CORINTHIA"=>46, "2 CORINTHIAN"=>46, "2 CORINTHIANS"=>46, "2CO"=>46, "2COR"=>46, "2CORI"=>46, "2CORIN"=>46, "2CORINT"=>46, "2CORINTH"=>46, "2CORINTHI"=>46, "2CORINTHIA"=>46, "2CORINTHIAN"=>46, "2CORINTHIANS"=>46, "CO2"=>46, "GA"=>47, "GAL"=>47, "GALA"=>47, "GALAT"=>47, "GALATI"=>47, "GALATIA"=>47, "GALATIAN"=>47, "GALATIANS"=>47, "GL"=>47, "GLT"=>47, "EPH"=>48, "EPHE"=>48, "EPHES"=>48, "EPHESI"=>48, "EPHESIA"=>48, "EPHESIAN"=>48, "EPHESIANS"=>48, "EPHS"=>48, "PHI"=>49, "PHIL"=>49, "PHILI"=>49, "PHILIP"=>49, "PHILIPP"=>49, "PHILIPPI"=>49, "PHILIPPIA"=>49, "PHILIPPIAN"=>49, "PHILIPPIANS"=>49, "PHL"=>49, "PHP"=>49, "PP"=>49, "PPS"=>49, "CLS"=>50, "COL"=>50, "COLO"=>50, "COLOS"=>50, "COLOSS"=>50, "COLOSSI"=>50, "COLOSSIA"=>50, "COLOSSIAN"=>50, "COLOSSIANS"=>50, "1 TH"=>51, "1 THE"=>51, "1 THES"=>51, "1 THESS"=>51, "1 THESSA"=>51, "1 THESSAL"=>51, "1 THESSALO"=>51, "1 THESSALON"=>51, "1 THESSALONI"=>51, "1 THESSALONIA"=>51, "1 THESSALONIAN"=>51, "1 THESSALONIANS"=>51, "1 THS"=>51, "1 THSS"=>51, "1TH"=>51, "1THE"=>51, "1THES"=>51, "1THESS"=>51, "1THESSA"=>51, "1THESSAL"=>51, "1THESSALO"=>51, "1THESSALON"=>51, "1THESSALONI"=>51, "1THESSALONIA"=>51, "1THESSALONIAN"=>51, "1THESSALONIANS"=>51, "1THS"=>51, "1THSS"=>51, "1TS"=>51, "TH1"=>51, "2 TH"=>52, "2 THE"=>52, "2 THES"=>52, "2 THESS"=>52, "2 THESSA"=>52, "2 THESSAL"=>52, "2 THESSALO"=>52, "2 THESSALON"=>52, "2 THESSALONI"=>52, "2 THESSALONIA"=>52, "2 THESSALONIAN"=>52, "2 THESSALONIANS"=>52, "2 THS"=>52, "2 THSS"=>52, "2TH"=>52, "2THE"=>52, "2THES"=>52, "2THESS"=>52, "2THESSA"=>52, "2THESSAL"=>52, "2THESSALO"=>52, "2THESSALON"=>52, "2THESSALONI"=>52, "2THESSALONIA"=>52, "2THESSALONIAN"=>52, "2THESSALONIANS"=>52, "2THS"=>52, "2THSS"=>52, "2TS"=>52, "TH2"=>52, "1 TI"=>53, "1 TIM"=>53, "1 TIMO"=>53, "1 TIMOT"=>53, "1 TIMOTH"=>53, "1 TIMOTHY"=>53, "1 TM"=>53, "1 TMT"=>53, "1TI"=>53, "1TIM"=>53, "1TIMO"=>53, "1TIMOT"=>53, "1TIMOTH"=>53, "1TIMOTHY"=>53, "1TM"=>53, "1TMT"=>53, "TI1"=>53, "2 TI"=>54, "2 TIM"=>54, "2 TIMO"=>54, "2 TIMOT"=>54, "2 TIMOTH"=>54, "2 TIMOTHY"=>54, "2 TM"=>54, "2 TMT"=>54, "2TI"=>54, "2TIM"=>54, "2TIMO"=>54, "2TIMOT"=>54, "2TIMOTH"=>54, "2TIMOTHY"=>54, "2TM"=>54, "2TMT"=>54, "TI2"=>54, "TIT"=>55, "TITU"=>55, "TITUS"=>55, "TT"=>55, "TTS"=>55, "PHILE"=>56, "PHILEM"=>56, "PHILEMO"=>56, "PHILEMON"=>56, "PHLM"=>56, "PHLMN"=>56, "PHM"=>56, "HBR"=>57, "HBRW"=>57, "HBRWS"=>57, "HE"=>57, "HEB"=>57, "HEBR"=>57, "HEBRE"=>57, "HEBREW"=>57, "HEBREWS"=>57, "JA"=>58, "JAC"=>58, "JACO"=>58, "JACOB"=>58, "JAM"=>58, "JAME"=>58, "JAMES"=>58, "JAS"=>58, "JM"=>58, "JMS"=>58, "1 PE"=>59, "1 PET"=>59, "1 PETE"=>59, "1 PETER"=>59, "1 PT"=>59, "1 PTR"=>59, "1PE"=>59, "1PET"=>59, "1PETE"=>59, "1PETER"=>59, "1PT"=>59, "1PTR"=>59, "PE1"=>59, "2 PE"=>60, "2 PET"=>60, "2 PETE"=>60, "2 PETER"=>60, "2 PT"=>60, "2 PTR"=>60, "2PE"=>60, "2PET"=>60, "2PETE"=>60, "2PETER"=>60, "2PT"=>60, "2PTR"=>60, "PE2"=>60, "1 J"=>61, "1 JH"=>61, "1 JHN"=>61, "1 JO"=>61, "1 JOH"=>61, "1 JOHN"=>61, "1J"=>61, "1JH"=>61, "1JHN"=>61, "1JN"=>61, "1JO"=>61, "1JOH"=>61, "1JOHN"=>61, "JN1"=>61, "2 J"=>62, "2 JH"=>62, "2 JHN"=>62, "2 JO"=>62, "2 JOH"=>62, "2 JOHN"=>62, "2J"=>62, "2JH"=>62, "2JHN"=>62, "2JN"=>62, "2JO"=>62, "2JOH"=>62, "2JOHN"=>62, "JN2"=>62, "3 J"=>63, "3 JH"=>63, "3 JHN"=>63, "3 JO"=>63, "3 JOH"=>63, "3 JOHN"=>63, "3J"=>63, "3JH"=>63, "3JHN"=>63, "3JN"=>63, "3JO"=>63, "3JOH"=>63, "3JOHN"=>63, "JN3"=>63, "JD"=>64, "JDE"=>64, "JUD"=>64, "JUDA"=>64, "JUDAS"=>64, "JUDE"=>64, "APOCALYPSE OF S"=>65, "APOCALYPSE OF ST"=>65, "APOCALYPSE OF ST."=>65, "APOCALYPSE OF ST. J"=>65, "APOCALYPSE OF ST. JO"=>65, "APOCALYPSE OF ST. JOH"=>65, "APOCALYPSE OF ST. JOHN"=>65, "APOCALYPSEOFS"=>65, "APOCALYPSEOFST"=>65, "APOCALYPSEOFST."=>65, "APOCALYPSEOFST.J"=>65, "APOCALYPSEOFST.JO"=>65, "APOCALYPSEOFST.JOH"=>65, "APOCALYPSEOFST.JOHN"=>65, "RE"=>65, "REV"=>65, "REVELATION"=>65, "REVELATION OF J"=>65, "REVELATION OF JO"=>65, "REVELATION OF JOH"=>65, "REVELATION OF JOHN"=>65, "REVELATIONOFJ"=>65, "REVELATIONOFJO"=>65, "REVELATIONOFJOH"=>65, "REVELATIONOFJOHN"=>65, "RVL"=>65, "ADD E"=>66, "ADD ES"=>66, "ADD EST"=>66, "ADD ESTH"=>66, "ADD ESTHE"=>66, "ADD ESTHER"=>66, "ADDE"=>66, "ADDES"=>66, "ADDEST"=>66, "ADDESTH"=>66, "ADDESTHE"=>66, "ADDESTHER"=>66, "ESG"=>66, "ESTHER (GREEK)"=>66, "ESTHER G"=>66, "ESTHER GR"=>66, "ESTHER GRE"=>66, "ESTHER GREE"=>66, "ESTHER GREEK"=>66, "ESTHERG"=>66, "ESTHERGR"=>66, "ESTHERGRE"=>66, "ESTHERGREE"=>66, "ESTHERGREEK"=>66, "ESTHG"=>66, "ESTHGR"=>66, "GK"=>66, "GKE"=>66, "GKES"=>66, "GREEK EST"=>66, "GREEK ESTH"=>66, "GREEK ESTHE"=>66, "GREEK ESTHER"=>66, "GREEKEST"=>66, "GREEKESTH"=>66, "GREEKESTHE"=>66, "GREEKESTHER"=>66, "ADE"=>67, "ESA"=>67, "ESTHER (ADDITIONS)"=>67, "ESTHER A"=>67, "ESTHER AD"=>67, "ESTHER ADD"=>67, "ESTHER ADDI"=>67, "ESTHER ADDIT"=>67, "ESTHER ADDITI"=>67, "ESTHER ADDITIO"=>67, "ESTHER ADDITION"=>67, "ESTHER ADDITIONS"=>67, "ESTHERA"=>67, "ESTHERAD"=>67, "ESTHERADD"=>67, "ESTHERADDI"=>67, "ESTHERADDIT"=>67, "ESTHERADDITI"=>67, "ESTHERADDITIO"=>67, "ESTHERADDITION"=>67, "ESTHERADDITIONS"=>67, "GREEK ADDITIONS TO E"=>67, "GREEK ADDITIONS TO ES"=>67, "GREEK ADDITIONS TO EST"=>67, "GREEK ADDITIONS TO ESTH"=>67, "GREEK ADDITIONS TO ESTHE"=>67, "GREEK ADDITIONS TO ESTHER"=>67, "GREEKADDITIONSTOE"=>67, "GREEKADDITIONSTOES"=>67, "GREEKADDITIONSTOEST"=>67, "GREEKADDITIONSTOESTH"=>67, "GREEKADDITIONSTOESTHE"=>67, "GREEKADDITIONSTOESTHER"=>67, "COMBINED HEBREW/GREEK E"=>68, "COMBINED HEBREW/GREEK ES"=>68, "COMBINED HEBREW/GREEK EST"=>68, "COMBINED HEBREW/GREEK ESTH"=>68, "COMBINED HEBREW/GREEK ESTHE"=>68, "COMBINED HEBREW/GREEK ESTHER"=>68, "COMBINEDHEBREW/GREEKE"=>68, "COMBINEDHEBREW/GREEKES"=>68, "COMBINEDHEBREW/GREEKEST"=>68, "COMBINEDHEBREW/GREEKESTH"=>68, "COMBINEDHEBREW/GREEKESTHE"=>68, "COMBINEDHEBREW/GREEKESTHER"=>68, "ESC"=>68, "ESTHER (COMBINED)"=>68, "ESTHER C"=>68, "ESTHER CO"=>68, "ESTHER COM"=>68, "ESTHER COMB"=>68, "ESTHER COMBI"=>68, "ESTHER COMBIN"=>68, "ESTHER COMBINE"=>68, "ESTHER COMBINED"=>68, "ESTHERC"=>68, "ESTHERCO"=>68, "ESTHERCOM"=>68, "ESTHERCOMB"=>68, "ESTHERCOMBI"=>68, "ESTHERCOMBIN"=>68, "ESTHERCOMBINE"=>68, "ESTHERCOMBINED"=>68, "DANG"=>69, "DANGR"=>69, "DANIEL (GREEK)"=>69, "DANIEL G"=>69, "DANIEL GR"=>69, "DANIEL GRE"=>69, "DANIEL GREE"=>69, "DANIEL GREEK"=>69, "DANIELG"=>69, "DANIELGR"=>69, "DANIELGRE"=>69, "DANIELGREE"=>69, "DANIELGREEK"=>69, "DNG"=>69, "GREEK D"=>69, "GREEK DA"=>69, "GREEK DAN"=>69, "GREEK DANI"=>69, "GREEK DANIE"=>69, "GREEK DANIEL"=>69, "GREEKD"=>69, "GREEKDA"=>69, "GREEKDAN"=>69, "GREEKDANI"=>69, "GREEKDANIE"=>69, "GREEKDANIEL"=>69, "DANIEL (ADDITIONS)"=>70, "DANIEL A"=>70, "DANIEL AD"=>70, "DANIEL ADD"=>70, "DANIEL ADDI"=>70, "DANIEL ADDIT"=>70, "DANIEL ADDITI"=>70, "DANIEL ADDITIO"=>70, "DANIEL ADDITION"=>70, "DANIEL ADDITIONS"=>70, "DANIELA"=>70, "DANIELAD"=>70, "DANIELADD"=>70, "DANIELADDI"=>70, "DANIELADDIT"=>70, "DANIELADDITI"=>70, "DANIELADDITIO"=>70, "DANIELADDITION"=>70, "DANIELADDITIONS"=>70, "DNA"=>70, "GREEK ADDITIONS TO D"=>70, "GREEK ADDITIONS TO DA"=>70, "GREEK ADDITIONS TO DAN"=>70, "GREEK ADDITIONS TO DANI"=>70, "GREEK ADDITIONS TO DANIE"=>70, "GREEK ADDITIONS TO DANIEL"=>70, "GREEKADDITIONSTOD"=>70, "GREEKADDITIONSTODA"=>70, "GREEKADDITIONSTODAN"=>70, "GREEKADDITIONSTODANI"=>70, "GREEKADDITIONSTODANIE"=>70, "GREEKADDITIONSTODANIEL"=>70, "COMBINED HEBREW/GREEK D"=>71, "COMBINED HEBREW/GREEK DA"=>71, "COMBINED HEBREW/GREEK DAN"=>71, "COMBINED HEBREW/GREEK DANI"=>71, "COMBINED HEBREW/GREEK DANIE"=>71, "COMBINED HEBREW/GREEK DANIEL"=>71, "COMBINEDHEBREW/GREEKD"=>71, "COMBINEDHEBREW/GREEKDA"=>71, "COMBINEDHEBREW/GREEKDAN"=>71, "COMBINEDHEBREW/GREEKDANI"=>71, "COMBINEDHEBREW/GREEKDANIE"=>71, "COMBINEDHEBREW/GREEKDANIEL"=>71, "DANIEL (COMBINED)"=>71, "DANIEL C"=>71, "DANIEL CO"=>71, "DANIEL COM"=>71, "DANIEL COMB"=>71, "DANIEL COMBI"=>71, "DANIEL COMBIN"=>71, "DANIEL COMBINE"=>71, "DANIEL COMBINED"=>71, "DANIELC"=>71, "DANIELCO"=>71, "DANIELCOM"=>71, "DANIELCOMB"=>71, "DANIELCOMBI"=>71, "DANIELCOMBIN"=>71, "DANIELCOMBINE"=>71, "DANIELCOMBINED"=>71, "DNC"=>71, "BEL"=>72, "BEL AND THE DRAGON"=>72, "BELANDTHEDRAGON"=>72, "SUS"=>73, "SUSANNA"=>73, "JDT"=>74, "JUDI"=>74, "JUDIT"=>74, "JUDITH"=>74, "MAN"=>75, "PR M"=>75, "PR MA"=>75, "PR MAN"=>75, "PRAYER OF M"=>75, "PRAYER OF MA"=>75, "PRAYER OF MAN"=>75, "PRAYER OF MANA"=>75, "PRAYER OF MANAS"=>75, "PRAYER OF MANASS"=>75, "PRAYER OF MANASSE"=>75, "PRAYER OF MANASSEH"=>75, "PRAYER OF MANASSES"=>75, "PRAYEROFM"=>75, "PRAYEROFMA"=>75, "PRAYEROFMAN"=>75, "PRAYEROFMANA"=>75, "PRAYEROFMANAS"=>75, "PRAYEROFMANASS"=>75, "PRAYEROFMANASSE"=>75, "PRAYEROFMANASSEH"=>75, "PRAYEROFMANASSES"=>75, "PRM"=>75, "PRMA"=>75, "PRMAN"=>75, "ECCLESIASTI"=>76, "ECCLESIASTIC"=>76, "ECCLESIASTICU"=>76, "ECCLESIASTICUS"=>76, "ECCLSTC"=>76, "ECCLSTCS"=>76, "ECCLSTCU"=>76, "ECCLSTCUS"=>76, "ECCLSTU"=>76, "ECCLU"=>76, "ECCLUS"=>76, "SIR"=>76, "SIRACH"=>76, "WISDOM OF B"=>76, "WISDOM OF BE"=>76, "WISDOM OF BEN"=>76, "WISDOM OF BEN S"=>76, "WISDOM OF BEN SI"=>76, "WISDOM OF BEN SIR"=>76, "WISDOM OF BEN SIRA"=>76, "WISDOM OF JESUS S"=>76, "WISDOM OF JESUS SO"=>76, "WISDOM OF JESUS SON"=>76, "WISDOM OF JESUS SON O"=>76, "WISDOM OF JESUS SON OF"=>76, "WISDOM OF JESUS SON OF S"=>76, "WISDOM OF JESUS SON OF SI"=>76, "WISDOM OF JESUS SON OF SIR"=>76, "WISDOM OF JESUS SON OF SIRA"=>76, "WISDOM OF JESUS SON OF SIRAC"=>76, "WISDOM OF JESUS SON OF SIRACH"=>76, "WISDOMOFB"=>76, "WISDOMOFBE"=>76, "WISDOMOFBEN"=>76, "WISDOMOFBENS"=>76, "WISDOMOFBENSI"=>76, "WISDOMOFBENSIR"=>76, "WISDOMOFBENSIRA"=>76, "WISDOMOFJESUSS"=>76, "WISDOMOFJESUSSO"=>76, "WISDOMOFJESUSSON"=>76, "WISDOMOFJESUSSONO"=>76, "WISDOMOFJESUSSONOF"=>76, "WISDOMOFJESUSSONOFS"=>76, "WISDOMOFJESUSSONOFSI"=>76, "WISDOMOFJESUSSONOFSIR"=>76, "WISDOMOFJESUSSONOFSIRA"=>76, "WISDOMOFJESUSSONOFSIRAC"=>76, "WISDOMOFJESUSSONOFSIRACH"=>76, "SIP"=>77, "SIRACH P"=>77, "SIRACH PR"=>77, "SIRACH PRO"=>77, "SIRACH PROL"=>77, "SIRACH PROLO"=>77, "SIRACH PROLOG"=>77, "SIRACH PROLOGU"=>77, "SIRACH PROLOGUE"=>77, "SIRACHP"=>77, "SIRACHPR"=>77, "SIRACHPRO"=>77, "SIRACHPROL"=>77, "SIRACHPROLO"=>77, "SIRACHPROLOG"=>77, "SIRACHPROLOGU"=>77, "SIRACHPROLOGUE"=>77, "SIRP"=>77, "TBT"=>78, "TOB"=>78, "TOBI"=>78, "TOBIA"=>78, "TOBIAS"=>78, "TOBIT"=>78, "WIS"=>79, "WISD"=>79, "WISDM"=>79, "WISDO"=>79, "WISDOM"=>79, "WISDOM OF S"=>79, "WISDOM OF SO"=>79, "WISDOM OF SOL"=>79, "WISDOM OF SOLO"=>79, "WISDOM OF SOLOM"=>79, "WISDOM OF SOLOMO"=>79, "WISDOM OF SOLOMON"=>79, "WISDOMOFS"=>79, "WISDOMOFSO"=>79, "WISDOMOFSOL"=>79, "WISDOMOFSOLO"=>79, "WISDOMOFSOLOM"=>79, "WISDOMOFSOLOMO"=>79, "WISDOMOFSOLOMON"=>79, "AZ"=>80, "PAZ"=>80, "PR A"=>80, "PR AZ"=>80, "PR AZA"=>80, "PR AZAR"=>80, "PRA"=>80, "PRAYER OF A"=>80, "PRAYER OF AZ"=>80, "PRAYER OF AZA"=>80, "PRAYER OF AZAR"=>80, "PRAYER OF AZARI"=>80, "PRAYER OF AZARIA"=>80, "PRAYER OF AZARIAH"=>80, "PRAYER OF AZARIAH A"=>80, "PRAYER OF AZARIAH AN"=>80, "PRAYER OF AZARIAH AND"=>80, "PRAYER OF AZARIAH AND T"=>80, "PRAYER OF AZARIAH AND TH"=>80, "PRAYER OF AZARIAH AND THE"=>80, "PRAYER OF AZARIAH AND THE S"=>80, "PRAYER OF AZARIAH AND THE SO"=>80, "PRAYER OF AZARIAH AND THE SON"=>80, "PRAYER OF AZARIAH AND THE SONG"=>80, "PRAYER OF AZARIAH AND THE SONG O"=>80, "PRAYER OF AZARIAH AND THE SONG OF"=>80, "PRAYER OF AZARIAH AND THE SONG OF T"=>80, "PRAYER OF AZARIAH AND THE SONG OF TH"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE T"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE TH"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THR"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THRE"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE Y"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YO"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YOU"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUN"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG M"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG ME"=>80, "PRAYER OF AZARIAH AND THE SONG OF THE THREE YOUNG MEN"=>80, "PRAYEROFA"=>80, "PRAYEROFAZ"=>80, "PRAYEROFAZA"=>80, "PRAYEROFAZAR"=>80, "PRAYEROFAZARI"=>80, "PRAYEROFAZARIA"=>80, "PRAYEROFAZARIAH"=>80, "PRAYEROFAZARIAHA"=>80, "PRAYEROFAZARIAHAN"=>80, "PRAYEROFAZARIAHAND"=>80, "PRAYEROFAZARIAHANDT"=>80, "PRAYEROFAZARIAHANDTH"=>80, "PRAYEROFAZARIAHANDTHE"=>80, "PRAYEROFAZARIAHANDTHES"=>80, "PRAYEROFAZARIAHANDTHESO"=>80, "PRAYEROFAZARIAHANDTHESON"=>80, "PRAYEROFAZARIAHANDTHESONG"=>80, "PRAYEROFAZARIAHANDTHESONGO"=>80, "PRAYEROFAZARIAHANDTHESONGOF"=>80, "PRAYEROFAZARIAHANDTHESONGOFT"=>80, "PRAYEROFAZARIAHANDTHESONGOFTH"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHE"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHET"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETH"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHR"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHRE"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREE"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEY"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYO"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOU"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUN"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNG"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNGM"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNGME"=>80, "PRAYEROFAZARIAHANDTHESONGOFTHETHREEYOUNGMEN"=>80, "PRAZ"=>80, "PRAZA"=>80, "PRAZAR"=>80, "S3"=>80, "S3Y"=>80, "SONG OF T"=>80, "SONG OF TH"=>80, "SONG OF THE"=>80, "SONG OF THE T"=>80, "SONG OF THE TH"=>80, "SONG OF THE THR"=>80, "SONG OF THE THRE"=>80, "SONG OF THE THREE"=>80, "SONG OF THE THREE C"=>80, "SONG OF THE THREE CH"=>80, "SONG OF THE THREE CHI"=>80, "SONG OF THE THREE CHIL"=>80, "SONG OF THE THREE CHILD"=>80, "SONG OF THE THREE CHILDR"=>80, "SONG OF THE THREE CHILDRE"=>80, "SONG OF THE THREE CHILDREN"=>80, "SONG OF THE THREE Y"=>80, "SONG OF THE THREE YO"=>80, "SONG OF THE THREE YOU"=>80, "SONG OF THE THREE YOUN"=>80, "SONG OF THE THREE YOUNG"=>80, "SONG OF THE THREE YOUNG M"=>80, "SONG OF THE THREE YOUNG ME"=>80, "SONG OF THE THREE YOUNG MEN"=>80, "SONGOFT"=>80, "SONGOFTH"=>80, "SONGOFTHE"=>80, "SONGOFTHET"=>80, "SONGOFTHETH"=>80, "SONGOFTHETHR"=>80, "SONGOFTHETHRE"=>80, "SONGOFTHETHREE"=>80, "SONGOFTHETHREEC"=>80, "SONGOFTHETHREECH"=>80, "SONGOFTHETHREECHI"=>80, "SONGOFTHETHREECHIL"=>80, "SONGOFTHETHREECHILD"=>80, "SONGOFTHETHREECHILDR"=>80, "SONGOFTHETHREECHILDRE"=>80, "SONGOFTHETHREECHILDREN"=>80, "SONGOFTHETHREEY"=>80, "SONGOFTHETHREEYO"=>80, "SONGOFTHETHREEYOU"=>80, "SONGOFTHETHREEYOUN"=>80, "SONGOFTHETHREEYOUNG"=>80, "SONGOFTHETHREEYOUNGM"=>80, "SONGOFTHETHREEYOUNGME"=>80, "SONGOFTHETHREEYOUNGMEN"=>80, "SONGT"=>80, "SONGTH"=>80, "SONGTHR"=>80, "1 ES"=>81, "1 ESD"=>81, "1ES"=>81, "1ESD"=>81, "3 E"=>81, "3 EZ"=>81, "3 EZR"=>81, "3 EZRA"=>81, "3EZ"=>81, "3EZR"=>81, "3EZRA"=>81, "GES"=>81, "GREEK ESD"=>81, "GREEK ESDR"=>81, "GREEK ESDRA"=>81, "GREEK ESDRAS"=>81, "GREEK EZ"=>81, "GREEK EZR"=>81, "GREEK EZRA"=>81, "GREEKESD"=>81, "GREEKESDR"=>81, "GREEKESDRA"=>81, "GREEKESDRAS"=>81, "GREEKEZ"=>81, "GREEKEZR"=>81, "GREEKEZRA"=>81, "2 ES"=>82, "2 ESD"=>82, "2ES"=>82, "2ESD"=>82, "2ESDR"=>82, "4E"=>82, "4ES"=>82, "4ESD"=>82, "4ESDR"=>82, "LATIN E"=>82, "LATIN ES"=>82, "LATIN ESD"=>82, "LATIN ESDR"=>82, "LATIN ESDRA"=>82, "LATIN ESDRAS"=>82, "LATINE"=>82, "LATINES"=>82, "LATINESD"=>82, "LATINESDR"=>82, "LATINESDRA"=>82, "LATINESDRAS"=>82, "LES"=>82, "APOCALYPSE OF E"=>83, "APOCALYPSE OF EZ"=>83, "APOCALYPSE OF EZR"=>83, "APOCALYPSE OF EZRA"=>83, "APOCALYPSEOFE"=>83, "APOCALYPSEOFEZ"=>83, "APOCALYPSEOFEZR"=>83, "APOCALYPSEOFEZRA"=>83, "EZA"=>83, "EZRA A"=>83, "EZRA AP"=>83, "EZRA APO"=>83, "EZRA APOC"=>83, "EZRA APOCA"=>83, "EZRA APOCAL"=>83, "EZRA APOCALY"=>83, "EZRA APOCALYP"=>83, "EZRA APOCALYPS"=>83, "EZRA APOCALYPSE"=>83, "EZRA S"=>83, "EZRA SH"=>83, "EZRA SHE"=>83, "EZRA SHEA"=>83, "EZRA SHEAL"=>83, "EZRA SHEALT"=>83, "EZRA SHEALTI"=>83, "EZRA SHEALTIE"=>83, "EZRA SHEALTIEL"=>83, "EZRAA"=>83, "EZRAAP"=>83, "EZRAAPO"=>83, "EZRAAPOC"=>83, "EZRAAPOCA"=>83, "EZRAAPOCAL"=>83, "EZRAAPOCALY"=>83, "EZRAAPOCALYP"=>83, "EZRAAPOCALYPS"=>83, "EZRAAPOCALYPSE"=>83, "EZRAS"=>83, "EZRASH"=>83, "EZRASHE"=>83, "EZRASHEA"=>83, "EZRASHEAL"=>83, "EZRASHEALT"=>83, "EZRASHEALTI"=>83, "EZRASHEALTIE"=>83, "EZRASHEALTIEL"=>83, "3ES"=>84, "3ESD"=>84, "3ESDR"=>84, "5 E"=>84, "5 EZ"=>84, "5 EZR"=>84, "5 EZRA"=>84, "5E"=>84, "5ES"=>84, "5ESD"=>84, "5ESDR"=>84, "5EZ"=>84, "5EZR"=>84, "5EZRA"=>84, "EZ5"=>84, "6"=>85, "6 E"=>85, "6 ES"=>85, "6 ESD"=>85, "6 ESDR"=>85, "6 ESDRA"=>85, "6 ESDRAS"=>85, "6 EZ"=>85, "6 EZR"=>85, "6 EZRA"=>85, "6E"=>85, "6ES"=>85, "6ESD"=>85, "6ESDR"=>85, "6ESDRA"=>85, "6ESDRAS"=>85, "6EZ"=>85, "6EZR"=>85, "6EZRA"=>85, "EZ6"=>85, "REVELATION OF E"=>86, "REVELATION OF EZ"=>86, "REVELATION OF EZR"=>86, "REVELATION OF EZRA"=>86, "REVELATIONOFE"=>86, "REVELATIONOFEZ"=>86, "REVELATIONOFEZR"=>86, "REVELATIONOFEZRA"=>86, "REVEZ"=>86, "REVEZR"=>86, "REVEZRA"=>86, "RVE"=>86, "VISE"=>87, "VISEZ"=>87, "VISEZR"=>87, "VISEZRA"=>87, "VISO"=>87, "VISON"=>87, "VISON O"=>87, "VISON OF"=>87, "VISON OF E"=>87, "VISON OF EZ"=>87, "VISON OF EZR"=>87, "VISON OF EZRA"=>87, "VISONO"=>87, "VISONOF"=>87, "VISONOFE"=>87, "VISONOFEZ"=>87, "VISONOFEZR"=>87, "VISONOFEZRA"=>87, "VS"=>87, "VSE"=>87, "EP JER"=>88, "EPISTLE OF JEREMIAH"=>88, "EPISTLEOFJEREMIAH"=>88, "EPJ"=>88, "EPJER"=>88, "LETJ"=>88, "LETJE"=>88, "LETJER"=>88, "LETTER OF JEREMIAH"=>88, "LETTEROFJEREMIAH"=>88, "LJE"=>88, "1 B"=>89, "1 BA"=>89, "1 BAR"=>89, "1 BARU"=>89, "1 BARUC"=>89, "1 BARUCH"=>89, "1B"=>89, "1BA"=>89, "1BAR"=>89, "1BARU"=>89, "1BARUC"=>89, "1BARUCH"=>89, "BAR"=>89, "BARU"=>89, "BARUC"=>89, "BARUCH"=>89, "EPISTLE OF JEREMIAH W"=>90, "EPISTLE OF JEREMIAH WI"=>90, "EPISTLE OF JEREMIAH WIT"=>90, "EPISTLE OF JEREMIAH WITH"=>90, "EPISTLE OF JEREMIAH WITH B"=>90, "EPISTLE OF JEREMIAH WITH BA"=>90, "EPISTLE OF JEREMIAH WITH BAR"=>90, "EPISTLE OF JEREMIAH WITH BARU"=>90, "EPISTLE OF JEREMIAH WITH BARUC"=>90, "EPISTLE OF JEREMIAH WITH BARUCH"=>90, "EPISTLEOFJEREMIAHW"=>90, "EPISTLEOFJEREMIAHWI"=>90, "EPISTLEOFJEREMIAHWIT"=>90, "EPISTLEOFJEREMIAHWITH"=>90, "EPISTLEOFJEREMIAHWITHB"=>90, "EPISTLEOFJEREMIAHWITHBA"=>90, "EPISTLEOFJEREMIAHWITHBAR"=>90, "EPISTLEOFJEREMIAHWITHBARU"=>90, "EPISTLEOFJEREMIAHWITHBARUC"=>90, "EPISTLEOFJEREMIAHWITHBARUCH"=>90, "LETTER OF JEREMIAH W"=>90, "LETTER OF JEREMIAH WI"=>90, "LETTER OF JEREMIAH WIT"=>90, "LETTER OF JEREMIAH WITH"=>90, "LETTER OF JEREMIAH WITH B"=>90, "LETTER OF JEREMIAH WITH BA"=>90, "LETTER OF JEREMIAH WITH BAR"=>90, "LETTER OF JEREMIAH WITH BARU"=>90, "LETTER OF JEREMIAH WITH BARUC"=>90, "LETTER OF JEREMIAH WITH BARUCH"=>90, "LETTEROFJEREMIAHW"=>90, "LETTEROFJEREMIAHWI"=>90, "LETTEROFJEREMIAHWIT"=>90, "LETTEROFJEREMIAHWITH"=>90, "LETTEROFJEREMIAHWITHB"=>90, "LETTEROFJEREMIAHWITHBA"=>90, "LETTEROFJEREMIAHWITHBAR"=>90, "LETTEROFJEREMIAHWITHBARU"=>90, "LETTEROFJEREMIAHWITHBARUC"=>90, "LETTEROFJEREMIAHWITHBARUCH"=>90, "LJB"=>90, "2BA"=>91, "2BAR"=>91, "2BARUCH"=>91, "APOCALYPSE OF B"=>91, "APOCALYPSE OF BA"=>91, "APOCALYPSE OF BAR"=>91, "APOCALYPSE OF BARU"=>91, "APOCALYPSE OF BARUC"=>91, "APOCALYPSE OF BARUCH"=>91, "APOCALYPSEOFB"=>91, "APOCALYPSEOFBA"=>91, "APOCALYPSEOFBAR"=>91, "APOCALYPSEOFBARU"=>91, "APOCALYPSEOFBARUC"=>91, "APOCALYPSEOFBARUCH"=>91, "BA2"=>91, "EPB"=>92, "EPBA"=>92, "EPBAR"=>92, "EPISTLE OF BARU"=>92, "EPISTLE OF BARUC"=>92, "EPISTLE OF BARUCH"=>92, "EPISTLEOFBARU"=>92, "EPISTLEOFBARUC"=>92, "EPISTLEOFBARUCH"=>92, "LBA"=>92, "LETTER OF BARU"=>92, "LETTER OF BARUC"=>92, "LETTER OF BARUCH"=>92, "LETTEROFBARU"=>92, "LETTEROFBARUC"=>92, "LETTEROFBARUCH"=>92, "2 B"=>93, "2 BA"=>93, "2 BAR"=>93, "2 BARU"=>93, "2 BARUC"=>93, "2 BARUCH W"=>93, "2 BARUCH WI"=>93, "2 BARUCH WIT"=>93, "2 BARUCH WITH"=>93, "2 BARUCH WITH T"=>93, "2 BARUCH WITH TH"=>93, "2 BARUCH WITH THE"=>93, "2 BARUCH WITH THE L"=>93, "2 BARUCH WITH THE LE"=>93, "2 BARUCH WITH THE LET"=>93, "2 BARUCH WITH THE LETT"=>93, "2 BARUCH WITH THE LETTE"=>93, "2 BARUCH WITH THE LETTER"=>93, "2 BARUCH WITH THE LETTER O"=>93, "2 BARUCH WITH THE LETTER OF"=>93, "2 BARUCH WITH THE LETTER OF B"=>93, "2 BARUCH WITH THE LETTER OF BA"=>93, "2 BARUCH WITH THE LETTER OF BAR"=>93, "2 BARUCH WITH THE LETTER OF BARU"=>93, "2 BARUCH WITH THE LETTER OF BARUC"=>93, "2 BARUCH WITH THE LETTER OF BARUCH"=>93, "2BARUCHW"=>93, "2BARUCHWI"=>93, "2BARUCHWIT"=>93, "2BARUCHWITH"=>93, "2BARUCHWITHT"=>93, "2BARUCHWITHTH"=>93, "2BARUCHWITHTHE"=>93, "2BARUCHWITHTHEL"=>93, "2BARUCHWITHTHELE"=>93, "2BARUCHWITHTHELET"=>93, "2BARUCHWITHTHELETT"=>93, "2BARUCHWITHTHELETTE"=>93, "2BARUCHWITHTHELETTER"=>93, "2BARUCHWITHTHELETTERO"=>93, "2BARUCHWITHTHELETTEROF"=>93, "2BARUCHWITHTHELETTEROFB"=>93, "2BARUCHWITHTHELETTEROFBA"=>93, "2BARUCHWITHTHELETTEROFBAR"=>93, "2BARUCHWITHTHELETTEROFBARU"=>93, "2BARUCHWITHTHELETTEROFBARUC"=>93, "2BARUCHWITHTHELETTEROFBARUCH"=>93, "LB2"=>93, "3 B"=>94, "3 BA"=>94, "3 BAR"=>94, "3 BARU"=>94, "3 BARUC"=>94, "3 BARUCH"=>94, "3B"=>94, "3BA"=>94, "3BAR"=>94, "3BARU"=>94, "3BARUC"=>94, "3BARUCH"=>94, "BA3"=>94, "4 B"=>95, "4 BA"=>95, "4 BAR"=>95, "4 BARU"=>95, "4 BARUC"=>95, "4 BARUCH"=>95, "4B"=>95, "4BA"=>95, "4BAR"=>95, "4BARU"=>95, "4BARUC"=>95, "4BARUCH"=>95, "BA4"=>95, "PAR"=>95, "PARA"=>95, "PARAL"=>95, "PARALE"=>95, "PARALEI"=>95, "PARALEIP"=>95, "PARALEIPO"=>95, "PARALEIPOM"=>95, "PARALEIPOME"=>95, "PARALEIPOMEN"=>95, "PARALEIPOMENA"=>95, "PARALEIPOMENA I"=>95, "PARALEIPOMENA IE"=>95, "PARALEIPOMENA IER"=>95, "PARALEIPOMENA IERE"=>95, "PARALEIPOMENA IEREM"=>95, "PARALEIPOMENA IEREMI"=>95, "PARALEIPOMENA IEREMIO"=>95, "PARALEIPOMENA IEREMIOU"=>95, "PARALEIPOMENAI"=>95, "PARALEIPOMENAIE"=>95, "PARALEIPOMENAIER"=>95, "PARALEIPOMENAIERE"=>95, "PARALEIPOMENAIEREM"=>95, "PARALEIPOMENAIEREMI"=>95, "PARALEIPOMENAIEREMIO"=>95, "PARALEIPOMENAIEREMIOU"=>95, "PARALI"=>95, "PARALIP"=>95, "PARALIPO"=>95, "PARALIPOM"=>95, "PARALIPOME"=>95, "PARALIPOMEN"=>95, "PARALIPOMENO"=>95, "PARALIPOMENON"=>95, "PARALIPOMENON O"=>95, "PARALIPOMENON OF"=>95, "PARALIPOMENON OF J"=>95, "PARALIPOMENON OF JE"=>95, "PARALIPOMENON OF JER"=>95, "PARALIPOMENON OF JERE"=>95, "PARALIPOMENON OF JEREM"=>95, "PARALIPOMENON OF JEREMI"=>95, "PARALIPOMENON OF JEREMIA"=>95, "PARALIPOMENON OF JEREMIAH"=>95, "PARALIPOMENONO"=>95, "PARALIPOMENONOF"=>95, "PARALIPOMENONOFJ"=>95, "PARALIPOMENONOFJE"=>95, "PARALIPOMENONOFJER"=>95, "PARALIPOMENONOFJERE"=>95, "PARALIPOMENONOFJEREM"=>95, "PARALIPOMENONOFJEREMI"=>95, "PARALIPOMENONOFJEREMIA"=>95, "PARALIPOMENONOFJEREMIAH"=>95, "REST OF T"=>95, "REST OF TH"=>95, "REST OF THE"=>95, "REST OF THE W"=>95, "REST OF THE WO"=>95, "REST OF THE WOR"=>95, "REST OF THE WORD"=>95, "REST OF THE WORDS"=>95, "REST OF THE WORDS O"=>95, "REST OF THE WORDS OF"=>95, "REST OF THE WORDS OF B"=>95, "REST OF THE WORDS OF BA"=>95, "REST OF THE WORDS OF BAR"=>95, "REST OF THE WORDS OF BARU"=>95, "REST OF THE WORDS OF BARUC"=>95, "REST OF THE WORDS OF BARUCH"=>95, "RESTOFT"=>95, "RESTOFTH"=>95, "RESTOFTHE"=>95, "RESTOFTHEW"=>95, "RESTOFTHEWO"=>95, "RESTOFTHEWOR"=>95, "RESTOFTHEWORD"=>95, "RESTOFTHEWORDS"=>95, "RESTOFTHEWORDSO"=>95, "RESTOFTHEWORDSOF"=>95, "RESTOFTHEWORDSOFB"=>95, "RESTOFTHEWORDSOFBA"=>95, "RESTOFTHEWORDSOFBAR"=>95, "RESTOFTHEWORDSOFBARU"=>95, "RESTOFTHEWORDSOFBARUC"=>95, "RESTOFTHEWORDSOFBARUCH"=>95, "ADDITIONS TO J"=>96, "ADDITIONS TO JE"=>96, "ADDITIONS TO JER"=>96, "ADDITIONS TO JERE"=>96, "ADDITIONS TO JEREM"=>96, "ADDITIONS TO JEREMI"=>96, "ADDITIONS TO JEREMIA"=>96, "ADDITIONS TO JEREMIAH"=>96, "ADDITIONSTOJ"=>96, "ADDITIONSTOJE"=>96, "ADDITIONSTOJER"=>96, "ADDITIONSTOJERE"=>96, "ADDITIONSTOJEREM"=>96, "ADDITIONSTOJEREMI"=>96, "ADDITIONSTOJEREMIA"=>96, "ADDITIONSTOJEREMIAH"=>96, "ADDJ"=>96, "ADDJE"=>96, "ADDJER"=>96, "JRA"=>96, "REST OF J"=>96, "REST OF JE"=>96, "REST OF JER"=>96, "REST OF JERE"=>96, "REST OF JEREM"=>96, "REST OF JEREMI"=>96, "REST OF JEREMIA"=>96, "REST OF JEREMIAH"=>96, "RESTOFJ"=>96, "RESTOFJE"=>96, "RESTOFJER"=>96, "RESTOFJERE"=>96, "RESTOFJEREM"=>96, "RESTOFJEREMI"=>96, "RESTOFJEREMIA"=>96, "RESTOFJEREMIAH"=>96, "PSEUDO-J"=>97, "PSEUDO-JO"=>97, "PSEUDO-JOS"=>97, "PSEUDO-JOSE"=>97, "PSEUDO-JOSEP"=>97, "PSEUDO-JOSEPH"=>97, "PSEUDO-JOSEPHU"=>97, "PSEUDO-JOSEPHUS"=>97, "PSJ"=>97, "PSJO"=>97, "PSJOS"=>97, "MAC"=>98, "MACC"=>98, "MACCA"=>98, "MACCAB"=>98, "MACCABE"=>98, "MACCABEE"=>98, "MACCABEES"=>98, "1 MAC"=>99, "1 MACC"=>99, "1 MACCA"=>99, "1 MACCAB"=>99, "1 MACCABE"=>99, "1 MACCABEE"=>99, "1 MACCABEES"=>99, "1 MC"=>99, "1 MCB"=>99, "1MA"=>99, "1MAC"=>99, "1MACC"=>99, "1MACCA"=>99, "1MACCAB"=>99, "1MACCABE"=>99, "1MACCABEE"=>99, "1MACCABEES"=>99, "1MC"=>99, "1MCB"=>99, "MA1"=>99, "2 MAC"=>100, "2 MACC"=>100, "2 MACCA"=>100, "2 MACCAB"=>100, "2 MACCABE"=>100, "2 MACCABEE"=>100, "2 MACCABEES"=>100, "2 MC"=>100, "2 MCB"=>100, "2MA"=>100, "2MAC"=>100, "2MACC"=>100, "2MACCA"=>100, "2MACCAB"=>100, "2MACCABE"=>100, "2MACCABEE"=>100, "2MACCABEES"=>100, "2MC"=>100, "2MCB"=>100, "MA2"=>100, "3 MAC"=>101, "3 MACC"=>101, "3 MACCA"=>101, "3 MACCAB"=>101, "3 MACCABE"=>101, "3 MACCABEE"=>101, "3 MACCABEES"=>101, "3 MC"=>101, "3 MCB"=>101, "3MA"=>101, "3MAC"=>101, "3MACC"=>101, "3MACCA"=>101, "3MACCAB"=>101, "3MACCABE"=>101, "3MACCABEE"=>101, "3MACCABEES"=>101, "3MC"=>101, "3MCB"=>101, "MA3"=>101, "4 MA"=>102, "4 MAC"=>102, "4 MACC"=>102, "4 MACCA"=>102, "4 MACCAB"=>102, "4 MACCABE"=>102, "4 MACCABEE"=>102, "4 MACCABEES"=>102, "4 MC"=>102, "4 MCB"=>102, "4MA"=>102, "4MAC"=>102, "4MACC"=>102, "4MACCA"=>102, "4MACCAB"=>102, "4MACCABE"=>102, "4MACCABEE"=>102, "4MACCABEES"=>102, "4MC"=>102, "4MCB"=>102, "MA4"=>102, "5 MA"=>103, "5 MAC"=>103, "5 MACC"=>103, "5 MACCA"=>103, "5 MACCAB"=>103, "5 MACCABE"=>103, "5 MACCABEE"=>103, "5 MACCABEES"=>103, "5 MC"=>103, "5 MCB"=>103, "5MA"=>103, "5MAC"=>103, "5MACC"=>103, "5MACCA"=>103, "5MACCAB"=>103, "5MACCABE"=>103, "5MACCABEE"=>103, "5MACCABEES"=>103, "5MC"=>103, "5MCB"=>103, "MA5"=>103, "1 MAK"=>104, "1 MAKA"=>104, "1 MAKAB"=>104, "1 MAKABI"=>104, "1 MAKABIA"=>104, "1 MAKABIAN"=>104, "1 ME"=>104, "1 MEQ"=>104, "1 MEQA"=>104, "1 MEQAB"=>104, "1 MEQABY"=>104, "1 MEQABYA"=>104, "1 MEQABYAN"=>104, "1MAK"=>104, "1MAKA"=>104, "1MAKAB"=>104, "1MAKABI"=>104, "1MAKABIA"=>104, "1MAKABIAN"=>104, "1ME"=>104, "1MEQ"=>104, "1MEQA"=>104, "1MEQAB"=>104, "1MEQABY"=>104, "1MEQABYA"=>104, "1MEQABYAN"=>104, "1MQ"=>104, "MEKABIS OF B"=>104, "MEKABIS OF BE"=>104, "MEKABIS OF BEN"=>104, "MEKABIS OF BENJ"=>104, "MEKABIS OF BENJA"=>104, "MEKABIS OF BENJAM"=>104, "MEKABIS OF BENJAMI"=>104, "MEKABIS OF BENJAMIN"=>104, "MEKABISOFB"=>104, "MEKABISOFBE"=>104, "MEKABISOFBEN"=>104, "MEKABISOFBENJ"=>104, "MEKABISOFBENJA"=>104, "MEKABISOFBENJAM"=>104, "MEKABISOFBENJAMI"=>104, "MEKABISOFBENJAMIN"=>104, "MQ1"=>104, "2 MAK"=>105, "2 MAKA"=>105, "2 MAKAB"=>105, "2 MAKABI"=>105, "2 MAKABIA"=>105, "2 MAKABIAN"=>105, "2 ME"=>105, "2 MEQ"=>105, "2 MEQA"=>105, "2 MEQAB"=>105, "2 MEQABY"=>105, "2 MEQABYA"=>105, "2 MEQABYAN"=>105, "2MAK"=>105, "2MAKA"=>105, "2MAKAB"=>105, "2MAKABI"=>105, "2MAKABIA"=>105, "2MAKABIAN"=>105, "2ME"=>105, "2MEQ"=>105, "2MEQA"=>105, "2MEQAB"=>105, "2MEQABY"=>105, "2MEQABYA"=>105, "2MEQABYAN"=>105, "2MQ"=>105, "MEKABIS OF M"=>105, "MEKABIS OF MO"=>105, "MEKABIS OF MOA"=>105, "MEKABIS OF MOAB"=>105, "MEKABISOFM"=>105, "MEKABISOFMO"=>105, "MEKABISOFMOA"=>105, "MEKABISOFMOAB"=>105, "MQ2"=>105, "3 MAK"=>106, "3 MAKA"=>106, "3 MAKAB"=>106, "3 MAKABI"=>106, "3 MAKABIA"=>106, "3 MAKABIAN"=>106, "3 ME"=>106, "3 MEQ"=>106, "3 MEQA"=>106, "3 MEQAB"=>106, "3 MEQABY"=>106, "3 MEQABYA"=>106, "3 MEQABYAN"=>106, "3MAK"=>106, "3MAKA"=>106, "3MAKAB"=>106, "3MAKABI"=>106, "3MAKABIA"=>106, "3MAKABIAN"=>106, "3ME"=>106, "3MEQ"=>106, "3MEQA"=>106, "3MEQAB"=>106, "3MEQABY"=>106, "3MEQABYA"=>106, "3MEQABYAN"=>106, "3MQ"=>106, "MQ3"=>106, "PB1"=>107, "PSALMS BOOK 1"=>107, "PSALMS BOOK I"=>107, "PSALMSBOOK1"=>107, "PSALMSBOOKI"=>107, "PB2"=>108, "PSALMS BOOK 2"=>108, "PSALMS BOOK II"=>108, "PSALMSBOOK2"=>108, "PSALMSBOOKII"=>108, "PB3"=>109, "PSALMS BOOK 3"=>109, "PSALMS BOOK III"=>109, "PSALMSBOOK3"=>109, "PSALMSBOOKIII"=>109, "PB4"=>110, "PSALMS BOOK 4"=>110, "PSALMS BOOK IV"=>110, "PSALMSBOOK4"=>110, "PSALMSBOOKIV"=>110, "PB5"=>111, "PSALMS BOOK 5"=>111, "PSALMS BOOK V"=>111, "PSALMSBOOK5"=>111, "PSALMSBOOKV"=>111, "ALTERNATIVE PS"=>112, "ALTERNATIVE PSA"=>112, "ALTERNATIVE PSAL"=>112, "ALTERNATIVE PSALM"=>112, "ALTERNATIVE PSALMS"=>112, "ALTERNATIVEP"=>112, "ALTERNATIVEPS"=>112, "ALTERNATIVEPSA"=>112, "ALTERNATIVEPSAL"=>112, "ALTERNATIVEPSALM"=>112, "ALTERNATIVEPSALMS"=>112, "GRA"=>112, "GRAI"=>112, "GRAIL"=>112, "GRAIL P"=>112, "GRAIL PS"=>112, "GRAIL PSA"=>112, "GRAIL PSAL"=>112, "GRAIL PSALM"=>112, "GRAIL PSALMS"=>112, "GRAILP"=>112, "GRAILPS"=>112, "GRAILPSA"=>112, "GRAILPSAL"=>112, "GRAILPSALM"=>112, "GRAILPSALMS"=>112, "LIT"=>112, "LITU"=>112, "LITUR"=>112, "LITURG"=>112, "LITURGI"=>112, "LITURGIC"=>112, "LITURGICA"=>112, "LITURGICAL"=>112, "LITURGICAL P"=>112, "LITURGICAL PS"=>112, "LITURGICAL PSA"=>112, "LITURGICAL PSAL"=>112, "LITURGICAL PSALM"=>112, "LITURGICAL PSALMS"=>112, "LITURGICALP"=>112, "LITURGICALPS"=>112, "LITURGICALPSA"=>112, "LITURGICALPSAL"=>112, "LITURGICALPSALM"=>112, "LITURGICALPSALMS"=>112, "MET"=>112, "METR"=>112, "METRI"=>112, "METRIC"=>112, "METRICA"=>112, "METRICAL"=>112, "METRICAL P"=>112, "METRICAL PS"=>112, "METRICAL PSA"=>112, "METRICAL PSAL"=>112, "METRICAL PSALM"=>112, "METRICAL PSALMS"=>112, "METRICALP"=>112, "METRICALPS"=>112, "METRICALPSA"=>112, "METRICALPSAL"=>112, "METRICALPSALM"=>112, "METRICALPSALMS"=>112, "PSALMSB"=>112, "PSB"=>112, "PSM"=>112, "PSME"=>112, "PSMET"=>112, "ADDP"=>113, "ADDPS"=>113, "PS1"=>113, "PS15"=>113, "PS151"=>113, "PS2"=>113, "PSALM 151"=>113, "PSX"=>113, "5A"=>114, "5AP"=>114, "5APO"=>114, "5APOC"=>114, "5APOCS"=>114, "5APOCSY"=>114, "5APOCSYR"=>114, "5APOCSYRP"=>114, "5APOCSYRPS"=>114, "5APOCSYRPSS"=>114, "ADDITIONAL S"=>114, "ADDITIONAL SY"=>114, "ADDITIONAL SYR"=>114, "ADDITIONAL SYRI"=>114, "ADDITIONAL SYRIA"=>114, "ADDITIONAL SYRIAC"=>114, "ADDITIONAL SYRIAC P"=>114, "ADDITIONAL SYRIAC PS"=>114, "ADDITIONAL SYRIAC PSA"=>114, "ADDITIONAL SYRIAC PSAL"=>114, "ADDITIONAL SYRIAC PSALM"=>114, "ADDITIONAL SYRIAC PSALMS"=>114, "ADDITIONALS"=>114, "ADDITIONALSY"=>114, "ADDITIONALSYR"=>114, "ADDITIONALSYRI"=>114, "ADDITIONALSYRIA"=>114, "ADDITIONALSYRIAC"=>114, "ADDITIONALSYRIACP"=>114, "ADDITIONALSYRIACPS"=>114, "ADDITIONALSYRIACPSA"=>114, "ADDITIONALSYRIACPSAL"=>114, "ADDITIONALSYRIACPSALM"=>114, "ADDITIONALSYRIACPSALMS"=>114, "FI"=>114, "FIV"=>114, "FIVE"=>114, "FIVE A"=>114, "FIVE AP"=>114, "FIVE APO"=>114, "FIVE APOC"=>114, "FIVE APOCR"=>114, "FIVE APOCRY"=>114, "FIVE APOCRYP"=>114, "FIVE APOCRYPH"=>114, "FIVE APOCRYPHA"=>114, "FIVE APOCRYPHAL"=>114, "FIVE APOCRYPHAL S"=>114, "FIVE APOCRYPHAL SY"=>114, "FIVE APOCRYPHAL SYR"=>114, "FIVE APOCRYPHAL SYRI"=>114, "FIVE APOCRYPHAL SYRIA"=>114, "FIVE APOCRYPHAL SYRIAC"=>114, "FIVE APOCRYPHAL SYRIAC P"=>114, "FIVE APOCRYPHAL SYRIAC PS"=>114, "FIVE APOCRYPHAL SYRIAC PSA"=>114, "FIVE APOCRYPHAL SYRIAC PSAL"=>114, "FIVE APOCRYPHAL SYRIAC PSALM"=>114, "FIVE APOCRYPHAL SYRIAC PSALMS"=>114, "FIVEA"=>114, "FIVEAP"=>114, "FIVEAPO"=>114, "FIVEAPOC"=>114, "FIVEAPOCR"=>114, "FIVEAPOCRY"=>114, "FIVEAPOCRYP"=>114, "FIVEAPOCRYPH"=>114, "FIVEAPOCRYPHA"=>114, "FIVEAPOCRYPHAL"=>114, "FIVEAPOCRYPHALS"=>114, "FIVEAPOCRYPHALSY"=>114, "FIVEAPOCRYPHALSYR"=>114, "FIVEAPOCRYPHALSYRI"=>114, "FIVEAPOCRYPHALSYRIA"=>114, "FIVEAPOCRYPHALSYRIAC"=>114, "FIVEAPOCRYPHALSYRIACP"=>114, "FIVEAPOCRYPHALSYRIACPS"=>114, "FIVEAPOCRYPHALSYRIACPSA"=>114, "FIVEAPOCRYPHALSYRIACPSAL"=>114, "FIVEAPOCRYPHALSYRIACPSALM"=>114, "FIVEAPOCRYPHALSYRIACPSALMS"=>114, "PS3"=>114, "SY"=>114, "SYR"=>114, "SYRI"=>114, "SYRIA"=>114, "SYRIAC"=>114, "SYRIAC P"=>114, "SYRIAC PS"=>114, "SYRIAC PSA"=>114, "SYRIAC PSAL"=>114, "SYRIAC PSALM"=>114, "SYRIAC PSALMS"=>114, "SYRIAC PSALMS 1"=>114, "SYRIAC PSALMS 15"=>114, "SYRIAC PSALMS 152"=>114, "SYRIAC PSALMS 152-"=>114, "SYRIAC PSALMS 152-1"=>114, "SYRIAC PSALMS 152-15"=>114, "SYRIAC PSALMS 152-155"=>114, "SYRIACP"=>114, "SYRIACPS"=>114, "SYRIACPSA"=>114, "SYRIACPSAL"=>114, "SYRIACPSALM"=>114, "SYRIACPSALMS"=>114, "SYRIACPSALMS1"=>114, "SYRIACPSALMS15"=>114, "SYRIACPSALMS152"=>114, "SYRIACPSALMS152-"=>114, "SYRIACPSALMS152-1"=>114, "SYRIACPSALMS152-15"=>114, "SYRIACPSALMS152-155"=>114, "ADDITIONAL P"=>115, "ADDITIONAL PS"=>115, "ADDITIONAL PSA"=>115, "ADDITIONAL PSAL"=>115, "ADDITIONAL PSALM"=>115, "ADDITIONAL PSALMS"=>115, "ADDITIONAL PSALMS O"=>115, "ADDITIONAL PSALMS OF"=>115, "ADDITIONAL PSALMS OF S"=>115, "ADDITIONAL PSALMS OF SO"=>115, "ADDITIONAL PSALMS OF SOL"=>115, "ADDITIONAL PSALMS OF SOLO"=>115, "ADDITIONAL PSALMS OF SOLOM"=>115, "ADDITIONAL PSALMS OF SOLOMO"=>115, "ADDITIONAL PSALMS OF SOLOMON"=>115, "ADDITIONALP"=>115, "ADDITIONALPS"=>115, "ADDITIONALPSA"=>115, "ADDITIONALPSAL"=>115, "ADDITIONALPSALM"=>115, "ADDITIONALPSALMS"=>115, "ADDITIONALPSALMSO"=>115, "ADDITIONALPSALMSOF"=>115, "ADDITIONALPSALMSOFS"=>115, "ADDITIONALPSALMSOFSO"=>115, "ADDITIONALPSALMSOFSOL"=>115, "ADDITIONALPSALMSOFSOLO"=>115, "ADDITIONALPSALMSOFSOLOM"=>115, "ADDITIONALPSALMSOFSOLOMO"=>115, "ADDITIONALPSALMSOFSOLOMON"=>115, "PSS"=>115, "PSSO"=>115, "PSSOL"=>115, "PSSS"=>115, "PSSSO"=>115, "PSSSOL"=>115, "EPLA"=>116, "EPLAO"=>116, "LAO"=>116, "LAOD"=>116, "LETTER T"=>116, "LETTER TO"=>116, "LETTER TO T"=>116, "LETTER TO TH"=>116, "LETTER TO THE"=>116, "LETTER TO THE L"=>116, "LETTER TO THE LA"=>116, "LETTER TO THE LAO"=>116, "LETTER TO THE LAOD"=>116, "LETTER TO THE LAODI"=>116, "LETTER TO THE LAODIC"=>116, "LETTER TO THE LAODICE"=>116, "LETTER TO THE LAODICEA"=>116, "LETTER TO THE LAODICEAN"=>116, "LETTER TO THE LAODICEANS"=>116, "LETTERT"=>116, "LETTERTO"=>116, "LETTERTOT"=>116, "LETTERTOTH"=>116, "LETTERTOTHE"=>116, "LETTERTOTHEL"=>116, "LETTERTOTHELA"=>116, "LETTERTOTHELAO"=>116, "LETTERTOTHELAOD"=>116, "LETTERTOTHELAODI"=>116, "LETTERTOTHELAODIC"=>116, "LETTERTOTHELAODICE"=>116, "LETTERTOTHELAODICEA"=>116, "LETTERTOTHELAODICEAN"=>116, "LETTERTOTHELAODICEANS"=>116, "OD"=>117, "ODA"=>117, "ODAE"=>117, "ODE"=>117, "PRAYER OF S"=>118, "PRAYER OF SO"=>118, "PRAYER OF SOL"=>118, "PRAYER OF SOLO"=>118, "PRAYER OF SOLOM"=>118, "PRAYER OF SOLOMO"=>118, "PRAYER OF SOLOMON"=>118, "PRAYEROFS"=>118, "PRAYEROFSO"=>118, "PRAYEROFSOL"=>118, "PRAYEROFSOLO"=>118, "PRAYEROFSOLOM"=>118, "PRAYEROFSOLOMO"=>118, "PRAYEROFSOLOMON"=>118, "PRS"=>118, "PRSO"=>118, "PRSOL"=>118, "PSO"=>118, "PJ"=>119, "PJE"=>119, "PRAYER OF J"=>119, "PRAYER OF JE"=>119, "PRAYER OF JER"=>119, "PRAYER OF JERE"=>119, "PRAYER OF JEREM"=>119, "PRAYER OF JEREMI"=>119, "PRAYER OF JEREMIA"=>119, "PRAYER OF JEREMIAH"=>119, "PRAYEROFJ"=>119, "PRAYEROFJE"=>119, "PRAYEROFJER"=>119, "PRAYEROFJERE"=>119, "PRAYEROFJEREM"=>119, "PRAYEROFJEREMI"=>119, "PRAYEROFJEREMIA"=>119, "PRAYEROFJEREMIAH"=>119, "PRJ"=>119, "PRJE"=>119, "PRJER"=>119, "WORDS"=>120, "WORDS O"=>120, "WORDS OF"=>120, "WORDS OF S"=>120, "WORDS OF SI"=>120, "WORDS OF SIR"=>120, "WORDS OF SIRA"=>120, "WORDS OF SIRAC"=>120, "WORDS OF SIRACH"=>120, "WORDSO"=>120, "WORDSOF"=>120, "WORDSOFS"=>120, "WORDSOFSI"=>120, "WORDSOFSIR"=>120, "WORDSOFSIRA"=>120, "WORDSOFSIRAC"=>120, "WORDSOFSIRACH"=>120, "WS"=>120, "WSI"=>120, "WSIR"=>120, "COP"=>121, "EPC"=>121, "EPCO"=>121, "EPCOR"=>121, "EPCORP"=>121, "EPCORPA"=>121, "EPCORPAU"=>121, "EPCORPAUL"=>121, "EPISTLE OF T"=>121, "EPISTLE OF TH"=>121, "EPISTLE OF THE"=>121, "EPISTLE OF THE C"=>121, "EPISTLE OF THE CO"=>121, "EPISTLE OF THE COR"=>121, "EPISTLE OF THE CORI"=>121, "EPISTLE OF THE CORIN"=>121, "EPISTLE OF THE CORINT"=>121, "EPISTLE OF THE CORINTH"=>121, "EPISTLE OF THE CORINTHI"=>121, "EPISTLE OF THE CORINTHIA"=>121, "EPISTLE OF THE CORINTHIAN"=>121, "EPISTLE OF THE CORINTHIANS"=>121, "EPISTLE OF THE CORINTHIANS T"=>121, "EPISTLE OF THE CORINTHIANS TO"=>121, "EPISTLE OF THE CORINTHIANS TO P"=>121, "EPISTLE OF THE CORINTHIANS TO PA"=>121, "EPISTLE OF THE CORINTHIANS TO PAU"=>121, "EPISTLE OF THE CORINTHIANS TO PAUL"=>121, "EPISTLEOFT"=>121, "EPISTLEOFTH"=>121, "EPISTLEOFTHE"=>121, "EPISTLEOFTHEC"=>121, "EPISTLEOFTHECO"=>121, "EPISTLEOFTHECOR"=>121, "EPISTLEOFTHECORI"=>121, "EPISTLEOFTHECORIN"=>121, "EPISTLEOFTHECORINT"=>121, "EPISTLEOFTHECORINTH"=>121, "EPISTLEOFTHECORINTHI"=>121, "EPISTLEOFTHECORINTHIA"=>121, "EPISTLEOFTHECORINTHIAN"=>121, "EPISTLEOFTHECORINTHIANS"=>121, "EPISTLEOFTHECORINTHIANST"=>121, "EPISTLEOFTHECORINTHIANSTO"=>121, "EPISTLEOFTHECORINTHIANSTOP"=>121, "EPISTLEOFTHECORINTHIANSTOPA"=>121, "EPISTLEOFTHECORINTHIANSTOPAU"=>121, "EPISTLEOFTHECORINTHIANSTOPAUL"=>121, "LETTER OF T"=>121, "LETTER OF TH"=>121, "LETTER OF THE"=>121, "LETTER OF THE C"=>121, "LETTER OF THE CO"=>121, "LETTER OF THE COR"=>121, "LETTER OF THE CORI"=>121, "LETTER OF THE CORIN"=>121, "LETTER OF THE CORINT"=>121, "LETTER OF THE CORINTH"=>121, "LETTER OF THE CORINTHI"=>121, "LETTER OF THE CORINTHIA"=>121, "LETTER OF THE CORINTHIAN"=>121, "LETTER OF THE CORINTHIANS"=>121, "LETTER OF THE CORINTHIANS T"=>121, "LETTER OF THE CORINTHIANS TO"=>121, "LETTER OF THE CORINTHIANS TO P"=>121, "LETTER OF THE CORINTHIANS TO PA"=>121, "LETTER OF THE CORINTHIANS TO PAU"=>121, "LETTER OF THE CORINTHIANS TO PAUL"=>121, "LETTEROFT"=>121, "LETTEROFTH"=>121, "LETTEROFTHE"=>121, "LETTEROFTHEC"=>121, "LETTEROFTHECO"=>121, "LETTEROFTHECOR"=>121, "LETTEROFTHECORI"=>121, "LETTEROFTHECORIN"=>121, "LETTEROFTHECORINT"=>121, "LETTEROFTHECORINTH"=>121, "LETTEROFTHECORINTHI"=>121, "LETTEROFTHECORINTHIA"=>121, "LETTEROFTHECORINTHIAN"=>121, "LETTEROFTHECORINTHIANS"=>121, "LETTEROFTHECORINTHIANST"=>121, "LETTEROFTHECORINTHIANSTO"=>121, "LETTEROFTHECORINTHIANSTOP"=>121, "LETTEROFTHECORINTHIANSTOPA"=>121, "LETTEROFTHECORINTHIANSTOPAU"=>121, "LETTEROFTHECORINTHIANSTOPAUL"=>121, "3 C"=>122, "3 CO"=>122, "3 COR"=>122, "3 CORI"=>122, "3 CORIN"=>122, "3 CORINT"=>122, "3 CORINTH"=>122, "3 CORINTHI"=>122, "3 CORINTHIA"=>122, "3 CORINTHIAN"=>122, "3 CORINTHIANS"=>122, "3C"=>122, "3CO"=>122, "3COR"=>122, "3CORI"=>122, "3CORIN"=>122, "3CORINT"=>122, "3CORINTH"=>122, "3CORINTHI"=>122, "3CORINTHIA"=>122, "3CORINTHIAN"=>122, "3CORINTHIANS"=>122, "CO3"=>122, "EUT"=>123, "PRAYER OF E"=>123, "PRAYER OF EU"=>123, "PRAYER OF EUT"=>123, "PRAYER OF EUTH"=>123, "PRAYER OF EUTHA"=>123, "PRAYER OF EUTHAL"=>123, "PRAYER OF EUTHALI"=>123, "PRAYER OF EUTHALIU"=>123, "PRAYER OF EUTHALIUS"=>123, "PRAYEROFE"=>123, "PRAYEROFEU"=>123, "PRAYEROFEUT"=>123, "PRAYEROFEUTH"=>123, "PRAYEROFEUTHA"=>123, "PRAYEROFEUTHAL"=>123, "PRAYEROFEUTHALI"=>123, "PRAYEROFEUTHALIU"=>123, "PRAYEROFEUTHALIUS"=>123, "PRE"=>123, "PREU"=>123, "PREUT"=>123, "PREUTH"=>123, "DO"=>124, "DOJ"=>124, "DOR"=>124, "DORM"=>124, "DORMI"=>124, "DORMIT"=>124, "DORMITI"=>124, "DORMITIO"=>124, "DORMITION"=>124, "DORMITION O"=>124, "DORMITION OF"=>124, "DORMITION OF J"=>124, "DORMITION OF JO"=>124, "DORMITION OF JOH"=>124, "DORMITION OF JOHN"=>124, "DORMITIONO"=>124, "DORMITIONOF"=>124, "DORMITIONOFJ"=>124, "DORMITIONOFJO"=>124, "DORMITIONOFJOH"=>124, "DORMITIONOFJOHN"=>124, "DORMJ"=>124, "DORMJO"=>124, "DORMJOH"=>124, "DORMJOHN"=>124, "JUB"=>125, "JUBI"=>125, "JUBIL"=>125, "JUBILE"=>125, "JUBILEE"=>125, "JUBILEES"=>125, "1 EN"=>126, "1 ENO"=>126, "1 ENOC"=>126, "1 ENOCH"=>126, "1EN"=>126, "1ENO"=>126, "1ENOC"=>126, "1ENOCH"=>126, "ENO"=>126, "ENOC"=>126, "ENOCH"=>126, "ET"=>126, "ETH"=>126, "ETHI"=>126, "ETHIO"=>126, "ETHIOP"=>126, "ETHIOPI"=>126, "ETHIOPIC"=>126, "ETHIOPIC A"=>126, "ETHIOPIC AP"=>126, "ETHIOPIC APO"=>126, "ETHIOPIC APOC"=>126, "ETHIOPIC APOCA"=>126, "ETHIOPIC APOCAL"=>126, "ETHIOPIC APOCALY"=>126, "ETHIOPIC APOCALYP"=>126, "ETHIOPIC APOCALYPS"=>126, "ETHIOPIC APOCALYPSE"=>126, "ETHIOPIC APOCALYPSE O"=>126, "ETHIOPIC APOCALYPSE OF"=>126, "ETHIOPIC APOCALYPSE OF E"=>126, "ETHIOPIC APOCALYPSE OF EN"=>126, "ETHIOPIC APOCALYPSE OF ENO"=>126, "ETHIOPIC APOCALYPSE OF ENOC"=>126, "ETHIOPIC APOCALYPSE OF ENOCH"=>126, "ETHIOPICA"=>126, "ETHIOPICAP"=>126, "ETHIOPICAPO"=>126, "ETHIOPICAPOC"=>126, "ETHIOPICAPOCA"=>126, "ETHIOPICAPOCAL"=>126, "ETHIOPICAPOCALY"=>126, "ETHIOPICAPOCALYP"=>126, "ETHIOPICAPOCALYPS"=>126, "ETHIOPICAPOCALYPSE"=>126, "ETHIOPICAPOCALYPSEO"=>126, "ETHIOPICAPOCALYPSEOF"=>126, "ETHIOPICAPOCALYPSEOFE"=>126, "ETHIOPICAPOCALYPSEOFEN"=>126, "ETHIOPICAPOCALYPSEOFENO"=>126, "ETHIOPICAPOCALYPSEOFENOC"=>126, "ETHIOPICAPOCALYPSEOFENOCH"=>126, "2 EN"=>127, "2 ENO"=>127, "2 ENOC"=>127, "2 ENOCH"=>127, "2EN"=>127, "2ENO"=>127, "2ENOC"=>127, "2ENOCH"=>127, "EN2"=>127, "REP"=>128, "REPR"=>128, "REPRO"=>128, "REPROO"=>128, "REPROOF"=>128, "TAA"=>128, "TAAG"=>128, "TAAGS"=>128, "TAAGSA"=>128, "TAAGSAS"=>128, "TEG"=>128, "TEGS"=>128, "TEGSA"=>128, "TEGSAS"=>128, "TEGSAT"=>128, "TEGSATS"=>128, "BARN"=>129, "BARNA"=>129, "BARNAB"=>129, "BARNABA"=>129, "BARNABAS"=>129, "EPISTLE OF BARN"=>129, "EPISTLE OF BARNA"=>129, "EPISTLE OF BARNAB"=>129, "EPISTLE OF BARNABA"=>129, "EPISTLE OF BARNABAS"=>129, "EPISTLEOFBARN"=>129, "EPISTLEOFBARNA"=>129, "EPISTLEOFBARNAB"=>129, "EPISTLEOFBARNABA"=>129, "EPISTLEOFBARNABAS"=>129, "LBB"=>129, "LETTER OF BARN"=>129, "LETTER OF BARNA"=>129, "LETTER OF BARNAB"=>129, "LETTER OF BARNABA"=>129, "LETTER OF BARNABAS"=>129, "LETTEROFBARN"=>129, "LETTEROFBARNA"=>129, "LETTEROFBARNAB"=>129, "LETTEROFBARNABA"=>129, "LETTEROFBARNABAS"=>129, "1 CL"=>130, "1 CLE"=>130, "1 CLEM"=>130, "1 CLEME"=>130, "1 CLEMEN"=>130, "1 CLEMENT"=>130, "1CL"=>130, "1CLE"=>130, "1CLEM"=>130, "1CLEME"=>130, "1CLEMEN"=>130, "1CLEMENT"=>130, "CL1"=>130, "2 CL"=>131, "2 CLE"=>131, "2 CLEM"=>131, "2 CLEME"=>131, "2 CLEMEN"=>131, "2 CLEMENT"=>131, "2CL"=>131, "2CLE"=>131, "2CLEM"=>131, "2CLEME"=>131, "2CLEMEN"=>131, "2CLEMENT"=>131, "CL2"=>131, "DID"=>132, "DIDA"=>132, "DIDAC"=>132, "DIDACH"=>132, "DIDACHE"=>132, "JOSHA"=>133, "JOSHUA A"=>133, "JOSHUAA"=>133, "JSA"=>133, "JOSHB"=>134, "JOSHUA B"=>134, "JOSHUAB"=>134, "JSB"=>134, "JDA"=>135, "JGA"=>135, "JUDGA"=>135, "JUDGES A"=>135, "JUDGESA"=>135, "JDB"=>136, "JGB"=>136, "JUDGB"=>136, "JUDGES B"=>136, "JUDGESB"=>136, "TBS"=>137, "TOBIT S"=>137, "TOBITS"=>137, "TOBS"=>137, "SST"=>138, "SUSANNA T"=>138, "SUSANNA TH"=>138, "SUSANNA Θ"=>138, "SUSANNAT"=>138, "SUSANNATH"=>138, "SUSANNAΘ"=>138, "SUST"=>138, "SUSTH"=>138, "SUT"=>138, "DANIEL T"=>139, "DANIEL TH"=>139, "DANIEL Θ"=>139, "DANIELT"=>139, "DANIELTH"=>139, "DANIELΘ"=>139, "DANT"=>139, "DANTH"=>139, "DAT"=>139, "DNT"=>139, "BEL AND THE DRAGON T"=>140, "BEL AND THE DRAGON TH"=>140, "BEL AND THE DRAGON Θ"=>140, "BELANDTHEDRAGONT"=>140, "BELANDTHEDRAGONTH"=>140, "BELANDTHEDRAGONΘ"=>140, "BELT"=>140, "BELTH"=>140, "BET"=>140, "BL"=>140, "BLT"=>140, "GH"=>141, "GHE"=>141, "GOSPEL OF THE H"=>141, "GOSPEL OF THE HE"=>141, "GOSPEL OF THE HEB"=>141, "GOSPEL OF THE HEBR"=>141, "GOSPEL OF THE HEBRE"=>141, "GOSPEL OF THE HEBREW"=>141, "GOSPEL OF THE HEBREWS"=>141, "GOSPELOFTHEH"=>141, "GOSPELOFTHEHE"=>141, "GOSPELOFTHEHEB"=>141, "GOSPELOFTHEHEBR"=>141, "GOSPELOFTHEHEBRE"=>141, "GOSPELOFTHEHEBREW"=>141, "GOSPELOFTHEHEBREWS"=>141, "GOSPEL OF TR"=>142, "GOSPEL OF TRU"=>142, "GOSPEL OF TRUT"=>142, "GOSPEL OF TRUTH"=>142, "GOSPELOFTR"=>142, "GOSPELOFTRU"=>142, "GOSPELOFTRUT"=>142, "GOSPELOFTRUTH"=>142, "GTR"=>142, "GMA"=>143, "GOSPEL OF MARY"=>143, "GOSPELOFMARY"=>143, "GMC"=>144, "GOSPEL OF MARC"=>144, "GOSPEL OF MARCI"=>144, "GOSPEL OF MARCIO"=>144, "GOSPEL OF MARCION"=>144, "GOSPELOFMARC"=>144, "GOSPELOFMARCI"=>144, "GOSPELOFMARCIO"=>144, "GOSPELOFMARCION"=>144, "GOSPEL OF THE T"=>145, "GOSPEL OF THE TW"=>145, "GOSPEL OF THE TWE"=>145, "GOSPEL OF THE TWEL"=>145, "GOSPEL OF THE TWELV"=>145, "GOSPEL OF THE TWELVE"=>145, "GOSPELOFTHET"=>145, "GOSPELOFTHETW"=>145, "GOSPELOFTHETWE"=>145, "GOSPELOFTHETWEL"=>145, "GOSPELOFTHETWELV"=>145, "GOSPELOFTHETWELVE"=>145, "GTW"=>145, "GOSPEL OF P"=>146, "GOSPEL OF PH"=>146, "GOSPEL OF PHI"=>146, "GOSPEL OF PHIL"=>146, "GOSPEL OF PHILI"=>146, "GOSPEL OF PHILIP"=>146, "GOSPELOFP"=>146, "GOSPELOFPH"=>146, "GOSPELOFPHI"=>146, "GOSPELOFPHIL"=>146, "GOSPELOFPHILI"=>146, "GOSPELOFPHILIP"=>146, "GP"=>146, "GPH"=>146, "GOSPEL OF THO"=>147, "GOSPEL OF THOM"=>147, "GOSPEL OF THOMA"=>147, "GOSPEL OF THOMAS"=>147, "GOSPELOFTHO"=>147, "GOSPELOFTHOM"=>147, "GOSPELOFTHOMA"=>147, "GOSPELOFTHOMAS"=>147, "GTH"=>147, "GJ"=>148, "GJU"=>148, "GOSPEL OF J"=>148, "GOSPEL OF JU"=>148, "GOSPEL OF JUD"=>148, "GOSPEL OF JUDA"=>148, "GOSPEL OF JUDAS"=>148, "GOSPELOFJ"=>148, "GOSPELOFJU"=>148, "GOSPELOFJUD"=>148, "GOSPELOFJUDA"=>148, "GOSPELOFJUDAS"=>148, "GB"=>149, "GBA"=>149, "GOSPEL OF B"=>149, "GOSPEL OF BA"=>149, "GOSPEL OF BAR"=>149, "GOSPEL OF BART"=>149, "GOSPEL OF BARTH"=>149, "GOSPEL OF BARTHO"=>149, "GOSPEL OF BARTHOL"=>149, "GOSPEL OF BARTHOLO"=>149, "GOSPEL OF BARTHOLOM"=>149, "GOSPEL OF BARTHOLOME"=>149, "GOSPEL OF BARTHOLOMEW"=>149, "GOSPELOFB"=>149, "GOSPELOFBA"=>149, "GOSPELOFBAR"=>149, "GOSPELOFBART"=>149, "GOSPELOFBARTH"=>149, "GOSPELOFBARTHO"=>149, "GOSPELOFBARTHOL"=>149, "GOSPELOFBARTHOLO"=>149, "GOSPELOFBARTHOLOM"=>149, "GOSPELOFBARTHOLOME"=>149, "GOSPELOFBARTHOLOMEW"=>149, "AJ"=>150, "AJA"=>150, "APOCRYPHA OF J"=>150, "APOCRYPHA OF JA"=>150, "APOCRYPHA OF JAM"=>150, "APOCRYPHA OF JAME"=>150, "APOCRYPHA OF JAMES"=>150, "APOCRYPHAL J"=>150, "APOCRYPHAL JA"=>150, "APOCRYPHAL JAM"=>150, "APOCRYPHAL JAME"=>150, "APOCRYPHAL JAMES"=>150, "APOCRYPHALJ"=>150, "APOCRYPHALJA"=>150, "APOCRYPHALJAM"=>150, "APOCRYPHALJAME"=>150, "APOCRYPHALJAMES"=>150, "APOCRYPHAOFJ"=>150, "APOCRYPHAOFJA"=>150, "APOCRYPHAOFJAM"=>150, "APOCRYPHAOFJAME"=>150, "APOCRYPHAOFJAMES"=>150, "WISDOM OF JESUS C"=>151, "WISDOM OF JESUS CH"=>151, "WISDOM OF JESUS CHR"=>151, "WISDOM OF JESUS CHRI"=>151, "WISDOM OF JESUS CHRIS"=>151, "WISDOM OF JESUS CHRIST"=>151, "WISDOMOFJESUSC"=>151, "WISDOMOFJESUSCH"=>151, "WISDOMOFJESUSCHR"=>151, "WISDOMOFJESUSCHRI"=>151, "WISDOMOFJESUSCHRIS"=>151, "WISDOMOFJESUSCHRIST"=>151, "WJ"=>151, "WJC"=>151, "TAD"=>152, "TESTAMENT OF AD"=>152, "TESTAMENT OF ADA"=>152, "TESTAMENT OF ADAM"=>152, "TESTAMENTOFAD"=>152, "TESTAMENTOFADA"=>152, "TESTAMENTOFADAM"=>152, "LAE"=>153, "LIF"=>153, "LIFE"=>153, "LIFE O"=>153, "LIFE OF"=>153, "LIFE OF A"=>153, "LIFE OF AD"=>153, "LIFE OF ADA"=>153, "LIFE OF ADAM"=>153, "LIFE OF ADAM A"=>153, "LIFE OF ADAM AN"=>153, "LIFE OF ADAM AND"=>153, "LIFE OF ADAM AND E"=>153, "LIFE OF ADAM AND EV"=>153, "LIFE OF ADAM AND EVE"=>153, "LIFEO"=>153, "LIFEOF"=>153, "LIFEOFA"=>153, "LIFEOFAD"=>153, "LIFEOFADA"=>153, "LIFEOFADAM"=>153, "LIFEOFADAMA"=>153, "LIFEOFADAMAN"=>153, "LIFEOFADAMAND"=>153, "LIFEOFADAMANDE"=>153, "LIFEOFADAMANDEV"=>153, "LIFEOFADAMANDEVE"=>153, "AS"=>154, "ASS"=>154, "ASSU"=>154, "ASSUM"=>154, "ASSUMP"=>154, "ASSUMPT"=>154, "ASSUMPTI"=>154, "ASSUMPTIO"=>154, "ASSUMPTION"=>154, "ASSUMPTION O"=>154, "ASSUMPTION OF"=>154, "ASSUMPTION OF M"=>154, "ASSUMPTION OF MO"=>154, "ASSUMPTION OF MOS"=>154, "ASSUMPTION OF MOSE"=>154, "ASSUMPTION OF MOSES"=>154, "ASSUMPTIONO"=>154, "ASSUMPTIONOF"=>154, "ASSUMPTIONOFM"=>154, "ASSUMPTIONOFMO"=>154, "ASSUMPTIONOFMOS"=>154, "ASSUMPTIONOFMOSE"=>154, "ASSUMPTIONOFMOSES"=>154, "TESTAMENT OF M"=>154, "TESTAMENT OF MO"=>154, "TESTAMENT OF MOS"=>154, "TESTAMENT OF MOSE"=>154, "TESTAMENT OF MOSES"=>154, "TESTAMENTOFM"=>154, "TESTAMENTOFMO"=>154, "TESTAMENTOFMOS"=>154, "TESTAMENTOFMOSE"=>154, "TESTAMENTOFMOSES"=>154, "TESTAMENT OF SO"=>155, "TESTAMENT OF SOL"=>155, "TESTAMENT OF SOLO"=>155, "TESTAMENT OF SOLOM"=>155, "TESTAMENT OF SOLOMO"=>155, "TESTAMENT OF SOLOMON"=>155, "TESTAMENTOFSO"=>155, "TESTAMENTOFSOL"=>155, "TESTAMENTOFSOLO"=>155, "TESTAMENTOFSOLOM"=>155, "TESTAMENTOFSOLOMO"=>155, "TESTAMENTOFSOLOMON"=>155, "TSO"=>155, "TESTAMENT OF JOB"=>156, "TESTAMENTOFJOB"=>156, "TJ"=>156, "TJB"=>156, "MAI"=>157, "MARTYR"=>157, "MARTYRD"=>157, "MARTYRDO"=>157, "MARTYRDOM"=>157, "MARTYRDOM A"=>157, "MARTYRDOM AN"=>157, "MARTYRDOM AND"=>157, "MARTYRDOM AND A"=>157, "MARTYRDOM AND AS"=>157, "MARTYRDOM AND ASC"=>157, "MARTYRDOM AND ASCE"=>157, "MARTYRDOM AND ASCEN"=>157, "MARTYRDOM AND ASCENS"=>157, "MARTYRDOM AND ASCENSI"=>157, "MARTYRDOM AND ASCENSIO"=>157, "MARTYRDOM AND ASCENSION"=>157, "MARTYRDOM AND ASCENSION O"=>157, "MARTYRDOM AND ASCENSION OF"=>157, "MARTYRDOM AND ASCENSION OF I"=>157, "MARTYRDOM AND ASCENSION OF IS"=>157, "MARTYRDOM AND ASCENSION OF ISA"=>157, "MARTYRDOM AND ASCENSION OF ISAI"=>157, "MARTYRDOM AND ASCENSION OF ISAIA"=>157, "MARTYRDOM AND ASCENSION OF ISAIAH"=>157, "MARTYRDOMA"=>157, "MARTYRDOMAN"=>157, "MARTYRDOMAND"=>157, "MARTYRDOMANDA"=>157, "MARTYRDOMANDAS"=>157, "MARTYRDOMANDASC"=>157, "MARTYRDOMANDASCE"=>157, "MARTYRDOMANDASCEN"=>157, "MARTYRDOMANDASCENS"=>157, "MARTYRDOMANDASCENSI"=>157, "MARTYRDOMANDASCENSIO"=>157, "MARTYRDOMANDASCENSION"=>157, "MARTYRDOMANDASCENSIONO"=>157, "MARTYRDOMANDASCENSIONOF"=>157, "MARTYRDOMANDASCENSIONOFI"=>157, "MARTYRDOMANDASCENSIONOFIS"=>157, "MARTYRDOMANDASCENSIONOFISA"=>157, "MARTYRDOMANDASCENSIONOFISAI"=>157, "MARTYRDOMANDASCENSIONOFISAIA"=>157, "MARTYRDOMANDASCENSIONOFISAIAH"=>157, "AE"=>158, "AEZ"=>158, "APOCRYPHA OF E"=>158, "APOCRYPHA OF EZ"=>158, "APOCRYPHA OF EZE"=>158, "APOCRYPHA OF EZEK"=>158, "APOCRYPHA OF EZEKI"=>158, "APOCRYPHA OF EZEKIE"=>158, "APOCRYPHA OF EZEKIEL"=>158, "APOCRYPHAOFE"=>158, "APOCRYPHAOFEZ"=>158, "APOCRYPHAOFEZE"=>158, "APOCRYPHAOFEZEK"=>158, "APOCRYPHAOFEZEKI"=>158, "APOCRYPHAOFEZEKIE"=>158, "APOCRYPHAOFEZEKIEL"=>158, "APOCRYPHO"=>158, "APOCRYPHON"=>158, "APOCRYPHON O"=>158, "APOCRYPHON OF"=>158, "APOCRYPHON OF E"=>158, "APOCRYPHON OF EZ"=>158, "APOCRYPHON OF EZE"=>158, "APOCRYPHON OF EZEK"=>158, "APOCRYPHON OF EZEKI"=>158, "APOCRYPHON OF EZEKIE"=>158, "APOCRYPHON OF EZEKIEL"=>158, "APOCRYPHONO"=>158, "APOCRYPHONOF"=>158, "APOCRYPHONOFE"=>158, "APOCRYPHONOFEZ"=>158, "APOCRYPHONOFEZE"=>158, "APOCRYPHONOFEZEK"=>158, "APOCRYPHONOFEZEKI"=>158, "APOCRYPHONOFEZEKIE"=>158, "APOCRYPHONOFEZEKIEL"=>158, "EZEKIEL T"=>159, "EZEKIEL TH"=>159, "EZEKIEL THE"=>159, "EZEKIEL THE T"=>159, "EZEKIEL THE TR"=>159, "EZEKIEL THE TRA"=>159, "EZEKIEL THE TRAG"=>159, "EZEKIEL THE TRAGE"=>159, "EZEKIEL THE TRAGED"=>159, "EZEKIEL THE TRAGEDI"=>159, "EZEKIEL THE TRAGEDIA"=>159, "EZEKIEL THE TRAGEDIAN"=>159, "EZEKIELT"=>159, "EZEKIELTH"=>159, "EZEKIELTHE"=>159, "EZEKIELTHET"=>159, "EZEKIELTHETR"=>159, "EZEKIELTHETRA"=>159, "EZEKIELTHETRAG"=>159, "EZEKIELTHETRAGE"=>159, "EZEKIELTHETRAGED"=>159, "EZEKIELTHETRAGEDI"=>159, "EZEKIELTHETRAGEDIA"=>159, "EZEKIELTHETRAGEDIAN"=>159, "EZT"=>159, "EUP"=>160, "EUPO"=>160, "EUPOL"=>160, "EUPOLE"=>160, "EUPOLEM"=>160, "EUPOLEMU"=>160, "EUPOLEMUS"=>160, "PEU"=>161, "PSEUDO O"=>161, "PSEUDO OF"=>161, "PSEUDO OF E"=>161, "PSEUDO OF EU"=>161, "PSEUDO OF EUP"=>161, "PSEUDO OF EUPO"=>161, "PSEUDO OF EUPOL"=>161, "PSEUDO OF EUPOLE"=>161, "PSEUDO OF EUPOLEM"=>161, "PSEUDO OF EUPOLEMU"=>161, "PSEUDO OF EUPOLEMUS"=>161, "PSEUDO-E"=>161, "PSEUDO-EU"=>161, "PSEUDO-EUP"=>161, "PSEUDO-EUPO"=>161, "PSEUDO-EUPOL"=>161, "PSEUDO-EUPOLE"=>161, "PSEUDO-EUPOLEM"=>161, "PSEUDO-EUPOLEMU"=>161, "PSEUDO-EUPOLEMUS"=>161, "PSEUDOO"=>161, "PSEUDOOF"=>161, "PSEUDOOFE"=>161, "PSEUDOOFEU"=>161, "PSEUDOOFEUP"=>161, "PSEUDOOFEUPO"=>161, "PSEUDOOFEUPOL"=>161, "PSEUDOOFEUPOLE"=>161, "PSEUDOOFEUPOLEM"=>161, "PSEUDOOFEUPOLEMU"=>161, "PSEUDOOFEUPOLEMUS"=>161, "VA"=>162, "VAM"=>162, "VISI"=>162, "VISIO"=>162, "VISION"=>162, "VISIONS"=>162, "VISIONS O"=>162, "VISIONS OF"=>162, "VISIONS OF A"=>162, "VISIONS OF AM"=>162, "VISIONS OF AMR"=>162, "VISIONS OF AMRA"=>162, "VISIONS OF AMRAM"=>162, "VISIONSO"=>162, "VISIONSOF"=>162, "VISIONSOFA"=>162, "VISIONSOFAM"=>162, "VISIONSOFAMR"=>162, "VISIONSOFAMRA"=>162, "VISIONSOFAMRAM"=>162, "EPISTLE OF A"=>163, "EPISTLE OF AR"=>163, "EPISTLE OF ARI"=>163, "EPISTLE OF ARIS"=>163, "EPISTLE OF ARIST"=>163, "EPISTLE OF ARISTE"=>163, "EPISTLE OF ARISTEA"=>163, "EPISTLE OF ARISTEAS"=>163, "EPISTLEOFA"=>163, "EPISTLEOFAR"=>163, "EPISTLEOFARI"=>163, "EPISTLEOFARIS"=>163, "EPISTLEOFARIST"=>163, "EPISTLEOFARISTE"=>163, "EPISTLEOFARISTEA"=>163, "EPISTLEOFARISTEAS"=>163, "LAR"=>163, "LETTER OF A"=>163, "LETTER OF AR"=>163, "LETTER OF ARI"=>163, "LETTER OF ARIS"=>163, "LETTER OF ARIST"=>163, "LETTER OF ARISTE"=>163, "LETTER OF ARISTEA"=>163, "LETTER OF ARISTEAS"=>163, "LETTEROFA"=>163, "LETTEROFAR"=>163, "LETTEROFARI"=>163, "LETTEROFARIS"=>163, "LETTEROFARIST"=>163, "LETTEROFARISTE"=>163, "LETTEROFARISTEA"=>163, "LETTEROFARISTEAS"=>163, "ARI"=>164, "ARISTE"=>164, "ARISTEA"=>164, "ARISTEAS"=>164, "ARISTEAS T"=>164, "ARISTEAS TH"=>164, "ARISTEAS THE"=>164, "ARISTEAS THE E"=>164, "ARISTEAS THE EX"=>164, "ARISTEAS THE EXE"=>164, "ARISTEAS THE EXEG"=>164, "ARISTEAS THE EXEGE"=>164, "ARISTEAS THE EXEGET"=>164, "ARISTEAS THE EXEGETE"=>164, "ARISTEAST"=>164, "ARISTEASTH"=>164, "ARISTEASTHE"=>164, "ARISTEASTHEE"=>164, "ARISTEASTHEEX"=>164, "ARISTEASTHEEXE"=>164, "ARISTEASTHEEXEG"=>164, "ARISTEASTHEEXEGE"=>164, "ARISTEASTHEEXEGET"=>164, "ARISTEASTHEEXEGETE"=>164, "AB"=>165, "ABL"=>165, "ARISTO"=>165, "ARISTOB"=>165, "ARISTOBU"=>165, "ARISTOBUL"=>165, "ARISTOBULU"=>165, "ARISTOBULUS"=>165, "ART"=>166, "ARTA"=>166, "ARTAP"=>166, "ARTAPA"=>166, "ARTAPAN"=>166, "ARTAPANU"=>166, "ARTAPANUS"=>166, "CLE"=>167, "CLEO"=>167, "CLEOD"=>167, "CLEODE"=>167, "CLEODEM"=>167, "CLEODEMU"=>167, "CLEODEMUS"=>167, "CLEODEMUS M"=>167, "CLEODEMUS MA"=>167, "CLEODEMUS MAL"=>167, "CLEODEMUS MALC"=>167, "CLEODEMUS MALCH"=>167, "CLEODEMUS MALCHU"=>167, "CLEODEMUS MALCHUS"=>167, "CLEODEMUSM"=>167, "CLEODEMUSMA"=>167, "CLEODEMUSMAL"=>167, "CLEODEMUSMALC"=>167, "CLEODEMUSMALCH"=>167, "CLEODEMUSMALCHU"=>167, "CLEODEMUSMALCHUS"=>167, "CLM"=>167, "EL"=>168, "ELD"=>168, "ELDA"=>168, "ELDAD"=>168, "ELDAD A"=>168, "ELDAD AN"=>168, "ELDAD AND"=>168, "ELDAD AND M"=>168, "ELDAD AND MO"=>168, "ELDAD AND MOD"=>168, "ELDAD AND MODA"=>168, "ELDAD AND MODAD"=>168, "ELDADA"=>168, "ELDADAN"=>168, "ELDADAND"=>168, "ELDADANDM"=>168, "ELDADANDMO"=>168, "ELDADANDMOD"=>168, "ELDADANDMODA"=>168, "ELDADANDMODAD"=>168, "ELM"=>168, "HI"=>169, "HIS"=>169, "HIST"=>169, "HISTO"=>169, "HISTOR"=>169, "HISTORY"=>169, "HISTORY O"=>169, "HISTORY OF"=>169, "HISTORY OF T"=>169, "HISTORY OF TH"=>169, "HISTORY OF THE"=>169, "HISTORY OF THE R"=>169, "HISTORY OF THE RE"=>169, "HISTORY OF THE REC"=>169, "HISTORY OF THE RECH"=>169, "HISTORY OF THE RECHA"=>169, "HISTORY OF THE RECHAB"=>169, "HISTORY OF THE RECHABI"=>169, "HISTORY OF THE RECHABIT"=>169, "HISTORY OF THE RECHABITE"=>169, "HISTORY OF THE RECHABITES"=>169, "HISTORYO"=>169, "HISTORYOF"=>169, "HISTORYOFT"=>169, "HISTORYOFTH"=>169, "HISTORYOFTHE"=>169, "HISTORYOFTHER"=>169, "HISTORYOFTHERE"=>169, "HISTORYOFTHEREC"=>169, "HISTORYOFTHERECH"=>169, "HISTORYOFTHERECHA"=>169, "HISTORYOFTHERECHAB"=>169, "HISTORYOFTHERECHABI"=>169, "HISTORYOFTHERECHABIT"=>169, "HISTORYOFTHERECHABITE"=>169, "HISTORYOFTHERECHABITES"=>169, "HR"=>169, "HRE"=>169, "LIV"=>170, "LIVE"=>170, "LIVES"=>170, "LIVES O"=>170, "LIVES OF"=>170, "LIVES OF T"=>170, "LIVES OF TH"=>170, "LIVES OF THE"=>170, "LIVES OF THE P"=>170, "LIVES OF THE PR"=>170, "LIVES OF THE PRO"=>170, "LIVES OF THE PROP"=>170, "LIVES OF THE PROPH"=>170, "LIVES OF THE PROPHE"=>170, "LIVES OF THE PROPHET"=>170, "LIVES OF THE PROPHETS"=>170, "LIVESO"=>170, "LIVESOF"=>170, "LIVESOFT"=>170, "LIVESOFTH"=>170, "LIVESOFTHE"=>170, "LIVESOFTHEP"=>170, "LIVESOFTHEPR"=>170, "LIVESOFTHEPRO"=>170, "LIVESOFTHEPROP"=>170, "LIVESOFTHEPROPH"=>170, "LIVESOFTHEPROPHE"=>170, "LIVESOFTHEPROPHET"=>170, "LIVESOFTHEPROPHETS"=>170, "LP"=>170, "LPR"=>170, "EPL"=>171, "PHILO"=>171, "PHILO T"=>171, "PHILO TH"=>171, "PHILO THE"=>171, "PHILO THE E"=>171, "PHILO THE EP"=>171, "PHILO THE EPI"=>171, "PHILO THE EPIC"=>171, "PHILO THE EPIC P"=>171, "PHILO THE EPIC PO"=>171, "PHILO THE EPIC POE"=>171, "PHILO THE EPIC POET"=>171, "PHILOT"=>171, "PHILOTH"=>171, "PHILOTHE"=>171, "PHILOTHEE"=>171, "PHILOTHEEP"=>171, "PHILOTHEEPI"=>171, "PHILOTHEEPIC"=>171, "PHILOTHEEPICP"=>171, "PHILOTHEEPICPO"=>171, "PHILOTHEEPICPOE"=>171, "PHILOTHEEPICPOET"=>171, "TEO"=>172, "THEO"=>172, "THEOD"=>172, "THEODO"=>172, "THEODOT"=>172, "THEODOTU"=>172, "THEODOTUS"=>172, "T12PATR"=>173, "TESTAMENTS"=>173, "TESTAMENTS O"=>173, "TESTAMENTS OF"=>173, "TESTAMENTS OF T"=>173, "TESTAMENTS OF TH"=>173, "TESTAMENTS OF THE"=>173, "TESTAMENTS OF THE T"=>173, "TESTAMENTS OF THE TW"=>173, "TESTAMENTS OF THE TWE"=>173, "TESTAMENTS OF THE TWEL"=>173, "TESTAMENTS OF THE TWELV"=>173, "TESTAMENTS OF THE TWELVE"=>173, "TESTAMENTS OF THE TWELVE P"=>173, "TESTAMENTS OF THE TWELVE PA"=>173, "TESTAMENTS OF THE TWELVE PAT"=>173, "TESTAMENTS OF THE TWELVE PATR"=>173, "TESTAMENTS OF THE TWELVE PATRI"=>173, "TESTAMENTS OF THE TWELVE PATRIA"=>173, "TESTAMENTS OF THE TWELVE PATRIAR"=>173, "TESTAMENTS OF THE TWELVE PATRIARC"=>173, "TESTAMENTS OF THE TWELVE PATRIARCH"=>173, "TESTAMENTS OF THE TWELVE PATRIARCHS"=>173, "TESTAMENTSO"=>173, "TESTAMENTSOF"=>173, "TESTAMENTSOFT"=>173, "TESTAMENTSOFTH"=>173, "TESTAMENTSOFTHE"=>173, "TESTAMENTSOFTHET"=>173, "TESTAMENTSOFTHETW"=>173, "TESTAMENTSOFTHETWE"=>173, "TESTAMENTSOFTHETWEL"=>173, "TESTAMENTSOFTHETWELV"=>173, "TESTAMENTSOFTHETWELVE"=>173, "TESTAMENTSOFTHETWELVEP"=>173, "TESTAMENTSOFTHETWELVEPA"=>173, "TESTAMENTSOFTHETWELVEPAT"=>173, "TESTAMENTSOFTHETWELVEPATR"=>173, "TESTAMENTSOFTHETWELVEPATRI"=>173, "TESTAMENTSOFTHETWELVEPATRIA"=>173, "TESTAMENTSOFTHETWELVEPATRIAR"=>173, "TESTAMENTSOFTHETWELVEPATRIARC"=>173, "TESTAMENTSOFTHETWELVEPATRIARCH"=>173, "TESTAMENTSOFTHETWELVEPATRIARCHS"=>173, "TTP"=>173, "T12PATR.TA"=>174, "T12PATR.TAS"=>174, "T12PATR.TASH"=>174, "TESTAMENT OF AS"=>174, "TESTAMENT OF ASH"=>174, "TESTAMENT OF ASHE"=>174, "TESTAMENT OF ASHER"=>174, "TESTAMENTOFAS"=>174, "TESTAMENTOFASH"=>174, "TESTAMENTOFASHE"=>174, "TESTAMENTOFASHER"=>174, "TSA"=>174, "T12PATR.TB"=>175, "T12PATR.TBE"=>175, "T12PATR.TBEN"=>175, "T12PATR.TBENJ"=>175, "TESTAMENT OF B"=>175, "TESTAMENT OF BE"=>175, "TESTAMENT OF BEN"=>175, "TESTAMENT OF BENJ"=>175, "TESTAMENT OF BENJA"=>175, "TESTAMENT OF BENJAM"=>175, "TESTAMENT OF BENJAMI"=>175, "TESTAMENT OF BENJAMIN"=>175, "TESTAMENTOFB"=>175, "TESTAMENTOFBE"=>175, "TESTAMENTOFBEN"=>175, "TESTAMENTOFBENJ"=>175, "TESTAMENTOFBENJA"=>175, "TESTAMENTOFBENJAM"=>175, "TESTAMENTOFBENJAMI"=>175, "TESTAMENTOFBENJAMIN"=>175, "TSB"=>175, "T12PATR.TD"=>176, "T12PATR.TDA"=>176, "T12PATR.TDAN"=>176, "TESTAMENT OF D"=>176, "TESTAMENT OF DA"=>176, "TESTAMENT OF DAN"=>176, "TESTAMENTOFD"=>176, "TESTAMENTOFDA"=>176, "TESTAMENTOFDAN"=>176, "TSD"=>176, "T12PATR.TG"=>177, "T12PATR.TGA"=>177, "T12PATR.TGAD"=>177, "TESTAMENT OF G"=>177, "TESTAMENT OF GA"=>177, "TESTAMENT OF GAD"=>177, "TESTAMENTOFG"=>177, "TESTAMENTOFGA"=>177, "TESTAMENTOFGAD"=>177, "TSG"=>177, "T12PATR.TI"=>178, "T12PATR.TIS"=>178, "T12PATR.TISS"=>178, "TESTAMENT OF I"=>178, "TESTAMENT OF IS"=>178, "TESTAMENT OF ISS"=>178, "TESTAMENT OF ISSA"=>178, "TESTAMENT OF ISSAC"=>178, "TESTAMENT OF ISSACH"=>178, "TESTAMENT OF ISSACHA"=>178, "TESTAMENT OF ISSACHAR"=>178, "TESTAMENTOFI"=>178, "TESTAMENTOFIS"=>178, "TESTAMENTOFISS"=>178, "TESTAMENTOFISSA"=>178, "TESTAMENTOFISSAC"=>178, "TESTAMENTOFISSACH"=>178, "TESTAMENTOFISSACHA"=>178, "TESTAMENTOFISSACHAR"=>178, "TSI"=>178, "T12PATR.TJO"=>179, "T12PATR.TJOS"=>179, "TESTAMENT OF JOS"=>179, "TESTAMENT OF JOSE"=>179, "TESTAMENT OF JOSEP"=>179, "TESTAMENT OF JOSEPH"=>179, "TESTAMENTOFJOS"=>179, "TESTAMENTOFJOSE"=>179, "TESTAMENTOFJOSEP"=>179, "TESTAMENTOFJOSEPH"=>179, "TSJ"=>179, "T12PATR.TJU"=>180, "T12PATR.TJUD"=>180, "TESTAMENT OF JU"=>180, "TESTAMENT OF JUD"=>180, "TESTAMENT OF JUDA"=>180, "TESTAMENT OF JUDAH"=>180, "TESTAMENTOFJU"=>180, "TESTAMENTOFJUD"=>180, "TESTAMENTOFJUDA"=>180, "TESTAMENTOFJUDAH"=>180, "TSY"=>180, "T12PATR.TL"=>181, "T12PATR.TLE"=>181, "T12PATR.TLEV"=>181, "T12PATR.TLEVI"=>181, "TESTAMENT OF L"=>181, "TESTAMENT OF LE"=>181, "TESTAMENT OF LEV"=>181, "TESTAMENT OF LEVI"=>181, "TESTAMENTOFL"=>181, "TESTAMENTOFLE"=>181, "TESTAMENTOFLEV"=>181, "TESTAMENTOFLEVI"=>181, "TSL"=>181, "T12PATR.TN"=>182, "T12PATR.TNA"=>182, "T12PATR.TNAP"=>182, "T12PATR.TNAPH"=>182, "TESTAMENT OF N"=>182, "TESTAMENT OF NA"=>182, "TESTAMENT OF NAP"=>182, "TESTAMENT OF NAPH"=>182, "TESTAMENT OF NAPHT"=>182, "TESTAMENT OF NAPHTA"=>182, "TESTAMENT OF NAPHTAL"=>182, "TESTAMENT OF NAPHTALI"=>182, "TESTAMENTOFN"=>182, "TESTAMENTOFNA"=>182, "TESTAMENTOFNAP"=>182, "TESTAMENTOFNAPH"=>182, "TESTAMENTOFNAPHT"=>182, "TESTAMENTOFNAPHTA"=>182, "TESTAMENTOFNAPHTAL"=>182, "TESTAMENTOFNAPHTALI"=>182, "TSN"=>182, "T12PATR.TR"=>183, "T12PATR.TRE"=>183, "T12PATR.TREU"=>183, "TESTAMENT OF R"=>183, "TESTAMENT OF RE"=>183, "TESTAMENT OF REU"=>183, "TESTAMENT OF REUB"=>183, "TESTAMENT OF REUBE"=>183, "TESTAMENT OF REUBEN"=>183, "TESTAMENTOFR"=>183, "TESTAMENTOFRE"=>183, "TESTAMENTOFREU"=>183, "TESTAMENTOFREUB"=>183, "TESTAMENTOFREUBE"=>183, "TESTAMENTOFREUBEN"=>183, "TSR"=>183, "T12PATR.TS"=>184, "T12PATR.TSI"=>184, "T12PATR.TSIM"=>184, "TESTAMENT OF SI"=>184, "TESTAMENT OF SIM"=>184, "TESTAMENT OF SIME"=>184, "TESTAMENT OF SIMEO"=>184, "TESTAMENT OF SIMEON"=>184, "TESTAMENTOFSI"=>184, "TESTAMENTOFSIM"=>184, "TESTAMENTOFSIME"=>184, "TESTAMENTOFSIMEO"=>184, "TESTAMENTOFSIMEON"=>184, "TSS"=>184, "T12PATR.TZ"=>185, "T12PATR.TZE"=>185, "T12PATR.TZEB"=>185, "TESTAMENT OF Z"=>185, "TESTAMENT OF ZE"=>185, "TESTAMENT OF ZEB"=>185, "TESTAMENT OF ZEBU"=>185, "TESTAMENT OF ZEBUL"=>185, "TESTAMENT OF ZEBULU"=>185, "TESTAMENT OF ZEBULUN"=>185, "TESTAMENTOFZ"=>185, "TESTAMENTOFZE"=>185, "TESTAMENTOFZEB"=>185, "TESTAMENTOFZEBU"=>185, "TESTAMENTOFZEBUL"=>185, "TESTAMENTOFZEBULU"=>185, "TESTAMENTOFZEBULUN"=>185, "TSZ"=>185, "JJ"=>186, "JJW"=>186, "JOSE"=>186, "JOSEP"=>186, "JOSEPH"=>186, "JOSEPHU"=>186, "JOSEPHUS"=>186, "JOSEPHUS J"=>186, "JOSEPHUS JE"=>186, "JOSEPHUS JEW"=>186, "JOSEPHUS JEWI"=>186, "JOSEPHUS JEWIS"=>186, "JOSEPHUS JEWISH"=>186, "JOSEPHUS JEWISH W"=>186, "JOSEPHUS JEWISH WA"=>186, "JOSEPHUS JEWISH WAR"=>186, "JOSEPHUS JEWISH WAR V"=>186, "JOSEPHUS JEWISH WAR VI"=>186, "JOSEPHUSJ"=>186, "JOSEPHUSJE"=>186, "JOSEPHUSJEW"=>186, "JOSEPHUSJEWI"=>186, "JOSEPHUSJEWIS"=>186, "JOSEPHUSJEWISH"=>186, "JOSEPHUSJEWISHW"=>186, "JOSEPHUSJEWISHWA"=>186, "JOSEPHUSJEWISHWAR"=>186, "JOSEPHUSJEWISHWARV"=>186, "JOSEPHUSJEWISHWARVI"=>186, "JOSEPHUSJW"=>186, "JOSEPHUSJWV"=>186, "JOSEPHUSJWVI"=>186, "SHE"=>187, "SHEPHERD OF HERMAS"=>187, "SHEPHERDOFHERMAS"=>187, "SHEPHERD OF HERMAS M"=>188, "SHEPHERD OF HERMAS MA"=>188, "SHEPHERD OF HERMAS MAN"=>188, "SHEPHERD OF HERMAS MAND"=>188, "SHEPHERD OF HERMAS MANDA"=>188, "SHEPHERD OF HERMAS MANDAT"=>188, "SHEPHERD OF HERMAS MANDATE"=>188, "SHEPHERD OF HERMAS MANDATES"=>188, "SHEPHERDOFHERMASM"=>188, "SHEPHERDOFHERMASMA"=>188, "SHEPHERDOFHERMASMAN"=>188, "SHEPHERDOFHERMASMAND"=>188, "SHEPHERDOFHERMASMANDA"=>188, "SHEPHERDOFHERMASMANDAT"=>188, "SHEPHERDOFHERMASMANDATE"=>188, "SHEPHERDOFHERMASMANDATES"=>188, "SHM"=>188, "SHEPHERD OF HERMAS S"=>189, "SHEPHERD OF HERMAS SI"=>189, "SHEPHERD OF HERMAS SIM"=>189, "SHEPHERD OF HERMAS SIMI"=>189, "SHEPHERD OF HERMAS SIMIL"=>189, "SHEPHERD OF HERMAS SIMILI"=>189, "SHEPHERD OF HERMAS SIMILIT"=>189, "SHEPHERD OF HERMAS SIMILITU"=>189, "SHEPHERD OF HERMAS SIMILITUD"=>189, "SHEPHERD OF HERMAS SIMILITUDE"=>189, "SHEPHERD OF HERMAS SIMILITUDES"=>189, "SHEPHERDOFHERMASS"=>189, "SHEPHERDOFHERMASSI"=>189, "SHEPHERDOFHERMASSIM"=>189, "SHEPHERDOFHERMASSIMI"=>189, "SHEPHERDOFHERMASSIMIL"=>189, "SHEPHERDOFHERMASSIMILI"=>189, "SHEPHERDOFHERMASSIMILIT"=>189, "SHEPHERDOFHERMASSIMILITU"=>189, "SHEPHERDOFHERMASSIMILITUD"=>189, "SHEPHERDOFHERMASSIMILITUDE"=>189, "SHEPHERDOFHERMASSIMILITUDES"=>189, "SHS"=>189, "SHEPHERD OF HERMAS V"=>190, "SHEPHERD OF HERMAS VI"=>190, "SHEPHERD OF HERMAS VIS"=>190, "SHEPHERD OF HERMAS VISI"=>190, "SHEPHERD OF HERMAS VISIO"=>190, "SHEPHERD OF HERMAS VISION"=>190, "SHEPHERD OF HERMAS VISIONS"=>190, "SHEPHERDOFHERMASV"=>190, "SHEPHERDOFHERMASVI"=>190, "SHEPHERDOFHERMASVIS"=>190, "SHEPHERDOFHERMASVISI"=>190, "SHEPHERDOFHERMASVISIO"=>190, "SHEPHERDOFHERMASVISION"=>190, "SHEPHERDOFHERMASVISIONS"=>190, "SHV"=>190, "IGE"=>191, "IGNATIUS TO THE E"=>191, "IGNATIUS TO THE EP"=>191, "IGNATIUS TO THE EPH"=>191, "IGNATIUS TO THE EPHE"=>191, "IGNATIUS TO THE EPHES"=>191, "IGNATIUS TO THE EPHESI"=>191, "IGNATIUS TO THE EPHESIA"=>191, "IGNATIUS TO THE EPHESIAN"=>191, "IGNATIUS TO THE EPHESIANS"=>191, "IGNATIUSTOTHEE"=>191, "IGNATIUSTOTHEEP"=>191, "IGNATIUSTOTHEEPH"=>191, "IGNATIUSTOTHEEPHE"=>191, "IGNATIUSTOTHEEPHES"=>191, "IGNATIUSTOTHEEPHESI"=>191, "IGNATIUSTOTHEEPHESIA"=>191, "IGNATIUSTOTHEEPHESIAN"=>191, "IGNATIUSTOTHEEPHESIANS"=>191, "IGNE"=>191, "IGNEP"=>191, "IGNEPH"=>191, "IGM"=>192, "IGNATIUS TO THE M"=>192, "IGNATIUS TO THE MA"=>192, "IGNATIUS TO THE MAG"=>192, "IGNATIUS TO THE MAGN"=>192, "IGNATIUS TO THE MAGNE"=>192, "IGNATIUS TO THE MAGNES"=>192, "IGNATIUS TO THE MAGNESI"=>192, "IGNATIUS TO THE MAGNESIA"=>192, "IGNATIUS TO THE MAGNESIAN"=>192, "IGNATIUS TO THE MAGNESIANS"=>192, "IGNATIUSTOTHEM"=>192, "IGNATIUSTOTHEMA"=>192, "IGNATIUSTOTHEMAG"=>192, "IGNATIUSTOTHEMAGN"=>192, "IGNATIUSTOTHEMAGNE"=>192, "IGNATIUSTOTHEMAGNES"=>192, "IGNATIUSTOTHEMAGNESI"=>192, "IGNATIUSTOTHEMAGNESIA"=>192, "IGNATIUSTOTHEMAGNESIAN"=>192, "IGNATIUSTOTHEMAGNESIANS"=>192, "IGNM"=>192, "IGNMA"=>192, "IGNMAG"=>192, "IGNMAGN"=>192, "IGNATIUS TO THE T"=>193, "IGNATIUS TO THE TR"=>193, "IGNATIUS TO THE TRA"=>193, "IGNATIUS TO THE TRAL"=>193, "IGNATIUS TO THE TRALL"=>193, "IGNATIUS TO THE TRALLI"=>193, "IGNATIUS TO THE TRALLIA"=>193, "IGNATIUS TO THE TRALLIAN"=>193, "IGNATIUS TO THE TRALLIANS"=>193, "IGNATIUSTOTHET"=>193, "IGNATIUSTOTHETR"=>193, "IGNATIUSTOTHETRA"=>193, "IGNATIUSTOTHETRAL"=>193, "IGNATIUSTOTHETRALL"=>193, "IGNATIUSTOTHETRALLI"=>193, "IGNATIUSTOTHETRALLIA"=>193, "IGNATIUSTOTHETRALLIAN"=>193, "IGNATIUSTOTHETRALLIANS"=>193, "IGNT"=>193, "IGNTR"=>193, "IGNTRA"=>193, "IGNTRAL"=>193, "IGNTRALL"=>193, "IGT"=>193, "IGNATIUS TO THE R"=>194, "IGNATIUS TO THE RO"=>194, "IGNATIUS TO THE ROM"=>194, "IGNATIUS TO THE ROMA"=>194, "IGNATIUS TO THE ROMAN"=>194, "IGNATIUS TO THE ROMANS"=>194, "IGNATIUSTOTHER"=>194, "IGNATIUSTOTHERO"=>194, "IGNATIUSTOTHEROM"=>194, "IGNATIUSTOTHEROMA"=>194, "IGNATIUSTOTHEROMAN"=>194, "IGNATIUSTOTHEROMANS"=>194, "IGNR"=>194, "IGNRO"=>194, "IGNROM"=>194, "IGR"=>194, "IGNATIUS TO THE P"=>195, "IGNATIUS TO THE PH"=>195, "IGNATIUS TO THE PHI"=>195, "IGNATIUS TO THE PHIL"=>195, "IGNATIUS TO THE PHILA"=>195, "IGNATIUS TO THE PHILAD"=>195, "IGNATIUS TO THE PHILADE"=>195, "IGNATIUS TO THE PHILADEL"=>195, "IGNATIUS TO THE PHILADELP"=>195, "IGNATIUS TO THE PHILADELPH"=>195, "IGNATIUS TO THE PHILADELPHI"=>195, "IGNATIUS TO THE PHILADELPHIA"=>195, "IGNATIUS TO THE PHILADELPHIAN"=>195, "IGNATIUS TO THE PHILADELPHIANS"=>195, "IGNATIUSTOTHEP"=>195, "IGNATIUSTOTHEPH"=>195, "IGNATIUSTOTHEPHI"=>195, "IGNATIUSTOTHEPHIL"=>195, "IGNATIUSTOTHEPHILA"=>195, "IGNATIUSTOTHEPHILAD"=>195, "IGNATIUSTOTHEPHILADE"=>195, "IGNATIUSTOTHEPHILADEL"=>195, "IGNATIUSTOTHEPHILADELP"=>195, "IGNATIUSTOTHEPHILADELPH"=>195, "IGNATIUSTOTHEPHILADELPHI"=>195, "IGNATIUSTOTHEPHILADELPHIA"=>195, "IGNATIUSTOTHEPHILADELPHIAN"=>195, "IGNATIUSTOTHEPHILADELPHIANS"=>195, "IGNPH"=>195, "IGNPHL"=>195, "IGNPHLD"=>195, "IGP"=>195, "IGNATIUS TO THE S"=>196, "IGNATIUS TO THE SM"=>196, "IGNATIUS TO THE SMY"=>196, "IGNATIUS TO THE SMYR"=>196, "IGNATIUS TO THE SMYRN"=>196, "IGNATIUS TO THE SMYRNA"=>196, "IGNATIUS TO THE SMYRNAE"=>196, "IGNATIUS TO THE SMYRNAEA"=>196, "IGNATIUS TO THE SMYRNAEAN"=>196, "IGNATIUS TO THE SMYRNAEANS"=>196, "IGNATIUSTOTHES"=>196, "IGNATIUSTOTHESM"=>196, "IGNATIUSTOTHESMY"=>196, "IGNATIUSTOTHESMYR"=>196, "IGNATIUSTOTHESMYRN"=>196, "IGNATIUSTOTHESMYRNA"=>196, "IGNATIUSTOTHESMYRNAE"=>196, "IGNATIUSTOTHESMYRNAEA"=>196, "IGNATIUSTOTHESMYRNAEAN"=>196, "IGNATIUSTOTHESMYRNAEANS"=>196, "IGNS"=>196, "IGNSM"=>196, "IGNSMY"=>196, "IGNSMYR"=>196, "IGNSMYRN"=>196, "IGS"=>196, "IGC"=>197, "IGNATIUS TO P"=>197, "IGNATIUS TO PO"=>197, "IGNATIUS TO POL"=>197, "IGNATIUS TO POLY"=>197, "IGNATIUS TO POLYC"=>197, "IGNATIUS TO POLYCA"=>197, "IGNATIUS TO POLYCAR"=>197, "IGNATIUS TO POLYCARP"=>197, "IGNATIUSTOP"=>197, "IGNATIUSTOPO"=>197, "IGNATIUSTOPOL"=>197, "IGNATIUSTOPOLY"=>197, "IGNATIUSTOPOLYC"=>197, "IGNATIUSTOPOLYCA"=>197, "IGNATIUSTOPOLYCAR"=>197, "IGNATIUSTOPOLYCARP"=>197, "IGNPO"=>197, "IGNPOL"=>197, "PC"=>198, "PCP"=>198, "PO"=>198, "POL"=>198, "POLP"=>198, "POLPH"=>198, "POLPHI"=>198, "POLPHIL"=>198, "POLY"=>198, "POLYC"=>198, "POLYCA"=>198, "POLYCAR"=>198, "POLYCARP"=>198, "POLYCARP T"=>198, "POLYCARP TO"=>198, "POLYCARP TO T"=>198, "POLYCARP TO TH"=>198, "POLYCARP TO THE"=>198, "POLYCARP TO THE P"=>198, "POLYCARP TO THE PH"=>198, "POLYCARP TO THE PHI"=>198, "POLYCARP TO THE PHIL"=>198, "POLYCARP TO THE PHILI"=>198, "POLYCARP TO THE PHILIP"=>198, "POLYCARP TO THE PHILIPP"=>198, "POLYCARP TO THE PHILIPPI"=>198, "POLYCARP TO THE PHILIPPIA"=>198, "POLYCARP TO THE PHILIPPIAN"=>198, "POLYCARP TO THE PHILIPPIANS"=>198, "POLYCARPT"=>198, "POLYCARPTO"=>198, "POLYCARPTOT"=>198, "POLYCARPTOTH"=>198, "POLYCARPTOTHE"=>198, "POLYCARPTOTHEP"=>198, "POLYCARPTOTHEPH"=>198, "POLYCARPTOTHEPHI"=>198, "POLYCARPTOTHEPHIL"=>198, "POLYCARPTOTHEPHILI"=>198, "POLYCARPTOTHEPHILIP"=>198, "POLYCARPTOTHEPHILIPP"=>198, "POLYCARPTOTHEPHILIPPI"=>198, "POLYCARPTOTHEPHILIPPIA"=>198, "POLYCARPTOTHEPHILIPPIAN"=>198, "POLYCARPTOTHEPHILIPPIANS"=>198, "MARTP"=>199, "MARTPO"=>199, "MARTPOL"=>199, "MARTYD"=>199, "MARTYDO"=>199, "MARTYDOM"=>199, "MARTYDOM O"=>199, "MARTYDOM OF"=>199, "MARTYDOM OF P"=>199, "MARTYDOM OF PO"=>199, "MARTYDOM OF POL"=>199, "MARTYDOM OF POLY"=>199, "MARTYDOM OF POLYC"=>199, "MARTYDOM OF POLYCA"=>199, "MARTYDOM OF POLYCAR"=>199, "MARTYDOM OF POLYCARP"=>199, "MARTYDOMO"=>199, "MARTYDOMOF"=>199, "MARTYDOMOFP"=>199, "MARTYDOMOFPO"=>199, "MARTYDOMOFPOL"=>199, "MARTYDOMOFPOLY"=>199, "MARTYDOMOFPOLYC"=>199, "MARTYDOMOFPOLYCA"=>199, "MARTYDOMOFPOLYCAR"=>199, "MARTYDOMOFPOLYCARP"=>199, "MP"=>199, "MPC"=>199, "DIO"=>200, "DIOG"=>200, "DIOGN"=>200, "DIOGNE"=>200, "DIOGNET"=>200, "DIOGNETU"=>200, "DIOGNETUS"=>200, "APC"=>201, "APOSC"=>201, "APOSCR"=>201, "APOSCRE"=>201, "APOSCREE"=>201, "APOSCREED"=>201, "APOSTL"=>201, "APOSTLE"=>201, "APOSTLES"=>201, "APOSTLES'"=>201, "APOSTLES' C"=>201, "APOSTLES' CR"=>201, "APOSTLES' CRE"=>201, "APOSTLES' CREE"=>201, "APOSTLES' CREED"=>201, "APOSTLES'C"=>201, "APOSTLES'CR"=>201, "APOSTLES'CRE"=>201, "APOSTLES'CREE"=>201, "APOSTLES'CREED"=>201, "FOP"=>202, "FRAGMENTS"=>202, "FRAGMENTS O"=>202, "FRAGMENTS OF"=>202, "FRAGMENTS OF P"=>202, "FRAGMENTS OF PA"=>202, "FRAGMENTS OF PAP"=>202, "FRAGMENTS OF PAPI"=>202, "FRAGMENTS OF PAPIA"=>202, "FRAGMENTS OF PAPIAS"=>202, "FRAGMENTSO"=>202, "FRAGMENTSOF"=>202, "FRAGMENTSOFP"=>202, "FRAGMENTSOFPA"=>202, "FRAGMENTSOFPAP"=>202, "FRAGMENTSOFPAPI"=>202, "FRAGMENTSOFPAPIA"=>202, "FRAGMENTSOFPAPIAS"=>202, "PAP"=>202, "PAPF"=>202, "PAPFR"=>202, "PAPFRA"=>202, "PAPFRAG"=>202, "REL"=>203, "RELE"=>203, "RELEL"=>203, "RELELD"=>203, "RELELDE"=>203, "RELELDER"=>203, "RELELDERS"=>203, "RELI"=>203, "RELIQ"=>203, "RELIQU"=>203, "RELIQUE"=>203, "RELIQUES"=>203, "RELIQUES O"=>203, "RELIQUES OF"=>203, "RELIQUES OF T"=>203, "RELIQUES OF TH"=>203, "RELIQUES OF THE"=>203, "RELIQUES OF THE E"=>203, "RELIQUES OF THE EL"=>203, "RELIQUES OF THE ELD"=>203, "RELIQUES OF THE ELDE"=>203, "RELIQUES OF THE ELDER"=>203, "RELIQUES OF THE ELDERS"=>203, "RELIQUESO"=>203, "RELIQUESOF"=>203, "RELIQUESOFT"=>203, "RELIQUESOFTH"=>203, "RELIQUESOFTHE"=>203, "RELIQUESOFTHEE"=>203, "RELIQUESOFTHEEL"=>203, "RELIQUESOFTHEELD"=>203, "RELIQUESOFTHEELDE"=>203, "RELIQUESOFTHEELDER"=>203, "RELIQUESOFTHEELDERS"=>203, "ROE"=>203, "FOQ"=>204, "FRAGMENT O"=>204, "FRAGMENT OF"=>204, "FRAGMENT OF Q"=>204, "FRAGMENT OF QU"=>204, "FRAGMENT OF QUA"=>204, "FRAGMENT OF QUAD"=>204, "FRAGMENT OF QUADR"=>204, "FRAGMENT OF QUADRA"=>204, "FRAGMENT OF QUADRAT"=>204, "FRAGMENT OF QUADRATU"=>204, "FRAGMENT OF QUADRATUS"=>204, "FRAGMENTO"=>204, "FRAGMENTOF"=>204, "FRAGMENTOFQ"=>204, "FRAGMENTOFQU"=>204, "FRAGMENTOFQUA"=>204, "FRAGMENTOFQUAD"=>204, "FRAGMENTOFQUADR"=>204, "FRAGMENTOFQUADRA"=>204, "FRAGMENTOFQUADRAT"=>204, "FRAGMENTOFQUADRATU"=>204, "FRAGMENTOFQUADRATUS"=>204, "QU"=>204, "QUA"=>204, "QUAD"=>204, "QUADF"=>204, "QUADFR"=>204, "QUADFRA"=>204, "QUADFRAG"=>204, "DIA"=>205, "DIAT"=>205, "DIATE"=>205, "DIATES"=>205, "DIATESS"=>205, "DIATESSA"=>205, "DIATESSAR"=>205, "DIATESSARO"=>205, "DIATESSARON"=>205, "TAT"=>205, "TATD"=>205, "TATDI"=>205, "TATDIA"=>205, "TATDIAT"=>205, "TATI"=>205, "TATIA"=>205, "TATIAN"=>205, "TATIAN'"=>205, "TATIAN'S"=>205, "TATIAN'S D"=>205, "TATIAN'S DI"=>205, "TATIAN'S DIA"=>205, "TATIAN'S DIAT"=>205, "TATIAN'S DIATE"=>205, "TATIAN'S DIATES"=>205, "TATIAN'S DIATESS"=>205, "TATIAN'S DIATESSA"=>205, "TATIAN'S DIATESSAR"=>205, "TATIAN'S DIATESSARO"=>205, "TATIAN'S DIATESSARON"=>205, "TATIAN'SD"=>205, "TATIAN'SDI"=>205, "TATIAN'SDIA"=>205, "TATIAN'SDIAT"=>205, "TATIAN'SDIATE"=>205, "TATIAN'SDIATES"=>205, "TATIAN'SDIATESS"=>205, "TATIAN'SDIATESSA"=>205, "TATIAN'SDIATESSAR"=>205, "TATIAN'SDIATESSARO"=>205, "TATIAN'SDIATESSARON"=>205, "P4"=>206, "P4D"=>206, "PLE"=>206, "PLEA"=>206, "PLEA F"=>206, "PLEA FO"=>206, "PLEA FOR"=>206, "PLEA FOR D"=>206, "PLEA FOR DE"=>206, "PLEA FOR DEL"=>206, "PLEA FOR DELI"=>206, "PLEA FOR DELIV"=>206, "PLEA FOR DELIVE"=>206, "PLEA FOR DELIVER"=>206, "PLEA FOR DELIVERA"=>206, "PLEA FOR DELIVERAN"=>206, "PLEA FOR DELIVERANC"=>206, "PLEA FOR DELIVERANCE"=>206, "PLEAF"=>206, "PLEAFO"=>206, "PLEAFOR"=>206, "PLEAFORD"=>206, "PLEAFORDE"=>206, "PLEAFORDEL"=>206, "PLEAFORDELI"=>206, "PLEAFORDELIV"=>206, "PLEAFORDELIVE"=>206, "PLEAFORDELIVER"=>206, "PLEAFORDELIVERA"=>206, "PLEAFORDELIVERAN"=>206, "PLEAFORDELIVERANC"=>206, "PLEAFORDELIVERANCE"=>206, "A2Z"=>207, "APOSTROPHE TO Z"=>207, "APOSTROPHE TO ZI"=>207, "APOSTROPHE TO ZIO"=>207, "APOSTROPHE TO ZION"=>207, "APOSTROPHETOZ"=>207, "APOSTROPHETOZI"=>207, "APOSTROPHETOZIO"=>207, "APOSTROPHETOZION"=>207, "EH"=>208, "EHY"=>208, "ESCH"=>208, "ESCHA"=>208, "ESCHAT"=>208, "ESCHATA"=>208, "ESCHATAL"=>208, "ESCHATALO"=>208, "ESCHATALOG"=>208, "ESCHATALOGI"=>208, "ESCHATALOGIC"=>208, "ESCHATALOGICA"=>208, "ESCHATALOGICAL"=>208, "ESCHATALOGICAL H"=>208, "ESCHATALOGICAL HY"=>208, "ESCHATALOGICAL HYM"=>208, "ESCHATALOGICAL HYMN"=>208, "ESCHATALOGICALH"=>208, "ESCHATALOGICALHY"=>208, "ESCHATALOGICALHYM"=>208, "ESCHATALOGICALHYMN"=>208, "A2J"=>209, "APOSTROPHE TO J"=>209, "APOSTROPHE TO JU"=>209, "APOSTROPHE TO JUD"=>209, "APOSTROPHE TO JUDA"=>209, "APOSTROPHE TO JUDAH"=>209, "APOSTROPHETOJ"=>209, "APOSTROPHETOJU"=>209, "APOSTROPHETOJUD"=>209, "APOSTROPHETOJUDA"=>209, "APOSTROPHETOJUDAH"=>209, "H2"=>210, "H2C"=>210, "HYM"=>210, "HYMN"=>210, "HYMN T"=>210, "HYMN TO"=>210, "HYMN TO T"=>210, "HYMN TO TH"=>210, "HYMN TO THE"=>210, "HYMN TO THE C"=>210, "HYMN TO THE CR"=>210, "HYMN TO THE CRE"=>210, "HYMN TO THE CREA"=>210, "HYMN TO THE CREAT"=>210, "HYMN TO THE CREATO"=>210, "HYMN TO THE CREATOR"=>210, "HYMNT"=>210, "HYMNTO"=>210, "HYMNTOT"=>210, "HYMNTOTH"=>210, "HYMNTOTHE"=>210, "HYMNTOTHEC"=>210, "HYMNTOTHECR"=>210, "HYMNTOTHECRE"=>210, "HYMNTOTHECREA"=>210, "HYMNTOTHECREAT"=>210, "HYMNTOTHECREATO"=>210, "HYMNTOTHECREATOR"=>210, "CM"=>211, "CMP"=>211, "DAV"=>211, "DAVI"=>211, "DAVID"=>211, "DAVID'"=>211, "DAVID'S"=>211, "DAVID'S C"=>211, "DAVID'S CO"=>211, "DAVID'S COM"=>211, "DAVID'S COMP"=>211, "DAVID'S COMPO"=>211, "DAVID'S COMPOS"=>211, "DAVID'S COMPOSI"=>211, "DAVID'S COMPOSIT"=>211, "DAVID'S COMPOSITI"=>211, "DAVID'S COMPOSITIO"=>211, "DAVID'S COMPOSITION"=>211, "DAVID'S COMPOSITIONS"=>211, "DAVID'SC"=>211, "DAVID'SCO"=>211, "DAVID'SCOM"=>211, "DAVID'SCOMP"=>211, "DAVID'SCOMPO"=>211, "DAVID'SCOMPOS"=>211, "DAVID'SCOMPOSI"=>211, "DAVID'SCOMPOSIT"=>211, "DAVID'SCOMPOSITI"=>211, "DAVID'SCOMPOSITIO"=>211, "DAVID'SCOMPOSITION"=>211, "DAVID'SCOMPOSITIONS"=>211, "APOCRYPHAL P"=>212, "APOCRYPHAL PS"=>212, "APOCRYPHAL PSA"=>212, "APOCRYPHAL PSAL"=>212, "APOCRYPHAL PSALM"=>212, "APOCRYPHAL PSALMS"=>212, "APOCRYPHALP"=>212, "APOCRYPHALPS"=>212, "APOCRYPHALPSA"=>212, "APOCRYPHALPSAL"=>212, "APOCRYPHALPSALM"=>212, "APOCRYPHALPSALMS"=>212, "APS"=>212, "CAT"=>213, "CATE"=>213, "CATEN"=>213, "CATENA"=>213, "P1A"=>214, "PSALM 151A"=>214, "PSALM151A"=>214, "P1B"=>215, "PSALM 151B"=>215, "PSALM151B"=>215, "BOOK OF THE PE"=>216, "BOOK OF THE PEN"=>216, "BOOK OF THE PENT"=>216, "BOOK OF THE PENTA"=>216, "BOOK OF THE PENTAT"=>216, "BOOK OF THE PENTATE"=>216, "BOOK OF THE PENTATEU"=>216, "BOOK OF THE PENTATEUC"=>216, "BOOK OF THE PENTATEUCH"=>216, "BOOKOFTHEPE"=>216, "BOOKOFTHEPEN"=>216, "BOOKOFTHEPENT"=>216, "BOOKOFTHEPENTA"=>216, "BOOKOFTHEPENTAT"=>216, "BOOKOFTHEPENTATE"=>216, "BOOKOFTHEPENTATEU"=>216, "BOOKOFTHEPENTATEUC"=>216, "BOOKOFTHEPENTATEUCH"=>216, "BOOKS"=>216, "BOOKS O"=>216, "BOOKS OF"=>216, "BOOKS OF M"=>216, "BOOKS OF MO"=>216, "BOOKS OF MOS"=>216, "BOOKS OF MOSE"=>216, "BOOKS OF MOSES"=>216, "BOOKSO"=>216, "BOOKSOF"=>216, "BOOKSOFM"=>216, "BOOKSOFMO"=>216, "BOOKSOFMOS"=>216, "BOOKSOFMOSE"=>216, "BOOKSOFMOSES"=>216, "MO"=>216, "MOS"=>216, "PEN"=>216, "PENT"=>216, "PENTA"=>216, "PENTAT"=>216, "PENTATE"=>216, "PENTATEU"=>216, "PENTATEUC"=>216, "PENTATEUCH"=>216, "TOR"=>216, "TORA"=>216, "TORAH"=>216, "SAM"=>217, "SAMUEL"=>217, "KG"=>218, "KGS"=>218, "KI"=>218, "KIN"=>218, "KING"=>218, "KINGS"=>218, "SAMUEL-"=>219, "SAMUEL-K"=>219, "SAMUEL-KI"=>219, "SAMUEL-KIN"=>219, "SAMUEL-KING"=>219, "SAMUEL-KINGS"=>219, "SMK"=>219, "CHR"=>220, "CHRO"=>220, "CHRON"=>220, "CHRONI"=>220, "CHRONIC"=>220, "CHRONICL"=>220, "CHRONICLE"=>220, "CHRONICLES"=>220, "EZN"=>221, "EZRA-"=>221, "EZRA-N"=>221, "EZRA-NE"=>221, "EZRA-NEH"=>221, "EZRA-NEHE"=>221, "EZRA-NEHEM"=>221, "EZRA-NEHEMI"=>221, "EZRA-NEHEMIA"=>221, "EZRA-NEHEMIAH"=>221, "P12"=>222, "TW"=>222, "TWE"=>222, "TWEL"=>222, "TWELV"=>222, "TWELVE"=>222, "TWELVE P"=>222, "TWELVE PR"=>222, "TWELVE PRO"=>222, "TWELVE PROP"=>222, "TWELVE PROPH"=>222, "TWELVE PROPHE"=>222, "TWELVE PROPHET"=>222, "TWELVE PROPHETS"=>222, "TWELVEP"=>222, "TWELVEPR"=>222, "TWELVEPRO"=>222, "TWELVEPROP"=>222, "TWELVEPROPH"=>222, "TWELVEPROPHE"=>222, "TWELVEPROPHET"=>222, "TWELVEPROPHETS"=>222, "BOOK OF W"=>223, "BOOK OF WO"=>223, "BOOK OF WOM"=>223, "BOOK OF WOME"=>223, "BOOK OF WOMEN"=>223, "BOOKOFW"=>223, "BOOKOFWO"=>223, "BOOKOFWOM"=>223, "BOOKOFWOME"=>223, "BOOKOFWOMEN"=>223, "WOM"=>223, "BOOK OF S"=>224, "BOOK OF SE"=>224, "BOOK OF SES"=>224, "BOOK OF SESS"=>224, "BOOK OF SESSI"=>224, "BOOK OF SESSIO"=>224, "BOOK OF SESSION"=>224, "BOOK OF SESSIONS"=>224, "BOOKOFS"=>224, "BOOKOFSE"=>224, "BOOKOFSES"=>224, "BOOKOFSESS"=>224, "BOOKOFSESSI"=>224, "BOOKOFSESSIO"=>224, "BOOKOFSESSION"=>224, "BOOKOFSESSIONS"=>224, "SES"=>224, "BOOK OF THE PR"=>225, "BOOK OF THE PRO"=>225, "BOOK OF THE PROP"=>225, "BOOK OF THE PROPH"=>225, "BOOK OF THE PROPHE"=>225, "BOOK OF THE PROPHET"=>225, "BOOK OF THE PROPHETS"=>225, "BOOKOFTHEPR"=>225, "BOOKOFTHEPRO"=>225, "BOOKOFTHEPROP"=>225, "BOOKOFTHEPROPH"=>225, "BOOKOFTHEPROPHE"=>225, "BOOKOFTHEPROPHET"=>225, "BOOKOFTHEPROPHETS"=>225, "SPR"=>225, "BOOK OF THE M"=>226, "BOOK OF THE MA"=>226, "BOOK OF THE MAC"=>226, "BOOK OF THE MACC"=>226, "BOOK OF THE MACCA"=>226, "BOOK OF THE MACCAB"=>226, "BOOK OF THE MACCABE"=>226, "BOOK OF THE MACCABEE"=>226, "BOOK OF THE MACCABEES"=>226, "BOOKOFTHEM"=>226, "BOOKOFTHEMA"=>226, "BOOKOFTHEMAC"=>226, "BOOKOFTHEMACC"=>226, "BOOKOFTHEMACCA"=>226, "BOOKOFTHEMACCAB"=>226, "BOOKOFTHEMACCABE"=>226, "BOOKOFTHEMACCABEE"=>226, "BOOKOFTHEMACCABEES"=>226, "SMC"=>226, "LKA"=>227, "LUKE-"=>227, "LUKE-A"=>227, "LUKE-AC"=>227, "LUKE-ACT"=>227, "LUKE-ACTS"=>227, "FRT"=>228, "GENERIC F"=>228, "GENERIC FR"=>228, "GENERIC FRO"=>228, "GENERIC FRON"=>228, "GENERIC FRONT"=>228, "GENERIC FRONT M"=>228, "GENERIC FRONT MA"=>228, "GENERIC FRONT MAT"=>228, "GENERIC FRONT MATT"=>228, "GENERIC FRONT MATTE"=>228, "GENERIC FRONT MATTER"=>228, "GENERICF"=>228, "GENERICFR"=>228, "GENERICFRO"=>228, "GENERICFRON"=>228, "GENERICFRONT"=>228, "GENERICFRONTM"=>228, "GENERICFRONTMA"=>228, "GENERICFRONTMAT"=>228, "GENERICFRONTMATT"=>228, "GENERICFRONTMATTE"=>228, "GENERICFRONTMATTER"=>228, "PREF"=>229, "PREFA"=>229, "PREFAC"=>229, "PREFACE"=>229, "PRF"=>229, "ACK"=>230, "ACKN"=>230, "ACKNO"=>230, "ACKNOW"=>230, "ACKNOWL"=>230, "ACKNOWLE"=>230, "ACKNOWLED"=>230, "ACKNOWLEDG"=>230, "ACKNOWLEDGE"=>230, "ACKNOWLEDGEM"=>230, "ACKNOWLEDGEME"=>230, "ACKNOWLEDGEMEN"=>230, "ACKNOWLEDGEMENT"=>230, "ACKNOWLEDGEMENTS"=>230, "IN"=>231, "INT"=>231, "INTR"=>231, "INTRO"=>231, "INTROD"=>231, "INTRODU"=>231, "INTRODUC"=>231, "INTRODUCT"=>231, "INTRODUCTI"=>231, "INTRODUCTIO"=>231, "INTRODUCTION"=>231, "TAB"=>232, "TABL"=>232, "TABLE"=>232, "TABLE O"=>232, "TABLE OF"=>232, "TABLE OF C"=>232, "TABLE OF CO"=>232, "TABLE OF CON"=>232, "TABLE OF CONT"=>232, "TABLE OF CONTE"=>232, "TABLE OF CONTEN"=>232, "TABLE OF CONTENT"=>232, "TABLE OF CONTENTS"=>232, "TABLEO"=>232, "TABLEOF"=>232, "TABLEOFC"=>232, "TABLEOFCO"=>232, "TABLEOFCON"=>232, "TABLEOFCONT"=>232, "TABLEOFCONTE"=>232, "TABLEOFCONTEN"=>232, "TABLEOFCONTENT"=>232, "TABLEOFCONTENTS"=>232, "TOC"=>232, "GLO"=>233, "GLOS"=>233, "GLOSS"=>233, "GLOSSA"=>233, "GLOSSAR"=>233, "GLOSSARY"=>233, "GLS"=>233, "CNC"=>234, "CON"=>234, "CONC"=>234, "CONCO"=>234, "CONCOR"=>234, "CONCORD"=>234, "CONCORDA"=>234, "CONCORDAN"=>234, "CONCORDANC"=>234, "CONCORDANCE"=>234, "TOP"=>235, "TOPI"=>235, "TOPIC"=>235, "TOPICA"=>235, "TOPICAL"=>235, "TOPICAL C"=>235, "TOPICAL CO"=>235, "TOPICAL CON"=>235, "TOPICAL CONC"=>235, "TOPICAL CONCO"=>235, "TOPICAL CONCOR"=>235, "TOPICAL CONCORD"=>235, "TOPICAL CONCORDA"=>235, "TOPICAL CONCORDAN"=>235, "TOPICAL CONCORDANC"=>235, "TOPICAL CONCORDANCE"=>235, "TOPICALC"=>235, "TOPICALCO"=>235, "TOPICALCON"=>235, "TOPICALCONC"=>235, "TOPICALCONCO"=>235, "TOPICALCONCOR"=>235, "TOPICALCONCORD"=>235, "TOPICALCONCORDA"=>235, "TOPICALCONCORDAN"=>235, "TOPICALCONCORDANC"=>235, "TOPICALCONCORDANCE"=>235, "TP"=>235, "TPC"=>235, "IXN"=>236, "NAME"=>236, "NAMES"=>236, "NAMES I"=>236, "NAMES IN"=>236, "NAMES IND"=>236, "NAMES INDE"=>236, "NAMES INDEX"=>236, "NAMESI"=>236, "NAMESIN"=>236, "NAMESIND"=>236, "NAMESINDE"=>236, "NAMESINDEX"=>236, "ND"=>236, "NDX"=>236, "IXP"=>237, "PLA"=>237, "PLAC"=>237, "PLACE"=>237, "PLACES"=>237, "PLACES I"=>237, "PLACES IN"=>237, "PLACES IND"=>237, "PLACES INDE"=>237, "PLACES INDEX"=>237, "PLACESI"=>237, "PLACESIN"=>237, "PLACESIND"=>237, "PLACESINDE"=>237, "PLACESINDEX"=>237, "MAP"=>238, "MAPS"=>238, "IXT"=>239, "TD"=>239, "TDX"=>239, "THEM"=>239, "THEMA"=>239, "THEMAT"=>239, "THEMATI"=>239, "THEMATIC"=>239, "THEMATIC I"=>239, "THEMATIC IN"=>239, "THEMATIC IND"=>239, "THEMATIC INDE"=>239, "THEMATIC INDEX"=>239, "THEMATICI"=>239, "THEMATICIN"=>239, "THEMATICIND"=>239, "THEMATICINDE"=>239, "THEMATICINDEX"=>239, "BAK"=>240, "GENERIC B"=>240, "GENERIC BA"=>240, "GENERIC BAC"=>240, "GENERIC BACK"=>240, "GENERIC BACK M"=>240, "GENERIC BACK MA"=>240, "GENERIC BACK MAT"=>240, "GENERIC BACK MATT"=>240, "GENERIC BACK MATTE"=>240, "GENERIC BACK MATTER"=>240, "GENERICB"=>240, "GENERICBA"=>240, "GENERICBAC"=>240, "GENERICBACK"=>240, "GENERICBACKM"=>240, "GENERICBACKMA"=>240, "GENERICBACKMAT"=>240, "GENERICBACKMATT"=>240, "GENERICBACKMATTE"=>240, "GENERICBACKMATTER"=>240, "OT"=>241, "OTH"=>241, "UNSPECIFIED O"=>241, "UNSPECIFIED OT"=>241, "UNSPECIFIED OTH"=>241, "UNSPECIFIED OTHE"=>241, "UNSPECIFIED OTHER"=>241, "UNSPECIFIED OTHER O"=>241, "UNSPECIFIED OTHER OT"=>241, "UNSPECIFIED OTHER OTH"=>241, "UNSPECIFIEDO"=>241, "UNSPECIFIEDOT"=>241, "UNSPECIFIEDOTH"=>241, "UNSPECIFIEDOTHE"=>241, "UNSPECIFIEDOTHER"=>241, "UNSPECIFIEDOTHERO"=>241, "UNSPECIFIEDOTHEROT"=>241, "UNSPECIFIEDOTHEROTH"=>241, "SPE"=>242, "SPEL"=>242, "SPELL"=>242, "SPELLI"=>242, "SPELLIN"=>242, "SPELLING"=>242, "SPELLING D"=>242, "SPELLING DI"=>242, "SPELLING DIC"=>242, "SPELLING DICT"=>242, "SPELLING DICTI"=>242, "SPELLING DICTIO"=>242, "SPELLING DICTION"=>242, "SPELLING DICTIONA"=>242, "SPELLING DICTIONAR"=>242, "SPELLING DICTIONARY"=>242, "SPELLINGD"=>242, "SPELLINGDI"=>242, "SPELLINGDIC"=>242, "SPELLINGDICT"=>242, "SPELLINGDICTI"=>242, "SPELLINGDICTIO"=>242, "SPELLINGDICTION"=>242, "SPELLINGDICTIONA"=>242, "SPELLINGDICTIONAR"=>242, "SPELLINGDICTIONARY"=>242, "WORD L"=>242, "WORD LI"=>242, "WORD LIS"=>242, "WORD LIST"=>242, "WORDL"=>242, "WORDLI"=>242, "WORDLIS"=>242, "WORDLIST"=>242, "WR"=>242, "WRD"=>242, "WD"=>243, "WDF"=>243, "WORD D"=>243, "WORD DE"=>243, "WORD DEF"=>243, "WORD DEFI"=>243, "WORD DEFIN"=>243, "WORD DEFINI"=>243, "WORD DEFINIT"=>243, "WORD DEFINITI"=>243, "WORD DEFINITIO"=>243, "WORD DEFINITION"=>243, "WORD DEFINITIONS"=>243, "WORD DEFINITIONS D"=>243, "WORD DEFINITIONS DI"=>243, "WORD DEFINITIONS DIC"=>243, "WORD DEFINITIONS DICT"=>243, "WORD DEFINITIONS DICTI"=>243, "WORD DEFINITIONS DICTIO"=>243, "WORD DEFINITIONS DICTION"=>243, "WORD DEFINITIONS DICTIONA"=>243, "WORD DEFINITIONS DICTIONAR"=>243, "WORD DEFINITIONS DICTIONARY"=>243, "WORDD"=>243, "WORDDE"=>243, "WORDDEF"=>243, "WORDDEFI"=>243, "WORDDEFIN"=>243, "WORDDEFINI"=>243, "WORDDEFINIT"=>243, "WORDDEFINITI"=>243, "WORDDEFINITIO"=>243, "WORDDEFINITION"=>243, "WORDDEFINITIONS"=>243, "WORDDEFINITIONSD"=>243, "WORDDEFINITIONSDI"=>243, "WORDDEFINITIONSDIC"=>243, "WORDDEFINITIONSDICT"=>243, "WORDDEFINITIONSDICTI"=>243, "WORDDEFINITIONSDICTIO"=>243, "WORDDEFINITIONSDICTION"=>243, "WORDDEFINITIONSDICTIONA"=>243, "WORDDEFINITIONSDICTIONAR"=>243, "WORDDEFINITIONSDICTIONARY"=>243, "HYP"=>244, "HYPH"=>244, "HYPHE"=>244, "HYPHEN"=>244, "HYPHENA"=>244, "HYPHENAT"=>244, "HYPHENATI"=>244, "HYPHENATIO"=>244, "HYPHENATION"=>244, "HYPHENATION D"=>244, "HYPHENATION DI"=>244, "HYPHENATION DIC"=>244, "HYPHENATION DICT"=>244, "HYPHENATION DICTI"=>244, "HYPHENATION DICTIO"=>244, "HYPHENATION DICTION"=>244, "HYPHENATION DICTIONA"=>244, "HYPHENATION DICTIONAR"=>244, "HYPHENATION DICTIONARY"=>244, "HYPHENATIOND"=>244, "HYPHENATIONDI"=>244, "HYPHENATIONDIC"=>244, "HYPHENATIONDICT"=>244, "HYPHENATIONDICTI"=>244, "HYPHENATIONDICTIO"=>244, "HYPHENATIONDICTION"=>244, "HYPHENATIONDICTIONA"=>244, "HYPHENATIONDICTIONAR"=>244, "HYPHENATIONDICTIONARY"=>244, "ST"=>245, "STR"=>245, "STRN"=>245, "STRNG"=>245, "STRNGS"=>245, "STRO"=>245, "STRON"=>245, "STRONG"=>245, "STRONG'"=>245, "STRONG'S"=>245, "STRONG'S D"=>245, "STRONG'S DA"=>245, "STRONG'S DAT"=>245, "STRONG'S DATA"=>245, "STRONG'S DATAB"=>245, "STRONG'S DATABA"=>245, "STRONG'S DATABAS"=>245, "STRONG'S DATABASE"=>245, "STRONG'SD"=>245, "STRONG'SDA"=>245, "STRONG'SDAT"=>245, "STRONG'SDATA"=>245, "STRONG'SDATAB"=>245, "STRONG'SDATABA"=>245, "STRONG'SDATABAS"=>245, "STRONG'SDATABASE"=>245, "STRONGS"=>245, "STRONGS D"=>245, "STRONGS DA"=>245, "STRONGS DAT"=>245, "STRONGS DATA"=>245, "STRONGS DATAB"=>245, "STRONGS DATABA"=>245, "STRONGS DATABAS"=>245, "STRONGS DATABASE"=>245, "STRONGSD"=>245, "STRONGSDA"=>245, "STRONGSDAT"=>245, "STRONGSDATA"=>245, "STRONGSDATAB"=>245, "STRONGSDATABA"=>245, "STRONGSDATABAS"=>245, "STRONGSDATABASE"=>245, "SEM"=>246, "SEMA"=>246, "SEMAN"=>246, "SEMANT"=>246, "SEMANTI"=>246, "SEMANTIC"=>246, "SEMANTIC D"=>246, "SEMANTIC DA"=>246, "SEMANTIC DAT"=>246, "SEMANTIC DATA"=>246, "SEMANTIC DATAB"=>246, "SEMANTIC DATABA"=>246, "SEMANTIC DATABAS"=>246, "SEMANTIC DATABASE"=>246, "SEMANTICD"=>246, "SEMANTICDA"=>246, "SEMANTICDAT"=>246, "SEMANTICDATA"=>246, "SEMANTICDATAB"=>246, "SEMANTICDATABA"=>246, "SEMANTICDATABAS"=>246, "SEMANTICDATABASE"=>246, "UNSPECIFIED EXTRA XXA"=>247, "UNSPECIFIEDEXTRAXXA"=>247, "XXA"=>247, "UNSPECIFIED EXTRA XXB"=>248, "UNSPECIFIEDEXTRAXXB"=>248, "XXB"=>248, "UNSPECIFIED EXTRA XXC"=>249, "UNSPECIFIEDEXTRAXXC"=>249, "XXC"=>249, "UNSPECIFIED EXTRA XXD"=>250, "UNSPECIFIEDEXTRAXXD"=>250, "XXD"=>250, "UNSPECIFIED EXTRA XXE"=>251, "UNSPECIFIEDEXTRAXXE"=>251, "XXE"=>251, "UNSPECIFIED EXTRA XXF"=>252, "UNSPECIFIEDEXTRAXXF"=>252, "XXF"=>252, "UNSPECIFIED EXTRA XXG"=>253, "UNSPECIFIEDEXTRAXXG"=>253, "XXG"=>253, "OBS"=>254, "OP"=>254, "OPE"=>254, "OPEN"=>254, "OPEN B"=>254, "OPEN BI"=>254, "OPEN BIB"=>254, "OPEN BIBL"=>254, "OPEN BIBLE"=>254, "OPEN BIBLE S"=>254, "OPEN BIBLE ST"=>254, "OPEN BIBLE STO"=>254, "OPEN BIBLE STOR"=>254, "OPEN BIBLE STORI"=>254, "OPEN BIBLE STORIE"=>254, "OPEN BIBLE STORIES"=>254, "OPENB"=>254, "OPENBI"=>254, "OPENBIB"=>254, "OPENBIBL"=>254, "OPENBIBLE"=>254, "OPENBIBLES"=>254, "OPENBIBLEST"=>254, "OPENBIBLESTO"=>254, "OPENBIBLESTOR"=>254, "OPENBIBLESTORI"=>254, "OPENBIBLESTORIE"=>254, "OPENBIBLESTORIES"=>254, "TEST"=>255, "TST"=>255, "UNK"=>256, "UNKN"=>256, "UNKNO"=>256, "UNKNOW"=>256, "UNKNOWN"=>256 };
 
+/// A BibleOrgSys (BOS) three-character book code (e.g., "CO1") that's known to be in our table.
+/// Ordering is by BOS_sequence_number, i.e., the usual canonical order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BookCode(&'static str);
 
+impl BookCode {
+    pub fn new(bos_book_code: &str) -> Option<BookCode> {
+        let array_index = *REFERENCE_ABBREVIATION_MAP.get(bos_book_code)?;
+        Some(BookCode(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code))
+    }
 
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
 
-#[derive(Debug, PartialEq)]
-pub enum LookupError<'a> {
-    AbbrevNotFound(&'a str, &'a str),
-    // ValueIsNone(String),
+    /// Returns the full table entry for this book.
+    #[inline]
+    pub fn entry(self) -> &'static BibleBooksCodesArrayEntry<'static> {
+        &BIBLE_BOOKS_CODES_ARRAY[REFERENCE_ABBREVIATION_MAP[self.0]]
+    }
 }
 
-impl<'a> fmt::Display for LookupError<'a> {
+impl fmt::Display for BookCode {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Ord for BookCode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entry().BOS_sequence_number.cmp(&other.entry().BOS_sequence_number)
+    }
+}
+
+impl PartialOrd for BookCode {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+/// The books codes systems (i.e., abbreviation columns) in our table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CodeSystem {
+    Bos,
+    Short,
+    Sbl,
+    Osis,
+    Sword,
+    Usfm,
+    Unbound,
+    Logos,
+    NetBible,
+    DrupalBible,
+    BibleWorks,
+    Byzantine,
+    /// The two-character book numbers used in Paratext filenames, e.g., "47" for CO1
+    UsfmNumber,
+    /// The three-digit book numbers used by USX, e.g., "046" for CO1
+    UsxNumber,
+    /// English book names and their abbreviations, e.g., "1 Corinthians" or "1 Cor" for CO1
+    EnglishName,
+    /// Hebrew or Greek book names, e.g., "Πρὸς Κορινθίους α" for CO1
+    OriginalLanguageName,
+}
+
+impl CodeSystem {
+    pub fn name(self) -> &'static str {
         match self {
-            LookupError::AbbrevNotFound(t,k) => write!(f, "{} abbreviation '{}' not found", t,k),
-            // LookupError::ValueIsNone(k) => write!(f, "Key '{}' found but value is None", k),
+            CodeSystem::Bos => "BOS",
+            CodeSystem::Short => "Short",
+            CodeSystem::Sbl => "SBL",
+            CodeSystem::Osis => "OSIS",
+            CodeSystem::Sword => "Sword",
+            CodeSystem::Usfm => "USFM",
+            CodeSystem::Unbound => "Unbound",
+            CodeSystem::Logos => "Logos",
+            CodeSystem::NetBible => "NETBible",
+            CodeSystem::DrupalBible => "DrupalBible",
+            CodeSystem::BibleWorks => "BibleWorks",
+            CodeSystem::Byzantine => "Byzantine",
+            CodeSystem::UsfmNumber => "USFM number",
+            CodeSystem::UsxNumber => "USX number",
+            CodeSystem::EnglishName => "English name",
+            CodeSystem::OriginalLanguageName => "original language name",
+        }
+    }
+
+    /// Returns the book's abbreviation in this system (if it has one).
+    pub fn abbreviation(self, book: BookCode) -> Option<&'static str> {
+        let entry = book.entry();
+        match self {
+            CodeSystem::Bos => Some(entry.BOS_book_code),
+            CodeSystem::Short => entry.short_abbreviation,
+            CodeSystem::Sbl => entry.SBL_abbreviation,
+            CodeSystem::Osis => entry.OSIS_abbreviation,
+            CodeSystem::Sword => entry.Sword_abbreviation,
+            CodeSystem::Usfm => entry.USFM_abbreviation,
+            CodeSystem::Unbound => entry.Unbound_Code,
+            CodeSystem::Logos => entry.Logos_abbreviation,
+            CodeSystem::NetBible => entry.NET_Bible_abbreviation,
+            CodeSystem::DrupalBible => entry.Drupal_Bible_abbreviation,
+            CodeSystem::BibleWorks => entry.Bible_Works_abbreviation,
+            CodeSystem::Byzantine => entry.Byzantine_abbreviation,
+            CodeSystem::UsfmNumber => entry.USFM_number_str,
+            CodeSystem::UsxNumber => entry.USX_number_str,
+            CodeSystem::EnglishName => Some(entry.book_name_English_guide),
+            CodeSystem::OriginalLanguageName => Some(entry.original_language_book_name),
+        }
+    }
+
+    /// Converts the BOS book code to this system, e.g., CodeSystem::Osis.convert("CO1") gives "1Cor".
+    pub fn convert(self, bos_book_code: &str) -> Conversion<'_> {
+        let book = BookCode::new(bos_book_code)
+            .ok_or_else(|| LookupError::not_found(CodeSystem::Bos, bos_book_code, REFERENCE_ABBREVIATION_MAP.keys().copied()))?;
+        self.abbreviation(book).ok_or_else(|| LookupError::no_equivalent(self, bos_book_code))
+    }
+
+    /// Finds the book with this abbreviation in the system (also accepting uppercase USFM abbreviations).
+    /// Unlike usfm_abbrev_to_bos_book_code() (which gives the usual book),
+    ///     a code which is shared by several books (e.g., OSIS "Ezra" for EZR and EZN) is Ambiguous.
+    pub fn lookup(self, code: &str) -> Conversion<'_, BookCode> {
+        let books: Vec<BookCode> = BIBLE_BOOKS_CODES_ARRAY
+            .iter()
+            .map(|entry| BookCode(entry.BOS_book_code))
+            .filter(|&book| self.abbreviation(book)
+                .is_some_and(|abbreviation| abbreviation == code || (self == CodeSystem::Usfm && abbreviation.to_uppercase() == code)))
+            .collect();
+        match books[..] {
+            [book] => Ok(book),
+            [] => Err(LookupError::not_found(self, code, BIBLE_BOOKS_CODES_ARRAY.iter().filter_map(|entry| self.abbreviation(BookCode(entry.BOS_book_code))))),
+            _ => Err(LookupError::Ambiguous {
+                system: self,
+                code: code.into(),
+                bos_book_codes: books.iter().map(|book| book.as_str().to_string()).collect(),
+            }),
         }
     }
 }
 
-impl Error for LookupError<'_> {}
+impl fmt::Display for CodeSystem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
 
 
 #[inline]
@@ -8081,22 +262,18 @@ pub fn is_valid_bos_book_code(bos_book_code: &str) -> bool {
     REFERENCE_ABBREVIATION_MAP.contains_key(bos_book_code)
 }
 
+/// Gives Err(LookupError::NoEquivalent) for books without a USFM abbreviation, e.g., SAM.
 #[inline]
-pub fn bos_book_code_to_usfm_abbrev<'a>(
+pub fn bos_book_code_to_usfm_abbrev(
     bos_book_code: &str,
-) -> Result<Option<&'static str>, LookupError<'_>> {
-    let array_index = *REFERENCE_ABBREVIATION_MAP.get(bos_book_code)
-        .ok_or_else(|| LookupError::AbbrevNotFound("Reference", bos_book_code))?;
-
-    Ok(BIBLE_BOOKS_CODES_ARRAY[array_index].USFM_abbreviation)
-        // .as_ref()
-        // .ok_or_else(|| Box::new(LookupError::ValueIsNone(bos_book_code.to_string())) as Box<dyn Error>)?)
+) -> Conversion<'_> {
+    CodeSystem::Usfm.convert(bos_book_code)
 }
 
 #[inline]
 pub fn usfm_abbrev_to_bos_book_code<'a>(
     usfm_abbreviation: &'a str,
-) -> Result<&'static str, LookupError<'a>> {
+) -> Conversion<'a> {
     // println!("usfm_abbrev_to_bos_book_code for {}", &usfm_abbreviation);
     // let USFM_ABBREVIATION_MAP: HashMap<&str, usize> = hash_map!{ "NEG"=>1,"OXE"=>2,"VEL"=>3,};
     // println!("The unmutable hash map is {:?}", USFM_ABBREVIATION_MAP);
@@ -8119,25 +296,34 @@ pub fn usfm_abbrev_to_bos_book_code<'a>(
     } else if let Some(&array_index) = UPPERCASE_USFM_ABBREVIATION_MAP.get(usfm_abbreviation) {
         Ok(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
     } else {
-        Err(LookupError::AbbrevNotFound("USFM", usfm_abbreviation))
+        Err(LookupError::not_found(CodeSystem::Usfm, usfm_abbreviation, USFM_ABBREVIATION_MAP.keys().copied()))
     }
 }
 
 #[inline]
 pub fn osis_book_code_to_bos_book_code<'a>(
     osis_book_code: &'a str,
-) -> Result<&'static str, LookupError<'a>> {
+) -> Conversion<'a> {
     if let Some(&array_index) = OSIS_ABBREVIATION_MAP.get(osis_book_code) {
         Ok(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
     } else {
-        Err(LookupError::AbbrevNotFound("OSIS", osis_book_code))
+        Err(LookupError::not_found(CodeSystem::Osis, osis_book_code, OSIS_ABBREVIATION_MAP.keys().copied()))
     }
 }
 
-pub fn english_name_to_bos_book_code(english_name: &str,) -> Option<&'static str> {
+/// Tries to find the book from its English name or an abbreviation of it, e.g., "1 Corinthians" or "1 Cor" or "I.Cor".
+pub fn english_name_to_bos_book_code(english_name: &str) -> Conversion<'_> {
+    let array_index = find_english_name(english_name, |name| ENGLISH_NAME_MAP.get(name).copied())
+        .ok_or_else(|| LookupError::not_found(CodeSystem::EnglishName, english_name, ENGLISH_NAME_MAP.keys().copied()))?;
+    Ok(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
+}
+
+/// Looks up the uppercased English name,
+///     then tries again with any leading "1." or "I " or "II." (etc.) changed to a plain number.
+pub(crate) fn find_english_name<T>(english_name: &str, lookup: impl Fn(&str) -> Option<T>) -> Option<T> {
     let adj_english_name = english_name.to_uppercase();
-    if let Some(&array_index) = ENGLISH_NAME_MAP.get(&adj_english_name) {
-        return Some(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
+    if let Some(found) = lookup(&adj_english_name) {
+        return Some(found)
     }
 
     let pairs = [
@@ -8150,16 +336,55 @@ pub fn english_name_to_bos_book_code(english_name: &str,) -> Option<&'static str
     ];
 
     for (s1, s2) in pairs {
-        if adj_english_name.starts_with(s1) {
-            if let Some(&array_index) = ENGLISH_NAME_MAP.get(&format!("{}{}", s2, &adj_english_name[s1.len()..])) {
-                return Some(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
-            }
+        if let Some(rest) = adj_english_name.strip_prefix(s1)
+            && let Some(found) = lookup(&format!("{}{}", s2, rest)) {
+            return Some(found)
         }
     }
 
     None
 }
 
+/// Removes Hebrew vowel points and cantillation marks and Greek accents and breathings
+///     (i.e., all combining marks after Unicode canonical decomposition),
+///     then lowercases (folding final sigma) and tidies the spaces.
+/// NOTE: This must match normalise_original_language_name() in build.rs
+pub fn normalise_original_language_name(name: &str) -> String {
+    let unpointed_name: String = name.nfd().filter(|&c| !is_combining_mark(c)).collect();
+    unpointed_name
+        .to_lowercase()
+        .replace('ς', "σ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Tries to find the book from its original language (Hebrew or Greek) name,
+///     e.g., "בְּרֵאשִׁית" or "בראשית" or "Κατὰ Μαθθαῖον" or "ΚΑΤΑ ΜΑΘΘΑΙΟΝ".
+pub fn original_language_name_to_bos_book_code(original_language_name: &str) -> Conversion<'_> {
+    let array_index = *ORIGINAL_LANGUAGE_NAME_MAP.get(&normalise_original_language_name(original_language_name)).ok_or_else(|| {
+        LookupError::not_found(CodeSystem::OriginalLanguageName, original_language_name, ORIGINAL_LANGUAGE_NAME_MAP.keys().copied())
+    })?;
+    Ok(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
+}
+
+/// Returns how the book's chapters relate to each other,
+///     or None if the book isn't expected to have chapters.
+#[inline]
+pub fn chapter_structure(bos_book_code: &str) -> Result<Option<ChapterStructure>, LookupError<'_>> {
+    let array_index = *REFERENCE_ABBREVIATION_MAP.get(bos_book_code)
+        .ok_or_else(|| LookupError::not_found(CodeSystem::Bos, bos_book_code, REFERENCE_ABBREVIATION_MAP.keys().copied()))?;
+    Ok(BIBLE_BOOKS_CODES_ARRAY[array_index].chapter_structure)
+}
+
+/// Returns true if the storyline of the book continues through the chapters,
+///     i.e., the chapter divisions are artificial (so paragraphs may continue across them).
+/// Returns false for books like Psalms and Lamentations where the chapters are actual units.
+#[inline]
+pub fn continues_through_chapters(bos_book_code: &str) -> Result<bool, LookupError<'_>> {
+    Ok(chapter_structure(bos_book_code)? != Some(ChapterStructure::Units))
+}
+
 // pub fn usfm_num_to_usfm_abbreviation(usfm_num_str: &str) -> Result<String, Box<dyn Error>> {
 //     println!("usfm_num_to_usfm_bbb for {:?}", usfm_num_str);
 //     if !&self.USFMNumberDict.contains_key(usfm_num_str) {
@@ -8203,16 +428,80 @@ mod tests {
         assert!(BIBLE_BOOKS_CODES_ARRAY.len() > USFM_ABBREVIATION_MAP.len());
     }
 
+    /// build.rs reads the copies in sourceXML/ (so that the published crate builds),
+    ///     so they must be kept the same as the repository's ../../sourceXML files
+    ///     (which aren't there when testing the packaged crate)
+    #[test]
+    fn vendored_source_xml_matches_repository() {
+        for filename in ["BibleBooksCodes.xml", "BibleBooksCodes.rnc"] {
+            let Ok(repository_contents) = std::fs::read_to_string(format!("../../sourceXML/{filename}")) else {
+                continue;
+            };
+            let vendored_contents = std::fs::read_to_string(format!("sourceXML/{filename}")).unwrap();
+            assert!(
+                vendored_contents == repository_contents,
+                "sourceXML/{filename} differs from ../../sourceXML/{filename}: copy it over (cp ../../sourceXML/{filename} sourceXML/)"
+            );
+        }
+    }
+
+    /// Empty XML elements should give empty lists (not a list with an empty string)
+    #[test]
+    fn test_no_empty_list_values() {
+        assert!(BIBLE_BOOKS_CODES_ARRAY.iter().all(|entry| !entry.possible_alternative_abbreviations.contains(&"")));
+        assert!(BIBLE_BOOKS_CODES_ARRAY.iter().all(|entry| !entry.possible_alternative_books_codes.contains(&"")));
+        assert!(BIBLE_BOOKS_CODES_ARRAY.iter().any(|entry| entry.possible_alternative_books_codes.len() > 1));
+    }
+
+    #[test]
+    fn test_book_code() {
+        assert_eq!(BookCode::new("CO1").map(BookCode::as_str), Some("CO1"));
+        assert_eq!(BookCode::new("Co1"), None);
+        assert_eq!(BookCode::new("CO1").unwrap().entry().OSIS_abbreviation, Some("1Cor"));
+        assert!(BookCode::new("MAL").unwrap() < BookCode::new("MAT").unwrap());
+        assert!(BookCode::new("REV").unwrap() > BookCode::new("GEN").unwrap());
+    }
+
+    #[test]
+    fn test_code_system_abbreviation() {
+        let co1 = BookCode::new("CO1").unwrap();
+        assert_eq!(CodeSystem::Bos.abbreviation(co1), Some("CO1"));
+        assert_eq!(CodeSystem::Osis.abbreviation(co1), Some("1Cor"));
+        assert_eq!(CodeSystem::Sbl.abbreviation(co1), Some("1 Cor"));
+        assert_eq!(CodeSystem::Usfm.abbreviation(BookCode::new("SAM").unwrap()), None);
+        assert_eq!(CodeSystem::UsfmNumber.abbreviation(co1), Some("47"));
+    }
+
+    #[test]
+    fn test_code_system_convert() {
+        assert_eq!(CodeSystem::Osis.convert("CO1"), Ok("1Cor"));
+        assert_eq!(CodeSystem::Osis.convert("SAM"), Err(LookupError::no_equivalent(CodeSystem::Osis, "SAM")));
+        assert!(matches!(CodeSystem::Osis.convert("XyZ"), Err(LookupError::Unknown { system: CodeSystem::Bos, .. })));
+    }
+
+    #[test]
+    fn test_code_system_lookup() {
+        assert_eq!(CodeSystem::Sbl.lookup("1 Cor").map(BookCode::as_str), Ok("CO1"));
+        assert_eq!(CodeSystem::Usfm.lookup("1CO").map(BookCode::as_str), Ok("CO1"));
+        assert_eq!(CodeSystem::UsxNumber.lookup("046").map(BookCode::as_str), Ok("CO1"));
+        assert_eq!(
+            CodeSystem::Osis.lookup("Ezra"),
+            Err(LookupError::Ambiguous { system: CodeSystem::Osis, code: "Ezra".into(), bos_book_codes: vec!["EZR".to_string(), "EZN".to_string()] })
+        );
+        assert!(matches!(CodeSystem::Sbl.lookup("1 Corr"), Err(LookupError::Unknown { suggestions, .. }) if suggestions[0] == "1 Cor"));
+        assert!(matches!(CodeSystem::UsfmNumber.lookup("470"), Err(LookupError::Malformed { .. })));
+    }
+
     #[test]
     fn test_is_valid_bos_book_code() {
-        assert_eq!(is_valid_bos_book_code("SAM"), true);
-        assert_eq!(is_valid_bos_book_code("SIM"), false);
+        assert!(is_valid_bos_book_code("SAM"));
+        assert!(!is_valid_bos_book_code("SIM"));
     }
 
     #[test]
     fn test_bos_book_code_to_usfm_abbrev() {
-        assert_eq!(bos_book_code_to_usfm_abbrev("EXO"), Ok(Some("Exo")));
-        assert_eq!(bos_book_code_to_usfm_abbrev("CH1"), Ok(Some("1Ch")));
+        assert_eq!(bos_book_code_to_usfm_abbrev("EXO"), Ok("Exo"));
+        assert_eq!(bos_book_code_to_usfm_abbrev("CH1"), Ok("1Ch"));
         println!(
             "    bos_book_code_to_usfm_abbrev for 'SAM' got {:?}",
             bos_book_code_to_usfm_abbrev("SAM")
@@ -8221,9 +510,10 @@ mod tests {
             "    bos_book_code_to_usfm_abbrev for 'XyZ' got {:?}",
             bos_book_code_to_usfm_abbrev("XyZ")
         );
-        assert_eq!(bos_book_code_to_usfm_abbrev("SAM"), Ok(None));
-        assert!(matches!(bos_book_code_to_usfm_abbrev("XyZ"), Err(LookupError::AbbrevNotFound("Reference",ref key)) if *key == "XyZ"));
-        assert!(matches!(bos_book_code_to_usfm_abbrev("XyZ"), Err(LookupError::AbbrevNotFound("Reference","XyZ"))));
+        assert_eq!(bos_book_code_to_usfm_abbrev("SAM"), Err(LookupError::no_equivalent(CodeSystem::Usfm, "SAM")));
+        assert!(matches!(bos_book_code_to_usfm_abbrev("XyZ"), Err(LookupError::Unknown { system: CodeSystem::Bos, code, .. }) if code == "XyZ"));
+        assert!(matches!(bos_book_code_to_usfm_abbrev("Gen"), Err(LookupError::Unknown { suggestions, .. }) if suggestions[0] == "GEN"));
+        assert!(matches!(bos_book_code_to_usfm_abbrev("Genesis"), Err(LookupError::Malformed { system: CodeSystem::Bos, .. })));
     }
 
     #[test]
@@ -8233,28 +523,66 @@ mod tests {
         assert_eq!(usfm_abbrev_to_bos_book_code("MAT"), Ok("MAT"));
         assert_eq!(usfm_abbrev_to_bos_book_code("1PE"), Ok("PE1"));
         assert!(usfm_abbrev_to_bos_book_code("XyZ").is_err());
-        assert!(matches!(usfm_abbrev_to_bos_book_code("XyZ"), Err(LookupError::AbbrevNotFound("USFM","XyZ"))));
+        assert!(matches!(usfm_abbrev_to_bos_book_code("XyZ"), Err(LookupError::Unknown { system: CodeSystem::Usfm, code, .. }) if code == "XyZ"));
+        assert_eq!(usfm_abbrev_to_bos_book_code("1co").unwrap_err().to_string(), "USFM abbreviation '1co' not found (did you mean '1Co', '1Ch' or '1Cl'?)");
     }
 
     #[test]
     fn test_osis_to_bos_book_code() {
         assert_eq!(osis_book_code_to_bos_book_code("Exod"), Ok("EXO"));
         assert!(osis_book_code_to_bos_book_code("XyZ").is_err());
-        assert!(matches!(osis_book_code_to_bos_book_code("XyZ"), Err(LookupError::AbbrevNotFound("OSIS","XyZ"))));
+        assert!(matches!(osis_book_code_to_bos_book_code("XyZ"), Err(LookupError::Unknown { system: CodeSystem::Osis, code, .. }) if code == "XyZ"));
+        assert!(matches!(osis_book_code_to_bos_book_code("Exodus"), Err(LookupError::Unknown { suggestions, .. }) if suggestions == ["Exod"]));
+        assert!(matches!(osis_book_code_to_bos_book_code(""), Err(LookupError::Malformed { system: CodeSystem::Osis, .. })));
     }
 
     #[test]
     fn test_english_name_to_bos_book_code() {
-        assert_eq!(english_name_to_bos_book_code("Exodus"), Some("EXO"));
-        assert_eq!(english_name_to_bos_book_code("Esther"), Some("EST"));
-        assert_eq!(english_name_to_bos_book_code("Ester"), Some("EST"));
-        assert_eq!(english_name_to_bos_book_code("Eccle"), Some("ECC"));
-        assert_eq!(english_name_to_bos_book_code("1 Cor"), Some("CO1"));
-        assert_eq!(english_name_to_bos_book_code("1 Co"), Some("CO1"));
-        assert_eq!(english_name_to_bos_book_code("1Cor"), Some("CO1"));
-        assert_eq!(english_name_to_bos_book_code("1Co"), Some("CO1"));
-        assert_eq!(english_name_to_bos_book_code("1.Cor"), Some("CO1"));
-        assert_eq!(english_name_to_bos_book_code("1.Co"), Some("CO1"));
-        assert_eq!(english_name_to_bos_book_code("XyZ"), None);
+        assert_eq!(english_name_to_bos_book_code("Exodus"), Ok("EXO"));
+        assert_eq!(english_name_to_bos_book_code("Esther"), Ok("EST"));
+        assert_eq!(english_name_to_bos_book_code("Ester"), Ok("EST"));
+        assert_eq!(english_name_to_bos_book_code("Eccle"), Ok("ECC"));
+        assert_eq!(english_name_to_bos_book_code("1 Cor"), Ok("CO1"));
+        assert_eq!(english_name_to_bos_book_code("1 Co"), Ok("CO1"));
+        assert_eq!(english_name_to_bos_book_code("1Cor"), Ok("CO1"));
+        assert_eq!(english_name_to_bos_book_code("1Co"), Ok("CO1"));
+        assert_eq!(english_name_to_bos_book_code("1.Cor"), Ok("CO1"));
+        assert_eq!(english_name_to_bos_book_code("1.Co"), Ok("CO1"));
+        assert!(matches!(english_name_to_bos_book_code("XyZ"), Err(LookupError::Unknown { system: CodeSystem::EnglishName, .. })));
+        assert_eq!(
+            english_name_to_bos_book_code("Genisis").unwrap_err().to_string(),
+            "English name 'Genisis' not found (did you mean 'GENESIS' or 'GENESI'?)"
+        );
+    }
+
+    #[test]
+    fn test_original_language_name_to_bos_book_code() {
+        assert_eq!(original_language_name_to_bos_book_code("בְּרֵאשִׁית"), Ok("GEN"));
+        assert_eq!(original_language_name_to_bos_book_code("בראשית"), Ok("GEN"));
+        assert_eq!(original_language_name_to_bos_book_code("שמואל א"), Ok("SA1"));
+        assert_eq!(original_language_name_to_bos_book_code("שמואל"), Ok("SAM"));
+        assert_eq!(original_language_name_to_bos_book_code("אסתר"), Ok("EST"));
+        assert_eq!(original_language_name_to_bos_book_code("Κατὰ Μαθθαῖον"), Ok("MAT"));
+        assert_eq!(original_language_name_to_bos_book_code("Κατα Μαθθαιον"), Ok("MAT"));
+        assert_eq!(original_language_name_to_bos_book_code("ΠΡΟΣ  ΚΟΡΙΝΘΙΟΥΣ Α"), Ok("CO1"));
+        assert!(matches!(
+            original_language_name_to_bos_book_code("Preface"),
+            Err(LookupError::Unknown { system: CodeSystem::OriginalLanguageName, .. })
+        ));
+        assert!(matches!(original_language_name_to_bos_book_code("XyZ"), Err(LookupError::Unknown { .. })));
+        assert!(matches!(original_language_name_to_bos_book_code(""), Err(LookupError::Malformed { .. })));
+    }
+
+    #[test]
+    fn test_chapter_structure() {
+        assert_eq!(chapter_structure("GEN"), Ok(Some(ChapterStructure::Continuous)));
+        assert_eq!(chapter_structure("PSA"), Ok(Some(ChapterStructure::Units)));
+        assert_eq!(chapter_structure("FRT"), Ok(None));
+        for bos_book_code in ["PSA", "PS2", "LAM"] {
+            assert_eq!(continues_through_chapters(bos_book_code), Ok(false));
+        }
+        assert_eq!(BIBLE_BOOKS_CODES_ARRAY.iter().filter(|entry| entry.chapter_structure == Some(ChapterStructure::Units)).count(), 3);
+        assert_eq!(continues_through_chapters("FRT"), Ok(true)); // Same as the Python
+        assert!(matches!(continues_through_chapters("XyZ"), Err(LookupError::Unknown { system: CodeSystem::Bos, .. })));
     }
 }
@@ -0,0 +1,174 @@
+// The error returned when a book code can't be found (or converted),
+//   including "did you mean" suggestions from the codes that we do know about.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use crate::CodeSystem;
+
+/// The most suggestions that we give for an unknown code
+const MAX_SUGGESTIONS: usize = 3;
+
+
+/// What every book code converter returns, i.e., the converted code (or book),
+///     Err(NoEquivalent) if the book is known but has no code in the target system,
+///     or another LookupError if the input isn't a known code.
+pub type Conversion<'a, T = &'static str> = Result<T, LookupError<'a>>;
+
+
+/// Borrows the code that was looked up (if it can): use into_owned() to return it through `?` as a `Box<dyn Error>`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LookupError<'a> {
+    /// There's no book with this code in the system (the suggestions are the closest known codes, if any)
+    Unknown { system: CodeSystem, code: Cow<'a, str>, suggestions: Vec<String> },
+    /// The book is known but has no code in the system, e.g., SAM has no USFM abbreviation
+    NoEquivalent { system: CodeSystem, bos_book_code: Cow<'a, str> },
+    /// The code is used by several books in the system, e.g., OSIS "Ezra" for EZR and EZN
+    Ambiguous { system: CodeSystem, code: Cow<'a, str>, bos_book_codes: Vec<String> },
+    /// The code couldn't be in the system, e.g., it's empty or has surrounding spaces (or isn't three characters for BOS)
+    Malformed { system: CodeSystem, code: Cow<'a, str> },
+}
+
+impl<'a> LookupError<'a> {
+    /// Returns Malformed if the code couldn't possibly be in the system,
+    ///     else Unknown with the closest of the known codes as suggestions.
+    pub(crate) fn not_found<'k>(system: CodeSystem, code: &'a str, known_codes: impl IntoIterator<Item = &'k str>) -> Self {
+        if is_malformed(system, code) {
+            LookupError::Malformed { system, code: Cow::Borrowed(code) }
+        } else {
+            LookupError::Unknown { system, code: Cow::Borrowed(code), suggestions: suggestions(code, known_codes) }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn no_equivalent(system: CodeSystem, bos_book_code: &'a str) -> Self {
+        LookupError::NoEquivalent { system, bos_book_code: Cow::Borrowed(bos_book_code) }
+    }
+
+    pub fn system(&self) -> CodeSystem {
+        match self {
+            LookupError::Unknown { system, .. }
+            | LookupError::NoEquivalent { system, .. }
+            | LookupError::Ambiguous { system, .. }
+            | LookupError::Malformed { system, .. } => *system,
+        }
+    }
+
+    /// Returns the error without borrowing the code that was looked up.
+    pub fn into_owned(self) -> LookupError<'static> {
+        let owned = |code: Cow<'a, str>| Cow::Owned(code.into_owned());
+        match self {
+            LookupError::Unknown { system, code, suggestions } => LookupError::Unknown { system, code: owned(code), suggestions },
+            LookupError::NoEquivalent { system, bos_book_code } => LookupError::NoEquivalent { system, bos_book_code: owned(bos_book_code) },
+            LookupError::Ambiguous { system, code, bos_book_codes } => LookupError::Ambiguous { system, code: owned(code), bos_book_codes },
+            LookupError::Malformed { system, code } => LookupError::Malformed { system, code: owned(code) },
+        }
+    }
+}
+
+impl fmt::Display for LookupError<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LookupError::Unknown { system, code, suggestions } => {
+                match system {
+                    CodeSystem::EnglishName | CodeSystem::OriginalLanguageName => write!(f, "{system} '{code}' not found")?,
+                    _ => write!(f, "{system} abbreviation '{code}' not found")?,
+                }
+                if let Some((last, others)) = suggestions.split_last() {
+                    let others: Vec<String> = others.iter().map(|suggestion| format!("'{suggestion}'")).collect();
+                    match others.is_empty() {
+                        true => write!(f, " (did you mean '{last}'?)")?,
+                        false => write!(f, " (did you mean {} or '{last}'?)", others.join(", "))?,
+                    }
+                }
+                Ok(())
+            }
+            LookupError::NoEquivalent { system, bos_book_code } => write!(f, "BOS book code '{bos_book_code}' has no {system} equivalent"),
+            LookupError::Ambiguous { system, code, bos_book_codes } => {
+                write!(f, "{system} abbreviation '{code}' is used by several books: {}", bos_book_codes.join(", "))
+            }
+            LookupError::Malformed { system, code } => write!(f, "'{code}' isn't a well-formed {system} abbreviation"),
+        }
+    }
+}
+
+impl Error for LookupError<'_> {}
+
+
+/// The BOS and USFM codes (and the book numbers) are always the same length and only letters and digits,
+///     but the other systems can have spaces (e.g., SBL "1 Cor") or punctuation (e.g., Sword "T12Patr.TAsh").
+fn is_malformed(system: CodeSystem, code: &str) -> bool {
+    let fixed_length = match system {
+        CodeSystem::Bos | CodeSystem::Usfm | CodeSystem::UsxNumber => Some(3),
+        CodeSystem::UsfmNumber => Some(2),
+        _ => None,
+    };
+    code.is_empty()
+        || code.trim() != code
+        || code.chars().any(char::is_control)
+        || fixed_length.is_some_and(|length| code.len() != length || !code.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Returns the known codes which are close to the code (ignoring case), closest first.
+fn suggestions<'k>(code: &str, known_codes: impl IntoIterator<Item = &'k str>) -> Vec<String> {
+    let uppercase_code = code.to_uppercase();
+    let max_distance = (uppercase_code.chars().count() / 3).max(1);
+    let mut close_codes: Vec<(usize, &str)> = known_codes
+        .into_iter()
+        .map(|known_code| (strsim::damerau_levenshtein(&uppercase_code, &known_code.to_uppercase()), known_code))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .collect();
+    close_codes.sort_unstable();
+    close_codes.dedup_by_key(|&mut (_, known_code)| known_code);
+    close_codes.into_iter().take(MAX_SUGGESTIONS).map(|(_, known_code)| known_code.to_string()).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found() {
+        assert_eq!(
+            LookupError::not_found(CodeSystem::Osis, "exod", ["Exod", "Gen", "Lev"]),
+            LookupError::Unknown { system: CodeSystem::Osis, code: "exod".into(), suggestions: vec!["Exod".to_string()] }
+        );
+        assert_eq!(
+            LookupError::not_found(CodeSystem::Bos, "GEM", ["GEN", "LEV", "JDT", "GEN"]),
+            LookupError::Unknown { system: CodeSystem::Bos, code: "GEM".into(), suggestions: vec!["GEN".to_string()] }
+        );
+        assert_eq!(
+            LookupError::not_found(CodeSystem::Bos, "XyZ", ["GEN", "LEV"]),
+            LookupError::Unknown { system: CodeSystem::Bos, code: "XyZ".into(), suggestions: Vec::new() }
+        );
+        for code in ["", " GEN", "GENE", "GE-"] {
+            assert_eq!(LookupError::not_found(CodeSystem::Bos, code, ["GEN"]), LookupError::Malformed { system: CodeSystem::Bos, code: code.into() });
+        }
+        assert!(matches!(LookupError::not_found(CodeSystem::Sbl, "1 Corr", ["1 Cor"]), LookupError::Unknown { .. }));
+    }
+
+    #[test]
+    fn test_display() {
+        let error = LookupError::not_found(CodeSystem::Usfm, "1CR", ["1Co", "2Co", "1Ch", "1Cr", "Gen"]);
+        assert_eq!(error.to_string(), "USFM abbreviation '1CR' not found (did you mean '1Cr', '1Ch' or '1Co'?)");
+        let error = LookupError::not_found(CodeSystem::Bos, "GEM", ["GEN"]);
+        assert_eq!(error.to_string(), "BOS abbreviation 'GEM' not found (did you mean 'GEN'?)");
+        assert_eq!(LookupError::no_equivalent(CodeSystem::Usfm, "SAM").to_string(), "BOS book code 'SAM' has no USFM equivalent");
+        let error = LookupError::Ambiguous { system: CodeSystem::Osis, code: "Ezra".into(), bos_book_codes: vec!["EZR".to_string(), "EZN".to_string()] };
+        assert_eq!(error.to_string(), "OSIS abbreviation 'Ezra' is used by several books: EZR, EZN");
+        assert_eq!(LookupError::not_found(CodeSystem::UsfmNumber, "1", []).to_string(), "'1' isn't a well-formed USFM number abbreviation");
+    }
+
+    #[test]
+    fn test_into_owned() {
+        fn find_book(code: &str) -> Result<(), Box<dyn Error>> {
+            Err(LookupError::not_found(CodeSystem::Bos, code, ["GEN"]).into_owned())?
+        }
+        let error = find_book(&String::from("GEM")).unwrap_err();
+        assert_eq!(error.downcast_ref::<LookupError>().map(LookupError::system), Some(CodeSystem::Bos));
+    }
+}
@@ -0,0 +1,111 @@
+// Parsing and formatting of OSIS osisID and osisRef attribute values,
+//   e.g., osisID="Gen.1.1", osisRef="Matt.5.3-Matt.5.12", or work-prefixed "Bible:Gen.1.1".
+
+use crate::reference::parse_number;
+use crate::{osis_book_code_to_bos_book_code, BookCode, LookupError, RefRange, Reference, ReferenceParseError, ReferenceStyle};
+
+
+/// Splits off any OSIS work prefix, e.g., "Bible.KJV:Gen.1.1" gives (Some("Bible.KJV"), "Gen.1.1").
+pub fn split_osis_work(osis_id: &str) -> (Option<&str>, &str) {
+    match osis_id.split_once(':') {
+        Some((work, rest)) => (Some(work), rest),
+        None => (None, osis_id),
+    }
+}
+
+/// Parses a single osisID like "Gen", "Gen.1", "Gen.1.1" or "Bible:Gen.1.1"
+///     (the work prefix and any grain identifier like "!a" are ignored).
+pub fn parse_osis_id(osis_id: &str) -> Result<Reference, ReferenceParseError<'_>> {
+    let osis_id = osis_id.trim();
+    if osis_id.is_empty() {
+        return Err(ReferenceParseError::Empty);
+    }
+    let (_work, osis_id) = split_osis_work(osis_id);
+    let osis_id = osis_id.split_once('!').map_or(osis_id, |(osis_id, _grain)| osis_id);
+
+    let mut parts = osis_id.split('.');
+    let book_part = parts.next().unwrap_or_default();
+    let book = osis_book_code_to_bos_book_code(book_part)
+        .ok()
+        .and_then(BookCode::new)
+        .ok_or(ReferenceParseError::UnknownBook(book_part))?;
+    let chapter = parts.next().map(parse_number);
+    let verse = parts.next().map(parse_number);
+    match (chapter, verse, parts.next()) {
+        (None, None, None) => Ok(Reference::new(book, None, None)),
+        (Some(Some(chapter)), None, None) => Ok(Reference::new(book, Some(chapter), None)),
+        (Some(Some(chapter)), Some(Some(verse)), None) => Ok(Reference::new(book, Some(chapter), Some(verse))),
+        _ => Err(ReferenceParseError::InvalidChapterVerse(osis_id)),
+    }
+}
+
+/// Parses a space-separated osisID attribute like "Gen.1.1 Gen.1.2".
+pub fn parse_osis_ids(osis_ids: &str) -> Result<Vec<Reference>, ReferenceParseError<'_>> {
+    let references = osis_ids.split_whitespace().map(parse_osis_id).collect::<Result<Vec<_>, _>>()?;
+    if references.is_empty() {
+        return Err(ReferenceParseError::Empty);
+    }
+    Ok(references)
+}
+
+/// Parses an osisRef like "Matt.5.3-Matt.5.12" or "Bible:Gen.1.1" (a single reference).
+pub fn parse_osis_ref(osis_ref: &str) -> Result<RefRange, ReferenceParseError<'_>> {
+    let Some((start, end)) = osis_ref.split_once('-') else {
+        return Ok(RefRange::single(parse_osis_id(osis_ref)?));
+    };
+    let range = RefRange { start: parse_osis_id(start)?, end: parse_osis_id(end)? };
+    if range.end < range.start {
+        return Err(ReferenceParseError::InvalidChapterVerse(osis_ref.trim()));
+    }
+    Ok(range)
+}
+
+impl Reference {
+    /// Formats the reference as an osisID, e.g., "1Cor.13.4".
+    #[inline]
+    pub fn to_osis_id(&self) -> Result<String, LookupError<'static>> {
+        self.format(&ReferenceStyle::OSIS)
+    }
+}
+
+impl RefRange {
+    /// Formats the range as an osisRef, e.g., "Matt.5.3-Matt.5.12" (or just "Matt.5.3" if single).
+    #[inline]
+    pub fn to_osis_ref(&self) -> Result<String, LookupError<'static>> {
+        self.format(&ReferenceStyle::OSIS)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(bos_book_code: &str, chapter: Option<u16>, verse: Option<u16>) -> Reference {
+        Reference::new(BookCode::new(bos_book_code).unwrap(), chapter, verse)
+    }
+
+    #[test]
+    fn test_parse_osis_id() {
+        assert_eq!(parse_osis_id("Gen.1.1"), Ok(reference("GEN", Some(1), Some(1))));
+        assert_eq!(parse_osis_id("Bible:Gen.1.1"), Ok(reference("GEN", Some(1), Some(1))));
+        assert_eq!(parse_osis_id("Bible.KJV:1Cor.13"), Ok(reference("CO1", Some(13), None)));
+        assert_eq!(parse_osis_id("Ps.23.1!a"), Ok(reference("PSA", Some(23), Some(1))));
+        assert_eq!(parse_osis_id("Exod"), Ok(reference("EXO", None, None)));
+        assert_eq!(parse_osis_id("Xyz.1.1"), Err(ReferenceParseError::UnknownBook("Xyz")));
+        assert_eq!(parse_osis_id("Gen.1.x"), Err(ReferenceParseError::InvalidChapterVerse("Gen.1.x")));
+        assert_eq!(parse_osis_id("Gen.1.1.1"), Err(ReferenceParseError::InvalidChapterVerse("Gen.1.1.1")));
+        assert_eq!(split_osis_work("Bible.KJV:Gen.1.1"), (Some("Bible.KJV"), "Gen.1.1"));
+        assert_eq!(parse_osis_ids("Gen.1.1 Gen.1.2").map(|references| references.len()), Ok(2));
+    }
+
+    #[test]
+    fn test_parse_and_format_osis_ref() {
+        let range = parse_osis_ref("Matt.5.3-Matt.5.12").unwrap();
+        assert_eq!(range, RefRange { start: reference("MAT", Some(5), Some(3)), end: reference("MAT", Some(5), Some(12)) });
+        assert_eq!(range.to_osis_ref(), Ok("Matt.5.3-Matt.5.12".to_string()));
+        assert_eq!(parse_osis_ref("Gen.1.1").unwrap().to_osis_ref(), Ok("Gen.1.1".to_string()));
+        assert_eq!(reference("CO1", Some(13), Some(4)).to_osis_id(), Ok("1Cor.13.4".to_string()));
+        assert!(parse_osis_ref("Matt.5.12-Matt.5.3").is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// Which Psalms have a title/superscription (the USFM \d field),
+//   e.g., "A Psalm of David, when he fled from Absalom his son" before Psalm 3.
+
+use crate::PSALM_TITLE_TABLE;
+
+
+/// How the Psalms are numbered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PsalmNumbering {
+    /// As in the Hebrew (Masoretic) text and most English Bibles
+    Hebrew,
+    /// As in the Septuagint (LXX) and Vulgate, e.g., Hebrew Psalm 23 is Greek Psalm 22
+    Greek,
+}
+
+/// The chapters (as inclusive ranges) that have a title in a book numbered a certain way,
+///     and those where the title is numbered as verse 1 (or verses 1 and 2)
+///     (PSALM_TITLE_TABLE is made by build.rs from PsalmTitles.tsv).
+pub(crate) struct PsalmTitleEntry {
+    pub(crate) bos_book_code: &'static str,
+    pub(crate) numbering: PsalmNumbering,
+    pub(crate) titled_chapters: &'static [(u16, u16)],
+    pub(crate) one_verse_title_chapters: &'static [(u16, u16)],
+    pub(crate) two_verse_title_chapters: &'static [(u16, u16)],
+}
+
+fn find_entry(bos_book_code: &str, numbering: PsalmNumbering) -> Option<&'static PsalmTitleEntry> {
+    PSALM_TITLE_TABLE.iter().find(|entry| entry.bos_book_code == bos_book_code && entry.numbering == numbering)
+}
+
+fn contains(chapter_ranges: &[(u16, u16)], chapter: u16) -> bool {
+    chapter_ranges.iter().any(|&(first, last)| (first..=last).contains(&chapter))
+}
+
+/// Returns true if the Psalm (in the Hebrew numbering) traditionally has a title, i.e., a USFM \d field.
+/// Returns false for the other 34 Psalms, and for chapters of other books.
+#[inline]
+pub fn has_psalm_title(bos_book_code: &str, chapter: u16) -> bool {
+    has_psalm_title_numbered(bos_book_code, chapter, PsalmNumbering::Hebrew)
+}
+
+/// Returns true if the Psalm (in the given numbering) traditionally has a title, i.e., a USFM \d field.
+/// The titled Psalms for each book and numbering come from PsalmTitles.tsv,
+///     e.g., the Greek numbering follows the Septuagint where every Psalm except Psalms 1 and 2 has a title.
+pub fn has_psalm_title_numbered(bos_book_code: &str, chapter: u16, numbering: PsalmNumbering) -> bool {
+    find_entry(bos_book_code, numbering).is_some_and(|entry| contains(entry.titled_chapters, chapter))
+}
+
+/// Returns how many verses the Psalm title is numbered as (in the given numbering), i.e., 1 or 2 if the text starts at verse 2 or 3,
+///     e.g., Hebrew Psalm 3:1 and Psalm 51:1-2 are titles so those Psalms start at KJV 3:1 and 51:1.
+/// Returns 0 if there's no title or it's part of verse 1 (e.g., Psalm 23), and for chapters of other books.
+/// NOTE: The KJV never numbers the titles, so its verses in these Psalms are the Hebrew ones less the title verses.
+pub fn psalm_title_verses(bos_book_code: &str, chapter: u16, numbering: PsalmNumbering) -> u16 {
+    match find_entry(bos_book_code, numbering) {
+        Some(entry) if contains(entry.one_verse_title_chapters, chapter) => 1,
+        Some(entry) if contains(entry.two_verse_title_chapters, chapter) => 2,
+        _ => 0,
+    }
+}
+
+/// Converts a Greek Psalm number to the (first) Hebrew Psalm it covers.
+/// Greek 9 is Hebrew 9-10, Greek 113 is Hebrew 114-115, Greek 114-115 are Hebrew 116,
+///     and Greek 146-147 are Hebrew 147.
+pub fn greek_to_hebrew_psalm(greek_psalm: u16) -> u16 {
+    match greek_psalm {
+        10..=112 => greek_psalm + 1,
+        113 => 114,
+        114 | 115 => 116,
+        116..=145 => greek_psalm + 1,
+        146 | 147 => 147,
+        _ => greek_psalm,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_psalm_title() {
+        const UNTITLED: [u16; 34] = [
+            1, 2, 10, 33, 43, 71, 91, 93, 94, 95, 96, 97, 99, 104, 105, 106, 107, 111, 112, 113, 114, 115, 116, 117, 118,
+            119, 135, 136, 137, 146, 147, 148, 149, 150,
+        ];
+        for psalm in 1..=150 {
+            assert_eq!(has_psalm_title("PSA", psalm), !UNTITLED.contains(&psalm), "Psalm {}", psalm);
+        }
+        assert_eq!((1..=150).filter(|&psalm| has_psalm_title("PSA", psalm)).count(), 116);
+        assert!(has_psalm_title("PSA", 151));
+        assert!(has_psalm_title("PS2", 1));
+        assert!(!has_psalm_title("GEN", 3));
+        assert!(!has_psalm_title("XyZ", 3));
+    }
+
+    #[test]
+    fn test_psalm_title_verses() {
+        assert_eq!(psalm_title_verses("PSA", 3, PsalmNumbering::Hebrew), 1);
+        assert_eq!(psalm_title_verses("PSA", 23, PsalmNumbering::Hebrew), 0); // Part of verse 1
+        assert_eq!(psalm_title_verses("PSA", 51, PsalmNumbering::Hebrew), 2);
+        assert_eq!(psalm_title_verses("PSA", 60, PsalmNumbering::Hebrew), 2);
+        assert_eq!(psalm_title_verses("PSA", 1, PsalmNumbering::Hebrew), 0);
+        assert_eq!(psalm_title_verses("PS2", 1, PsalmNumbering::Hebrew), 0);
+        assert_eq!(psalm_title_verses("GEN", 3, PsalmNumbering::Hebrew), 0);
+        // The Hebrew Psalter has 2527 verses but the KJV (which doesn't number the titles) has 2461,
+        //     and Psalm 13 has six verses in both (KJV 13:5-6 are Hebrew 13:6)
+        let num_title_verses: u16 = (1..=150).map(|psalm| psalm_title_verses("PSA", psalm, PsalmNumbering::Hebrew)).sum();
+        assert_eq!(num_title_verses - 1, 2527 - 2461);
+        // The Greek numbering numbers the same titles
+        for psalm in 1..=150 {
+            if let Some(greek_psalm) = (1..=151).find(|&greek_psalm| greek_to_hebrew_psalm(greek_psalm) == psalm) {
+                assert_eq!(psalm_title_verses("PSA", greek_psalm, PsalmNumbering::Greek), psalm_title_verses("PSA", psalm, PsalmNumbering::Hebrew), "Psalm {}", psalm);
+            }
+        }
+    }
+
+    #[test]
+    fn test_has_psalm_title_greek() {
+        for psalm in 1..=151 {
+            assert_eq!(has_psalm_title_numbered("PSA", psalm, PsalmNumbering::Greek), psalm > 2, "Greek Psalm {}", psalm);
+        }
+        assert!(has_psalm_title_numbered("PSA", 32, PsalmNumbering::Greek)); // Hebrew 33 has no title
+        assert!(!has_psalm_title_numbered("PSA", 152, PsalmNumbering::Greek));
+        assert!(has_psalm_title_numbered("PS2", 1, PsalmNumbering::Greek));
+        assert!(!has_psalm_title_numbered("GEN", 3, PsalmNumbering::Greek));
+        assert_eq!(greek_to_hebrew_psalm(50), 51);
+        assert_eq!(greek_to_hebrew_psalm(59), 60);
+        assert_eq!(greek_to_hebrew_psalm(150), 150);
+    }
+}
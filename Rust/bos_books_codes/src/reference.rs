@@ -0,0 +1,249 @@
+// Parsing of human-written Bible references like "1 Cor 13:4-7", "Gen 1:1–2:3; Ps 23" or "Jn 3.16"
+//   into Reference and RefRange values keyed by BOS book code.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{find_english_name, BookCode, OptionalNumberOrTwoNumbers, BIBLE_BOOKS_CODES_ARRAY, ENGLISH_NAME_MAP};
+
+
+/// A book, optionally with a chapter, and optionally with a verse within that chapter.
+/// Ordering is canonical (by book sequence number, then chapter, then verse).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Reference {
+    pub book: BookCode,
+    pub chapter: Option<u16>,
+    pub verse: Option<u16>,
+}
+
+impl Reference {
+    #[inline]
+    pub fn new(book: BookCode, chapter: Option<u16>, verse: Option<u16>) -> Self {
+        Reference { book, chapter, verse }
+    }
+}
+
+/// An inclusive range of references (start and end are the same for a single reference).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RefRange {
+    pub start: Reference,
+    pub end: Reference,
+}
+
+impl RefRange {
+    #[inline]
+    pub fn single(reference: Reference) -> Self {
+        RefRange { start: reference, end: reference }
+    }
+
+    #[inline]
+    pub fn is_single(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+
+#[derive(Debug, PartialEq)]
+pub enum ReferenceParseError<'a> {
+    Empty,
+    UnknownBook(&'a str),
+    MissingBook(&'a str),
+    InvalidChapterVerse(&'a str),
+}
+
+impl fmt::Display for ReferenceParseError<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferenceParseError::Empty => write!(f, "reference is empty"),
+            ReferenceParseError::UnknownBook(t) => write!(f, "no book name found in '{}'", t),
+            ReferenceParseError::MissingBook(t) => write!(f, "'{}' has no preceding book", t),
+            ReferenceParseError::InvalidChapterVerse(t) => write!(f, "invalid chapter/verse in '{}'", t),
+        }
+    }
+}
+
+impl Error for ReferenceParseError<'_> {}
+
+
+const RANGE_DASHES: [char; 3] = ['-', '–', '—'];
+
+/// Parses a list of references separated by commas and/or semicolons,
+///     e.g., "1 Cor 13:4-7", "Gen 1:1–2:3; Ps 23", "Jn 3.16", "Jude 3", "Rom 8:1, 28; 12".
+/// Items without a book name continue with the previous book. After a comma, a bare number
+///     is a verse if the previous item ended in a verse, otherwise it's a chapter.
+/// Books with only one chapter (from expected_num_chapters) may omit the chapter number.
+pub fn parse_reference(text: &str) -> Result<Vec<RefRange>, ReferenceParseError<'_>> {
+    let mut ranges: Vec<RefRange> = Vec::new();
+    let mut after_comma = false;
+    let mut item_start = 0;
+    for (i, c) in text.char_indices().chain([(text.len(), ';')]) {
+        if c != ',' && c != ';' {
+            continue;
+        }
+        let item = text[item_start..i].trim();
+        if !item.is_empty() {
+            let range = if item.chars().any(char::is_alphabetic) {
+                parse_book_item(item)?
+            } else {
+                let previous = ranges.last().ok_or(ReferenceParseError::MissingBook(item))?.end;
+                let verse_context = if after_comma && previous.verse.is_some() { previous.chapter } else { None };
+                parse_chapter_verse(previous.book, item, verse_context)
+                    .ok_or(ReferenceParseError::InvalidChapterVerse(item))?
+            };
+            ranges.push(range);
+        }
+        after_comma = c == ',';
+        item_start = i + c.len_utf8();
+    }
+
+    if ranges.is_empty() {
+        return Err(ReferenceParseError::Empty);
+    }
+    Ok(ranges)
+}
+
+fn book_name_to_book_code(book_name: &str) -> Option<BookCode> {
+    let book_name = book_name.trim().trim_end_matches('.').trim_end();
+    if book_name.is_empty() {
+        return None;
+    }
+    // (Not english_name_to_bos_book_code() as we try several splits and don't want the suggestions for the failures)
+    let array_index = find_english_name(book_name, |name| ENGLISH_NAME_MAP.get(name).copied())?;
+    BookCode::new(BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code)
+}
+
+/// Parses an item that starts with a book name, e.g., "1 Cor 13:4-7" or "Jn3.16" or "Gen".
+/// We try to split the book name off at the start of each run of digits (from the right)
+///     so that book names containing digits (like "1 Cor" or "KI1") are still found.
+fn parse_book_item(item: &str) -> Result<RefRange, ReferenceParseError<'_>> {
+    if let Some(book) = book_name_to_book_code(item) {
+        return Ok(RefRange::single(Reference::new(book, None, None)));
+    }
+
+    let mut found_book = false;
+    for (i, c) in item.char_indices().rev() {
+        if i == 0 || !c.is_ascii_digit() || item[..i].ends_with(|p: char| p.is_ascii_digit()) {
+            continue;
+        }
+        if let Some(book) = book_name_to_book_code(&item[..i]) {
+            found_book = true;
+            if let Some(range) = parse_chapter_verse(book, &item[i..], None) {
+                return Ok(range);
+            }
+        }
+    }
+
+    Err(if found_book {
+        ReferenceParseError::InvalidChapterVerse(item)
+    } else {
+        ReferenceParseError::UnknownBook(item)
+    })
+}
+
+/// Parses "N" or "N:M" or "N.M" (with an optional range after a dash) for the given book.
+/// If verse_context is given, a bare starting number is a verse in that chapter.
+fn parse_chapter_verse(book: BookCode, spec: &str, verse_context: Option<u16>) -> Option<RefRange> {
+    let (start_spec, end_spec) = match spec.split_once(RANGE_DASHES) {
+        Some((start_spec, end_spec)) => (start_spec, Some(end_spec)),
+        None => (spec, None),
+    };
+
+    let start = match (parse_number_pair(start_spec)?, verse_context) {
+        ((chapter, Some(verse)), _) => Reference::new(book, Some(chapter), Some(verse)),
+        ((verse, None), Some(chapter)) => Reference::new(book, Some(chapter), Some(verse)),
+        ((verse, None), None) if is_single_chapter_book(book) => Reference::new(book, Some(1), Some(verse)),
+        ((chapter, None), None) => Reference::new(book, Some(chapter), None),
+    };
+    let Some(end_spec) = end_spec else {
+        return Some(RefRange::single(start));
+    };
+
+    let end = match parse_number_pair(end_spec)? {
+        (chapter, Some(verse)) => Reference::new(book, Some(chapter), Some(verse)),
+        (verse, None) if start.verse.is_some() => Reference::new(book, start.chapter, Some(verse)),
+        (chapter, None) => Reference::new(book, Some(chapter), None),
+    };
+    if end < start {
+        return None;
+    }
+    Some(RefRange { start, end })
+}
+
+/// Parses a chapter or verse number (digits only, and must be one or more).
+pub(crate) fn parse_number(number_str: &str) -> Option<u16> {
+    let number_str = number_str.trim();
+    if number_str.is_empty() || !number_str.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number_str.parse::<u16>().ok().filter(|&n| n > 0)
+}
+
+/// Parses "N" or "N:M" or "N.M".
+fn parse_number_pair(spec: &str) -> Option<(u16, Option<u16>)> {
+    match spec.split_once([':', '.']) {
+        Some((first, second)) => Some((parse_number(first)?, Some(parse_number(second)?))),
+        None => Some((parse_number(spec)?, None)),
+    }
+}
+
+#[inline]
+fn is_single_chapter_book(book: BookCode) -> bool {
+    matches!(book.entry().expected_num_chapters, OptionalNumberOrTwoNumbers::Number(1))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(bos_book_code: &str, chapter: Option<u16>, verse: Option<u16>) -> Reference {
+        Reference::new(BookCode::new(bos_book_code).unwrap(), chapter, verse)
+    }
+
+    fn range(start: Reference, end: Reference) -> RefRange {
+        RefRange { start, end }
+    }
+
+    #[test]
+    fn test_parse_single_references() {
+        assert_eq!(parse_reference("Jn 3.16"), Ok(vec![RefRange::single(reference("JHN", Some(3), Some(16)))]));
+        assert_eq!(parse_reference("Gen 1:1"), Ok(vec![RefRange::single(reference("GEN", Some(1), Some(1)))]));
+        assert_eq!(parse_reference("Ps 23"), Ok(vec![RefRange::single(reference("PSA", Some(23), None))]));
+        assert_eq!(parse_reference("1 John"), Ok(vec![RefRange::single(reference("JN1", None, None))]));
+        assert_eq!(parse_reference("Jude 3"), Ok(vec![RefRange::single(reference("JDE", Some(1), Some(3)))]));
+        assert_eq!(parse_reference("CO1 13:4"), Ok(vec![RefRange::single(reference("CO1", Some(13), Some(4)))]));
+        assert_eq!(parse_reference("1.Kings 2"), Ok(vec![RefRange::single(reference("KI1", Some(2), None))]));
+    }
+
+    #[test]
+    fn test_parse_ranges_and_lists() {
+        assert_eq!(parse_reference("1 Cor 13:4-7"), Ok(vec![range(reference("CO1", Some(13), Some(4)), reference("CO1", Some(13), Some(7)))]));
+        assert_eq!(
+            parse_reference("Gen 1:1–2:3; Ps 23"),
+            Ok(vec![
+                range(reference("GEN", Some(1), Some(1)), reference("GEN", Some(2), Some(3))),
+                RefRange::single(reference("PSA", Some(23), None)),
+            ])
+        );
+        assert_eq!(
+            parse_reference("Rom 8:1, 28; 12, 14-15"),
+            Ok(vec![
+                RefRange::single(reference("ROM", Some(8), Some(1))),
+                RefRange::single(reference("ROM", Some(8), Some(28))),
+                RefRange::single(reference("ROM", Some(12), None)),
+                range(reference("ROM", Some(14), None), reference("ROM", Some(15), None)),
+            ])
+        );
+        assert_eq!(parse_reference("Jude 3-5"), Ok(vec![range(reference("JDE", Some(1), Some(3)), reference("JDE", Some(1), Some(5)))]));
+    }
+
+    #[test]
+    fn test_parse_reference_errors() {
+        assert_eq!(parse_reference(" ; "), Err(ReferenceParseError::Empty));
+        assert_eq!(parse_reference("3:16"), Err(ReferenceParseError::MissingBook("3:16")));
+        assert_eq!(parse_reference("XyZ 3:16"), Err(ReferenceParseError::UnknownBook("XyZ 3:16")));
+        assert_eq!(parse_reference("Gen 0:1"), Err(ReferenceParseError::InvalidChapterVerse("Gen 0:1")));
+        assert_eq!(parse_reference("Gen 1:5-3"), Err(ReferenceParseError::InvalidChapterVerse("Gen 1:5-3")));
+    }
+}
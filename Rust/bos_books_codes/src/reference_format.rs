@@ -0,0 +1,198 @@
+// Formatting of Reference and RefRange values in the various books codes systems,
+//   e.g., "1Cor.13.4" (OSIS), "1CO 13:4" (USFM), "1 Cor 13:4" (SBL) or "CO1_13:4" (BOS).
+
+use std::fmt;
+
+use crate::{BookCode, CodeSystem, LookupError, RefRange, Reference};
+
+
+/// Which form of the book name to output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookNameForm {
+    /// The book's abbreviation in the given code system, e.g., "1Cor" for OSIS
+    Abbreviation(CodeSystem),
+    /// As above but uppercased, e.g., "1CO" for USFM
+    UppercaseAbbreviation(CodeSystem),
+    /// The first name in book_name_English_guide, e.g., "Genesis" from "Genesis / 1 Moses"
+    EnglishGuide,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReferenceStyle {
+    pub book_name: BookNameForm,
+    pub book_chapter_separator: &'static str,
+    pub chapter_verse_separator: &'static str,
+    pub range_separator: &'static str,
+    /// If true, the end of a range always repeats the book and chapter, e.g., OSIS "Gen.1.1-Gen.1.3"
+    pub full_range_end: bool,
+}
+
+impl ReferenceStyle {
+    pub const BOS: ReferenceStyle = ReferenceStyle {
+        book_name: BookNameForm::Abbreviation(CodeSystem::Bos),
+        book_chapter_separator: "_",
+        chapter_verse_separator: ":",
+        range_separator: "-",
+        full_range_end: false,
+    };
+    pub const OSIS: ReferenceStyle = ReferenceStyle {
+        book_name: BookNameForm::Abbreviation(CodeSystem::Osis),
+        book_chapter_separator: ".",
+        chapter_verse_separator: ".",
+        range_separator: "-",
+        full_range_end: true,
+    };
+    pub const USFM: ReferenceStyle = ReferenceStyle {
+        book_name: BookNameForm::UppercaseAbbreviation(CodeSystem::Usfm),
+        book_chapter_separator: " ",
+        chapter_verse_separator: ":",
+        range_separator: "-",
+        full_range_end: false,
+    };
+    pub const SBL: ReferenceStyle = ReferenceStyle {
+        book_name: BookNameForm::Abbreviation(CodeSystem::Sbl),
+        book_chapter_separator: " ",
+        chapter_verse_separator: ":",
+        range_separator: "–",
+        full_range_end: false,
+    };
+    pub const ENGLISH: ReferenceStyle = ReferenceStyle {
+        book_name: BookNameForm::EnglishGuide,
+        book_chapter_separator: " ",
+        chapter_verse_separator: ":",
+        range_separator: "–",
+        full_range_end: false,
+    };
+
+    /// Returns the usual style for the given code system
+    ///     (systems without their own style get the book abbreviation with "1:2" numbers).
+    pub fn for_code_system(code_system: CodeSystem) -> Self {
+        match code_system {
+            CodeSystem::Bos => ReferenceStyle::BOS,
+            CodeSystem::Osis => ReferenceStyle::OSIS,
+            CodeSystem::Usfm => ReferenceStyle::USFM,
+            CodeSystem::Sbl => ReferenceStyle::SBL,
+            _ => ReferenceStyle {
+                book_name: BookNameForm::Abbreviation(code_system),
+                ..ReferenceStyle::SBL
+            },
+        }
+    }
+
+    fn book_name(&self, book: BookCode) -> Result<String, LookupError<'static>> {
+        match self.book_name {
+            BookNameForm::Abbreviation(code_system) => code_system
+                .abbreviation(book)
+                .map(str::to_string)
+                .ok_or_else(|| LookupError::no_equivalent(code_system, book.as_str())),
+            BookNameForm::UppercaseAbbreviation(code_system) => code_system
+                .abbreviation(book)
+                .map(str::to_uppercase)
+                .ok_or_else(|| LookupError::no_equivalent(code_system, book.as_str())),
+            BookNameForm::EnglishGuide => {
+                let english_guide = book.entry().book_name_English_guide;
+                Ok(english_guide.split(" / ").next().unwrap_or(english_guide).to_string())
+            }
+        }
+    }
+
+    fn write_reference(&self, output: &mut String, reference: &Reference) -> Result<(), LookupError<'static>> {
+        output.push_str(&self.book_name(reference.book)?);
+        if let Some(chapter) = reference.chapter {
+            output.push_str(self.book_chapter_separator);
+            self.write_chapter_verse(output, chapter, reference.verse);
+        }
+        Ok(())
+    }
+
+    fn write_chapter_verse(&self, output: &mut String, chapter: u16, verse: Option<u16>) {
+        output.push_str(&chapter.to_string());
+        if let Some(verse) = verse {
+            output.push_str(self.chapter_verse_separator);
+            output.push_str(&verse.to_string());
+        }
+    }
+}
+
+
+impl Reference {
+    /// Formats the reference in the given style, e.g., "1Cor.13.4" for ReferenceStyle::OSIS.
+    /// Fails if the book has no abbreviation in the style's code system.
+    pub fn format(&self, style: &ReferenceStyle) -> Result<String, LookupError<'static>> {
+        let mut output = String::new();
+        style.write_reference(&mut output, self)?;
+        Ok(output)
+    }
+}
+
+impl RefRange {
+    /// Formats the range in the given style, e.g., "1 Cor 13:4–7" for ReferenceStyle::SBL.
+    /// The end is abbreviated as much as possible unless style.full_range_end is set.
+    pub fn format(&self, style: &ReferenceStyle) -> Result<String, LookupError<'static>> {
+        let mut output = String::new();
+        style.write_reference(&mut output, &self.start)?;
+        if self.is_single() {
+            return Ok(output);
+        }
+
+        output.push_str(style.range_separator);
+        match self.end.chapter {
+            Some(end_chapter) if !style.full_range_end && self.end.book == self.start.book => {
+                match (self.start.verse, self.end.verse) {
+                    (Some(_), Some(end_verse)) if self.end.chapter == self.start.chapter => {
+                        output.push_str(&end_verse.to_string())
+                    }
+                    (_, end_verse) => style.write_chapter_verse(&mut output, end_chapter, end_verse),
+                }
+            }
+            _ => style.write_reference(&mut output, &self.end)?,
+        }
+        Ok(output)
+    }
+}
+
+impl fmt::Display for Reference {
+    /// Displays the reference in the BOS style, e.g., "CO1_13:4"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(&ReferenceStyle::BOS).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl fmt::Display for RefRange {
+    /// Displays the range in the BOS style, e.g., "CO1_13:4-7"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(&ReferenceStyle::BOS).map_err(|_| fmt::Error)?)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_reference;
+
+    fn first_range(text: &str) -> RefRange {
+        parse_reference(text).unwrap()[0]
+    }
+
+    #[test]
+    fn test_format_reference() {
+        let reference = first_range("1 Cor 13:4").start;
+        assert_eq!(reference.format(&ReferenceStyle::OSIS), Ok("1Cor.13.4".to_string()));
+        assert_eq!(reference.format(&ReferenceStyle::USFM), Ok("1CO 13:4".to_string()));
+        assert_eq!(reference.format(&ReferenceStyle::SBL), Ok("1 Cor 13:4".to_string()));
+        assert_eq!(reference.format(&ReferenceStyle::BOS), Ok("CO1_13:4".to_string()));
+        assert_eq!(reference.to_string(), "CO1_13:4");
+        assert_eq!(first_range("Gen 3").start.format(&ReferenceStyle::ENGLISH), Ok("Genesis 3".to_string()));
+        assert_eq!(first_range("Jude").start.format(&ReferenceStyle::for_code_system(CodeSystem::Logos)), Ok("Jud".to_string()));
+        assert_eq!(first_range("Samuel 3").start.format(&ReferenceStyle::OSIS), Err(LookupError::no_equivalent(CodeSystem::Osis, "SAM")));
+    }
+
+    #[test]
+    fn test_format_ref_range() {
+        assert_eq!(first_range("1 Cor 13:4-7").format(&ReferenceStyle::SBL), Ok("1 Cor 13:4–7".to_string()));
+        assert_eq!(first_range("1 Cor 13:4-7").format(&ReferenceStyle::OSIS), Ok("1Cor.13.4-1Cor.13.7".to_string()));
+        assert_eq!(first_range("Gen 1:1–2:3").format(&ReferenceStyle::USFM), Ok("GEN 1:1-2:3".to_string()));
+        assert_eq!(first_range("Ps 23-24").to_string(), "PSA_23-24");
+    }
+}
@@ -0,0 +1,138 @@
+// Scanning a project folder of USFM/USX/OSIS files to see which books are present.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{book_from_file_contents, book_from_filename, osis_book_code_to_bos_book_code, BookCode, Canon};
+
+
+/// Files with these extensions (case-insensitive) are expected to each contain a book.
+const BOOK_FILE_EXTENSIONS: [&str; 4] = ["sfm", "usfm", "usx", "xml"];
+
+/// The result of scan_folder(). All lists are in canonical (BOS_sequence_number) order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FolderScan {
+    /// Books in the canon that were found (with the first file found for each)
+    pub present: Vec<(BookCode, PathBuf)>,
+    /// Books in the canon that weren't found
+    pub missing: Vec<BookCode>,
+    /// Books that were found but aren't in the canon
+    pub unexpected: Vec<(BookCode, PathBuf)>,
+    /// Books that were found in more than one file (with all of those files)
+    pub duplicated: Vec<(BookCode, Vec<PathBuf>)>,
+    /// Book files where no book could be identified (in filename order)
+    pub unrecognised: Vec<PathBuf>,
+}
+
+/// Scans the folder (not including subfolders) for USFM, USX and OSIS book files,
+///     identifying each one from its contents (USFM \id line, USX <book> element or OSIS book <div>)
+///     or else from its filename, and compares the books found against the canon.
+pub fn scan_folder(folder: impl AsRef<Path>, canon: &Canon) -> io::Result<FolderScan> {
+    let mut paths = Vec::new();
+    for dir_entry in fs::read_dir(folder)? {
+        let path = dir_entry?.path();
+        if path.is_file() && is_book_file(&path) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut scan = FolderScan::default();
+    let mut found: Vec<(BookCode, Vec<PathBuf>)> = Vec::new();
+    for path in paths {
+        match identify_book_file(&path) {
+            Some(book) => match found.iter_mut().find(|(found_book, _)| *found_book == book) {
+                Some((_, book_paths)) => book_paths.push(path),
+                None => found.push((book, vec![path])),
+            },
+            None => scan.unrecognised.push(path),
+        }
+    }
+    found.sort_by_key(|&(book, _)| book);
+
+    for (book, book_paths) in found {
+        let first_path = book_paths[0].clone();
+        if canon.contains(book) {
+            scan.present.push((book, first_path));
+        } else {
+            scan.unexpected.push((book, first_path));
+        }
+        if book_paths.len() > 1 {
+            scan.duplicated.push((book, book_paths));
+        }
+    }
+    scan.missing = canon
+        .books()
+        .into_iter()
+        .filter(|book| !scan.present.iter().any(|(present_book, _)| present_book == book))
+        .collect();
+    Ok(scan)
+}
+
+fn is_book_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| BOOK_FILE_EXTENSIONS.iter().any(|book_extension| extension.eq_ignore_ascii_case(book_extension)))
+}
+
+/// The contents are preferred because the file might be misnamed.
+fn identify_book_file(path: &Path) -> Option<BookCode> {
+    if let Ok(identification) = book_from_file_contents(path) {
+        return Some(identification.book);
+    }
+    if let Ok(book) = book_from_filename(path) {
+        return Some(book);
+    }
+    let stem = path.file_stem()?.to_str()?;
+    osis_book_code_to_bos_book_code(stem).ok().and_then(BookCode::new)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(bos_book_code: &str) -> BookCode {
+        BookCode::new(bos_book_code).unwrap()
+    }
+
+    #[test]
+    fn test_scan_folder() {
+        let folder = std::env::temp_dir().join(format!("bos_books_codes_scan_test_{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        for (filename, contents) in [
+            ("40MATENG.SFM", "\\id MRK Misnamed Mark\n"), // The contents win
+            ("41MATENG.SFM", "\\id MAT\n"),
+            ("MAT.usfm", "\\id MAT Another Matthew\n"),
+            ("066REV.usx", ""),
+            ("Jude.xml", "<osis/>"),
+            ("Letter.xml", "<osis><osisText><div type=\"book\" osisID=\"Phlm\"></div></osisText></osis>"), // A misnamed OSIS file
+            ("TOB.usfm", ""),
+            ("notes.usfm", "\\p Some notes\n"),
+            ("Settings.xml.bak", ""),
+        ] {
+            fs::write(folder.join(filename), contents).unwrap();
+        }
+
+        let scan = scan_folder(&folder, &Canon::NewTestament).unwrap();
+        fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(
+            scan.present,
+            vec![
+                (book("MAT"), folder.join("41MATENG.SFM")),
+                (book("MRK"), folder.join("40MATENG.SFM")),
+                (book("PHM"), folder.join("Letter.xml")),
+                (book("JDE"), folder.join("Jude.xml")),
+                (book("REV"), folder.join("066REV.usx")),
+            ]
+        );
+        assert_eq!(scan.missing.len(), 27 - 5);
+        assert_eq!(scan.missing[0], book("LUK"));
+        assert_eq!(scan.unexpected, vec![(book("TOB"), folder.join("TOB.usfm"))]);
+        assert_eq!(scan.duplicated, vec![(book("MAT"), vec![folder.join("41MATENG.SFM"), folder.join("MAT.usfm")])]);
+        assert_eq!(scan.unrecognised, vec![folder.join("notes.usfm")]);
+        assert!(scan_folder(folder.join("missing"), &Canon::Protestant).is_err());
+    }
+}
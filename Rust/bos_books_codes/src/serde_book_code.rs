@@ -0,0 +1,134 @@
+// Serde support (with the serde feature): BookCode is serialized as the BOS book code (e.g., "CO1") by default,
+//   or use #[serde(with = "bos_books_codes::serde_book_code::usfm")] (or ::osis) to use those codes instead.
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{osis_book_code_to_bos_book_code, usfm_abbrev_to_bos_book_code, BookCode, CodeSystem, LookupError};
+
+impl Serialize for BookCode {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BookCode {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BookCode, D::Error> {
+        deserializer.deserialize_str(BookCodeVisitor(CodeSystem::Bos))
+    }
+}
+
+/// For #[serde(with = "bos_books_codes::serde_book_code::usfm")], e.g., "1Co" for CO1.
+/// Serializing a book without a USFM code is an error.
+pub mod usfm {
+    use super::*;
+
+    #[inline]
+    pub fn serialize<S: Serializer>(book: &BookCode, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(CodeSystem::Usfm, *book, serializer)
+    }
+
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BookCode, D::Error> {
+        deserializer.deserialize_str(BookCodeVisitor(CodeSystem::Usfm))
+    }
+}
+
+/// For #[serde(with = "bos_books_codes::serde_book_code::osis")], e.g., "1Cor" for CO1.
+/// Serializing a book without an OSIS code is an error.
+pub mod osis {
+    use super::*;
+
+    #[inline]
+    pub fn serialize<S: Serializer>(book: &BookCode, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(CodeSystem::Osis, *book, serializer)
+    }
+
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BookCode, D::Error> {
+        deserializer.deserialize_str(BookCodeVisitor(CodeSystem::Osis))
+    }
+}
+
+fn serialize_as<S: Serializer>(system: CodeSystem, book: BookCode, serializer: S) -> Result<S::Ok, S::Error> {
+    match system.abbreviation(book) {
+        Some(abbreviation) => serializer.serialize_str(abbreviation),
+        None => Err(ser::Error::custom(LookupError::no_equivalent(system, book.as_str()))),
+    }
+}
+
+/// Deserializes a book code in the given system (only BOS, USFM and OSIS are used).
+struct BookCodeVisitor(CodeSystem);
+
+impl Visitor<'_> for BookCodeVisitor {
+    type Value = BookCode;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {} book code", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, code: &str) -> Result<BookCode, E> {
+        let bos_book_code = match self.0 {
+            CodeSystem::Usfm => usfm_abbrev_to_bos_book_code(code).ok(),
+            CodeSystem::Osis => osis_book_code_to_bos_book_code(code).ok(),
+            _ => Some(code),
+        };
+        bos_book_code.and_then(BookCode::new).ok_or_else(|| E::invalid_value(de::Unexpected::Str(code), &self))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BooksCodesTableEntry, OptionalNumberOrTwoNumbers, BIBLE_BOOKS_CODES_ARRAY};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Cached {
+        book: BookCode,
+        #[serde(with = "usfm")]
+        usfm_book: BookCode,
+        #[serde(with = "osis")]
+        osis_book: BookCode,
+    }
+
+    #[test]
+    fn test_book_code() {
+        let co1 = BookCode::new("CO1").unwrap();
+        let cached = Cached { book: co1, usfm_book: co1, osis_book: co1 };
+        let json = serde_json::to_string(&cached).unwrap();
+        assert_eq!(json, r#"{"book":"CO1","usfm_book":"1Co","osis_book":"1Cor"}"#);
+        assert_eq!(serde_json::from_str::<Cached>(&json).unwrap(), cached);
+
+        assert!(serde_json::from_str::<BookCode>(r#""XYZ""#).is_err());
+        assert!(serde_json::from_str::<Cached>(r#"{"book":"CO1","usfm_book":"1Cor","osis_book":"1Cor"}"#).is_err());
+        // A book without an OSIS code
+        let no_osis_book = BIBLE_BOOKS_CODES_ARRAY.iter().find(|entry| entry.OSIS_abbreviation.is_none()).unwrap();
+        let no_osis_book = BookCode::new(no_osis_book.BOS_book_code).unwrap();
+        assert!(serde_json::to_string(&Cached { book: co1, usfm_book: co1, osis_book: no_osis_book }).is_err());
+    }
+
+    #[test]
+    fn test_entries() {
+        assert_eq!(serde_json::to_string(&OptionalNumberOrTwoNumbers::TwoNumbers([150, 151])).unwrap(), r#"{"TwoNumbers":[150,151]}"#);
+        for value in [OptionalNumberOrTwoNumbers::None, OptionalNumberOrTwoNumbers::Number(50)] {
+            assert_eq!(serde_json::from_str::<OptionalNumberOrTwoNumbers>(&serde_json::to_string(&value).unwrap()).unwrap(), value);
+        }
+
+        // An array entry is read back as the owned entry
+        for entry in BIBLE_BOOKS_CODES_ARRAY.iter() {
+            let json = serde_json::to_string(entry).unwrap();
+            assert_eq!(serde_json::from_str::<BooksCodesTableEntry>(&json).unwrap(), BooksCodesTableEntry::from(entry));
+        }
+
+        let error = crate::osis_book_code_to_bos_book_code("Exodus").unwrap_err();
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"Unknown":{"system":"Osis","code":"Exodus","suggestions":["Exod"]}}"#);
+        assert_eq!(serde_json::from_str::<LookupError>(&json).unwrap(), error);
+    }
+}
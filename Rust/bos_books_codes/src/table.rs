@@ -0,0 +1,740 @@
+// A books codes table that can be loaded at runtime (from a TSV file or the derivedFormats JSON)
+//   as an alternative to the compiled-in BIBLE_BOOKS_CODES_ARRAY, with the same lookups.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{
+    find_english_name, normalise_original_language_name, BibleBooksCodesArrayEntry, ChapterStructure, CodeSystem, Conversion, LookupError,
+    OptionalNumberOrTwoNumbers, BIBLE_BOOKS_CODES_ARRAY,
+};
+
+
+/// The columns which must be in a TSV table (any others written by the Python exportDataToTSV() are optional).
+const COMPULSORY_TSV_COLUMNS: [&str; 6] = [
+    "originalLanguageCode", "bookName", "bookNameEnglishGuide", "BOSReferenceAbbreviation", "BOSReferenceNumber", "BOSSequenceNumber",
+];
+
+#[derive(Debug)]
+pub enum TableLoadError {
+    Io(io::Error),
+    MissingColumn(String),
+    WrongColumnCount { line_number: usize, expected: usize, found: usize },
+    InvalidValue { line_number: usize, column: String, value: String },
+    DuplicateBookCode(String),
+    /// An overlay entry uses a code which another book already uses
+    CodeConflict { system: &'static str, code: String, bos_book_codes: [String; 2] },
+    /// The JSON isn't in the derivedFormats BibleBooksCodes_Tables.json layout
+    Json(String),
+    UnknownFileType(String),
+}
+
+impl fmt::Display for TableLoadError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableLoadError::Io(e) => write!(f, "couldn't read table: {}", e),
+            TableLoadError::MissingColumn(c) => write!(f, "table has no '{}' column", c),
+            TableLoadError::WrongColumnCount { line_number, expected, found } =>
+                write!(f, "line {} has {} columns instead of {}", line_number, found, expected),
+            TableLoadError::InvalidValue { line_number, column, value } =>
+                write!(f, "line {} has invalid {} '{}'", line_number, column, value),
+            TableLoadError::DuplicateBookCode(k) => write!(f, "BOS book code '{}' is in the table more than once", k),
+            TableLoadError::CodeConflict { system, code, bos_book_codes: [new_book, existing_book] } =>
+                write!(f, "{} code '{}' for '{}' is already used by '{}'", system, code, new_book, existing_book),
+            TableLoadError::Json(e) => write!(f, "unexpected JSON table: {}", e),
+            TableLoadError::UnknownFileType(k) => write!(f, "'{}' isn't a .tsv or .json table", k),
+        }
+    }
+}
+
+impl Error for TableLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TableLoadError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TableLoadError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        TableLoadError::Io(e)
+    }
+}
+
+
+/// An owned version of BibleBooksCodesArrayEntry (so that it can be loaded at runtime).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BooksCodesTableEntry {
+    pub original_language_code: String,
+    pub original_language_book_name: String,
+    pub book_name_English_guide: String,
+    pub BOS_book_code: String,
+    pub BOS_reference_number: u16,
+    pub BOS_sequence_number: u16,
+    pub expected_num_chapters: OptionalNumberOrTwoNumbers,
+    pub chapter_structure: Option<ChapterStructure>,
+    pub short_abbreviation: Option<String>,
+    pub SBL_abbreviation: Option<String>,
+    pub OSIS_abbreviation: Option<String>,
+    pub Sword_abbreviation: Option<String>,
+    pub CCEL_number: Option<u16>,
+    pub USFM_abbreviation: Option<String>,
+    pub USFM_number_str: Option<String>,
+    pub USX_number_str: Option<String>,
+    pub Unbound_Code: Option<String>,
+    pub Bibledit_number: Option<u16>,
+    pub Logos_number: Option<u16>,
+    pub Logos_abbreviation: Option<String>,
+    pub NET_Bible_abbreviation: Option<String>,
+    pub Drupal_Bible_abbreviation: Option<String>,
+    pub Bible_Works_abbreviation: Option<String>,
+    pub Byzantine_abbreviation: Option<String>,
+    pub possible_alternative_abbreviations: Vec<String>,
+    pub possible_alternative_books_codes: Vec<String>,
+    pub consists_of_books_codes: Option<String>,
+    pub typical_section: Option<String>,
+    pub typical_subsection: Option<String>,
+    /// Comma-separated (like in BIBLE_BOOKS_CODES_ARRAY)
+    pub all_English_derived_abbreviations: String,
+}
+
+impl From<&BibleBooksCodesArrayEntry<'_>> for BooksCodesTableEntry {
+    fn from(entry: &BibleBooksCodesArrayEntry<'_>) -> Self {
+        let owned = |field: Option<&str>| field.map(str::to_string);
+        BooksCodesTableEntry {
+            original_language_code: entry.original_language_code.to_string(),
+            original_language_book_name: entry.original_language_book_name.to_string(),
+            book_name_English_guide: entry.book_name_English_guide.to_string(),
+            BOS_book_code: entry.BOS_book_code.to_string(),
+            BOS_reference_number: entry.BOS_reference_number,
+            BOS_sequence_number: entry.BOS_sequence_number,
+            expected_num_chapters: entry.expected_num_chapters,
+            chapter_structure: entry.chapter_structure,
+            short_abbreviation: owned(entry.short_abbreviation),
+            SBL_abbreviation: owned(entry.SBL_abbreviation),
+            OSIS_abbreviation: owned(entry.OSIS_abbreviation),
+            Sword_abbreviation: owned(entry.Sword_abbreviation),
+            CCEL_number: entry.CCEL_number,
+            USFM_abbreviation: owned(entry.USFM_abbreviation),
+            USFM_number_str: owned(entry.USFM_number_str),
+            USX_number_str: owned(entry.USX_number_str),
+            Unbound_Code: owned(entry.Unbound_Code),
+            Bibledit_number: entry.Bibledit_number,
+            Logos_number: entry.Logos_number,
+            Logos_abbreviation: owned(entry.Logos_abbreviation),
+            NET_Bible_abbreviation: owned(entry.NET_Bible_abbreviation),
+            Drupal_Bible_abbreviation: owned(entry.Drupal_Bible_abbreviation),
+            Bible_Works_abbreviation: owned(entry.Bible_Works_abbreviation),
+            Byzantine_abbreviation: owned(entry.Byzantine_abbreviation),
+            possible_alternative_abbreviations: entry.possible_alternative_abbreviations.iter().map(|a| a.to_string()).collect(),
+            possible_alternative_books_codes: entry.possible_alternative_books_codes.iter().map(|c| c.to_string()).collect(),
+            consists_of_books_codes: owned(entry.consists_of_books_codes),
+            typical_section: owned(entry.typical_section),
+            typical_subsection: owned(entry.typical_subsection),
+            all_English_derived_abbreviations: entry.all_English_derived_abbreviations.to_string(),
+        }
+    }
+}
+
+
+/// A table of books codes with its lookup maps (like BIBLE_BOOKS_CODES_ARRAY and its phf maps),
+///     but which can be loaded at runtime, e.g., to add local books without rebuilding the crate.
+#[derive(Clone, Debug)]
+pub struct BooksCodesTable {
+    entries: Vec<BooksCodesTableEntry>,
+    // These maps contain the index of the entry in the above vector
+    reference_abbreviation_map: HashMap<String, usize>,
+    usfm_abbreviation_map: HashMap<String, usize>,
+    uppercase_usfm_abbreviation_map: HashMap<String, usize>,
+    osis_abbreviation_map: HashMap<String, usize>,
+    english_name_map: HashMap<String, usize>,
+    original_language_name_map: HashMap<String, usize>,
+}
+
+impl BooksCodesTable {
+    /// Returns the table compiled into the crate (i.e., BIBLE_BOOKS_CODES_ARRAY).
+    pub fn builtin() -> BooksCodesTable {
+        BooksCodesTable::from_entries(BIBLE_BOOKS_CODES_ARRAY.iter().map(BooksCodesTableEntry::from).collect())
+            .expect("The built-in table has already been checked by build.rs")
+    }
+
+    /// Makes the lookup maps for the entries (in the same way as build.rs does for the static maps),
+    ///     i.e., if a USFM or OSIS code or a name is used more than once, the first entry is found.
+    /// The English names are the English-derived abbreviations and then (where they're not already used)
+    ///     the uppercased alternative abbreviations, also without their spaces, e.g., "1 SML" and "1SML"
+    ///     (so that overlay entries can just add alternative abbreviations).
+    pub fn from_entries(entries: Vec<BooksCodesTableEntry>) -> Result<BooksCodesTable, TableLoadError> {
+        let mut table = BooksCodesTable {
+            entries: Vec::new(),
+            reference_abbreviation_map: HashMap::new(),
+            usfm_abbreviation_map: HashMap::new(),
+            uppercase_usfm_abbreviation_map: HashMap::new(),
+            osis_abbreviation_map: HashMap::new(),
+            english_name_map: HashMap::new(),
+            original_language_name_map: HashMap::new(),
+        };
+        for (n, entry) in entries.iter().enumerate() {
+            if table.reference_abbreviation_map.insert(entry.BOS_book_code.clone(), n).is_some() {
+                return Err(TableLoadError::DuplicateBookCode(entry.BOS_book_code.clone()));
+            }
+            if let Some(usfm_abbreviation) = &entry.USFM_abbreviation {
+                table.usfm_abbreviation_map.entry(usfm_abbreviation.clone()).or_insert(n);
+                table.uppercase_usfm_abbreviation_map.entry(usfm_abbreviation.to_uppercase()).or_insert(n);
+            }
+            if let Some(osis_abbreviation) = &entry.OSIS_abbreviation {
+                table.osis_abbreviation_map.entry(osis_abbreviation.clone()).or_insert(n);
+            }
+            if !["(None)", "(Unknown)"].contains(&entry.original_language_code.as_str()) && !entry.original_language_book_name.starts_with('(') {
+                // e.g., ESC has the same Hebrew name as EST
+                table.original_language_name_map.entry(normalise_original_language_name(&entry.original_language_book_name)).or_insert(n);
+            }
+        }
+        // The alternative abbreviations are added afterwards so that they don't hide any other book's derived abbreviations
+        //   (which build.rs has already chosen between), e.g., "3ESDR" is for EZ5 although LES has it as an alternative
+        for (n, entry) in entries.iter().enumerate() {
+            for english_name in entry.all_English_derived_abbreviations.split(',').filter(|name| !name.is_empty()) {
+                table.english_name_map.entry(english_name.to_string()).or_insert(n);
+            }
+        }
+        for (n, entry) in entries.iter().enumerate() {
+            for english_name in english_names(entry) {
+                table.english_name_map.entry(english_name).or_insert(n);
+            }
+        }
+        table.entries = entries;
+        Ok(table)
+    }
+
+    /// Loads a table in the layout written by exportDataToTSV() in BibleBooksCodesConverter.py
+    ///     (e.g., test_data/BibleBooksCodes_Tables_python_output.tsv) or by export::write_tsv().
+    /// The columns are found by their header, so only the compulsory ones need to be included,
+    ///     and if allEnglishDerivedAbbreviations is empty, the book can still be found by its BOS book code.
+    pub fn from_tsv(tsv_text: &str) -> Result<BooksCodesTable, TableLoadError> {
+        let mut lines = tsv_text.trim_start_matches('\u{feff}').lines().enumerate();
+        let headers: Vec<&str> = lines.next().map(|(_, line)| line.trim_end().split('\t').collect()).unwrap_or_default();
+        if let Some(missing_column) = COMPULSORY_TSV_COLUMNS.iter().find(|column| !headers.contains(column)) {
+            return Err(TableLoadError::MissingColumn(missing_column.to_string()));
+        }
+
+        let mut entries = Vec::new();
+        for (n, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+            let line_number = n + 1;
+            let fields: Vec<&str> = line.trim_end_matches(['\r', '\n']).split('\t').collect();
+            if fields.len() != headers.len() {
+                return Err(TableLoadError::WrongColumnCount { line_number, expected: headers.len(), found: fields.len() });
+            }
+            let get = |column: &str| headers.iter().position(|&header| header == column).map_or("", |index| fields[index]);
+            let optional = |column: &str| Some(get(column)).filter(|value| !value.is_empty()).map(str::to_string);
+            let invalid = |column: &str| TableLoadError::InvalidValue { line_number, column: column.to_string(), value: get(column).to_string() };
+            let number = |column: &str| get(column).parse::<u16>().map_err(|_| invalid(column));
+            let optional_number = |column: &str| match get(column) {
+                "" => Ok(None),
+                value => value.parse::<u16>().map(Some).map_err(|_| invalid(column)),
+            };
+            let list = |column: &str| get(column).split(',').filter(|value| !value.is_empty()).map(str::to_string).collect::<Vec<_>>();
+
+            let expected_chapters = get("expectedChapters");
+            let expected_num_chapters = parse_expected_chapters(expected_chapters).ok_or_else(|| invalid("expectedChapters"))?;
+            let chapter_structure = match get("chapterStructure") {
+                "units" => Some(ChapterStructure::Units),
+                "continuous" => Some(ChapterStructure::Continuous),
+                "" => default_chapter_structure(expected_num_chapters),
+                _ => return Err(invalid("chapterStructure")),
+            };
+            let bos_book_code = get("BOSReferenceAbbreviation").to_string();
+            let all_English_derived_abbreviations = match get("allEnglishDerivedAbbreviations") {
+                "" => bos_book_code.clone(),
+                abbreviations => abbreviations.to_string(),
+            };
+            entries.push(BooksCodesTableEntry {
+                original_language_code: get("originalLanguageCode").to_string(),
+                original_language_book_name: get("bookName").to_string(),
+                book_name_English_guide: get("bookNameEnglishGuide").to_string(),
+                BOS_reference_number: number("BOSReferenceNumber")?,
+                BOS_sequence_number: number("BOSSequenceNumber")?,
+                expected_num_chapters,
+                chapter_structure,
+                short_abbreviation: optional("shortAbbreviation"),
+                SBL_abbreviation: optional("SBLAbbreviation"),
+                OSIS_abbreviation: optional("OSISAbbreviation"),
+                Sword_abbreviation: optional("SwordAbbreviation"),
+                CCEL_number: optional_number("CCELNumber")?,
+                USFM_abbreviation: optional("USFMAbbreviation"),
+                USFM_number_str: optional("USFMNumber"),
+                USX_number_str: optional("USXNumber"),
+                Unbound_Code: optional("UnboundCode"),
+                Bibledit_number: optional_number("BibleditNumber")?,
+                Logos_number: optional_number("LogosNumber")?,
+                Logos_abbreviation: optional("LogosAbbreviation"),
+                NET_Bible_abbreviation: optional("NETBibleAbbreviation"),
+                Drupal_Bible_abbreviation: optional("DrupalBibleAbbreviation"),
+                Bible_Works_abbreviation: optional("BibleWorksAbbreviation"),
+                Byzantine_abbreviation: optional("ByzantineAbbreviation"),
+                possible_alternative_abbreviations: list("possibleAlternativeAbbreviations"),
+                possible_alternative_books_codes: list("possibleAlternativeBooksCodes"),
+                consists_of_books_codes: optional("consistsOfBooks"),
+                typical_section: optional("typicalSection"),
+                typical_subsection: optional("typicalSubsection"),
+                all_English_derived_abbreviations,
+                BOS_book_code: bos_book_code,
+            });
+        }
+        BooksCodesTable::from_entries(entries)
+    }
+
+    /// Loads the derivedFormats/BibleBooksCodes_Tables.json file (as exported by BibleBooksCodesConverter.py).
+    /// The JSON doesn't include every column (e.g., there's no Logos abbreviation,
+    ///     alternative abbreviations, consists of, typical subsection, or chapter structure),
+    ///     and the sequence numbers are just the position in its sequenceList.
+    /// The BibleWorks abbreviations come from its BibleWorksAbbreviationDict,
+    ///     and the chapter structures from the builtin table (so PSA and LAM are still Units)
+    ///     except for books which aren't in it.
+    pub fn from_json(json_text: &str) -> Result<BooksCodesTable, TableLoadError> {
+        use serde_json::Value;
+
+        let json: Value = serde_json::from_str(json_text).map_err(|e| TableLoadError::Json(e.to_string()))?;
+        let object = |key: &str| json.get(key).and_then(Value::as_object).ok_or_else(|| TableLoadError::Json(format!("no {key} object")));
+        let books = object("referenceAbbreviationDict")?;
+        let sequence_list = json.get("sequenceList").and_then(Value::as_array).ok_or_else(|| TableLoadError::Json("no sequenceList array".to_string()))?;
+        // Newer exports call this allEnglishAbbreviationsDict
+        let all_abbreviations = object("allEnglishAbbreviationsDict").or_else(|_| object("allAbbreviationsDict"))?;
+        let mut all_English_derived_abbreviations: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (abbreviation, bos_book_code) in all_abbreviations {
+            if let Some(bos_book_code) = bos_book_code.as_str() {
+                all_English_derived_abbreviations.entry(bos_book_code).or_default().push(abbreviation);
+            }
+        }
+
+        // The BibleWorks abbreviations are only in the dict, e.g., "GEN": [1, "GEN"] (or with a list of books if they share it)
+        let mut Bible_Works_abbreviations: HashMap<&str, &str> = HashMap::new();
+        for (abbreviation, books_value) in object("BibleWorksAbbreviationDict")? {
+            match books_value.get(1) {
+                Some(Value::Array(bos_book_codes)) => {
+                    for bos_book_code in bos_book_codes.iter().filter_map(Value::as_str) {
+                        Bible_Works_abbreviations.insert(bos_book_code, abbreviation);
+                    }
+                }
+                Some(Value::String(bos_book_code)) => {
+                    Bible_Works_abbreviations.insert(bos_book_code, abbreviation);
+                }
+                _ => return Err(TableLoadError::Json(format!("BibleWorksAbbreviationDict has {abbreviation}: {books_value}"))),
+            }
+        }
+
+        let mut entries = Vec::new();
+        for (n, bos_book_code) in sequence_list.iter().enumerate() {
+            let bos_book_code = bos_book_code.as_str().ok_or_else(|| TableLoadError::Json(format!("sequenceList has {bos_book_code}")))?;
+            let book = books.get(bos_book_code).ok_or_else(|| TableLoadError::Json(format!("sequenceList has unknown {bos_book_code}")))?;
+            let invalid = |key: &str| TableLoadError::Json(format!("{bos_book_code} has invalid {key} {}", book.get(key).unwrap_or(&Value::Null)));
+            let text = |key: &str| book.get(key).and_then(Value::as_str).map(str::to_string);
+            let compulsory_text = |key: &str| text(key).ok_or_else(|| invalid(key));
+            let optional_number = |key: &str| match text(key) {
+                None => Ok(None),
+                Some(value) => value.parse::<u16>().map(Some).map_err(|_| invalid(key)),
+            };
+
+            let expected_num_chapters = parse_expected_chapters(&text("numExpectedChapters").unwrap_or_default()).ok_or_else(|| invalid("numExpectedChapters"))?;
+            let mut abbreviations = all_English_derived_abbreviations.remove(bos_book_code).unwrap_or_default();
+            if !abbreviations.contains(&bos_book_code) {
+                abbreviations.push(bos_book_code);
+            }
+            abbreviations.sort_unstable();
+            entries.push(BooksCodesTableEntry {
+                original_language_code: compulsory_text("originalLanguageCode")?,
+                original_language_book_name: compulsory_text("bookName")?,
+                book_name_English_guide: compulsory_text("bookNameEnglishGuide")?,
+                BOS_book_code: bos_book_code.to_string(),
+                BOS_reference_number: book.get("referenceNumber").and_then(Value::as_u64).and_then(|number| u16::try_from(number).ok())
+                    .ok_or_else(|| invalid("referenceNumber"))?,
+                BOS_sequence_number: u16::try_from(n + 1).map_err(|_| TableLoadError::Json("sequenceList is too long".to_string()))?,
+                expected_num_chapters,
+                chapter_structure: crate::chapter_structure(bos_book_code).unwrap_or_else(|_| default_chapter_structure(expected_num_chapters)),
+                short_abbreviation: text("shortAbbreviation"),
+                SBL_abbreviation: text("SBLAbbreviation"),
+                OSIS_abbreviation: text("OSISAbbreviation"),
+                Sword_abbreviation: text("SwordAbbreviation"),
+                CCEL_number: optional_number("CCELNumberString")?,
+                USFM_abbreviation: text("USFMAbbreviation"),
+                USFM_number_str: text("USFMNumberString"),
+                USX_number_str: text("USXNumberString"),
+                Unbound_Code: text("UnboundCodeString"),
+                Bibledit_number: optional_number("BibleditNumberString")?,
+                Logos_number: optional_number("LogosNumberString")?,
+                Logos_abbreviation: None,
+                NET_Bible_abbreviation: text("NETBibleAbbreviation"),
+                Drupal_Bible_abbreviation: text("DrupalBibleAbbreviation"),
+                Bible_Works_abbreviation: Bible_Works_abbreviations.get(bos_book_code).map(|abbreviation| abbreviation.to_string()),
+                Byzantine_abbreviation: text("ByzantineAbbreviation"),
+                possible_alternative_abbreviations: Vec::new(),
+                possible_alternative_books_codes: book.get("possibleAlternativeBooks").and_then(Value::as_array)
+                    .map(|codes| codes.iter().filter_map(Value::as_str).map(str::to_string).collect()).unwrap_or_default(),
+                consists_of_books_codes: None,
+                typical_section: text("typicalSection"),
+                typical_subsection: None,
+                all_English_derived_abbreviations: abbreviations.join(","),
+            });
+        }
+        if entries.len() != books.len() {
+            return Err(TableLoadError::Json(format!("sequenceList has {} books but referenceAbbreviationDict has {}", entries.len(), books.len())));
+        }
+        BooksCodesTable::from_entries(entries)
+    }
+
+    /// Loads a .tsv or .json table file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<BooksCodesTable, TableLoadError> {
+        let path = path.as_ref();
+        match path.extension().and_then(|extension| extension.to_str()).map(str::to_lowercase).as_deref() {
+            Some("tsv") => BooksCodesTable::from_tsv(&fs::read_to_string(path)?),
+            Some("json") => BooksCodesTable::from_json(&fs::read_to_string(path)?),
+            _ => Err(TableLoadError::UnknownFileType(path.display().to_string())),
+        }
+    }
+
+    /// Returns a new table with the overlay entries merged over this one's,
+    ///     e.g., BooksCodesTable::builtin().with_overlay(local_entries).
+    /// An overlay entry with the BOS book code of an existing entry replaces it (in the same position),
+    ///     otherwise it's added at the end. All of the lookup maps are rebuilt.
+    /// It's an error if an overlay entry uses a USFM (in any case) or OSIS code or an English name (see from_entries())
+    ///     that another book uses (unless it's an existing entry that already shared that code, like EZR and EZN).
+    pub fn with_overlay(&self, overlay: impl IntoIterator<Item = BooksCodesTableEntry>) -> Result<BooksCodesTable, TableLoadError> {
+        let mut entries = self.entries.clone();
+        let mut overlaid_indexes = Vec::new();
+        for entry in overlay {
+            if overlaid_indexes.iter().any(|&n: &usize| entries[n].BOS_book_code == entry.BOS_book_code) {
+                return Err(TableLoadError::DuplicateBookCode(entry.BOS_book_code));
+            }
+            match self.reference_abbreviation_map.get(&entry.BOS_book_code) {
+                Some(&n) => {
+                    entries[n] = entry;
+                    overlaid_indexes.push(n);
+                }
+                None => {
+                    overlaid_indexes.push(entries.len());
+                    entries.push(entry);
+                }
+            }
+        }
+
+        for system in ["USFM", "OSIS", "English name"] {
+            let codes: Vec<Vec<String>> = entries.iter().map(|entry| overlay_conflict_codes(system, entry)).collect();
+            let existing_codes: Vec<Vec<String>> = self.entries.iter().map(|entry| overlay_conflict_codes(system, entry)).collect();
+            // Sharing which is already in this table (like EZR and EZN) isn't a conflict
+            let already_had = |bos_book_code: &str, code: &String| {
+                self.reference_abbreviation_map.get(bos_book_code).is_some_and(|&m| existing_codes[m].contains(code))
+            };
+            for &n in &overlaid_indexes {
+                let entry = &entries[n];
+                for code in &codes[n] {
+                    let conflicting_entry = entries.iter().enumerate().find(|&(m, other)| {
+                        m != n && codes[m].contains(code) && !(already_had(&entry.BOS_book_code, code) && already_had(&other.BOS_book_code, code))
+                    });
+                    if let Some((_, other)) = conflicting_entry {
+                        let bos_book_codes = [entry.BOS_book_code.clone(), other.BOS_book_code.clone()];
+                        return Err(TableLoadError::CodeConflict { system, code: code.clone(), bos_book_codes });
+                    }
+                }
+            }
+        }
+        BooksCodesTable::from_entries(entries)
+    }
+
+    /// Returns the entries, e.g., to use a table loaded from a file as an overlay.
+    #[inline]
+    pub fn into_entries(self) -> Vec<BooksCodesTableEntry> {
+        self.entries
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns all the entries (in the order that they were loaded).
+    #[inline]
+    pub fn entries(&self) -> &[BooksCodesTableEntry] {
+        &self.entries
+    }
+
+    /// Returns the full table entry for the book.
+    #[inline]
+    pub fn entry(&self, bos_book_code: &str) -> Option<&BooksCodesTableEntry> {
+        Some(&self.entries[*self.reference_abbreviation_map.get(bos_book_code)?])
+    }
+
+    #[inline]
+    fn entry_or_error<'a>(&self, bos_book_code: &'a str) -> Result<&BooksCodesTableEntry, LookupError<'a>> {
+        self.entry(bos_book_code)
+            .ok_or_else(|| LookupError::not_found(CodeSystem::Bos, bos_book_code, self.reference_abbreviation_map.keys().map(String::as_str)))
+    }
+
+    #[inline]
+    pub fn is_valid_bos_book_code(&self, bos_book_code: &str) -> bool {
+        self.reference_abbreviation_map.contains_key(bos_book_code)
+    }
+
+    #[inline]
+    pub fn bos_book_code_to_usfm_abbrev<'a>(&self, bos_book_code: &'a str) -> Conversion<'a, &str> {
+        self.entry_or_error(bos_book_code)?.USFM_abbreviation.as_deref().ok_or_else(|| LookupError::no_equivalent(CodeSystem::Usfm, bos_book_code))
+    }
+
+    #[inline]
+    pub fn usfm_abbrev_to_bos_book_code<'a>(&self, usfm_abbreviation: &'a str) -> Conversion<'a, &str> {
+        let array_index = self.usfm_abbreviation_map.get(usfm_abbreviation)
+            .or_else(|| self.uppercase_usfm_abbreviation_map.get(usfm_abbreviation))
+            .ok_or_else(|| LookupError::not_found(CodeSystem::Usfm, usfm_abbreviation, self.usfm_abbreviation_map.keys().map(String::as_str)))?;
+        Ok(&self.entries[*array_index].BOS_book_code)
+    }
+
+    #[inline]
+    pub fn osis_book_code_to_bos_book_code<'a>(&self, osis_book_code: &'a str) -> Conversion<'a, &str> {
+        let array_index = self.osis_abbreviation_map.get(osis_book_code)
+            .ok_or_else(|| LookupError::not_found(CodeSystem::Osis, osis_book_code, self.osis_abbreviation_map.keys().map(String::as_str)))?;
+        Ok(&self.entries[*array_index].BOS_book_code)
+    }
+
+    pub fn english_name_to_bos_book_code<'a>(&self, english_name: &'a str) -> Conversion<'a, &str> {
+        let array_index = find_english_name(english_name, |name| self.english_name_map.get(name).copied())
+            .ok_or_else(|| LookupError::not_found(CodeSystem::EnglishName, english_name, self.english_name_map.keys().map(String::as_str)))?;
+        Ok(&self.entries[array_index].BOS_book_code)
+    }
+
+    /// Tries to find the book from its original language (Hebrew or Greek) name (see normalise_original_language_name()).
+    pub fn original_language_name_to_bos_book_code<'a>(&self, original_language_name: &'a str) -> Conversion<'a, &str> {
+        let array_index = *self.original_language_name_map.get(&normalise_original_language_name(original_language_name)).ok_or_else(|| {
+            let known_names = self.original_language_name_map.keys().map(String::as_str);
+            LookupError::not_found(CodeSystem::OriginalLanguageName, original_language_name, known_names)
+        })?;
+        Ok(&self.entries[array_index].BOS_book_code)
+    }
+
+    #[inline]
+    pub fn chapter_structure<'a>(&self, bos_book_code: &'a str) -> Result<Option<ChapterStructure>, LookupError<'a>> {
+        Ok(self.entry_or_error(bos_book_code)?.chapter_structure)
+    }
+
+    #[inline]
+    pub fn continues_through_chapters<'a>(&self, bos_book_code: &'a str) -> Result<bool, LookupError<'a>> {
+        Ok(self.chapter_structure(bos_book_code)? != Some(ChapterStructure::Units))
+    }
+}
+
+
+/// Parses "" or "50" or "150,151" (returning None if it's invalid).
+fn parse_expected_chapters(expected_chapters: &str) -> Option<OptionalNumberOrTwoNumbers> {
+    if expected_chapters.is_empty() {
+        return Some(OptionalNumberOrTwoNumbers::None);
+    }
+    let numbers = expected_chapters.split(',').map(str::parse::<u16>).collect::<Result<Vec<_>, _>>().ok()?;
+    match *numbers.as_slice() {
+        [num_chapters] => Some(OptionalNumberOrTwoNumbers::Number(num_chapters)),
+        [usual_num_chapters, alternative_num_chapters] => Some(OptionalNumberOrTwoNumbers::TwoNumbers([usual_num_chapters, alternative_num_chapters])),
+        _ => None,
+    }
+}
+
+/// NOTE: This matches build.rs, i.e., books with chapters are continuous unless the data says otherwise
+fn default_chapter_structure(expected_num_chapters: OptionalNumberOrTwoNumbers) -> Option<ChapterStructure> {
+    match expected_num_chapters {
+        OptionalNumberOrTwoNumbers::None => None,
+        _ => Some(ChapterStructure::Continuous),
+    }
+}
+
+/// Returns the names which english_name_map finds the entry by (see from_entries()).
+fn english_names(entry: &BooksCodesTableEntry) -> Vec<String> {
+    let mut english_names: Vec<String> =
+        entry.all_English_derived_abbreviations.split(',').filter(|name| !name.is_empty()).map(str::to_string).collect();
+    for alternative_abbreviation in entry.possible_alternative_abbreviations.iter().map(|abbreviation| abbreviation.to_uppercase()) {
+        let unspaced_abbreviation = alternative_abbreviation.replace(' ', "");
+        for name in [alternative_abbreviation, unspaced_abbreviation] {
+            if !name.is_empty() && !english_names.contains(&name) {
+                english_names.push(name);
+            }
+        }
+    }
+    english_names
+}
+
+/// Returns the codes which mustn't be used by two different books
+///     (USFM lookups fall back to uppercase so those are compared in uppercase).
+fn overlay_conflict_codes(system: &str, entry: &BooksCodesTableEntry) -> Vec<String> {
+    match system {
+        "USFM" => entry.USFM_abbreviation.iter().map(|abbreviation| abbreviation.to_uppercase()).collect(),
+        "OSIS" => entry.OSIS_abbreviation.iter().cloned().collect(),
+        _ => english_names(entry),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bos_book_code_to_usfm_abbrev, english_name_to_bos_book_code, original_language_name_to_bos_book_code, osis_book_code_to_bos_book_code,
+        test_support::TABLES_JSON_FILEPATH, usfm_abbrev_to_bos_book_code, BookCode,
+    };
+
+    const TEST_TSV: &str = "originalLanguageCode\tbookName\tbookNameEnglishGuide\tBOSReferenceAbbreviation\tBOSReferenceNumber\tBOSSequenceNumber\texpectedChapters\tUSFMAbbreviation\tOSISAbbreviation\tallEnglishDerivedAbbreviations
+hbo\tבְּרֵאשִׁית\tGenesis\tGEN\t1\t20\t50\tGen\tGen\tGEN,GENESIS
+(None)\t(None)\tLocal study notes\tXXA\t901\t999\t\tXXA\t\t
+";
+
+    /// The built-in table should give exactly the same results as the static functions
+    #[test]
+    fn test_builtin() {
+        let table = BooksCodesTable::builtin();
+        assert_eq!(table.len(), BIBLE_BOOKS_CODES_ARRAY.len());
+        for entry in BIBLE_BOOKS_CODES_ARRAY.iter() {
+            let bos_book_code = entry.BOS_book_code;
+            assert_eq!(table.bos_book_code_to_usfm_abbrev(bos_book_code), bos_book_code_to_usfm_abbrev(bos_book_code));
+            if let Some(usfm_abbreviation) = entry.USFM_abbreviation {
+                assert_eq!(table.usfm_abbrev_to_bos_book_code(usfm_abbreviation), usfm_abbrev_to_bos_book_code(usfm_abbreviation));
+                let uppercase_usfm_abbreviation = usfm_abbreviation.to_uppercase();
+                assert_eq!(table.usfm_abbrev_to_bos_book_code(&uppercase_usfm_abbreviation), usfm_abbrev_to_bos_book_code(&uppercase_usfm_abbreviation));
+            }
+            if let Some(osis_abbreviation) = entry.OSIS_abbreviation {
+                assert_eq!(table.osis_book_code_to_bos_book_code(osis_abbreviation), osis_book_code_to_bos_book_code(osis_abbreviation));
+            }
+            for english_name in entry.all_English_derived_abbreviations.split(',') {
+                assert_eq!(table.english_name_to_bos_book_code(english_name), english_name_to_bos_book_code(english_name));
+            }
+            let book_name = entry.original_language_book_name;
+            assert_eq!(table.original_language_name_to_bos_book_code(book_name), original_language_name_to_bos_book_code(book_name));
+            assert_eq!(table.chapter_structure(bos_book_code), crate::chapter_structure(bos_book_code));
+        }
+        assert_eq!(table.english_name_to_bos_book_code("1.Cor"), Ok("CO1"));
+        assert!(matches!(table.bos_book_code_to_usfm_abbrev("XyZ"), Err(LookupError::Unknown { system: CodeSystem::Bos, .. })));
+    }
+
+    #[test]
+    fn test_from_tsv() {
+        let table = BooksCodesTable::from_tsv(TEST_TSV).unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.usfm_abbrev_to_bos_book_code("XXA"), Ok("XXA"));
+        assert_eq!(table.usfm_abbrev_to_bos_book_code("GEN"), Ok("GEN"));
+        assert_eq!(table.english_name_to_bos_book_code("genesis"), Ok("GEN"));
+        assert_eq!(table.english_name_to_bos_book_code("xxa"), Ok("XXA"));
+        assert_eq!(table.original_language_name_to_bos_book_code("בראשית"), Ok("GEN"));
+        assert_eq!(table.entry("GEN").unwrap().expected_num_chapters, OptionalNumberOrTwoNumbers::Number(50));
+        assert_eq!(table.chapter_structure("XXA"), Ok(None));
+        assert!(matches!(table.osis_book_code_to_bos_book_code("XXA"), Err(LookupError::Unknown { system: CodeSystem::Osis, .. })));
+        assert!(!table.is_valid_bos_book_code("EXO"));
+
+        assert!(matches!(BooksCodesTable::from_tsv("BOSReferenceAbbreviation\n"), Err(TableLoadError::MissingColumn(c)) if c == "originalLanguageCode"));
+        assert!(matches!(
+            BooksCodesTable::from_tsv(&TEST_TSV.replace("\t901\t", "\tx\t")),
+            Err(TableLoadError::InvalidValue { line_number: 3, column, .. }) if column == "BOSReferenceNumber"
+        ));
+        assert!(matches!(
+            BooksCodesTable::from_tsv(&TEST_TSV.replace("\t50\t", "\t50\tExtra\t")),
+            Err(TableLoadError::WrongColumnCount { line_number: 2, expected: 10, found: 11 })
+        ));
+        assert!(matches!(BooksCodesTable::from_tsv(&TEST_TSV.replace("\tXXA\t901", "\tGEN\t901")), Err(TableLoadError::DuplicateBookCode(k)) if k == "GEN"));
+    }
+
+    /// test_data/BibleBooksCodes_Tables_python_output.tsv is some of the rows written by the Python exportDataToTSV()
+    #[test]
+    fn test_from_python_tsv() {
+        let table = BooksCodesTable::from_file("test_data/BibleBooksCodes_Tables_python_output.tsv").unwrap();
+        assert_eq!(table.len(), 9);
+        for entry in table.entries() {
+            let builtin_entry = BooksCodesTable::builtin().entry(&entry.BOS_book_code).cloned().unwrap();
+            assert_eq!(entry.BOS_reference_number, builtin_entry.BOS_reference_number);
+            assert_eq!(entry.expected_num_chapters, builtin_entry.expected_num_chapters, "{}", entry.BOS_book_code);
+            assert_eq!(entry.chapter_structure, builtin_entry.chapter_structure, "{}", entry.BOS_book_code);
+            assert_eq!(entry.USFM_abbreviation, builtin_entry.USFM_abbreviation);
+            assert_eq!(entry.OSIS_abbreviation, builtin_entry.OSIS_abbreviation);
+        }
+        assert_eq!(table.usfm_abbrev_to_bos_book_code("1Co"), Ok("CO1"));
+        assert_eq!(table.osis_book_code_to_bos_book_code("1Sam"), Ok("SA1"));
+        assert_eq!(table.english_name_to_bos_book_code("1 Moses"), Ok("GEN"));
+        assert_eq!(table.english_name_to_bos_book_code("Cant"), Ok("SNG"));
+        assert_eq!(table.entry("PSA").unwrap().possible_alternative_books_codes, ["PB1", "PB2", "PB3", "PB4", "PB5"]);
+        assert_eq!(table.entry("FRT").unwrap().typical_section.as_deref(), Some("FRT"));
+        assert!(table.entry("GEN").unwrap().BOS_sequence_number < table.entry("SA1").unwrap().BOS_sequence_number);
+    }
+
+    #[test]
+    fn test_with_overlay() {
+        let builtin = BooksCodesTable::builtin();
+        let mut genesis = builtin.entry("GEN").unwrap().clone();
+        genesis.possible_alternative_abbreviations.push("Be Reshit".to_string());
+        let mut local = BooksCodesTable::from_tsv(&TEST_TSV.replace("XXA", "ZZA")).unwrap().into_entries();
+        local.retain(|entry| entry.BOS_book_code == "ZZA");
+        let table = builtin.with_overlay(local.into_iter().chain([genesis])).unwrap();
+        assert_eq!(table.len(), builtin.len() + 1);
+        assert_eq!(table.entries()[0].BOS_book_code, builtin.entries()[0].BOS_book_code);
+        assert_eq!(table.english_name_to_bos_book_code("Bereshit"), Ok("GEN"));
+        assert_eq!(table.english_name_to_bos_book_code("be reshit"), Ok("GEN"));
+        assert_eq!(table.usfm_abbrev_to_bos_book_code("ZZA"), Ok("ZZA"));
+        assert_eq!(table.bos_book_code_to_usfm_abbrev("ZZA"), Ok("ZZA"));
+        assert_eq!(table.osis_book_code_to_bos_book_code("Exod"), Ok("EXO"));
+
+        // Books which already shared a code can still be overridden
+        let ezra = builtin.entry("EZR").unwrap().clone();
+        assert!(builtin.with_overlay([ezra]).is_ok());
+
+        let mut clash = builtin.entry("GEN").unwrap().clone();
+        clash.BOS_book_code = "ZZB".to_string();
+        clash.OSIS_abbreviation = None;
+        clash.USFM_abbreviation = Some("gen".to_string());
+        assert!(matches!(
+            builtin.with_overlay([clash.clone()]),
+            Err(TableLoadError::CodeConflict { system: "USFM", code, bos_book_codes: [new_book, existing_book] })
+                if code == "GEN" && new_book == "ZZB" && existing_book == "GEN"
+        ));
+        clash.USFM_abbreviation = None;
+        clash.OSIS_abbreviation = Some("Exod".to_string());
+        assert!(matches!(builtin.with_overlay([clash.clone()]), Err(TableLoadError::CodeConflict { system: "OSIS", .. })));
+        clash.OSIS_abbreviation = None;
+        clash.all_English_derived_abbreviations = "ZZB".to_string();
+        clash.possible_alternative_abbreviations = vec!["Exodus".to_string()];
+        assert!(matches!(
+            builtin.with_overlay([clash.clone()]),
+            Err(TableLoadError::CodeConflict { system: "English name", code, bos_book_codes: [new_book, existing_book] })
+                if code == "EXODUS" && new_book == "ZZB" && existing_book == "EXO"
+        ));
+        clash.possible_alternative_abbreviations.clear();
+        assert!(builtin.with_overlay([clash.clone()]).is_ok());
+        assert!(matches!(builtin.with_overlay([clash.clone(), clash]), Err(TableLoadError::DuplicateBookCode(k)) if k == "ZZB"));
+    }
+
+    /// derivedFormats/BibleBooksCodes_Tables.json is as written by the Python exportDataToJSON()
+    #[test]
+    fn test_from_json() {
+        // (It's not in the packaged crate)
+        let Ok(json_text) = std::fs::read_to_string(TABLES_JSON_FILEPATH) else {
+            return;
+        };
+        let table = BooksCodesTable::from_json(&json_text).unwrap();
+        assert!(table.len() > 250);
+        assert_eq!(table.usfm_abbrev_to_bos_book_code("1Co"), Ok("CO1"));
+        assert_eq!(table.osis_book_code_to_bos_book_code("Exod"), Ok("EXO"));
+        assert_eq!(table.english_name_to_bos_book_code("Gen"), Ok("GEN"));
+        assert_eq!(table.entry("PSA").unwrap().expected_num_chapters, OptionalNumberOrTwoNumbers::TwoNumbers([150, 151]));
+        assert!(table.entry("MAL").unwrap().BOS_sequence_number < table.entry("MAT").unwrap().BOS_sequence_number);
+        // It was written from an older BibleBooksCodes.xml (see test_support::EXPECTED_DIFFERENCE_PATHS)
+        assert_eq!(table.entry("BAR").unwrap().expected_num_chapters, OptionalNumberOrTwoNumbers::TwoNumbers([5, 6]));
+        // The chapter structures (which aren't in the JSON) agree with the builtin table
+        assert_eq!(table.continues_through_chapters("PSA"), Ok(false));
+        assert_eq!(table.continues_through_chapters("LAM"), Ok(false));
+        assert_eq!(table.continues_through_chapters("GEN"), Ok(true));
+        for entry in BIBLE_BOOKS_CODES_ARRAY.iter() {
+            assert_eq!(table.chapter_structure(entry.BOS_book_code), Ok(entry.chapter_structure), "{}", entry.BOS_book_code);
+        }
+        assert_eq!(table.entry("GEN").unwrap().Bible_Works_abbreviation.as_deref(), Some("GEN"));
+        assert_eq!(table.entry("CO1").unwrap().Bible_Works_abbreviation.as_deref(), BookCode::new("CO1").unwrap().entry().Bible_Works_abbreviation);
+    }
+}
@@ -0,0 +1,226 @@
+// Test support: checks that derivedFormats/BibleBooksCodes_Tables.json (which other tools also produce and use)
+//   hasn't drifted from BIBLE_BOOKS_CODES_ARRAY and the phf maps which build.rs creates from sourceXML.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::{
+    export::write_json, BIBLE_BOOKS_CODES_ARRAY, ENGLISH_NAME_MAP, OSIS_ABBREVIATION_MAP, UPPERCASE_USFM_ABBREVIATION_MAP,
+    USFM_ABBREVIATION_MAP,
+};
+
+
+pub(crate) const TABLES_JSON_FILEPATH: &str = "../../derivedFormats/BibleBooksCodes_Tables.json";
+
+/// The known differences between the JSON (written by BibleBooksCodesConverter.py from an older BibleBooksCodes.xml)
+///     and what export::write_json() writes from the current XML
+const EXPECTED_DIFFERENCE_PATHS: &[&str] = &[
+    // The XML now gives the English names first (the JSON has "קֹהֶלֶת / Qoholeth" and "Strong's Database")
+    "/referenceNumberDict/21/bookNameEnglishGuide",
+    "/referenceAbbreviationDict/ECC/bookNameEnglishGuide",
+    "/EnglishNameDict/ECCLESIASTES / QOHOLETH",
+    "/EnglishNameDict/קֹהֶלֶת / QOHOLETH",
+    "/referenceNumberDict/888/bookNameEnglishGuide",
+    "/referenceAbbreviationDict/STR/bookNameEnglishGuide",
+    "/EnglishNameDict/STRONGS DATABASE / STRONG'S DATABASE",
+    "/EnglishNameDict/STRONG'S DATABASE",
+    // The XML has no space in the language list (the JSON has "hbo, el-x-koine")
+    "/referenceNumberDict/72/originalLanguageCode",
+    "/referenceAbbreviationDict/ESC/originalLanguageCode",
+    "/referenceNumberDict/75/originalLanguageCode",
+    "/referenceAbbreviationDict/DNC/originalLanguageCode",
+    // The XML has five chapters for BAR (the JSON has "5,6") as its sixth is LJE (and the KJV's six-chapter Baruch is LJB)
+    "/referenceNumberDict/101/numExpectedChapters",
+    "/referenceAbbreviationDict/BAR/numExpectedChapters",
+    // The XML gives NHM as an alternative abbreviation for NAH (the JSON has NEH, derived from "Nehemiah")
+    "/allAbbreviationsDict/NHM (ENGLISH_NAME_MAP[\"NHM\"])",
+    // These are in BAD_ABBREVIATIONS in build.rs (as they're ambiguous) and the current converter drops them too
+    "/allAbbreviationsDict/4 EZRA (ENGLISH_NAME_MAP[\"4 EZRA\"])",
+    "/allAbbreviationsDict/4EZRA (ENGLISH_NAME_MAP[\"4EZRA\"])",
+    "/allAbbreviationsDict/ODES (ENGLISH_NAME_MAP[\"ODES\"])",
+    // The older XML had an empty alternative abbreviation for SNG
+    "/allAbbreviationsDict/ (ENGLISH_NAME_MAP[\"\"])",
+    // The older XML had these two-letter alternative abbreviations (which aren't in the current XML)
+    "/allAbbreviationsDict/1K (ENGLISH_NAME_MAP[\"1K\"])",
+    "/allAbbreviationsDict/2K (ENGLISH_NAME_MAP[\"2K\"])",
+    "/allAbbreviationsDict/NH (ENGLISH_NAME_MAP[\"NH\"])",
+    "/allAbbreviationsDict/JR (ENGLISH_NAME_MAP[\"JR\"])",
+    "/allAbbreviationsDict/EZ (ENGLISH_NAME_MAP[\"EZ\"])",
+    "/allAbbreviationsDict/EP (ENGLISH_NAME_MAP[\"EP\"])",
+    "/allAbbreviationsDict/PH (ENGLISH_NAME_MAP[\"PH\"])",
+    "/allAbbreviationsDict/CL (ENGLISH_NAME_MAP[\"CL\"])",
+    "/allAbbreviationsDict/TI (ENGLISH_NAME_MAP[\"TI\"])",
+    "/allAbbreviationsDict/HB (ENGLISH_NAME_MAP[\"HB\"])",
+    "/allAbbreviationsDict/1P (ENGLISH_NAME_MAP[\"1P\"])",
+    "/allAbbreviationsDict/2P (ENGLISH_NAME_MAP[\"2P\"])",
+    "/allAbbreviationsDict/RV (ENGLISH_NAME_MAP[\"RV\"])",
+    "/allAbbreviationsDict/TB (ENGLISH_NAME_MAP[\"TB\"])",
+];
+/// write_json() puts all the English-derived abbreviations from build.rs into allAbbreviationsDict
+///     (4,920 of them, the same as the current converter makes from the current XML, but the JSON only has 439),
+///     so it's checked through the ENGLISH_NAME_MAP lookups (in both directions) rather than compared
+const ALL_ABBREVIATIONS_DICT_NAME: &str = "allAbbreviationsDict";
+
+/// A value which is different (or missing) in the JSON, e.g., at referenceAbbreviationDict/GEN/USFMAbbreviation.
+#[derive(Debug, PartialEq)]
+pub(crate) struct FieldDifference {
+    pub path: String,
+    /// What the JSON has (None if it's missing)
+    pub json_value: Option<String>,
+    /// What it should be from our tables (None if it shouldn't be there)
+    pub expected_value: Option<String>,
+}
+
+impl fmt::Display for FieldDifference {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "nothing".to_string());
+        write!(f, "{}: JSON has {} but expected {}", self.path, show(&self.json_value), show(&self.expected_value))
+    }
+}
+
+/// Returns the differences (apart from the expected ones above) between the JSON text and what export::write_json() writes
+///     for BIBLE_BOOKS_CODES_ARRAY, then checks the USFM, OSIS and English name lookups of the phf maps against the JSON dictionaries.
+pub(crate) fn tables_json_differences(json_text: &str) -> Vec<FieldDifference> {
+    let json: Value = serde_json::from_str(json_text).expect("BibleBooksCodes_Tables.json should be valid JSON");
+    let mut expected_json_text = Vec::new();
+    write_json(&mut expected_json_text, BIBLE_BOOKS_CODES_ARRAY.iter()).unwrap();
+    let expected_json: Value = serde_json::from_slice(&expected_json_text).unwrap();
+
+    let mut differences = Vec::new();
+    let without_all_abbreviations_dict = |value: &Value| {
+        let mut value = value.clone();
+        value.as_object_mut().map(|tables| tables.shift_remove(ALL_ABBREVIATIONS_DICT_NAME));
+        value
+    };
+    value_differences("", &without_all_abbreviations_dict(&json), &without_all_abbreviations_dict(&expected_json), &mut differences);
+
+    // The maps give the first book which has the code so that should be the first one in the JSON
+    let first_book = |dict_name: &str, code: &str| match &json[dict_name][code][1] {
+        Value::Array(books) => books.first().and_then(Value::as_str).map(str::to_string),
+        books => books.as_str().map(str::to_string),
+    };
+    let mut check_map_entry = |(map_name, map_key): (&str, &str), (dict_name, dict_key): (&str, &str), json_value: Option<String>, array_index: usize| {
+        let bos_book_code = BIBLE_BOOKS_CODES_ARRAY[array_index].BOS_book_code;
+        if json_value.as_deref() != Some(bos_book_code) {
+            differences.push(FieldDifference {
+                path: format!("/{dict_name}/{dict_key} ({map_name}[\"{map_key}\"])"),
+                json_value,
+                expected_value: Some(bos_book_code.to_string()),
+            });
+        }
+    };
+    for (usfm_abbreviation, &array_index) in USFM_ABBREVIATION_MAP.entries() {
+        let uppercase_usfm_abbreviation = usfm_abbreviation.to_uppercase();
+        let json_value = first_book("USFMAbbreviationDict", &uppercase_usfm_abbreviation);
+        check_map_entry(("USFM_ABBREVIATION_MAP", usfm_abbreviation), ("USFMAbbreviationDict", &uppercase_usfm_abbreviation), json_value, array_index);
+    }
+    for (uppercase_usfm_abbreviation, &array_index) in UPPERCASE_USFM_ABBREVIATION_MAP.entries() {
+        let json_value = first_book("USFMAbbreviationDict", uppercase_usfm_abbreviation);
+        check_map_entry(("UPPERCASE_USFM_ABBREVIATION_MAP", uppercase_usfm_abbreviation), ("USFMAbbreviationDict", uppercase_usfm_abbreviation), json_value, array_index);
+    }
+    for (osis_abbreviation, &array_index) in OSIS_ABBREVIATION_MAP.entries() {
+        let uppercase_osis_abbreviation = osis_abbreviation.to_uppercase();
+        let json_value = first_book("OSISAbbreviationDict", &uppercase_osis_abbreviation);
+        check_map_entry(("OSIS_ABBREVIATION_MAP", osis_abbreviation), ("OSISAbbreviationDict", &uppercase_osis_abbreviation), json_value, array_index);
+    }
+    // (Only for the names which are in the JSON, as our allAbbreviationsDict is larger)
+    for (english_name, &array_index) in ENGLISH_NAME_MAP.entries() {
+        let Some(json_value) = json[ALL_ABBREVIATIONS_DICT_NAME][english_name].as_str().map(str::to_string) else {
+            continue;
+        };
+        check_map_entry(("ENGLISH_NAME_MAP", english_name), (ALL_ABBREVIATIONS_DICT_NAME, english_name), Some(json_value), array_index);
+    }
+    // ...but every name in the JSON should be in ENGLISH_NAME_MAP
+    for (english_name, bos_book_code) in json[ALL_ABBREVIATIONS_DICT_NAME].as_object().into_iter().flatten() {
+        if !ENGLISH_NAME_MAP.contains_key(english_name) {
+            differences.push(FieldDifference {
+                path: format!("/{ALL_ABBREVIATIONS_DICT_NAME}/{english_name} (ENGLISH_NAME_MAP[\"{english_name}\"])"),
+                json_value: bos_book_code.as_str().map(str::to_string),
+                expected_value: None,
+            });
+        }
+    }
+    differences.retain(|difference| !EXPECTED_DIFFERENCE_PATHS.contains(&difference.path.as_str()));
+    differences
+}
+
+/// Adds the differences between the two JSON values (walking down through objects and arrays).
+fn value_differences(path: &str, json_value: &Value, expected_value: &Value, differences: &mut Vec<FieldDifference>) {
+    match (json_value, expected_value) {
+        (Value::Object(json_object), Value::Object(expected_object)) => {
+            for (key, expected_field) in expected_object {
+                match json_object.get(key) {
+                    Some(json_field) => value_differences(&format!("{path}/{key}"), json_field, expected_field, differences),
+                    None => differences.push(FieldDifference {
+                        path: format!("{path}/{key}"),
+                        json_value: None,
+                        expected_value: Some(expected_field.to_string()),
+                    }),
+                }
+            }
+            for (key, json_field) in json_object.iter().filter(|(key, _)| !expected_object.contains_key(*key)) {
+                differences.push(FieldDifference { path: format!("{path}/{key}"), json_value: Some(json_field.to_string()), expected_value: None });
+            }
+        }
+        (Value::Array(json_array), Value::Array(expected_array)) if json_array.len() == expected_array.len() => {
+            for (n, (json_element, expected_element)) in json_array.iter().zip(expected_array).enumerate() {
+                value_differences(&format!("{path}/{n}"), json_element, expected_element, differences);
+            }
+        }
+        _ if json_value != expected_value => differences.push(FieldDifference {
+            path: path.to_string(),
+            json_value: Some(json_value.to_string()),
+            expected_value: Some(expected_value.to_string()),
+        }),
+        _ => {}
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// derivedFormats/BibleBooksCodes_Tables.json should only have the expected differences from what export::write_json() writes
+    ///     (if not, fix the XML or list the new difference in EXPECTED_DIFFERENCE_PATHS with why it's expected)
+    #[test]
+    fn test_tables_json_differences_are_expected() {
+        // (It's not in the packaged crate)
+        let Ok(json_text) = std::fs::read_to_string(TABLES_JSON_FILEPATH) else {
+            return;
+        };
+        let differences = tables_json_differences(&json_text);
+        assert!(
+            differences.is_empty(),
+            "{} differences from {TABLES_JSON_FILEPATH}:\n{}",
+            differences.len(),
+            differences.iter().take(50).map(|difference| format!("  {difference}")).collect::<Vec<_>>().join("\n")
+        );
+    }
+
+    #[test]
+    fn test_tables_json_differences() {
+        let Ok(json_text) = std::fs::read_to_string(TABLES_JSON_FILEPATH) else {
+            return;
+        };
+        let changed_json_text = json_text
+            .replacen("\"bookNameEnglishGuide\": \"Genesis / 1 Moses\"", "\"bookNameEnglishGuide\": \"Genesis\"", 1)
+            .replacen("\"allAbbreviationsDict\": {\n", "\"allAbbreviationsDict\": {\n    \"GENX\": \"GEN\",\n", 1)
+            .replacen("\"1CO\": [\n      46,\n      \"CO1\",\n      \"47\"", "\"1CO\": [\n      46,\n      \"CO2\",\n      \"47\"", 1);
+        let differences = tables_json_differences(&changed_json_text);
+        assert!(differences.contains(&FieldDifference {
+            path: "/referenceNumberDict/1/bookNameEnglishGuide".to_string(),
+            json_value: Some("\"Genesis\"".to_string()),
+            expected_value: Some("\"Genesis / 1 Moses\"".to_string()),
+        }));
+        assert!(differences.iter().any(|difference| difference.path == "/USFMAbbreviationDict/1CO (USFM_ABBREVIATION_MAP[\"1Co\"])"
+            && difference.json_value.as_deref() == Some("CO2")));
+        assert!(differences.contains(&FieldDifference {
+            path: "/allAbbreviationsDict/GENX (ENGLISH_NAME_MAP[\"GENX\"])".to_string(),
+            json_value: Some("GEN".to_string()),
+            expected_value: None,
+        }));
+    }
+}
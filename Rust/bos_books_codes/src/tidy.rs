@@ -0,0 +1,125 @@
+// Tidying BOS book codes for display (a port of tidyBBB and tidyBBBs from the Python package),
+//   e.g., "SA1" to "1SA", "1Sa", "1 SAM" or "1-Sam".
+
+use crate::{is_valid_bos_book_code, CodeSystem, LookupError, FOUR_CHARACTER_ABBREVIATION_MAP, REFERENCE_ABBREVIATION_MAP};
+
+
+/// The options for tidy_bos_book_code(). The defaults match the Python tidyBBB() defaults.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TidyOptions<'a> {
+    /// "1Sa" rather than "1SA"
+    pub title_case: bool,
+    /// "1SAM" rather than "1SA" (for some well-known books)
+    pub allow_four_chars: bool,
+    /// Inserted after a leading number, e.g., "-" gives "1-SA" which can't be mistaken for "ISA"
+    pub insert_str: &'a str,
+}
+
+impl Default for TidyOptions<'_> {
+    fn default() -> Self {
+        TidyOptions { title_case: false, allow_four_chars: true, insert_str: "" }
+    }
+}
+
+impl<'a> TidyOptions<'a> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn title_case(mut self, title_case: bool) -> Self {
+        self.title_case = title_case;
+        self
+    }
+
+    #[inline]
+    pub fn allow_four_chars(mut self, allow_four_chars: bool) -> Self {
+        self.allow_four_chars = allow_four_chars;
+        self
+    }
+
+    #[inline]
+    pub fn insert_str(mut self, insert_str: &'a str) -> Self {
+        self.insert_str = insert_str;
+        self
+    }
+}
+
+/// Changes book codes like "SA1" to the conventional "1SA"
+///     (or "1Sa" with title_case, or "1 SAM" with allow_four_chars and an insert_str of " ").
+pub fn tidy_bos_book_code<'a>(bos_book_code: &'a str, options: &TidyOptions<'_>) -> Result<String, LookupError<'a>> {
+    if !is_valid_bos_book_code(bos_book_code) {
+        return Err(LookupError::not_found(CodeSystem::Bos, bos_book_code, REFERENCE_ABBREVIATION_MAP.keys().copied()));
+    }
+    let (letters, number) = match bos_book_code.as_bytes()[2] {
+        b'0'..=b'9' => bos_book_code.split_at(2),
+        _ => (bos_book_code, ""),
+    };
+
+    // The four-character forms (from the XML fourCharacterAbbreviation) are used (if allowed) instead of the code letters,
+    //   e.g., "Sam" for SA1 (giving "1Sam") or "Ruth" for RUT
+    let name = match FOUR_CHARACTER_ABBREVIATION_MAP.get(bos_book_code) {
+        Some(four_character_form) if options.allow_four_chars => {
+            if options.title_case { four_character_form.to_string() } else { four_character_form.to_uppercase() }
+        }
+        _ if options.title_case => format!("{}{}", &letters[..1], letters[1..].to_lowercase()),
+        _ => letters.to_string(),
+    };
+    if number.is_empty() {
+        Ok(name)
+    } else {
+        Ok(format!("{}{}{}", number, options.insert_str, name))
+    }
+}
+
+/// Tidies a list of book codes as per tidy_bos_book_code().
+pub fn tidy_bos_book_codes<'a>(bos_book_codes: &[&'a str], options: &TidyOptions<'_>) -> Result<Vec<String>, LookupError<'a>> {
+    bos_book_codes.iter().map(|bos_book_code| tidy_bos_book_code(bos_book_code, options)).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BIBLE_BOOKS_CODES_ARRAY;
+
+    #[test]
+    fn test_tidy_bos_book_code() {
+        assert_eq!(tidy_bos_book_code("SA1", &TidyOptions::new().allow_four_chars(false)), Ok("1SA".to_string()));
+        assert_eq!(tidy_bos_book_code("SA1", &TidyOptions::new().allow_four_chars(false).title_case(true)), Ok("1Sa".to_string()));
+        assert_eq!(tidy_bos_book_code("SA1", &TidyOptions::new().insert_str(" ")), Ok("1 SAM".to_string()));
+        assert_eq!(tidy_bos_book_code("SA1", &TidyOptions::new().title_case(true).insert_str("-")), Ok("1-Sam".to_string()));
+        assert_eq!(tidy_bos_book_code("GEN", &TidyOptions::default()), Ok("GEN".to_string()));
+        assert!(matches!(tidy_bos_book_code("XyZ", &TidyOptions::default()), Err(LookupError::Unknown { system: CodeSystem::Bos, .. })));
+        assert_eq!(tidy_bos_book_codes(&["JN1", "JDE"], &TidyOptions::new().title_case(true)), Ok(vec!["1Jhn".to_string(), "Jude".to_string()]));
+    }
+
+    /// test_data/tidyBBB_python_output.tsv was made by running the Python tidyBBB() on every code
+    #[test]
+    fn test_tidy_bos_book_code_matches_python() {
+        let python_output = include_str!("../test_data/tidyBBB_python_output.tsv");
+        let mut num_lines = 0;
+        for line in python_output.lines().filter(|line| !line.starts_with('#')) {
+            let mut fields = line.split('\t');
+            let bos_book_code = fields.next().unwrap();
+            let mut expected_outputs = fields;
+            for title_case in [false, true] {
+                for allow_four_chars in [false, true] {
+                    for insert_str in ["", "-", " "] {
+                        let options = TidyOptions { title_case, allow_four_chars, insert_str };
+                        assert_eq!(
+                            tidy_bos_book_code(bos_book_code, &options).as_deref(),
+                            Ok(expected_outputs.next().unwrap()),
+                            "{} with {:?}",
+                            bos_book_code,
+                            options
+                        );
+                    }
+                }
+            }
+            num_lines += 1;
+        }
+        assert_eq!(num_lines, BIBLE_BOOKS_CODES_ARRAY.len());
+    }
+}
@@ -0,0 +1,68 @@
+// Checking chapter numbers against the number of chapters expected in each book.
+
+use crate::{CodeSystem, LookupError, OptionalNumberOrTwoNumbers, BIBLE_BOOKS_CODES_ARRAY, REFERENCE_ABBREVIATION_MAP};
+
+
+/// The result of validate_chapter().
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChapterValidity {
+    /// Within the usual number of chapters for the book
+    Valid,
+    /// Only within the alternative number of chapters, e.g., Psalm 151
+    ValidInAlternateTradition,
+    /// Zero, or more than any expected number of chapters
+    OutOfRange,
+    /// The book isn't expected to have chapters at all, e.g., FRT
+    BookHasNoChapters,
+}
+
+impl ChapterValidity {
+    /// Returns true for Valid and ValidInAlternateTradition.
+    #[inline]
+    pub fn is_acceptable(self) -> bool {
+        matches!(self, ChapterValidity::Valid | ChapterValidity::ValidInAlternateTradition)
+    }
+}
+
+/// Checks the chapter number against the expected number(s) of chapters in the book,
+///     e.g., 66 is valid for ISA but 67 is OutOfRange, and 151 is ValidInAlternateTradition for PSA.
+/// Note that the expected number of chapters in our table is only a guide (not authoritative).
+pub fn validate_chapter(bos_book_code: &str, chapter: u16) -> Result<ChapterValidity, LookupError<'_>> {
+    let array_index = *REFERENCE_ABBREVIATION_MAP.get(bos_book_code)
+        .ok_or_else(|| LookupError::not_found(CodeSystem::Bos, bos_book_code, REFERENCE_ABBREVIATION_MAP.keys().copied()))?;
+    let (usual_num_chapters, alternative_num_chapters) = match BIBLE_BOOKS_CODES_ARRAY[array_index].expected_num_chapters {
+        OptionalNumberOrTwoNumbers::Number(num_chapters) => (num_chapters, num_chapters),
+        OptionalNumberOrTwoNumbers::TwoNumbers([usual_num_chapters, alternative_num_chapters]) => (usual_num_chapters, alternative_num_chapters),
+        OptionalNumberOrTwoNumbers::None => return Ok(ChapterValidity::BookHasNoChapters),
+    };
+    Ok(if chapter == 0 {
+        ChapterValidity::OutOfRange
+    } else if chapter <= usual_num_chapters {
+        ChapterValidity::Valid
+    } else if chapter <= alternative_num_chapters {
+        ChapterValidity::ValidInAlternateTradition
+    } else {
+        ChapterValidity::OutOfRange
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_chapter() {
+        assert_eq!(validate_chapter("ISA", 66), Ok(ChapterValidity::Valid));
+        assert_eq!(validate_chapter("ISA", 67), Ok(ChapterValidity::OutOfRange));
+        assert_eq!(validate_chapter("ISA", 0), Ok(ChapterValidity::OutOfRange));
+        assert_eq!(validate_chapter("PSA", 150), Ok(ChapterValidity::Valid));
+        assert_eq!(validate_chapter("PSA", 151), Ok(ChapterValidity::ValidInAlternateTradition));
+        assert_eq!(validate_chapter("PSA", 152), Ok(ChapterValidity::OutOfRange));
+        assert_eq!(validate_chapter("PS3", 5), Ok(ChapterValidity::Valid)); // Where the alternative is fewer chapters
+        assert_eq!(validate_chapter("FRT", 1), Ok(ChapterValidity::BookHasNoChapters));
+        assert!(matches!(validate_chapter("XyZ", 1), Err(LookupError::Unknown { system: CodeSystem::Bos, .. })));
+        assert!(ChapterValidity::ValidInAlternateTradition.is_acceptable());
+        assert!(!ChapterValidity::OutOfRange.is_acceptable());
+    }
+}
@@ -0,0 +1,107 @@
+// The number of verses in each chapter (only with the verse-counts feature),
+//   and checking references against them.
+
+use crate::{is_valid_bos_book_code, CodeSystem, LookupError, Reference, REFERENCE_ABBREVIATION_MAP, VERSE_COUNTS_MAP};
+
+
+/// The versification used for the verse counts (from VerseCounts_KJV.tsv),
+///     i.e., the King James Version including the 1611 Apocrypha.
+/// The KJV Baruch (6 chapters, the last being the Epistle of Jeremy) is LJB,
+///     but BAR (Baruch 1-5) and LJE (KJV Baruch 6) also have verse counts for Bibles which separate them.
+/// The Rest of Esther (KJV Esther 10:4-16:24) doesn't have verse counts as its chapters continue on from Esther's.
+pub const VERSE_COUNTS_VERSIFICATION: &str = "KJV";
+
+/// The result of validate_reference().
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReferenceValidity {
+    Valid,
+    ChapterOutOfRange,
+    VerseOutOfRange,
+    /// We don't have verse counts for the book, so can't tell
+    NoVerseCounts,
+}
+
+/// Returns the number of verses in the chapter (in the KJV versification),
+///     or None if the chapter doesn't exist or we don't have verse counts for the book.
+pub fn verses_in_chapter(bos_book_code: &str, chapter: u16) -> Result<Option<u16>, LookupError<'_>> {
+    if !is_valid_bos_book_code(bos_book_code) {
+        return Err(LookupError::not_found(CodeSystem::Bos, bos_book_code, REFERENCE_ABBREVIATION_MAP.keys().copied()));
+    }
+    Ok(VERSE_COUNTS_MAP
+        .get(bos_book_code)
+        .and_then(|verse_counts| verse_counts.get(usize::from(chapter).checked_sub(1)?))
+        .copied())
+}
+
+/// Checks the chapter and verse (if given) against the verse counts (in the KJV versification).
+pub fn validate_reference(reference: &Reference) -> ReferenceValidity {
+    let Some(verse_counts) = VERSE_COUNTS_MAP.get(reference.book.as_str()) else {
+        return ReferenceValidity::NoVerseCounts;
+    };
+    let Some(chapter) = reference.chapter else {
+        return ReferenceValidity::Valid;
+    };
+    let Some(&num_verses) = usize::from(chapter).checked_sub(1).and_then(|chapter_index| verse_counts.get(chapter_index)) else {
+        return ReferenceValidity::ChapterOutOfRange;
+    };
+    match reference.verse {
+        Some(verse) if verse == 0 || verse > num_verses => ReferenceValidity::VerseOutOfRange,
+        _ => ReferenceValidity::Valid,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BookCode, BIBLE_BOOKS_CODES_ARRAY, OptionalNumberOrTwoNumbers};
+
+    fn reference(bos_book_code: &str, chapter: Option<u16>, verse: Option<u16>) -> Reference {
+        Reference { book: BookCode::new(bos_book_code).unwrap(), chapter, verse }
+    }
+
+    #[test]
+    fn test_verses_in_chapter() {
+        assert_eq!(verses_in_chapter("GEN", 1), Ok(Some(31)));
+        assert_eq!(verses_in_chapter("PSA", 119), Ok(Some(176)));
+        assert_eq!(verses_in_chapter("JN3", 1), Ok(Some(14)));
+        assert_eq!(verses_in_chapter("SIR", 51), Ok(Some(30)));
+        assert_eq!(verses_in_chapter("LJB", 6), Ok(Some(73)));
+        assert_eq!(verses_in_chapter("BAR", 6), Ok(None));
+        assert_eq!(verses_in_chapter("LJE", 1), Ok(Some(73)));
+        assert_eq!(verses_in_chapter("GEN", 51), Ok(None));
+        assert_eq!(verses_in_chapter("GEN", 0), Ok(None));
+        assert_eq!(verses_in_chapter("FRT", 1), Ok(None));
+        assert!(matches!(verses_in_chapter("XyZ", 1), Err(LookupError::Unknown { system: CodeSystem::Bos, .. })));
+    }
+
+    /// The well-known KJV totals
+    #[test]
+    fn test_verse_count_totals() {
+        let protestant_verse_counts = |first: u16, last: u16| {
+            BIBLE_BOOKS_CODES_ARRAY
+                .iter()
+                .filter(move |entry| (first..=last).contains(&entry.BOS_reference_number))
+                .map(|entry| VERSE_COUNTS_MAP[entry.BOS_book_code])
+        };
+        let num_verses = |first, last| protestant_verse_counts(first, last).flatten().map(|&verse_count| u32::from(verse_count)).sum::<u32>();
+        assert_eq!(num_verses(1, 39), 23_145);
+        assert_eq!(num_verses(40, 66), 7_957);
+        assert_eq!(protestant_verse_counts(1, 66).map(|verse_counts| verse_counts.len()).sum::<usize>(), 1_189);
+    }
+
+    #[test]
+    fn test_validate_reference() {
+        assert_eq!(validate_reference(&reference("JHN", Some(3), Some(16))), ReferenceValidity::Valid);
+        assert_eq!(validate_reference(&reference("JHN", Some(3), Some(37))), ReferenceValidity::VerseOutOfRange);
+        assert_eq!(validate_reference(&reference("JHN", Some(22), None)), ReferenceValidity::ChapterOutOfRange);
+        assert_eq!(validate_reference(&reference("JHN", None, None)), ReferenceValidity::Valid);
+        assert_eq!(validate_reference(&reference("FRT", Some(1), Some(1))), ReferenceValidity::NoVerseCounts);
+        for entry in BIBLE_BOOKS_CODES_ARRAY.iter() {
+            if let Some(verse_counts) = VERSE_COUNTS_MAP.get(entry.BOS_book_code) {
+                assert!(!matches!(entry.expected_num_chapters, OptionalNumberOrTwoNumbers::None), "{}", entry.BOS_book_code);
+                assert!(verse_counts.iter().all(|&verse_count| verse_count > 0), "{}", entry.BOS_book_code);
+            }
+        }
+    }
+}
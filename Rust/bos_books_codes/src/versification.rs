@@ -0,0 +1,310 @@
+// Mapping chapter/verse references between versification traditions,
+//   e.g., KJV Malachi 4:1 is 3:19 in the Hebrew, and KJV Psalm 23 is Psalm 22 in the Septuagint.
+
+use std::fmt;
+
+use crate::{psalm_title_verses, PsalmNumbering, RefRange, Reference, VERSIFICATION_MAPPINGS};
+
+
+/// The chapter/verse numbering traditions that we can map between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Versification {
+    /// As in the King James Version (and most English Bibles)
+    Kjv,
+    /// As in the original language editions, i.e., the Hebrew (Masoretic) text for the Old Testament
+    Original,
+    /// As in the Septuagint (Rahlfs), which mostly follows the Hebrew chapter and verse numbers
+    ///     (only partially mapped, e.g., not the different orders of Exodus 35-40, 3 Kingdoms and Proverbs 24-31,
+    ///     see VersificationMappings.tsv)
+    Lxx,
+    /// As in the (Clementine) Vulgate
+    Vulgate,
+}
+
+impl Versification {
+    pub fn name(self) -> &'static str {
+        match self {
+            Versification::Kjv => "KJV",
+            Versification::Original => "Original",
+            Versification::Lxx => "LXX",
+            Versification::Vulgate => "Vulgate",
+        }
+    }
+}
+
+impl fmt::Display for Versification {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+
+/// Where a block of KJV chapters (or verses within one KJV chapter) is found in other versifications
+///     (VERSIFICATION_MAPPINGS is made by build.rs from VersificationMappings.tsv).
+/// The verse numbers follow on consecutively within the block.
+pub(crate) struct VersificationMapping {
+    pub(crate) bos_book_code: &'static str,
+    pub(crate) versifications: &'static [Versification],
+    pub(crate) kjv_chapters: (u16, u16),
+    /// None if the whole chapters map (with unchanged verse numbers)
+    pub(crate) kjv_verses: Option<(u16, u16)>,
+    pub(crate) chapter: u16,
+    pub(crate) first_verse: u16,
+}
+
+fn mappings(bos_book_code: &str, versification: Versification) -> impl Iterator<Item = &'static VersificationMapping> {
+    VERSIFICATION_MAPPINGS
+        .iter()
+        .filter(move |mapping| mapping.bos_book_code == bos_book_code && mapping.versifications.contains(&versification))
+}
+
+/// Returns the number of Psalm title verses which aren't numbered in the KJV
+///     (the other versifications all number the titles like the Hebrew, e.g., KJV 51:1 is Hebrew 51:3).
+#[inline]
+fn title_verses(bos_book_code: &str, kjv_chapter: u16) -> u16 {
+    psalm_title_verses(bos_book_code, kjv_chapter, PsalmNumbering::Hebrew)
+}
+
+/// Maps a chapter and verse (with any Psalm title verses counted) from the KJV versification, returning None if it's unchanged.
+fn from_kjv(bos_book_code: &str, chapter: u16, verse: u16, versification: Versification) -> Option<(u16, u16)> {
+    mappings(bos_book_code, versification).find_map(|mapping| {
+        if !(mapping.kjv_chapters.0..=mapping.kjv_chapters.1).contains(&chapter) {
+            return None;
+        }
+        match mapping.kjv_verses {
+            None => Some((mapping.chapter + chapter - mapping.kjv_chapters.0, verse)),
+            Some((first, last)) if (first..=last).contains(&verse) => Some((mapping.chapter, mapping.first_verse + verse - first)),
+            Some(_) => None,
+        }
+    })
+}
+
+/// Maps a chapter and verse to the KJV versification (with any Psalm title verses still counted), returning None if it's unchanged.
+fn to_kjv(bos_book_code: &str, chapter: u16, verse: u16, versification: Versification) -> Option<(u16, u16)> {
+    mappings(bos_book_code, versification).find_map(|mapping| {
+        let (first_kjv_chapter, last_kjv_chapter) = mapping.kjv_chapters;
+        match mapping.kjv_verses {
+            None if (mapping.chapter..=mapping.chapter + last_kjv_chapter - first_kjv_chapter).contains(&chapter) => {
+                Some((first_kjv_chapter + chapter - mapping.chapter, verse))
+            }
+            Some((first, last)) if chapter == mapping.chapter && (mapping.first_verse..=mapping.first_verse + last - first).contains(&verse) => {
+                Some((first_kjv_chapter, first + verse - mapping.first_verse))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Returns the chapter with the most verses (the first one if there's a tie) from the (chapter, number of verses) pairs.
+fn chapter_with_most_verses(chapter_verse_counts: impl IntoIterator<Item = (u16, u16)>) -> Option<u16> {
+    let mut totals: Vec<(u16, u16)> = Vec::new();
+    for (chapter, num_verses) in chapter_verse_counts {
+        match totals.iter_mut().find(|(total_chapter, _)| *total_chapter == chapter) {
+            Some((_, total)) => *total += num_verses,
+            None => totals.push((chapter, num_verses)),
+        }
+    }
+    totals.iter().rev().max_by_key(|&&(_, total)| total).map(|&(chapter, _)| chapter)
+}
+
+/// Maps a whole KJV chapter to the chapter that most of its verses are in.
+fn chapter_from_kjv(bos_book_code: &str, kjv_chapter: u16, versification: Versification) -> u16 {
+    let mut verse_blocks = Vec::new();
+    for mapping in mappings(bos_book_code, versification).filter(|mapping| (mapping.kjv_chapters.0..=mapping.kjv_chapters.1).contains(&kjv_chapter)) {
+        match mapping.kjv_verses {
+            None => return mapping.chapter + kjv_chapter - mapping.kjv_chapters.0,
+            Some((first, last)) => verse_blocks.push((first, mapping.chapter, last - first + 1)),
+        }
+    }
+    // Any verses before the first mapped ones stay in the same chapter
+    let first_mapped_verse = verse_blocks.iter().map(|&(first, _, _)| first).min().unwrap_or(1);
+    let chapter_verse_counts = verse_blocks.iter().map(|&(_, chapter, num_verses)| (chapter, num_verses));
+    chapter_with_most_verses(std::iter::once((kjv_chapter, first_mapped_verse - 1)).chain(chapter_verse_counts)).unwrap_or(kjv_chapter)
+}
+
+/// Maps a whole chapter to the KJV chapter that most of its verses are in.
+fn chapter_to_kjv(bos_book_code: &str, chapter: u16, versification: Versification) -> u16 {
+    let mut verse_blocks = Vec::new();
+    for mapping in mappings(bos_book_code, versification) {
+        let (first_kjv_chapter, last_kjv_chapter) = mapping.kjv_chapters;
+        match mapping.kjv_verses {
+            None if (mapping.chapter..=mapping.chapter + last_kjv_chapter - first_kjv_chapter).contains(&chapter) => {
+                return first_kjv_chapter + chapter - mapping.chapter;
+            }
+            Some((first, last)) if chapter == mapping.chapter => verse_blocks.push((mapping.first_verse, first_kjv_chapter, last - first + 1)),
+            _ => {}
+        }
+    }
+    // Any verses before the first mapped ones come from the same KJV chapter
+    let first_mapped_verse = verse_blocks.iter().map(|&(first_verse, _, _)| first_verse).min().unwrap_or(1);
+    let chapter_verse_counts = verse_blocks.iter().map(|&(_, kjv_chapter, num_verses)| (kjv_chapter, num_verses));
+    chapter_with_most_verses(std::iter::once((chapter, first_mapped_verse - 1)).chain(chapter_verse_counts)).unwrap_or(chapter)
+}
+
+impl Reference {
+    /// Converts the chapter and verse numbers from one versification to another (via the KJV one),
+    ///     e.g., KJV JOL 3:1 is 4:1 in the Hebrew, KJV PSA 23:1 is 22:1 in the LXX,
+    ///     and KJV PSA 51:1 is 51:3 in the Hebrew (where the title is numbered as verses 1 and 2).
+    /// Psalm title verses map to KJV verse 1 (which the KJV prints the title above).
+    /// A reference without a verse is mapped to the chapter that most of its verses are in,
+    ///     e.g., KJV DEU 29 is Hebrew DEU 29 (even though KJV 29:1 is Hebrew 28:69).
+    pub fn map_versification(&self, from: Versification, to: Versification) -> Reference {
+        let Some(chapter) = self.chapter.filter(|_| from != to) else {
+            return *self;
+        };
+        let bos_book_code = self.book.as_str();
+        let Some(verse) = self.verse else {
+            let kjv_chapter = match from {
+                Versification::Kjv => chapter,
+                _ => chapter_to_kjv(bos_book_code, chapter, from),
+            };
+            let chapter = match to {
+                Versification::Kjv => kjv_chapter,
+                _ => chapter_from_kjv(bos_book_code, kjv_chapter, to),
+            };
+            return Reference { book: self.book, chapter: Some(chapter), verse: None };
+        };
+
+        let (kjv_chapter, kjv_verse) = match from {
+            Versification::Kjv => (chapter, verse),
+            _ => {
+                let (kjv_chapter, verse) = to_kjv(bos_book_code, chapter, verse, from).unwrap_or((chapter, verse));
+                (kjv_chapter, verse.saturating_sub(title_verses(bos_book_code, kjv_chapter)).max(1))
+            }
+        };
+        let (chapter, verse) = match to {
+            Versification::Kjv => (kjv_chapter, kjv_verse),
+            _ => {
+                let verse = kjv_verse + title_verses(bos_book_code, kjv_chapter);
+                from_kjv(bos_book_code, kjv_chapter, verse, to).unwrap_or((kjv_chapter, verse))
+            }
+        };
+        Reference { book: self.book, chapter: Some(chapter), verse: Some(verse) }
+    }
+}
+
+impl RefRange {
+    /// Converts both ends of the range as per Reference::map_versification().
+    #[inline]
+    pub fn map_versification(&self, from: Versification, to: Versification) -> RefRange {
+        RefRange { start: self.start.map_versification(from, to), end: self.end.map_versification(from, to) }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BookCode;
+
+    fn reference(bos_book_code: &str, chapter: u16, verse: u16) -> Reference {
+        Reference::new(BookCode::new(bos_book_code).unwrap(), Some(chapter), Some(verse))
+    }
+
+    #[test]
+    fn test_map_versification() {
+        use Versification::*;
+        assert_eq!(reference("MAL", 4, 5).map_versification(Kjv, Original), reference("MAL", 3, 23));
+        assert_eq!(reference("MAL", 4, 5).map_versification(Kjv, Lxx), reference("MAL", 3, 22));
+        assert_eq!(reference("MAL", 4, 5).map_versification(Kjv, Vulgate), reference("MAL", 4, 5));
+        assert_eq!(reference("JOL", 3, 1).map_versification(Original, Kjv), reference("JOL", 2, 28));
+        assert_eq!(reference("JOL", 4, 21).map_versification(Original, Kjv), reference("JOL", 3, 21));
+        assert_eq!(reference("PSA", 23, 1).map_versification(Kjv, Lxx), reference("PSA", 22, 1));
+        assert_eq!(reference("PSA", 9, 25).map_versification(Vulgate, Original), reference("PSA", 10, 4));
+        assert_eq!(reference("PSA", 113, 10).map_versification(Lxx, Kjv), reference("PSA", 115, 2));
+        assert_eq!(reference("PSA", 150, 6).map_versification(Lxx, Original), reference("PSA", 150, 6));
+        assert_eq!(reference("GEN", 1, 1).map_versification(Kjv, Original), reference("GEN", 1, 1));
+        assert_eq!(reference("EXO", 7, 26).map_versification(Original, Lxx), reference("EXO", 7, 26));
+        assert_eq!(reference("LEV", 6, 1).map_versification(Kjv, Lxx), reference("LEV", 5, 20));
+        assert_eq!(reference("NUM", 17, 28).map_versification(Lxx, Kjv), reference("NUM", 17, 13));
+        assert_eq!(reference("HOS", 1, 10).map_versification(Kjv, Lxx), reference("HOS", 2, 1));
+        assert_eq!(reference("KI1", 21, 1).map_versification(Kjv, Lxx), reference("KI1", 20, 1));
+        assert_eq!(reference("DAN", 3, 24).map_versification(Kjv, Lxx), reference("DAN", 3, 91));
+        assert_eq!(reference("DAN", 3, 31).map_versification(Original, Lxx), reference("DAN", 3, 98));
+    }
+
+    /// The Septuagint has the oracles against the nations in the middle of Jeremiah
+    #[test]
+    fn test_map_versification_lxx_jeremiah() {
+        use Versification::*;
+        assert_eq!(reference("JER", 25, 13).map_versification(Kjv, Lxx), reference("JER", 25, 13));
+        assert_eq!(reference("JER", 25, 15).map_versification(Kjv, Lxx), reference("JER", 32, 1));
+        assert_eq!(reference("JER", 31, 31).map_versification(Original, Lxx), reference("JER", 38, 31));
+        assert_eq!(reference("JER", 46, 2).map_versification(Kjv, Lxx), reference("JER", 26, 2));
+        assert_eq!(reference("JER", 49, 34).map_versification(Kjv, Lxx), reference("JER", 25, 20));
+        assert_eq!(reference("JER", 51, 31).map_versification(Lxx, Kjv), reference("JER", 45, 1));
+        assert_eq!(reference("JER", 51, 30).map_versification(Lxx, Original), reference("JER", 44, 30));
+        assert_eq!(reference("JER", 52, 1).map_versification(Kjv, Lxx), reference("JER", 52, 1));
+    }
+
+    /// The Psalm titles are numbered as verses in the other versifications (but not in the KJV)
+    #[test]
+    fn test_map_versification_psalm_titles() {
+        use Versification::*;
+        assert_eq!(reference("PSA", 3, 1).map_versification(Kjv, Original), reference("PSA", 3, 2));
+        assert_eq!(reference("PSA", 3, 8).map_versification(Kjv, Lxx), reference("PSA", 3, 9));
+        assert_eq!(reference("PSA", 3, 2).map_versification(Original, Kjv), reference("PSA", 3, 1));
+        assert_eq!(reference("PSA", 3, 1).map_versification(Original, Kjv), reference("PSA", 3, 1)); // The title
+        assert_eq!(reference("PSA", 51, 1).map_versification(Kjv, Original), reference("PSA", 51, 3));
+        assert_eq!(reference("PSA", 51, 1).map_versification(Kjv, Vulgate), reference("PSA", 50, 3));
+        assert_eq!(reference("PSA", 51, 21).map_versification(Original, Kjv), reference("PSA", 51, 19));
+        assert_eq!(reference("PSA", 50, 3).map_versification(Lxx, Original), reference("PSA", 51, 3));
+        assert_eq!(reference("PSA", 60, 1).map_versification(Kjv, Original), reference("PSA", 60, 3));
+        assert_eq!(reference("PSA", 60, 12).map_versification(Kjv, Lxx), reference("PSA", 59, 14));
+        assert_eq!(reference("PSA", 59, 14).map_versification(Vulgate, Kjv), reference("PSA", 60, 12));
+        assert_eq!(reference("PSA", 23, 1).map_versification(Kjv, Original), reference("PSA", 23, 1)); // The title is part of verse 1
+        assert_eq!(reference("PSA", 51, 3).map_versification(Original, Original), reference("PSA", 51, 3));
+    }
+
+    /// Without a verse, the chapter goes to where most of its verses are (rather than where its first verse goes)
+    #[test]
+    fn test_map_versification_chapter_only() {
+        use Versification::*;
+        let chapter = |bos_book_code: &str, chapter: u16| Reference::new(BookCode::new(bos_book_code).unwrap(), Some(chapter), None);
+        assert_eq!(chapter("PSA", 51).map_versification(Original, Vulgate), chapter("PSA", 50));
+        assert_eq!(chapter("DEU", 29).map_versification(Kjv, Original), chapter("DEU", 29));
+        assert_eq!(chapter("DEU", 28).map_versification(Original, Kjv), chapter("DEU", 28));
+        assert_eq!(chapter("GEN", 31).map_versification(Kjv, Original), chapter("GEN", 31));
+        assert_eq!(chapter("MAL", 4).map_versification(Kjv, Original), chapter("MAL", 3));
+        assert_eq!(chapter("JOL", 3).map_versification(Original, Kjv), chapter("JOL", 2));
+        assert_eq!(chapter("PSA", 10).map_versification(Kjv, Lxx), chapter("PSA", 9));
+        assert_eq!(chapter("PSA", 9).map_versification(Lxx, Kjv), chapter("PSA", 9));
+        assert_eq!(chapter("PSA", 113).map_versification(Lxx, Kjv), chapter("PSA", 115));
+        assert_eq!(chapter("GEN", 1).map_versification(Kjv, Lxx), chapter("GEN", 1));
+    }
+
+    /// Mapping to any versification and back again should give the original reference
+    #[test]
+    fn test_map_versification_round_trip() {
+        for mapping in VERSIFICATION_MAPPINGS {
+            let (first_verse, last_verse) = mapping.kjv_verses.unwrap_or((1, 1));
+            for chapter in mapping.kjv_chapters.0..=mapping.kjv_chapters.1 {
+                for verse in first_verse..=last_verse {
+                    let kjv_reference = reference(mapping.bos_book_code, chapter, verse);
+                    for &versification in mapping.versifications {
+                        let mapped_reference = kjv_reference.map_versification(Versification::Kjv, versification);
+                        assert_ne!(mapped_reference, kjv_reference);
+                        assert_eq!(mapped_reference.map_versification(versification, Versification::Kjv), kjv_reference, "{} {}", kjv_reference, versification);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every KJV Psalm verse should map to the other versifications and back again
+    #[cfg(feature = "verse-counts")]
+    #[test]
+    fn test_map_versification_psalms_round_trip() {
+        for psalm in 1..=150 {
+            let num_verses = crate::verses_in_chapter("PSA", psalm).unwrap().unwrap();
+            for verse in 1..=num_verses {
+                let kjv_reference = reference("PSA", psalm, verse);
+                for versification in [Versification::Original, Versification::Lxx, Versification::Vulgate] {
+                    let mapped_reference = kjv_reference.map_versification(Versification::Kjv, versification);
+                    assert_eq!(mapped_reference.map_versification(versification, Versification::Kjv), kjv_reference, "{} {}", kjv_reference, versification);
+                }
+            }
+        }
+    }
+}
@@ -1,46 +1,48 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 // use std::error::Error;
 use ::bos_books_codes::{
     english_name_to_bos_book_code, osis_book_code_to_bos_book_code,
     bos_book_code_to_usfm_abbrev, usfm_abbrev_to_bos_book_code,
+    LookupError,
 };
 
-/// Converts a USFM book code to a BibleOrgSys (BOS) reference abbreviation book code to a USFM book code.
+/// Unknown or malformed codes are a Python ValueError (with the "did you mean" suggestions in the message).
+fn value_error(error: LookupError<'_>) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Converts a BibleOrgSys (BOS) reference abbreviation book code to a USFM book code,
+///     giving None (like the Python getUSFMAbbreviation()) for books without one, e.g., SAM.
 #[pyfunction]
-// RUST pub fn usfm_abbrev_to_bos_book_code(bos_book_code: &str) -> Result<&str, Box<dyn Error>> {
-fn bos_book_code_to_usfm_abbrev_py(bos_book_code: &str) -> PyResult<String> {
-    Ok(bos_book_code_to_usfm_abbrev(bos_book_code)
-        .unwrap().unwrap()
-        .to_string())
+// RUST pub fn bos_book_code_to_usfm_abbrev(bos_book_code: &str) -> Conversion<'_> {
+fn bos_book_code_to_usfm_abbrev_py(bos_book_code: &str) -> PyResult<Option<&'static str>> {
+    match bos_book_code_to_usfm_abbrev(bos_book_code) {
+        Ok(usfm_abbreviation) => Ok(Some(usfm_abbreviation)),
+        Err(LookupError::NoEquivalent { .. }) => Ok(None),
+        Err(error) => Err(value_error(error)),
+    }
 }
 
 /// Converts a USFM book code to a BibleOrgSys (BOS) reference abbreviation book code.
 #[pyfunction]
-// RUST pub fn usfm_abbrev_to_bos_book_code(usfm_abbreviation: &str) -> Result<&str, Box<dyn Error>> {
-fn usfm_abbrev_to_bos_book_code_py(usfm_abbreviation: &str) -> PyResult<String> {
-    Ok(usfm_abbrev_to_bos_book_code(usfm_abbreviation)
-        .unwrap()
-        .to_string())
+// RUST pub fn usfm_abbrev_to_bos_book_code(usfm_abbreviation: &str) -> Conversion<'_> {
+fn usfm_abbrev_to_bos_book_code_py(usfm_abbreviation: &str) -> PyResult<&'static str> {
+    usfm_abbrev_to_bos_book_code(usfm_abbreviation).map_err(value_error)
 }
 
 /// Converts an OSIS book code to a BibleOrgSys (BOS) reference abbreviation book code.
 #[pyfunction]
-// RUST pub fn osis_book_code_to_bos_book_code(osis_book_code: &str) -> Result<&str, Box<dyn Error>> {
-fn osis_book_code_to_bos_book_code_py(osis_book_code: &str) -> PyResult<String> {
-    // match osis_book_code_to_bos_book_code(osis_book_code) { // From ChatGPT fails
-    //     Ok(abbrev) => Ok(abbrev),
-    //     Err(err_msg) => Err(pyo3::exceptions::PyValueError::new_err(err_msg)),
-    // }
-    Ok(osis_book_code_to_bos_book_code(osis_book_code)
-        .unwrap()
-        .to_string())
+// RUST pub fn osis_book_code_to_bos_book_code(osis_book_code: &str) -> Conversion<'_> {
+fn osis_book_code_to_bos_book_code_py(osis_book_code: &str) -> PyResult<&'static str> {
+    osis_book_code_to_bos_book_code(osis_book_code).map_err(value_error)
 }
 
 // Tries to see if an English book name can be narrowed down to a a reference abbreviation book code.
 #[pyfunction]
-// RUST pub fn english_name_to_bos_book_code(english_name: &str,) -> Option<&'static str> {
-fn english_name_to_bos_book_code_py(english_name: &str) -> PyResult<Option<&'static str>> {
-    Ok(english_name_to_bos_book_code(english_name))
+// RUST pub fn english_name_to_bos_book_code(english_name: &str) -> Conversion<'_> {
+fn english_name_to_bos_book_code_py(english_name: &str) -> PyResult<&'static str> {
+    english_name_to_bos_book_code(english_name).map_err(value_error)
 }
 
 /// A Python module implemented in Rust.